@@ -0,0 +1,182 @@
+use std::fmt::Debug;
+
+use authorization::backend::SpiceDbOpenApi;
+use clap::Parser;
+use error_stack::{Report, Result, ResultExt};
+use graph::store::{
+    DatabaseConnectionInfo, ExpiredRelationships, PostgresStorePool, RelationshipDiscrepancies,
+    StorePool,
+};
+use tokio_postgres::NoTls;
+
+use crate::error::GraphError;
+
+#[derive(Debug, Parser)]
+pub struct ReconcileArgs {
+    /// Creates missing owner relationships and deletes owner relationships to the wrong web,
+    /// dangling, and expired relationships instead of only reporting them.
+    ///
+    /// Relationships of resources which were created since they were compared are kept. The Graph
+    /// should still not be writing data while repairing, otherwise relationships of resources
+    /// which are currently being created may be deleted.
+    #[clap(long)]
+    pub repair: bool,
+}
+
+#[derive(Debug, Parser)]
+pub enum AuthzCommand {
+    /// Compares the relationships in Spice DB with the entities and types in the database and
+    /// finds expired relationships.
+    Reconcile(ReconcileArgs),
+}
+
+#[derive(Debug, Parser)]
+#[clap(version, author, about, long_about = None)]
+pub struct AuthzArgs {
+    #[command(subcommand)]
+    pub command: AuthzCommand,
+
+    #[clap(flatten)]
+    pub db_info: DatabaseConnectionInfo,
+
+    /// The host the Spice DB server is listening at.
+    #[clap(long, env = "HASH_SPICEDB_HOST")]
+    pub spicedb_host: String,
+
+    /// The port the Spice DB server is listening at.
+    #[clap(long, env = "HASH_SPICEDB_HTTP_PORT")]
+    pub spicedb_http_port: u16,
+
+    /// The secret key used to authenticate with the Spice DB server.
+    #[clap(long, env = "HASH_SPICEDB_GRPC_PRESHARED_KEY")]
+    pub spicedb_grpc_preshared_key: Option<String>,
+}
+
+/// Logs the discrepancies and returns how many were found.
+fn report_discrepancies<R: Debug, S: Debug>(
+    kind: &'static str,
+    discrepancies: &RelationshipDiscrepancies<R, S>,
+) -> usize {
+    for (resource, relationship) in &discrepancies.missing {
+        tracing::warn!(
+            kind,
+            ?resource,
+            ?relationship,
+            "Owner relationship is missing"
+        );
+    }
+    for (resource, relationship) in &discrepancies.wrong_owner {
+        tracing::warn!(
+            kind,
+            ?resource,
+            ?relationship,
+            "Owner relationship refers to the wrong web"
+        );
+    }
+    for (resource, relationship) in &discrepancies.dangling {
+        tracing::warn!(
+            kind,
+            ?resource,
+            ?relationship,
+            "Relationship refers to a resource which does not exist"
+        );
+    }
+
+    discrepancies.missing.len() + discrepancies.wrong_owner.len() + discrepancies.dangling.len()
+}
+
+/// Logs the expired relationships and returns how many were found.
+fn report_expired(expired: &ExpiredRelationships) -> usize {
+    for (resource, relationship) in &expired.entities {
+        tracing::info!(
+            kind = "entity",
+            ?resource,
+            ?relationship,
+            "Relationship expired"
+        );
+    }
+    for (resource, relationship) in &expired.webs {
+        tracing::info!(
+            kind = "web",
+            ?resource,
+            ?relationship,
+            "Relationship expired"
+        );
+    }
+    for (resource, relationship) in &expired.account_groups {
+        tracing::info!(
+            kind = "account group",
+            ?resource,
+            ?relationship,
+            "Relationship expired"
+        );
+    }
+
+    expired.len()
+}
+
+pub async fn authz(args: AuthzArgs) -> Result<(), GraphError> {
+    let pool = PostgresStorePool::new(&args.db_info, NoTls)
+        .await
+        .change_context(GraphError)
+        .map_err(|report| {
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Failed to connect to database"
+            );
+            report
+        })?;
+
+    let mut spicedb_client = SpiceDbOpenApi::new(
+        format!("{}:{}", args.spicedb_host, args.spicedb_http_port),
+        args.spicedb_grpc_preshared_key.as_deref(),
+    )
+    .change_context(GraphError)?;
+
+    match args.command {
+        AuthzCommand::Reconcile(args) => {
+            let store = pool
+                .acquire()
+                .await
+                .change_context(GraphError)
+                .map_err(|report| {
+                    tracing::error!(
+                        error = hash_tracing::report_value!(report),
+                        "Failed to acquire database connection"
+                    );
+                    report
+                })?;
+
+            let discrepancies = store
+                .find_authorization_discrepancies(&spicedb_client)
+                .await
+                .change_context(GraphError)
+                .attach_printable("Failed to compare the database with Spice DB")?;
+
+            let num_discrepancies = report_discrepancies("entity", &discrepancies.entities)
+                + report_discrepancies("entity type", &discrepancies.entity_types)
+                + report_discrepancies("property type", &discrepancies.property_types)
+                + report_discrepancies("data type", &discrepancies.data_types)
+                + report_expired(&discrepancies.expired);
+
+            if num_discrepancies == 0 {
+                tracing::info!("Spice DB is consistent with the database");
+            } else if args.repair {
+                store
+                    .repair_authorization_discrepancies(&discrepancies, &mut spicedb_client)
+                    .await
+                    .change_context(GraphError)
+                    .attach_printable("Failed to repair relationships")?;
+
+                tracing::info!(num_discrepancies, "Relationships repaired successfully");
+            } else {
+                return Err(Report::new(GraphError).attach_printable(format!(
+                    "Found {num_discrepancies} discrepancies between Spice DB and the database, \
+                     run with `--repair` to fix them"
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
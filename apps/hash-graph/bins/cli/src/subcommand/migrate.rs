@@ -1,3 +1,4 @@
+use authorization::backend::SpiceDbOpenApi;
 use clap::Parser;
 use error_stack::{Result, ResultExt};
 use graph::store::{DatabaseConnectionInfo, PostgresStorePool, StoreMigration, StorePool};
@@ -10,6 +11,20 @@ use crate::error::GraphError;
 pub struct MigrateArgs {
     #[clap(flatten)]
     pub db_info: DatabaseConnectionInfo,
+
+    /// The host the Spice DB server is listening at.
+    ///
+    /// If set, the authorization schema migrations are applied after the database migrations.
+    #[clap(long, env = "HASH_SPICEDB_HOST")]
+    pub spicedb_host: Option<String>,
+
+    /// The port the Spice DB server is listening at.
+    #[clap(long, env = "HASH_SPICEDB_HTTP_PORT", default_value_t = 8443)]
+    pub spicedb_http_port: u16,
+
+    /// The secret key used to authenticate with the Spice DB server.
+    #[clap(long, env = "HASH_SPICEDB_GRPC_PRESHARED_KEY")]
+    pub spicedb_grpc_preshared_key: Option<String>,
 }
 
 pub async fn migrate(args: MigrateArgs) -> Result<(), GraphError> {
@@ -17,7 +32,10 @@ pub async fn migrate(args: MigrateArgs) -> Result<(), GraphError> {
         .await
         .change_context(GraphError)
         .map_err(|report| {
-            tracing::error!(error = ?report, "Failed to connect to database");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Failed to connect to database"
+            );
             report
         })?;
 
@@ -26,7 +44,10 @@ pub async fn migrate(args: MigrateArgs) -> Result<(), GraphError> {
         .await
         .change_context(GraphError)
         .map_err(|report| {
-            tracing::error!(error = ?report, "Failed to acquire database connection");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Failed to acquire database connection"
+            );
             report
         })?;
 
@@ -35,9 +56,32 @@ pub async fn migrate(args: MigrateArgs) -> Result<(), GraphError> {
         .await
         .change_context(GraphError)
         .map_err(|report| {
-            tracing::error!(error = ?report, "Failed to run migrations");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Failed to run migrations"
+            );
             report
         })?;
 
+    if let Some(spicedb_host) = args.spicedb_host {
+        let mut spicedb_client = SpiceDbOpenApi::new(
+            format!("{spicedb_host}:{}", args.spicedb_http_port),
+            args.spicedb_grpc_preshared_key.as_deref(),
+        )
+        .change_context(GraphError)?;
+
+        connection
+            .run_authorization_migrations(&mut spicedb_client)
+            .await
+            .change_context(GraphError)
+            .map_err(|report| {
+                tracing::error!(
+                    error = hash_tracing::report_value!(report),
+                    "Failed to run authorization schema migrations"
+                );
+                report
+            })?;
+    }
+
     Ok(())
 }
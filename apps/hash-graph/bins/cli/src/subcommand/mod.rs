@@ -1,3 +1,4 @@
+mod authz;
 mod completions;
 mod migrate;
 mod server;
@@ -14,6 +15,7 @@ use hash_tracing::{init_tracing, TracingConfig};
 #[cfg(feature = "test-server")]
 pub use self::test_server::{test_server, TestServerArgs};
 pub use self::{
+    authz::{authz, AuthzArgs},
     completions::{completions, CompletionsArgs},
     migrate::{migrate, MigrateArgs},
     server::{server, ServerArgs},
@@ -35,6 +37,8 @@ pub enum Subcommand {
     Completions(CompletionsArgs),
     /// Snapshot API for the database.
     Snapshot(SnapshotArgs),
+    /// Consistency checks between the database and the authorization backend.
+    Authz(AuthzArgs),
     /// Test server
     #[cfg(feature = "test-server")]
     TestServer(TestServerArgs),
@@ -65,6 +69,7 @@ impl Subcommand {
                 Ok(())
             }
             Self::Snapshot(args) => block_on(snapshot(args), tracing_config),
+            Self::Authz(args) => block_on(authz(args), tracing_config),
             #[cfg(feature = "test-server")]
             Self::TestServer(args) => block_on(test_server(args), tracing_config),
         }
@@ -7,7 +7,8 @@ use std::{
 };
 
 use authorization::{
-    backend::{SpiceDbOpenApi, ZanzibarBackend},
+    backend::{InMemoryBackend, SpiceDbOpenApi, ZanzibarBackend},
+    migration::SchemaMigration,
     zanzibar::ZanzibarClient,
     AuthorizationApi,
 };
@@ -17,12 +18,17 @@ use graph::{
     ontology::domain_validator::DomainValidator,
     store::{DatabaseConnectionInfo, FetchingPool, PostgresStorePool, StorePool},
 };
-use graph_api::rest::{rest_api_router, OpenApiDocumentation, RestRouterDependencies};
+use graph_api::rest::{
+    authentication::{AuthenticationConfig, TokenKey},
+    rest_api_router, OpenApiDocumentation, RestRouterDependencies,
+};
+use graph_types::account::AccountId;
 use regex::Regex;
 use reqwest::{Client, Url};
 use temporal_client::TemporalClientConfig;
 use tokio::{net::TcpListener, time::timeout};
 use tokio_postgres::NoTls;
+use uuid::Uuid;
 
 use crate::{
     error::{GraphError, HealthcheckError},
@@ -57,6 +63,88 @@ impl TryFrom<ApiAddress> for SocketAddr {
     }
 }
 
+#[derive(Debug, Parser)]
+pub struct AuthenticationArgs {
+    /// The keys used to verify signed API tokens, specified as `<key-id>=<secret>`.
+    ///
+    /// Tokens are passed as `Authorization: Bearer <token>` and have to be signed with `HS256`.
+    /// If the token specifies a key ID (`kid`) only the key with that ID is used.
+    #[clap(
+        long = "api-token-key",
+        env = "HASH_GRAPH_API_TOKEN_KEYS",
+        value_delimiter = ',',
+        hide_env_values = true
+    )]
+    pub api_token_keys: Vec<TokenKey>,
+
+    /// Accepts requests which are only authenticated by the `X-Authenticated-User-Actor-Id`
+    /// header.
+    ///
+    /// The header is not verified, so it should only be accepted if the Graph is not reachable
+    /// without going through a trusted gateway.
+    #[clap(
+        long,
+        env = "HASH_GRAPH_TRUST_ACTOR_ID_HEADER",
+        default_value_t = false
+    )]
+    pub trust_actor_id_header: bool,
+
+    /// The accounts which are allowed to administrate the instance, e.g. to create service
+    /// accounts.
+    #[clap(
+        long = "instance-admin",
+        env = "HASH_GRAPH_INSTANCE_ADMINS",
+        value_delimiter = ','
+    )]
+    pub instance_admins: Vec<Uuid>,
+}
+
+impl From<AuthenticationArgs> for AuthenticationConfig {
+    fn from(args: AuthenticationArgs) -> Self {
+        Self {
+            token_keys: args.api_token_keys,
+            trust_actor_id_header: args.trust_actor_id_header,
+            instance_admins: args
+                .instance_admins
+                .into_iter()
+                .map(AccountId::new)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Parser)]
+pub struct AuthorizationArgs {
+    /// Authorizes requests with an in-memory backend instead of Spice DB.
+    ///
+    /// The latest authorization schema is imported on startup and all relationships are lost when
+    /// the server stops, so this is only meant for development and tests on a fresh database.
+    #[clap(
+        long,
+        default_value_t = false,
+        env = "HASH_GRAPH_IN_MEMORY_AUTHORIZATION"
+    )]
+    pub in_memory_authorization: bool,
+
+    /// The host the Spice DB server is listening at.
+    ///
+    /// Required unless `--in-memory-authorization` is passed.
+    #[clap(
+        long,
+        env = "HASH_SPICEDB_HOST",
+        required_unless_present = "in_memory_authorization"
+    )]
+    pub spicedb_host: Option<String>,
+
+    /// The port the Spice DB server is listening at.
+    #[clap(long, env = "HASH_SPICEDB_HTTP_PORT", default_value_t = 8443)]
+    pub spicedb_http_port: u16,
+
+    /// The secret key used to authenticate with the Spice DB server.
+    #[clap(long, env = "HASH_SPICEDB_GRPC_PRESHARED_KEY")]
+    pub spicedb_grpc_preshared_key: Option<String>,
+}
+
 #[derive(Debug, Parser)]
 pub struct ServerArgs {
     #[clap(flatten)]
@@ -102,17 +190,8 @@ pub struct ServerArgs {
     #[clap(long, default_value_t = false)]
     pub offline: bool,
 
-    /// The host the Spice DB server is listening at.
-    #[clap(long, env = "HASH_SPICEDB_HOST")]
-    pub spicedb_host: String,
-
-    /// The port the Spice DB server is listening at.
-    #[clap(long, env = "HASH_SPICEDB_HTTP_PORT", default_value_t = 8443)]
-    pub spicedb_http_port: u16,
-
-    /// The secret key used to authenticate with the Spice DB server.
-    #[clap(long, env = "HASH_SPICEDB_GRPC_PRESHARED_KEY")]
-    pub spicedb_grpc_preshared_key: Option<String>,
+    #[clap(flatten)]
+    pub authorization: AuthorizationArgs,
 
     /// The URL of the Temporal server.
     ///
@@ -123,6 +202,9 @@ pub struct ServerArgs {
     /// The URL of the Temporal server.
     #[clap(long, env = "HASH_TEMPORAL_SERVER_PORT", default_value_t = 7233)]
     pub temporal_port: u16,
+
+    #[clap(flatten)]
+    pub authentication: AuthenticationArgs,
 }
 
 pub async fn server(args: ServerArgs) -> Result<(), GraphError> {
@@ -162,15 +244,55 @@ pub async fn server(args: ServerArgs) -> Result<(), GraphError> {
         .await
         .change_context(GraphError)
         .map_err(|report| {
-            tracing::error!(error = ?report, "Failed to connect to database");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Failed to connect to database"
+            );
             report
         })?;
-    _ = pool
-        .acquire()
+
+    if args.authorization.in_memory_authorization {
+        tracing::warn!(
+            "Using the in-memory authorization backend, relationships are not persisted"
+        );
+        let mut in_memory_backend = InMemoryBackend::new();
+        in_memory_backend
+            .import_schema(SchemaMigration::latest().schema())
+            .await
+            .change_context(GraphError)
+            .attach_printable("could not import the authorization schema")?;
+        return serve(args, pool, in_memory_backend).await;
+    }
+
+    let Some(spicedb_host) = &args.authorization.spicedb_host else {
+        return Err(Report::new(GraphError).attach_printable("the Spice DB host is not set"));
+    };
+    let spicedb_client = SpiceDbOpenApi::new(
+        format!("{spicedb_host}:{}", args.authorization.spicedb_http_port),
+        args.authorization.spicedb_grpc_preshared_key.as_deref(),
+    )
+    .change_context(GraphError)?;
+    pool.acquire()
         .await
         .change_context(GraphError)
-        .attach_printable("Connection to database failed")?;
+        .attach_printable("Connection to database failed")?
+        .check_authorization_schema(&spicedb_client)
+        .await
+        .change_context(GraphError)
+        .attach_printable("The authorization schema is not up to date")?;
+
+    serve(args, pool, spicedb_client).await
+}
 
+/// Serves the REST API using `authorization_backend` to authorize requests.
+async fn serve<B>(
+    args: ServerArgs,
+    pool: PostgresStorePool<NoTls>,
+    authorization_backend: B,
+) -> Result<(), GraphError>
+where
+    B: ZanzibarBackend + Clone + Send + Sync + 'static,
+{
     let pool = if args.offline {
         FetchingPool::new_offline(pool)
     } else {
@@ -184,25 +306,14 @@ pub async fn server(args: ServerArgs) -> Result<(), GraphError> {
         )
     };
 
-    let mut spicedb_client = SpiceDbOpenApi::new(
-        format!("{}:{}", args.spicedb_host, args.spicedb_http_port),
-        args.spicedb_grpc_preshared_key.as_deref(),
-    )
-    .change_context(GraphError)?;
-    spicedb_client
-        .import_schema(include_str!(
-            "../../../../../../libs/@local/hash-authorization/schemas/v1__initial_schema.zed"
-        ))
-        .await
-        .change_context(GraphError)?;
-
-    let mut zanzibar_client = ZanzibarClient::new(spicedb_client);
+    let mut zanzibar_client = ZanzibarClient::new(authorization_backend);
     zanzibar_client.seed().await.change_context(GraphError)?;
 
     let router = rest_api_router(RestRouterDependencies {
         store: Arc::new(pool),
         authorization_api: Arc::new(zanzibar_client),
         domain_regex: DomainValidator::new(args.allowed_url_domain),
+        authentication: AuthenticationConfig::from(args.authentication),
         temporal_client: if let Some(host) = args.temporal_host {
             Some(
                 TemporalClientConfig::new(
@@ -1,15 +1,11 @@
 use std::{net::SocketAddr, time::Duration};
 
-use authorization::{
-    backend::{SpiceDbOpenApi, ZanzibarBackend},
-    zanzibar::ZanzibarClient,
-    AuthorizationApi,
-};
+use authorization::{backend::SpiceDbOpenApi, zanzibar::ZanzibarClient, AuthorizationApi};
 use clap::Parser;
 use error_stack::{Result, ResultExt};
 use graph::{
     snapshot::SnapshotEntry,
-    store::{DatabaseConnectionInfo, PostgresStorePool},
+    store::{DatabaseConnectionInfo, PostgresStorePool, StorePool},
 };
 use reqwest::Client;
 use tokio::{net::TcpListener, time::timeout};
@@ -60,7 +56,10 @@ pub async fn test_server(args: TestServerArgs) -> Result<(), GraphError> {
         .await
         .change_context(GraphError)
         .map_err(|report| {
-            tracing::error!(error = ?report, "Failed to connect to database");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Failed to connect to database"
+            );
             report
         })?;
 
@@ -69,12 +68,20 @@ pub async fn test_server(args: TestServerArgs) -> Result<(), GraphError> {
         args.spicedb_grpc_preshared_key.as_deref(),
     )
     .change_context(GraphError)?;
-    spicedb_client
-        .import_schema(include_str!(
-            "../../../../../../libs/@local/hash-authorization/schemas/v1__initial_schema.zed"
-        ))
+    pool.acquire()
+        .await
+        .change_context(GraphError)
+        .map_err(|report| {
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Failed to acquire database connection"
+            );
+            report
+        })?
+        .run_authorization_migrations(&mut spicedb_client)
         .await
-        .change_context(GraphError)?;
+        .change_context(GraphError)
+        .attach_printable("Failed to migrate the authorization schema")?;
 
     let mut zanzibar_client = ZanzibarClient::new(spicedb_client);
     zanzibar_client.seed().await.change_context(GraphError)?;
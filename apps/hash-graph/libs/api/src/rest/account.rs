@@ -5,7 +5,7 @@
 use std::sync::Arc;
 
 use authorization::{
-    backend::ModifyRelationshipOperation,
+    backend::{ModifyRelationshipOperation, PermissionAssertion},
     schema::{
         AccountGroupMemberSubject, AccountGroupPermission, AccountGroupRelationAndSubject,
         WebOwnerSubject,
@@ -17,22 +17,32 @@ use axum::{
     extract::Path,
     http::StatusCode,
     response::Response,
-    routing::{get, post},
+    routing::{delete, get, post},
     Extension, Router,
 };
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use error_stack::Report;
 use graph::store::{
-    account::{InsertAccountGroupIdParams, InsertAccountIdParams},
+    account::{
+        InsertAccountGroupIdParams, InsertAccountIdParams, InsertServiceAccountParams,
+        ServiceAccountScope,
+    },
     AccountStore, StorePool,
 };
 use graph_types::{
     account::{AccountGroupId, AccountId},
     owned_by_id::OwnedById,
 };
-use utoipa::OpenApi;
+use rand::RngCore;
+use serde::Serialize;
+use utoipa::{OpenApi, ToSchema};
 
 use super::api_resource::RoutedResource;
 use crate::rest::{
-    json::Json, status::report_to_response, AuthenticatedUserHeader, PermissionResponse,
+    authentication::{hash_service_account_token, Authenticator, SERVICE_ACCOUNT_TOKEN_PREFIX},
+    json::Json,
+    status::report_to_response,
+    AuthenticatedUserHeader, PermissionResponse,
 };
 
 #[derive(OpenApi)]
@@ -40,6 +50,8 @@ use crate::rest::{
     paths(
         create_account,
         create_account_group,
+        create_service_account,
+        revoke_service_account,
 
         check_account_group_permission,
         add_account_group_member,
@@ -53,6 +65,9 @@ use crate::rest::{
 
             InsertAccountIdParams,
             InsertAccountGroupIdParams,
+            InsertServiceAccountParams,
+            ServiceAccountScope,
+            CreatedServiceAccount,
         ),
     ),
     tags(
@@ -71,6 +86,12 @@ impl RoutedResource for AccountResource {
         // TODO: The URL format here is preliminary and will have to change.
         Router::new()
             .route("/accounts", post(create_account::<S, A>))
+            .nest(
+                "/service_accounts",
+                Router::new()
+                    .route("/", post(create_service_account::<S>))
+                    .route("/:account_id", delete(revoke_service_account::<S>)),
+            )
             .nest(
                 "/account_groups",
                 Router::new()
@@ -133,6 +154,112 @@ where
     Ok(Json(account_id))
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct CreatedServiceAccount {
+    account_id: AccountId,
+    /// The token used to authenticate as the service account.
+    ///
+    /// The token is only returned once and cannot be retrieved later.
+    token: String,
+}
+
+#[utoipa::path(
+    post,
+    path = "/service_accounts",
+    tag = "Account",
+    request_body = InsertServiceAccountParams,
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The created service account and its token", body = CreatedServiceAccount),
+
+        (status = 403, description = "The actor is not an instance admin"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authenticator))]
+async fn create_service_account<S>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    authenticator: Extension<Arc<Authenticator>>,
+    store_pool: Extension<Arc<S>>,
+    Json(params): Json<InsertServiceAccountParams>,
+) -> Result<Json<CreatedServiceAccount>, Response>
+where
+    S: StorePool + Send + Sync,
+{
+    if !authenticator.is_instance_admin(actor_id) {
+        tracing::error!("Only instance admins are allowed to create service accounts");
+        return Err(report_to_response(
+            Report::new(PermissionAssertion).attach(hash_status::StatusCode::PermissionDenied),
+        ));
+    }
+
+    let mut store = store_pool.acquire().await.map_err(report_to_response)?;
+
+    let mut secret = [0_u8; 32];
+    rand::thread_rng().fill_bytes(&mut secret);
+    let token = format!(
+        "{SERVICE_ACCOUNT_TOKEN_PREFIX}{}",
+        URL_SAFE_NO_PAD.encode(secret)
+    );
+
+    let account_id = params.account_id;
+    store
+        .insert_service_account(actor_id, params, &hash_service_account_token(&token))
+        .await
+        .map_err(report_to_response)?;
+
+    Ok(Json(CreatedServiceAccount { account_id, token }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/service_accounts/{account_id}",
+    tag = "Account",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("account_id" = AccountId, Path, description = "The ID of the service account to revoke"),
+    ),
+    responses(
+        (status = 204, description = "The token of the service account was revoked"),
+
+        (status = 403, description = "The actor is not an instance admin"),
+        (status = 404, description = "The service account does not exist"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authenticator))]
+async fn revoke_service_account<S>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    Path(account_id): Path<AccountId>,
+    authenticator: Extension<Arc<Authenticator>>,
+    store_pool: Extension<Arc<S>>,
+) -> Result<StatusCode, Response>
+where
+    S: StorePool + Send + Sync,
+{
+    // Any instance admin may revoke a service account, e.g. if the token of a service account
+    // leaked and its creator is not available anymore.
+    if !authenticator.is_instance_admin(actor_id) {
+        tracing::error!("Only instance admins are allowed to revoke service accounts");
+        return Err(report_to_response(
+            Report::new(PermissionAssertion).attach(hash_status::StatusCode::PermissionDenied),
+        ));
+    }
+
+    let mut store = store_pool.acquire().await.map_err(report_to_response)?;
+
+    store
+        .revoke_service_account(account_id)
+        .await
+        .map_err(report_to_response)?;
+    authenticator.forget_service_account(account_id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[utoipa::path(
     post,
     path = "/account_groups",
@@ -159,7 +286,10 @@ where
     A: AuthorizationApiPool + Send + Sync,
 {
     let mut store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
@@ -167,7 +297,10 @@ where
         .identify_owned_by_id(OwnedById::from(actor_id))
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not identify account");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not identify account"
+            );
             StatusCode::INTERNAL_SERVER_ERROR
         })?;
     if account != (WebOwnerSubject::Account { id: actor_id }) {
@@ -185,7 +318,10 @@ where
         .insert_account_group_id(actor_id, &mut authorization_api, params)
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not create account id");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not create account id"
+            );
 
             // Insertion/update errors are considered internal server errors.
             StatusCode::INTERNAL_SERVER_ERROR
@@ -303,6 +439,7 @@ where
             AccountGroupRelationAndSubject::Member {
                 subject: AccountGroupMemberSubject::Account { id: account_id },
                 level: 0,
+                expires_at: None,
             },
         )])
         .await
@@ -372,6 +509,7 @@ where
             AccountGroupRelationAndSubject::Member {
                 subject: AccountGroupMemberSubject::Account { id: account_id },
                 level: 0,
+                expires_at: None,
             },
         )])
         .await
@@ -0,0 +1,781 @@
+//! Authentication of requests to the REST API.
+//!
+//! Requests are authenticated by one of the following methods:
+//!
+//! - A signed token passed as `Authorization: Bearer <token>`. The token is a JWT signed with
+//!   `HS256` by one of the configured [`TokenKey`]s, the `sub` claim contains the [`AccountId`] of
+//!   the actor and the `exp` claim is required.
+//! - A service account token passed as `Authorization: Bearer hash_sa_<token>`. The token is looked
+//!   up in the store and the request is only accepted if the scope of the service account allows
+//!   it. Verified tokens are cached for [`SERVICE_ACCOUNT_CACHE_DURATION`].
+//! - The `X-Authenticated-User-Actor-Id` header, if [`AuthenticationConfig::trust_actor_id_header`]
+//!   is enabled. The header is not verified in any way, so this is disabled by default and must
+//!   only be enabled if the Graph is not reachable without going through a trusted gateway.
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    fmt,
+    str::FromStr,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use axum::http::{header::AUTHORIZATION, request::Parts, Method, StatusCode};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use error_stack::{Report, ResultExt};
+use futures::{future::BoxFuture, FutureExt};
+use graph::store::{
+    account::{ServiceAccount, ServiceAccountScope},
+    AccountStore, QueryError, StorePool,
+};
+use graph_types::account::AccountId;
+use hmac::{Hmac, Mac};
+use serde::{de::DeserializeOwned, Deserialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// The prefix of tokens issued to service accounts.
+pub const SERVICE_ACCOUNT_TOKEN_PREFIX: &str = "hash_sa_";
+
+/// Returns the hash of a service account token as it's stored in the database.
+#[must_use]
+pub fn hash_service_account_token(token: &str) -> Vec<u8> {
+    Sha256::digest(token.as_bytes()).to_vec()
+}
+
+/// A key used to verify signed tokens.
+///
+/// Parsed from `<key-id>=<secret>`.
+#[derive(Clone)]
+pub struct TokenKey {
+    id: String,
+    secret: Vec<u8>,
+}
+
+impl TokenKey {
+    #[must_use]
+    pub fn new(id: impl Into<String>, secret: impl Into<Vec<u8>>) -> Self {
+        Self {
+            id: id.into(),
+            secret: secret.into(),
+        }
+    }
+}
+
+impl fmt::Debug for TokenKey {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("TokenKey")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseTokenKeyError;
+
+impl fmt::Display for ParseTokenKeyError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("token key has to be specified as `<key-id>=<secret>`")
+    }
+}
+
+impl std::error::Error for ParseTokenKeyError {}
+
+impl FromStr for TokenKey {
+    type Err = ParseTokenKeyError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.split_once('=') {
+            Some((id, secret)) if !id.is_empty() && !secret.is_empty() => Ok(Self::new(id, secret)),
+            _ => Err(ParseTokenKeyError),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AuthenticationConfig {
+    /// The keys used to verify signed tokens. If empty, signed tokens are rejected.
+    pub token_keys: Vec<TokenKey>,
+    /// Accept the unverified `X-Authenticated-User-Actor-Id` header.
+    pub trust_actor_id_header: bool,
+    /// The accounts which are allowed to administrate the instance, e.g. to create service
+    /// accounts.
+    pub instance_admins: Vec<AccountId>,
+}
+
+type Rejection = (StatusCode, Cow<'static, str>);
+
+#[derive(Deserialize)]
+struct TokenHeader {
+    alg: String,
+    #[serde(default)]
+    kid: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TokenClaims {
+    sub: AccountId,
+    #[serde(default)]
+    exp: Option<u64>,
+    #[serde(default)]
+    nbf: Option<u64>,
+}
+
+const fn malformed_token() -> Rejection {
+    (StatusCode::UNAUTHORIZED, Cow::Borrowed("Malformed token"))
+}
+
+fn decode_segment<T: DeserializeOwned>(segment: &str) -> Result<T, Rejection> {
+    URL_SAFE_NO_PAD
+        .decode(segment)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+        .ok_or_else(malformed_token)
+}
+
+impl AuthenticationConfig {
+    /// Verifies a signed token and returns the actor it was issued for.
+    ///
+    /// `now` is the number of seconds since the Unix epoch and is used to validate the `exp` and
+    /// `nbf` claims. Tokens without an `exp` claim are rejected, so a leaked token cannot be used
+    /// indefinitely.
+    fn verify_signed_token(&self, token: &str, now: u64) -> Result<AccountId, Rejection> {
+        let (signed_content, signature) = token.rsplit_once('.').ok_or_else(malformed_token)?;
+        let (header, claims) = signed_content.split_once('.').ok_or_else(malformed_token)?;
+
+        let token_header: TokenHeader = decode_segment(header)?;
+        if token_header.alg != "HS256" {
+            return Err((
+                StatusCode::UNAUTHORIZED,
+                Cow::Borrowed("Unsupported token algorithm"),
+            ));
+        }
+
+        let signature = URL_SAFE_NO_PAD
+            .decode(signature)
+            .ok()
+            .ok_or_else(malformed_token)?;
+
+        let is_verified = self
+            .token_keys
+            .iter()
+            .filter(|key| token_header.kid.as_ref().map_or(true, |kid| *kid == key.id))
+            .any(|key| {
+                let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(&key.secret) else {
+                    return false;
+                };
+                mac.update(signed_content.as_bytes());
+                mac.verify_slice(&signature).is_ok()
+            });
+        if !is_verified {
+            return Err((
+                StatusCode::UNAUTHORIZED,
+                Cow::Borrowed("Invalid token signature"),
+            ));
+        }
+
+        let claims: TokenClaims = decode_segment(claims)?;
+        let Some(exp) = claims.exp else {
+            return Err((
+                StatusCode::UNAUTHORIZED,
+                Cow::Borrowed("Token has no expiration"),
+            ));
+        };
+        if now >= exp {
+            return Err((StatusCode::UNAUTHORIZED, Cow::Borrowed("Token has expired")));
+        }
+        if claims.nbf.is_some_and(|nbf| now < nbf) {
+            return Err((
+                StatusCode::UNAUTHORIZED,
+                Cow::Borrowed("Token is not valid yet"),
+            ));
+        }
+
+        Ok(claims.sub)
+    }
+}
+
+/// The `POST` routes which don't modify the graph.
+///
+/// Routes have to be listed explicitly, so a new route is not accessible by service accounts with
+/// the [`ServiceAccountScope::Read`] scope unless it's added here.
+const READ_ONLY_POST_ROUTES: &[&str] = &[
+    "/data-types/query",
+    "/property-types/query",
+    "/entity-types/query",
+    "/entities/query",
+    "/entities/validate",
+];
+
+/// Returns if a service account with the given `scope` is allowed to perform the request.
+fn scope_allows(scope: ServiceAccountScope, parts: &Parts) -> bool {
+    match scope {
+        ServiceAccountScope::Write => true,
+        ServiceAccountScope::Read => {
+            parts.method == Method::GET
+                || parts.method == Method::HEAD
+                || (parts.method == Method::POST
+                    && READ_ONLY_POST_ROUTES.contains(&parts.uri.path()))
+        }
+    }
+}
+
+type ServiceAccountLookup = Box<
+    dyn Fn(Vec<u8>) -> BoxFuture<'static, Result<Option<ServiceAccount>, Report<QueryError>>>
+        + Send
+        + Sync,
+>;
+
+/// How long a verified service account token is accepted without looking it up again.
+///
+/// Tokens revoked through this instance are rejected immediately, tokens revoked through another
+/// instance are rejected at the latest after this duration.
+pub const SERVICE_ACCOUNT_CACHE_DURATION: Duration = Duration::from_secs(30);
+
+/// Authenticates requests according to the [`AuthenticationConfig`].
+///
+/// The authenticator is added as an extension to the router and used by
+/// [`AuthenticatedUserHeader`].
+///
+/// [`AuthenticatedUserHeader`]: crate::rest::AuthenticatedUserHeader
+pub(crate) struct Authenticator {
+    config: AuthenticationConfig,
+    service_accounts: ServiceAccountLookup,
+    /// Verified service accounts by the hash of their token and the time they were looked up.
+    verified_service_accounts: Mutex<HashMap<Vec<u8>, (ServiceAccount, Instant)>>,
+}
+
+impl Authenticator {
+    pub(crate) fn new<S>(config: AuthenticationConfig, store_pool: Arc<S>) -> Self
+    where
+        S: StorePool + Send + Sync + 'static,
+    {
+        Self::with_lookup(
+            config,
+            Box::new(move |token_hash| {
+                let store_pool = Arc::clone(&store_pool);
+                async move {
+                    let store = store_pool.acquire().await.change_context(QueryError)?;
+                    store.get_service_account(&token_hash).await
+                }
+                .boxed()
+            }),
+        )
+    }
+
+    fn with_lookup(config: AuthenticationConfig, service_accounts: ServiceAccountLookup) -> Self {
+        Self {
+            config,
+            service_accounts,
+            verified_service_accounts: Mutex::default(),
+        }
+    }
+
+    /// Returns the service account authenticating with a token hashing to `token_hash`.
+    ///
+    /// The store is only queried if the token has not been verified within the last
+    /// [`SERVICE_ACCOUNT_CACHE_DURATION`].
+    async fn service_account(
+        &self,
+        token_hash: Vec<u8>,
+    ) -> Result<Option<ServiceAccount>, Report<QueryError>> {
+        let now = Instant::now();
+        let is_valid =
+            |verified_at: Instant| now.duration_since(verified_at) < SERVICE_ACCOUNT_CACHE_DURATION;
+
+        let cached = self
+            .verified_service_accounts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&token_hash)
+            .copied();
+        if let Some((service_account, verified_at)) = cached {
+            if is_valid(verified_at) {
+                return Ok(Some(service_account));
+            }
+        }
+
+        let service_account = (self.service_accounts)(token_hash.clone()).await?;
+
+        let mut verified_service_accounts = self
+            .verified_service_accounts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        verified_service_accounts.retain(|_, (_, verified_at)| is_valid(*verified_at));
+        if let Some(service_account) = service_account {
+            verified_service_accounts.insert(token_hash, (service_account, now));
+        }
+        drop(verified_service_accounts);
+
+        Ok(service_account)
+    }
+
+    /// Removes the service account from the cache of verified tokens, e.g. after it was revoked.
+    pub(crate) fn forget_service_account(&self, account_id: AccountId) {
+        self.verified_service_accounts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .retain(|_, (service_account, _)| service_account.account_id != account_id);
+    }
+
+    /// Returns if the actor is allowed to administrate the instance.
+    pub(crate) fn is_instance_admin(&self, actor_id: AccountId) -> bool {
+        self.config.instance_admins.contains(&actor_id)
+    }
+
+    pub(crate) async fn authenticate(&self, parts: &Parts) -> Result<AccountId, Rejection> {
+        if let Some(header_value) = parts.headers.get(AUTHORIZATION) {
+            let token = header_value
+                .to_str()
+                .ok()
+                .and_then(|value| value.strip_prefix("Bearer "))
+                .ok_or((
+                    StatusCode::UNAUTHORIZED,
+                    Cow::Borrowed("`Authorization` header has to contain a bearer token"),
+                ))?;
+
+            if token.starts_with(SERVICE_ACCOUNT_TOKEN_PREFIX) {
+                let service_account = self
+                    .service_account(hash_service_account_token(token))
+                    .await
+                    .map_err(|report| {
+                        tracing::error!(
+                            error = hash_tracing::report_value!(report),
+                            "Could not read service account"
+                        );
+                        (
+                            StatusCode::INTERNAL_SERVER_ERROR,
+                            Cow::Borrowed("Could not read service account"),
+                        )
+                    })?
+                    .ok_or((
+                        StatusCode::UNAUTHORIZED,
+                        Cow::Borrowed("Unknown service account token"),
+                    ))?;
+
+                if scope_allows(service_account.scope, parts) {
+                    Ok(service_account.account_id)
+                } else {
+                    Err((
+                        StatusCode::FORBIDDEN,
+                        Cow::Borrowed(
+                            "The scope of the service account does not allow this request",
+                        ),
+                    ))
+                }
+            } else {
+                let now = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |duration| duration.as_secs());
+                self.config.verify_signed_token(token, now)
+            }
+        } else if self.config.trust_actor_id_header {
+            let header_value = parts.headers.get("X-Authenticated-User-Actor-Id").ok_or((
+                StatusCode::BAD_REQUEST,
+                Cow::Borrowed("`X-Authenticated-User-Actor-Id` header is missing"),
+            ))?;
+            let header_string = header_value
+                .to_str()
+                .map_err(|error| (StatusCode::BAD_REQUEST, Cow::Owned(error.to_string())))?;
+            let uuid = Uuid::from_str(header_string)
+                .map_err(|error| (StatusCode::BAD_REQUEST, Cow::Owned(error.to_string())))?;
+            Ok(AccountId::new(uuid))
+        } else {
+            Err((
+                StatusCode::UNAUTHORIZED,
+                Cow::Borrowed("`Authorization` header is missing"),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    const ACTOR: &str = "d5e2e7a4-4b0a-4e0c-9d6e-5b6d8a3d6c01";
+
+    fn sign(key: &[u8], header: &str, claims: &str) -> String {
+        let content = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(header),
+            URL_SAFE_NO_PAD.encode(claims)
+        );
+        let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+        mac.update(content.as_bytes());
+        format!(
+            "{content}.{}",
+            URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes())
+        )
+    }
+
+    fn config() -> AuthenticationConfig {
+        AuthenticationConfig {
+            token_keys: vec![
+                TokenKey::new("first", "first-secret"),
+                TokenKey::new("second", "second-secret"),
+            ],
+            ..AuthenticationConfig::default()
+        }
+    }
+
+    const READ_TOKEN: &str = "hash_sa_read";
+    const WRITE_TOKEN: &str = "hash_sa_write";
+
+    fn actor() -> AccountId {
+        AccountId::new(Uuid::from_str(ACTOR).expect("invalid UUID"))
+    }
+
+    fn authenticator(config: AuthenticationConfig) -> Authenticator {
+        Authenticator::with_lookup(
+            config,
+            Box::new(|token_hash: Vec<u8>| {
+                let scope = if token_hash == hash_service_account_token(READ_TOKEN) {
+                    Some(ServiceAccountScope::Read)
+                } else if token_hash == hash_service_account_token(WRITE_TOKEN) {
+                    Some(ServiceAccountScope::Write)
+                } else {
+                    None
+                };
+                async move {
+                    Ok::<_, Report<QueryError>>(scope.map(|scope| ServiceAccount {
+                        account_id: actor(),
+                        scope,
+                    }))
+                }
+                .boxed()
+            }),
+        )
+    }
+
+    fn request(method: Method, path: &str, headers: &[(&str, &str)]) -> Parts {
+        let mut builder = axum::http::Request::builder().method(method).uri(path);
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        builder
+            .body(())
+            .expect("request should be valid")
+            .into_parts()
+            .0
+    }
+
+    #[test]
+    fn parse_token_key() {
+        let key = TokenKey::from_str("key=a=b").expect("should parse token key");
+        assert_eq!(key.id, "key");
+        assert_eq!(key.secret, b"a=b");
+
+        TokenKey::from_str("key").expect_err("secret is missing");
+        TokenKey::from_str("=secret").expect_err("key id is missing");
+    }
+
+    #[test]
+    fn verify_token() {
+        let claims = format!(r#"{{"sub":"{ACTOR}","exp":200}}"#);
+        let actor = actor();
+
+        let token = sign(
+            b"second-secret",
+            r#"{"alg":"HS256","kid":"second"}"#,
+            &claims,
+        );
+        assert_eq!(
+            config()
+                .verify_signed_token(&token, 100)
+                .expect("token should be valid"),
+            actor
+        );
+
+        let token = sign(b"first-secret", r#"{"alg":"HS256"}"#, &claims);
+        assert_eq!(
+            config()
+                .verify_signed_token(&token, 100)
+                .expect("token without key id should be valid"),
+            actor
+        );
+    }
+
+    #[test]
+    fn reject_invalid_token() {
+        let claims = format!(r#"{{"sub":"{ACTOR}","exp":200,"nbf":50}}"#);
+
+        let token = sign(
+            b"first-secret",
+            r#"{"alg":"HS256","kid":"second"}"#,
+            &claims,
+        );
+        config()
+            .verify_signed_token(&token, 100)
+            .expect_err("token was signed with a different key");
+
+        let token = sign(b"unknown-secret", r#"{"alg":"HS256"}"#, &claims);
+        config()
+            .verify_signed_token(&token, 100)
+            .expect_err("token was signed with an unknown key");
+
+        let token = sign(b"first-secret", r#"{"alg":"none"}"#, &claims);
+        config()
+            .verify_signed_token(&token, 100)
+            .expect_err("algorithm is not supported");
+
+        let token = sign(b"first-secret", r#"{"alg":"HS256"}"#, &claims);
+        config()
+            .verify_signed_token(&token, 200)
+            .expect_err("token has expired");
+        config()
+            .verify_signed_token(&token, 10)
+            .expect_err("token is not valid yet");
+
+        let (content, _) = token.rsplit_once('.').expect("token should be signed");
+        config()
+            .verify_signed_token(&format!("{content}.invalid"), 100)
+            .expect_err("signature is invalid");
+    }
+
+    #[test]
+    fn reject_malformed_token() {
+        for token in [
+            "",
+            "no-dots",
+            "header.claims",
+            "not-base64!.claims.signature",
+            "e30.e30.signature",
+        ] {
+            let (status, _) = config()
+                .verify_signed_token(token, 100)
+                .expect_err("token is malformed");
+            assert_eq!(status, StatusCode::UNAUTHORIZED, "{token}");
+        }
+    }
+
+    #[test]
+    fn reject_token_without_expiry() {
+        let token = sign(
+            b"first-secret",
+            r#"{"alg":"HS256"}"#,
+            &format!(r#"{{"sub":"{ACTOR}","nbf":50}}"#),
+        );
+
+        let (status, message) = config()
+            .verify_signed_token(&token, 100)
+            .expect_err("token without `exp` is rejected");
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(message, "Token has no expiration");
+    }
+
+    #[test]
+    fn expiry_is_exclusive() {
+        let token = sign(
+            b"first-secret",
+            r#"{"alg":"HS256"}"#,
+            &format!(r#"{{"sub":"{ACTOR}","exp":200,"nbf":50}}"#),
+        );
+
+        config()
+            .verify_signed_token(&token, 50)
+            .expect("token is valid from `nbf`");
+        config()
+            .verify_signed_token(&token, 199)
+            .expect("token is valid until `exp`");
+        let (status, message) = config()
+            .verify_signed_token(&token, 200)
+            .expect_err("token is not valid at `exp`");
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(message, "Token has expired");
+    }
+
+    #[test]
+    fn read_scope() {
+        let allows = |method: Method, path: &str| {
+            scope_allows(ServiceAccountScope::Read, &request(method, path, &[]))
+        };
+
+        assert!(allows(Method::GET, "/webs/permissions/view"));
+        assert!(allows(Method::HEAD, "/entities/query"));
+        for route in READ_ONLY_POST_ROUTES {
+            assert!(allows(Method::POST, route), "{route}");
+        }
+
+        assert!(!allows(Method::POST, "/entities"));
+        assert!(!allows(Method::PUT, "/entities"));
+        assert!(!allows(Method::POST, "/service_accounts/query"));
+        assert!(!allows(Method::POST, "/entities/query/"));
+        assert!(!allows(Method::PUT, "/entities/query"));
+    }
+
+    #[test]
+    fn write_scope() {
+        for method in [Method::GET, Method::POST, Method::PUT, Method::DELETE] {
+            assert!(scope_allows(
+                ServiceAccountScope::Write,
+                &request(method, "/entities", &[])
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn authenticate_signed_token() {
+        let authenticator = authenticator(config());
+
+        let token = sign(
+            b"first-secret",
+            r#"{"alg":"HS256"}"#,
+            &format!(r#"{{"sub":"{ACTOR}","exp":{}}}"#, u64::MAX),
+        );
+        let parts = request(
+            Method::POST,
+            "/entities",
+            &[("Authorization", format!("Bearer {token}").as_str())],
+        );
+        assert_eq!(
+            authenticator
+                .authenticate(&parts)
+                .await
+                .expect("token should be valid"),
+            actor()
+        );
+
+        let token = sign(
+            b"first-secret",
+            r#"{"alg":"HS256"}"#,
+            &format!(r#"{{"sub":"{ACTOR}","exp":1}}"#),
+        );
+        let parts = request(
+            Method::POST,
+            "/entities",
+            &[("Authorization", format!("Bearer {token}").as_str())],
+        );
+        let (status, _) = authenticator
+            .authenticate(&parts)
+            .await
+            .expect_err("token has expired");
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let parts = request(
+            Method::POST,
+            "/entities",
+            &[("Authorization", format!("Basic {token}").as_str())],
+        );
+        let (status, _) = authenticator
+            .authenticate(&parts)
+            .await
+            .expect_err("only bearer tokens are supported");
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn authenticate_service_account() {
+        let authenticator = &authenticator(config());
+        let authenticate = |method: Method, path: &'static str, token: &str| {
+            let parts = request(
+                method,
+                path,
+                &[("Authorization", format!("Bearer {token}").as_str())],
+            );
+            async move { authenticator.authenticate(&parts).await }
+        };
+
+        assert_eq!(
+            authenticate(Method::POST, "/entities/query", READ_TOKEN)
+                .await
+                .expect("read scope should allow queries"),
+            actor()
+        );
+        assert_eq!(
+            authenticate(Method::POST, "/entities", WRITE_TOKEN)
+                .await
+                .expect("write scope should allow modifications"),
+            actor()
+        );
+
+        let (status, _) = authenticate(Method::POST, "/entities", READ_TOKEN)
+            .await
+            .expect_err("read scope should not allow modifications");
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = authenticate(Method::GET, "/entities", "hash_sa_unknown")
+            .await
+            .expect_err("token is unknown");
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn cache_verified_service_accounts() {
+        let lookups = Arc::new(AtomicUsize::new(0));
+        let authenticator = Authenticator::with_lookup(config(), {
+            let lookups = Arc::clone(&lookups);
+            Box::new(move |_: Vec<u8>| {
+                lookups.fetch_add(1, Ordering::Relaxed);
+                async move {
+                    Ok::<_, Report<QueryError>>(Some(ServiceAccount {
+                        account_id: actor(),
+                        scope: ServiceAccountScope::Read,
+                    }))
+                }
+                .boxed()
+            })
+        });
+        let parts = request(
+            Method::GET,
+            "/entities",
+            &[("Authorization", format!("Bearer {READ_TOKEN}").as_str())],
+        );
+
+        for _ in 0..3 {
+            authenticator
+                .authenticate(&parts)
+                .await
+                .expect("service account should be authenticated");
+        }
+        assert_eq!(lookups.load(Ordering::Relaxed), 1);
+
+        authenticator.forget_service_account(actor());
+        authenticator
+            .authenticate(&parts)
+            .await
+            .expect("service account should be authenticated");
+        assert_eq!(lookups.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn authenticate_actor_id_header() {
+        let parts = request(
+            Method::POST,
+            "/entities",
+            &[("X-Authenticated-User-Actor-Id", ACTOR)],
+        );
+
+        let (status, _) = authenticator(config())
+            .authenticate(&parts)
+            .await
+            .expect_err("header is not trusted by default");
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let authenticator = authenticator(AuthenticationConfig {
+            trust_actor_id_header: true,
+            ..config()
+        });
+        assert_eq!(
+            authenticator
+                .authenticate(&parts)
+                .await
+                .expect("header should be trusted"),
+            actor()
+        );
+
+        let parts = request(
+            Method::POST,
+            "/entities",
+            &[("X-Authenticated-User-Actor-Id", "invalid")],
+        );
+        let (status, _) = authenticator
+            .authenticate(&parts)
+            .await
+            .expect_err("header is not a UUID");
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+}
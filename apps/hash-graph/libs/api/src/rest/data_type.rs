@@ -187,7 +187,10 @@ where
     A: AuthorizationApiPool + Send + Sync,
 {
     let mut store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
@@ -209,24 +212,34 @@ where
             actor_id,
             &mut authorization_api,
             temporal_client.as_deref(),
-            schema.into_iter().map(|schema| {
-                domain_validator.validate(&schema).map_err(|report| {
-                    tracing::error!(error=?report, id=schema.id().to_string(), "Data Type ID failed to validate");
-                    StatusCode::UNPROCESSABLE_ENTITY
-                })?;
-
-                Ok(CreateDataTypeParams {
-                    schema,
-                    classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
-                    relationships: relationships.clone(),
-                    conflict_behavior: ConflictBehavior::Fail,
+            schema
+                .into_iter()
+                .map(|schema| {
+                    domain_validator.validate(&schema).map_err(|report| {
+                        tracing::error!(
+                            error = hash_tracing::report_value!(report),
+                            id = schema.id().to_string(),
+                            "Data Type ID failed to validate"
+                        );
+                        StatusCode::UNPROCESSABLE_ENTITY
+                    })?;
+
+                    Ok(CreateDataTypeParams {
+                        schema,
+                        classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+                        relationships: relationships.clone(),
+                        conflict_behavior: ConflictBehavior::Fail,
+                    })
                 })
-            }).collect::<Result<Vec<_>, StatusCode>>()?
+                .collect::<Result<Vec<_>, StatusCode>>()?,
         )
         .await
         .map_err(|report| {
             // TODO: consider adding the data type, or at least its URL in the trace
-            tracing::error!(error=?report, "Could not create data types");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not create data types"
+            );
 
             if report.contains::<PermissionAssertion>() {
                 return StatusCode::FORBIDDEN;
@@ -471,14 +484,20 @@ where
     type_to_update.version += 1;
 
     let data_type = patch_id_and_parse(&type_to_update, schema).map_err(|report| {
-        tracing::error!(error=?report, "Couldn't patch schema and convert to Data Type");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Couldn't patch schema and convert to Data Type"
+        );
         StatusCode::UNPROCESSABLE_ENTITY
         // TODO - We should probably return more information to the client
         //  https://app.asana.com/0/1201095311341924/1202574350052904/f
     })?;
 
     let mut store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
@@ -499,7 +518,10 @@ where
         )
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not update data type");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not update data type"
+            );
 
             if report.contains::<PermissionAssertion>() {
                 return StatusCode::FORBIDDEN;
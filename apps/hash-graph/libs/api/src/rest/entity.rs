@@ -8,8 +8,8 @@ use authorization::{
     backend::{ModifyRelationshipOperation, PermissionAssertion},
     schema::{
         EntityAdministratorSubject, EntityEditorSubject, EntityOwnerSubject, EntityPermission,
-        EntityRelationAndSubject, EntitySetting, EntitySettingSubject, EntitySubjectSet,
-        EntityViewerSubject, WebOwnerSubject,
+        EntityRelationAndSubject, EntityRestrictedPropertyViewerSubject, EntitySetting,
+        EntitySettingSubject, EntitySubjectSet, EntityViewerSubject, WebOwnerSubject,
     },
     zanzibar::Consistency,
     AuthorizationApi, AuthorizationApiPool,
@@ -18,7 +18,7 @@ use axum::{
     extract::Path,
     http::StatusCode,
     response::Response,
-    routing::{get, post},
+    routing::{get, post, put},
     Extension, Router,
 };
 use error_stack::{Report, ResultExt};
@@ -27,8 +27,8 @@ use graph::{
     store::{
         error::{EntityDoesNotExist, RaceConditionOnUpdate},
         knowledge::{
-            CreateEntityRequest, GetEntityParams, UpdateEntityEmbeddingsParams, UpdateEntityParams,
-            ValidateEntityParams,
+            CreateEntityRequest, GetEntityParams, SetEntityPropertyRestrictionParams,
+            UpdateEntityEmbeddingsParams, UpdateEntityParams, ValidateEntityParams,
         },
         AccountStore, EntityQueryCursor, EntityQuerySorting, EntityQuerySortingRecord, EntityStore,
         EntityValidationType, NullOrdering, Ordering, StorePool,
@@ -36,6 +36,7 @@ use graph::{
     subgraph::{query::EntityStructuralQuery, temporal_axes::QueryTemporalAxesUnresolved},
 };
 use graph_types::{
+    account::AccountId,
     knowledge::{
         entity::{
             Entity, EntityEditionId, EntityEditionProvenanceMetadata, EntityEmbedding, EntityId,
@@ -56,7 +57,8 @@ use validation::ValidationProfile;
 
 use crate::rest::{
     api_resource::RoutedResource, json::Json, status::report_to_response,
-    utoipa_typedef::subgraph::Subgraph, AuthenticatedUserHeader, PermissionResponse,
+    utoipa_typedef::subgraph::Subgraph, AuthenticatedUserHeader, PermissionExplanation,
+    PermissionResponse,
 };
 
 #[derive(OpenApi)]
@@ -65,9 +67,11 @@ use crate::rest::{
         create_entity,
         validate_entity,
         check_entity_permission,
+        explain_entity_permission,
         get_entities_by_query,
         update_entity,
         update_entity_embeddings,
+        set_entity_property_restriction,
 
         get_entity_authorization_relationships,
         modify_entity_authorization_relationships,
@@ -86,6 +90,7 @@ use crate::rest::{
             UpdateEntityRequest,
             Embedding,
             UpdateEntityEmbeddingsParams,
+            SetEntityPropertyRestrictionParams,
             EntityEmbedding,
             EntityQueryToken,
             EntityStructuralQuery,
@@ -97,6 +102,7 @@ use crate::rest::{
             EntityAdministratorSubject,
             EntityEditorSubject,
             EntityViewerSubject,
+            EntityRestrictedPropertyViewerSubject,
             ModifyEntityAuthorizationRelationship,
             ModifyRelationshipOperation,
             EntitySetting,
@@ -149,6 +155,10 @@ impl RoutedResource for EntityResource {
                 )
                 .route("/validate", post(validate_entity::<S, A>))
                 .route("/embeddings", post(update_entity_embeddings::<S, A>))
+                .route(
+                    "/restricted-properties",
+                    put(set_entity_property_restriction::<S, A>),
+                )
                 .nest(
                     "/:entity_id",
                     Router::new()
@@ -168,6 +178,10 @@ impl RoutedResource for EntityResource {
                         .route(
                             "/permissions/:permission",
                             get(check_entity_permission::<A>),
+                        )
+                        .route(
+                            "/permissions/:permission/explanation/:actor",
+                            get(explain_entity_permission::<A>),
                         ),
                 )
                 .route("/query", post(get_entities_by_query::<S, A>)),
@@ -316,6 +330,89 @@ where
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/entities/{entity_id}/permissions/{permission}/explanation/{actor}",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("entity_id" = EntityId, Path, description = "The entity ID to explain the permission of the actor for"),
+        ("permission" = EntityPermission, Path, description = "The permission to explain"),
+        ("actor" = AccountId, Path, description = "The actor to explain the permission for"),
+    ),
+    responses(
+        (status = 200, body = PermissionExplanation, description = "Information if the actor has the permission for the entity and, if the requesting actor has full access to the entity, how it was computed"),
+
+        (status = 403, description = "The requesting actor is not allowed to explain the permissions of other actors for the entity"),
+        (status = 500, description = "Internal error occurred"),
+    )
+)]
+#[tracing::instrument(level = "info", skip(authorization_api_pool))]
+async fn explain_entity_permission<A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    Path((entity_id, permission, target_actor_id)): Path<(EntityId, EntityPermission, AccountId)>,
+    authorization_api_pool: Extension<Arc<A>>,
+) -> Result<Json<PermissionExplanation>, Response>
+where
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    // The permissions of other actors and the trace reveal who can access the entity, so they are
+    // only explained to actors who have full access to the entity, i.e. its administrators and the
+    // administrators of its web.
+    let has_full_access = authorization_api
+        .check_entity_permission(
+            actor_id,
+            EntityPermission::FullAccess,
+            entity_id,
+            Consistency::FullyConsistent,
+        )
+        .await
+        .map_err(report_to_response)?
+        .has_permission;
+
+    if has_full_access {
+        let explanation = authorization_api
+            .explain_entity_permission(
+                target_actor_id,
+                permission,
+                entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .map_err(report_to_response)?;
+
+        return Ok(Json(PermissionExplanation {
+            has_permission: explanation.has_permission,
+            trace: explanation.trace,
+        }));
+    }
+
+    if target_actor_id != actor_id {
+        return Err(report_to_response(
+            Report::new(PermissionAssertion).attach(hash_status::StatusCode::PermissionDenied),
+        ));
+    }
+
+    Ok(Json(PermissionExplanation {
+        has_permission: authorization_api
+            .check_entity_permission(
+                actor_id,
+                permission,
+                entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .map_err(report_to_response)?
+            .has_permission,
+        trace: None,
+    }))
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 struct GetEntityByQueryRequest<'q, 's, 'p> {
@@ -593,6 +690,54 @@ where
         .map_err(report_to_response)
 }
 
+#[utoipa::path(
+    put,
+    path = "/entities/restricted-properties",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 204, description = "The restriction of the property was updated"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to restrict properties of the entity"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = SetEntityPropertyRestrictionParams,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn set_entity_property_restriction<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    Json(params): Json<SetEntityPropertyRestrictionParams>,
+) -> Result<StatusCode, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let mut store = store_pool.acquire().await.map_err(report_to_response)?;
+    let mut authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .set_entity_property_restriction(actor_id, &mut authorization_api, params)
+        .await
+        .map_err(|report| {
+            if report.contains::<PermissionAssertion>() {
+                report.attach(hash_status::StatusCode::PermissionDenied)
+            } else {
+                report
+            }
+        })
+        .map_err(report_to_response)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[utoipa::path(
     get,
     path = "/entities/{entity_id}/relationships",
@@ -769,14 +914,20 @@ where
     }
 
     let store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
     let administrator_id = store
         .identify_owned_by_id(owned_by_id)
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not identify account or account group");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not identify account or account group"
+            );
             StatusCode::INTERNAL_SERVER_ERROR
         })?;
 
@@ -795,6 +946,7 @@ where
             EntityRelationAndSubject::Administrator {
                 subject: administrator,
                 level: 0,
+                expires_at: None,
             },
         )])
         .await
@@ -859,14 +1011,20 @@ where
     }
 
     let store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
     let administrator_id = store
         .identify_owned_by_id(owned_by_id)
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not identify account or account group");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not identify account or account group"
+            );
             StatusCode::INTERNAL_SERVER_ERROR
         })?;
 
@@ -885,6 +1043,7 @@ where
             EntityRelationAndSubject::Administrator {
                 subject: administrator,
                 level: 0,
+                expires_at: None,
             },
         )])
         .await
@@ -946,11 +1105,17 @@ where
     }
 
     let store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
     let editor_id = store.identify_owned_by_id(editor).await.map_err(|report| {
-        tracing::error!(error=?report, "Could not identify account or account group");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not identify account or account group"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
@@ -966,7 +1131,11 @@ where
         .modify_entity_relations([(
             ModifyRelationshipOperation::Create,
             entity_id,
-            EntityRelationAndSubject::Editor { subject, level: 0 },
+            EntityRelationAndSubject::Editor {
+                subject,
+                level: 0,
+                expires_at: None,
+            },
         )])
         .await
         .map_err(|error| {
@@ -1030,11 +1199,17 @@ where
     }
 
     let store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
     let editor_id = store.identify_owned_by_id(editor).await.map_err(|report| {
-        tracing::error!(error=?report, "Could not identify account or account group");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not identify account or account group"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
@@ -1050,7 +1225,11 @@ where
         .modify_entity_relations([(
             ModifyRelationshipOperation::Delete,
             entity_id,
-            EntityRelationAndSubject::Editor { subject, level: 0 },
+            EntityRelationAndSubject::Editor {
+                subject,
+                level: 0,
+                expires_at: None,
+            },
         )])
         .await
         .map_err(|error| {
@@ -366,7 +366,10 @@ where
     A: AuthorizationApiPool + Send + Sync,
 {
     let mut store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         status_to_response(Status::new(
             hash_status::StatusCode::Internal,
             Some(
@@ -416,41 +419,54 @@ where
             actor_id,
             &mut authorization_api,
             temporal_client.as_deref(),
-            schema.into_iter().map(|schema| {
-                domain_validator.validate(&schema).map_err(|report| {
-                    tracing::error!(error=?report, id=schema.id().to_string(), "Entity Type ID failed to validate");
-                    status_to_response(Status::new(
-                        hash_status::StatusCode::InvalidArgument,
-                        Some("Entity Type ID failed to validate against the given domain regex. Are you sure the service is able to host a type under the domain you supplied?".to_owned()),
-                        vec![StatusPayloads::ErrorInfo(ErrorInfo::new(
-                            HashMap::from([
-                                (
+            schema
+                .into_iter()
+                .map(|schema| {
+                    domain_validator.validate(&schema).map_err(|report| {
+                        tracing::error!(
+                            error = hash_tracing::report_value!(report),
+                            id = schema.id().to_string(),
+                            "Entity Type ID failed to validate"
+                        );
+                        status_to_response(Status::new(
+                            hash_status::StatusCode::InvalidArgument,
+                            Some(
+                                "Entity Type ID failed to validate against the given domain \
+                                 regex. Are you sure the service is able to host a type under the \
+                                 domain you supplied?"
+                                    .to_owned(),
+                            ),
+                            vec![StatusPayloads::ErrorInfo(ErrorInfo::new(
+                                HashMap::from([(
                                     "entityTypeId".to_owned(),
                                     serde_json::to_value(schema.id().to_string())
                                         .expect("Could not serialize entity type id"),
-                                ),
-                            ]),
-                            // TODO: We should encapsulate these Reasons within the type system, perhaps
-                            //  requiring top level contexts to implement a trait `ErrorReason::to_reason`
-                            //  or perhaps as a big enum
-                            "INVALID_TYPE_ID".to_owned()
-                        ))],
-                    ))
-                })?;
-
-                Ok(CreateEntityTypeParams {
-                    schema,
-                    classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
-                    relationships: relationships.clone(),
-                    icon: icon.clone(),
-                    label_property: label_property.clone(),
-                    conflict_behavior: ConflictBehavior::Fail,
+                                )]),
+                                // TODO: We should encapsulate these Reasons within the type
+                                //  system, perhaps requiring top level contexts to implement a
+                                //  trait `ErrorReason::to_reason` or perhaps as a big enum
+                                "INVALID_TYPE_ID".to_owned(),
+                            ))],
+                        ))
+                    })?;
+
+                    Ok(CreateEntityTypeParams {
+                        schema,
+                        classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+                        relationships: relationships.clone(),
+                        icon: icon.clone(),
+                        label_property: label_property.clone(),
+                        conflict_behavior: ConflictBehavior::Fail,
+                    })
                 })
-            }).collect::<Result<Vec<_>, _>>()?
+                .collect::<Result<Vec<_>, _>>()?,
         )
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not create entity types");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not create entity types"
+            );
 
             if report.contains::<PermissionAssertion>() {
                 return status_to_response(Status::new(
@@ -569,7 +585,10 @@ where
     A: AuthorizationApiPool + Send + Sync,
 {
     let mut store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         status_to_response(Status::new(
             hash_status::StatusCode::Internal,
             Some(
@@ -784,7 +803,10 @@ where
     type_to_update.version += 1;
 
     let entity_type = patch_id_and_parse(&type_to_update, schema).map_err(|report| {
-        tracing::error!(error=?report, "Couldn't convert schema to Entity Type");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Couldn't convert schema to Entity Type"
+        );
         // Shame there isn't an UNPROCESSABLE_ENTITY_TYPE code :D
         StatusCode::UNPROCESSABLE_ENTITY
         // TODO - We should probably return more information to the client
@@ -792,7 +814,10 @@ where
     })?;
 
     let mut store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
@@ -815,7 +840,10 @@ where
         )
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not update entity type");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not update entity type"
+            );
 
             if report.contains::<PermissionAssertion>() {
                 return StatusCode::FORBIDDEN;
@@ -5,6 +5,8 @@
 #[cfg(feature = "test-server")]
 pub mod test_server;
 
+pub mod authentication;
+
 mod api_resource;
 mod json;
 mod middleware;
@@ -18,10 +20,10 @@ mod entity_type;
 mod property_type;
 mod web;
 
-use std::{borrow::Cow, fs, io, str::FromStr, sync::Arc};
+use std::{borrow::Cow, fs, io, sync::Arc};
 
 use async_trait::async_trait;
-use authorization::{AuthorizationApi, AuthorizationApiPool};
+use authorization::{backend::PermissionTrace, AuthorizationApi, AuthorizationApiPool};
 use axum::{
     extract::{FromRequestParts, Path},
     http::{request::Parts, uri::PathAndQuery, HeaderValue, StatusCode},
@@ -76,10 +78,10 @@ use utoipa::{
     },
     Modify, OpenApi, ToSchema,
 };
-use uuid::Uuid;
 
 use self::{
     api_resource::RoutedResource,
+    authentication::{AuthenticationConfig, Authenticator},
     middleware::span_trace_layer,
     status::{report_to_response, status_to_response},
     utoipa_typedef::{
@@ -100,19 +102,16 @@ impl<S> FromRequestParts<S> for AuthenticatedUserHeader {
     type Rejection = (StatusCode, Cow<'static, str>);
 
     async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-        if let Some(header_value) = parts.headers.get("X-Authenticated-User-Actor-Id") {
-            let header_string = header_value
-                .to_str()
-                .map_err(|error| (StatusCode::BAD_REQUEST, Cow::Owned(error.to_string())))?;
-            let uuid = Uuid::from_str(header_string)
-                .map_err(|error| (StatusCode::BAD_REQUEST, Cow::Owned(error.to_string())))?;
-            Ok(Self(AccountId::new(uuid)))
-        } else {
-            Err((
-                StatusCode::BAD_REQUEST,
-                Cow::Borrowed("`X-Authenticated-User-Actor-Id` header is missing"),
-            ))
-        }
+        let authenticator = parts
+            .extensions
+            .get::<Arc<Authenticator>>()
+            .cloned()
+            .ok_or((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Cow::Borrowed("Authentication is not configured"),
+            ))?;
+
+        authenticator.authenticate(parts).await.map(Self)
     }
 }
 
@@ -121,6 +120,14 @@ pub struct PermissionResponse {
     has_permission: bool,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionExplanation {
+    has_permission: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    trace: Option<PermissionTrace>,
+}
+
 #[derive(Debug)]
 pub struct Cursor<T>(pub T);
 
@@ -269,6 +276,7 @@ where
     pub authorization_api: Arc<A>,
     pub temporal_client: Option<TemporalClient>,
     pub domain_regex: DomainValidator,
+    pub authentication: AuthenticationConfig,
 }
 
 /// A [`Router`] that only serves the `OpenAPI` specification (JSON, and necessary subschemas) for
@@ -299,6 +307,11 @@ where
     // super-router can then be used as any other router.
     // Make sure extensions are added at the end so they are made available to merged routers.
     // The `/api-doc` endpoints are nested as we don't want any layers or handlers for the api-doc
+    let authenticator = Arc::new(Authenticator::new(
+        dependencies.authentication,
+        Arc::clone(&dependencies.store),
+    ));
+
     merged_routes
         .layer(NewSentryLayer::new_from_top())
         .layer(SentryHttpLayer::with_transaction())
@@ -306,6 +319,7 @@ where
         .layer(Extension(dependencies.authorization_api))
         .layer(Extension(dependencies.temporal_client.map(Arc::new)))
         .layer(Extension(dependencies.domain_regex))
+        .layer(Extension(authenticator))
         .layer(span_trace_layer())
         .merge(openapi_only_router())
 }
@@ -343,6 +357,8 @@ async fn serve_static_schema(Path(path): Path<String>) -> Result<Response, Statu
     components(
         schemas(
             PermissionResponse,
+            PermissionExplanation,
+            PermissionTrace,
 
             BaseUrl,
             VersionedUrl,
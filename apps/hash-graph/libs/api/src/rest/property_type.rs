@@ -31,8 +31,8 @@ use graph::{
         error::VersionedUrlAlreadyExists,
         ontology::{
             ArchivePropertyTypeParams, CreatePropertyTypeParams, GetPropertyTypesParams,
-            UnarchivePropertyTypeParams, UpdatePropertyTypeEmbeddingParams,
-            UpdatePropertyTypesParams,
+            SetPropertyTypeRestrictionParams, UnarchivePropertyTypeParams,
+            UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
         },
         BaseUrlAlreadyExists, ConflictBehavior, OntologyVersionDoesNotExist, PropertyTypeStore,
         StorePool,
@@ -79,6 +79,7 @@ use crate::rest::{
         update_property_type_embeddings,
         archive_property_type,
         unarchive_property_type,
+        set_property_type_restriction,
     ),
     components(
         schemas(
@@ -102,6 +103,7 @@ use crate::rest::{
             PropertyTypeStructuralQuery,
             ArchivePropertyTypeParams,
             UnarchivePropertyTypeParams,
+            SetPropertyTypeRestrictionParams,
         )
     ),
     tags(
@@ -146,6 +148,7 @@ impl RoutedResource for PropertyTypeResource {
                 .route("/load", post(load_external_property_type::<S, A>))
                 .route("/archive", put(archive_property_type::<S, A>))
                 .route("/unarchive", put(unarchive_property_type::<S, A>))
+                .route("/restriction", put(set_property_type_restriction::<S, A>))
                 .route("/embeddings", post(update_property_type_embeddings::<S, A>)),
         )
     }
@@ -194,7 +197,10 @@ where
     A: AuthorizationApiPool + Send + Sync,
 {
     let mut store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
@@ -216,24 +222,34 @@ where
             actor_id,
             &mut authorization_api,
             temporal_client.as_deref(),
-            schema.into_iter().map(|schema| {
-                domain_validator.validate(&schema).map_err(|report| {
-                    tracing::error!(error=?report, id=schema.id().to_string(), "Property Type ID failed to validate");
-                    StatusCode::UNPROCESSABLE_ENTITY
-                })?;
-
-                Ok(CreatePropertyTypeParams {
-                    schema,
-                    classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
-                    relationships: relationships.clone(),
-                    conflict_behavior: ConflictBehavior::Fail,
+            schema
+                .into_iter()
+                .map(|schema| {
+                    domain_validator.validate(&schema).map_err(|report| {
+                        tracing::error!(
+                            error = hash_tracing::report_value!(report),
+                            id = schema.id().to_string(),
+                            "Property Type ID failed to validate"
+                        );
+                        StatusCode::UNPROCESSABLE_ENTITY
+                    })?;
+
+                    Ok(CreatePropertyTypeParams {
+                        schema,
+                        classification: OntologyTypeClassificationMetadata::Owned { owned_by_id },
+                        relationships: relationships.clone(),
+                        conflict_behavior: ConflictBehavior::Fail,
+                    })
                 })
-            }).collect::<Result<Vec<_>, StatusCode>>()?
+                .collect::<Result<Vec<_>, StatusCode>>()?,
         )
         .await
         .map_err(|report| {
             // TODO: consider adding the data type, or at least its URL in the trace
-            tracing::error!(error=?report, "Could not create data types");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not create data types"
+            );
 
             if report.contains::<PermissionAssertion>() {
                 return StatusCode::FORBIDDEN;
@@ -477,14 +493,20 @@ where
     type_to_update.version += 1;
 
     let property_type = patch_id_and_parse(&type_to_update, schema).map_err(|report| {
-        tracing::error!(error=?report, "Couldn't patch schema and convert to Property Type");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Couldn't patch schema and convert to Property Type"
+        );
         StatusCode::UNPROCESSABLE_ENTITY
         // TODO - We should probably return more information to the client
         //  https://app.asana.com/0/1201095311341924/1202574350052904/f
     })?;
 
     let mut store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
@@ -505,7 +527,10 @@ where
         )
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not update property type");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not update property type"
+            );
 
             if report.contains::<PermissionAssertion>() {
                 return StatusCode::FORBIDDEN;
@@ -674,6 +699,58 @@ where
         .map(Json)
 }
 
+#[utoipa::path(
+    put,
+    path = "/property-types/restriction",
+    tag = "PropertyType",
+    params(
+        ("X-Authenticated-User-Actor-Id" = AccountId, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 204, description = "The restriction of the property type was updated"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to update the property type"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = SetPropertyTypeRestrictionParams,
+)]
+#[tracing::instrument(level = "info", skip(store_pool, authorization_api_pool))]
+async fn set_property_type_restriction<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    Json(body): Json<serde_json::Value>,
+) -> Result<StatusCode, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    // Manually deserialize the request from a JSON value to allow borrowed deserialization and
+    // better error reporting.
+    let params = SetPropertyTypeRestrictionParams::deserialize(body)
+        .attach(hash_status::StatusCode::InvalidArgument)
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool.acquire().await.map_err(report_to_response)?;
+    let mut authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .set_property_type_restriction(actor_id, &mut authorization_api, params)
+        .await
+        .map_err(|mut report| {
+            if report.contains::<PermissionAssertion>() {
+                report = report.attach(hash_status::StatusCode::PermissionDenied);
+            }
+            report_to_response(report)
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 struct ModifyPropertyTypeAuthorizationRelationship {
@@ -1,22 +1,51 @@
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug, mem};
 
-use axum::{
-    response::{IntoResponse, Response},
-    Json,
-};
-use error_stack::{Context, Report};
+use axum::response::{IntoResponse, Response};
+use error_stack::{AttachmentKind, Context, FrameKind, Report};
 use hash_status::{Status, StatusCode};
 use serde::Serialize;
+use serde_json::Value;
+
+use crate::error::{ErrorInfo, StatusPayloads};
 
 pub(crate) fn status_to_response<T>(status: Status<T>) -> Response
 where
     T: Serialize + Send + Sync + Debug,
 {
-    let status_code = axum::http::StatusCode::from_u16(status.code().to_http_code())
-        .expect("HASH Status code should map to a valid HTTP status code");
-    let mut response = Json(status).into_response();
-    *response.status_mut() = status_code;
-    response
+    status.into_response()
+}
+
+/// Describes every context of the [`Report`] by an [`ErrorInfo`].
+///
+/// The printable attachments of a context are added as `attachments` to its metadata. Other
+/// attachments, e.g. the source locations, are not returned to the client.
+fn report_contents<C>(report: &Report<C>) -> Vec<StatusPayloads> {
+    let mut contents = Vec::new();
+    let mut attachments = Vec::new();
+
+    for frame in report.frames() {
+        match frame.kind() {
+            FrameKind::Context(context) => {
+                let mut metadata = HashMap::new();
+                if !attachments.is_empty() {
+                    metadata.insert(
+                        "attachments".to_owned(),
+                        Value::from(mem::take(&mut attachments)),
+                    );
+                }
+                contents.push(StatusPayloads::ErrorInfo(ErrorInfo::new(
+                    metadata,
+                    context.to_string(),
+                )));
+            }
+            FrameKind::Attachment(AttachmentKind::Printable(attachment)) => {
+                attachments.push(attachment.to_string());
+            }
+            FrameKind::Attachment(_) => {}
+        }
+    }
+
+    contents
 }
 
 pub(crate) fn report_to_response<C>(report: impl Into<Report<C>>) -> Response
@@ -24,20 +53,73 @@ where
     C: Context,
 {
     let report = report.into();
-    let status_code = report
-        .request_ref::<StatusCode>()
-        .next()
-        .copied()
-        .or_else(|| report.request_value::<StatusCode>().next())
-        .unwrap_or(StatusCode::Internal);
+    let status_code = StatusCode::from_report(&report);
     // TODO: Currently, this mostly duplicates the error printed below, when more information is
     //       added to the `Report` event consider commenting in this line again.
     // hash_tracing::sentry::capture_report(&report);
-    tracing::error!(error = ?report, tags.code = ?status_code.to_http_code());
+    tracing::error!(error = hash_tracing::report_value!(report), tags.code = ?status_code.to_http_code());
 
     status_to_response(Status::new(
         status_code,
-        Some(report.to_string()),
-        vec![report],
+        Some(report.current_context().to_string()),
+        report_contents(&report),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct QueryError;
+
+    impl fmt::Display for QueryError {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt.write_str("could not query the entity")
+        }
+    }
+
+    impl Context for QueryError {}
+
+    #[derive(Debug)]
+    struct NotFound;
+
+    impl fmt::Display for NotFound {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt.write_str("entity not found")
+        }
+    }
+
+    impl Context for NotFound {}
+
+    #[test]
+    fn contents_describe_contexts() {
+        let report = Report::new(NotFound)
+            .attach_printable("entity 1")
+            .attach(StatusCode::NotFound)
+            .change_context(QueryError);
+
+        let contents = report_contents(&report)
+            .into_iter()
+            .map(|payload| match payload {
+                StatusPayloads::ErrorInfo(info) => (info.reason, info.metadata),
+                payload => panic!("unexpected payload: {payload:?}"),
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            contents,
+            [
+                ("could not query the entity".to_owned(), HashMap::new()),
+                (
+                    "entity not found".to_owned(),
+                    HashMap::from([("attachments".to_owned(), Some(json!(["entity 1"])))]),
+                ),
+            ]
+        );
+    }
+}
@@ -55,7 +55,10 @@ where
     reason = "This is used inside of error-mapping functions only"
 )]
 fn store_acquisition_error(report: Report<impl Context>) -> Response {
-    tracing::error!(error=?report, "Could not acquire store");
+    tracing::error!(
+        error = hash_tracing::report_value!(report),
+        "Could not acquire store"
+    );
     status_to_response(Status::new(
         StatusCode::Internal,
         Some(format!(
@@ -119,7 +122,10 @@ where
         )
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not restore snapshot");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not restore snapshot"
+            );
             report_to_response(&report, "SNAPSHOT_RESTORATION_FAILURE")
         })?;
 
@@ -144,7 +150,10 @@ where
         .delete_accounts(AccountId::new(Uuid::nil()), &NoAuthorization)
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not delete accounts");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not delete accounts"
+            );
             report_to_response(&report, "ACCOUNT_DELETION_FAILURE")
         })?;
 
@@ -154,7 +163,10 @@ where
         ))
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not delete web relationships");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not delete web relationships"
+            );
             report_to_response(&report, "ACCOUNT_DELETION_FAILURE")
         })?;
 
@@ -164,7 +176,10 @@ where
         ))
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not delete account group relationships");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not delete account group relationships"
+            );
             report_to_response(&report, "ACCOUNT_DELETION_FAILURE")
         })?;
 
@@ -186,7 +201,10 @@ where
     let mut authorization_api = (**authorization_api).clone();
 
     store.delete_data_types().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not delete data types");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not delete data types"
+        );
         status_to_response(Status::new(
             report
                 .request_ref::<StatusCode>()
@@ -207,7 +225,10 @@ where
         ))
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not delete data type relationships");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not delete data type relationships"
+            );
             report_to_response(&report, "DATA_TYPE_DELETION_FAILURE")
         })?;
 
@@ -229,7 +250,10 @@ where
     let mut authorization_api = (**authorization_api).clone();
 
     store.delete_property_types().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not delete property types");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not delete property types"
+        );
         report_to_response(&report, "PROPERTY_TYPE_DELETION_FAILURE")
     })?;
 
@@ -239,7 +263,10 @@ where
         ))
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not delete property type relationships");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not delete property type relationships"
+            );
             report_to_response(&report, "PROPERTY_TYPE_DELETION_FAILURE")
         })?;
 
@@ -261,7 +288,10 @@ where
     let mut authorization_api = (**authorization_api).clone();
 
     store.delete_entity_types().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not delete entity types");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not delete entity types"
+        );
         report_to_response(&report, "ENTITY_TYPE_DELETION_FAILURE")
     })?;
 
@@ -271,7 +301,10 @@ where
         ))
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not delete entity type relationships");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not delete entity type relationships"
+            );
             report_to_response(&report, "ENTITY_TYPE_DELETION_FAILURE")
         })?;
 
@@ -293,7 +326,10 @@ where
     let mut authorization_api = (**authorization_api).clone();
 
     store.delete_entities().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not delete entities");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not delete entities"
+        );
         report_to_response(&report, "ENTITY_DELETION_FAILURE")
     })?;
 
@@ -303,7 +339,10 @@ where
         ))
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not delete entity relationships");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not delete entity relationships"
+            );
             report_to_response(&report, "ENTITY_DELETION_FAILURE")
         })?;
 
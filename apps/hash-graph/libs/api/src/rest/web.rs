@@ -114,7 +114,10 @@ where
     A: AuthorizationApiPool + Send + Sync,
 {
     let mut store = store_pool.acquire().await.map_err(|report| {
-        tracing::error!(error=?report, "Could not acquire store");
+        tracing::error!(
+            error = hash_tracing::report_value!(report),
+            "Could not acquire store"
+        );
         StatusCode::INTERNAL_SERVER_ERROR
     })?;
 
@@ -127,7 +130,10 @@ where
         .insert_web_id(actor_id, &mut authorization_api, params)
         .await
         .map_err(|report| {
-            tracing::error!(error=?report, "Could not create web id");
+            tracing::error!(
+                error = hash_tracing::report_value!(report),
+                "Could not create web id"
+            );
 
             StatusCode::INTERNAL_SERVER_ERROR
         })?;
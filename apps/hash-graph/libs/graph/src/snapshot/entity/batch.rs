@@ -16,7 +16,8 @@ use crate::{
     snapshot::{
         entity::{
             table::{EntityDraftRow, EntityEmbeddingRow},
-            EntityEditionRow, EntityIdRow, EntityLinkEdgeRow, EntityTemporalMetadataRow,
+            EntityEditionRow, EntityIdRow, EntityLinkEdgeRow, EntityPropertyRestrictionRow,
+            EntityTemporalMetadataRow,
         },
         WriteBatch,
     },
@@ -34,6 +35,7 @@ pub enum EntityRowBatch {
     Links(Vec<EntityLinkEdgeRow>),
     Relations(Vec<(EntityUuid, EntityRelationAndSubject)>),
     Embeddings(Vec<EntityEmbeddingRow>),
+    PropertyRestrictions(Vec<EntityPropertyRestrictionRow>),
 }
 
 #[async_trait]
@@ -82,6 +84,10 @@ impl<C: AsClient> WriteBatch<C> for EntityRowBatch {
                     CREATE TEMPORARY TABLE entity_embeddings_tmp
                         (LIKE entity_embeddings INCLUDING ALL)
                         ON COMMIT DROP;
+
+                    CREATE TEMPORARY TABLE restricted_entity_properties_tmp
+                        (LIKE restricted_entity_properties INCLUDING ALL)
+                        ON COMMIT DROP;
                 ",
             )
             .await
@@ -203,6 +209,23 @@ impl<C: AsClient> WriteBatch<C> for EntityRowBatch {
                     tracing::info!("Read {} entity embeddings", rows.len());
                 }
             }
+            Self::PropertyRestrictions(restrictions) => {
+                let rows = client
+                    .query(
+                        "
+                            INSERT INTO restricted_entity_properties_tmp
+                            SELECT DISTINCT * FROM UNNEST($1::restricted_entity_properties[])
+                            ON CONFLICT DO NOTHING
+                            RETURNING 1;
+                        ",
+                        &[&restrictions],
+                    )
+                    .await
+                    .change_context(InsertionError)?;
+                if !rows.is_empty() {
+                    tracing::info!("Read {} entity property restrictions", rows.len());
+                }
+            }
         }
         Ok(())
     }
@@ -260,6 +283,9 @@ impl<C: AsClient> WriteBatch<C> for EntityRowBatch {
 
                     INSERT INTO entity_embeddings
                         SELECT * FROM entity_embeddings_tmp;
+
+                    INSERT INTO restricted_entity_properties
+                        SELECT * FROM restricted_entity_properties_tmp;
             ",
             )
             .await
@@ -15,8 +15,8 @@ use graph_types::{knowledge::entity::EntityUuid, ontology::OntologyTypeVersion};
 use crate::snapshot::{
     entity::{
         table::{EntityDraftRow, EntityEmbeddingRow},
-        EntityEditionRow, EntityIdRow, EntityLinkEdgeRow, EntityRowBatch,
-        EntityTemporalMetadataRow,
+        EntityEditionRow, EntityIdRow, EntityLinkEdgeRow, EntityPropertyRestrictionRow,
+        EntityRowBatch, EntityTemporalMetadataRow,
     },
     EntitySnapshotRecord, SnapshotRestoreError,
 };
@@ -210,6 +210,7 @@ pub fn channel(
     chunk_size: usize,
     relation_rx: Receiver<(EntityUuid, EntityRelationAndSubject)>,
     embedding_rx: Receiver<EntityEmbeddingRow>,
+    property_restriction_rx: Receiver<EntityPropertyRestrictionRow>,
 ) -> (EntitySender, EntityReceiver) {
     let (id_tx, id_rx) = mpsc::channel(chunk_size);
     let (draft_tx, draft_rx) = mpsc::channel(chunk_size);
@@ -255,6 +256,10 @@ pub fn channel(
                     .ready_chunks(chunk_size)
                     .map(EntityRowBatch::Embeddings)
                     .boxed(),
+                property_restriction_rx
+                    .ready_chunks(chunk_size)
+                    .map(EntityRowBatch::PropertyRestrictions)
+                    .boxed(),
             ]),
         },
     )
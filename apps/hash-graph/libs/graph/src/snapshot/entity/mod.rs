@@ -6,9 +6,12 @@ mod table;
 pub use self::{
     batch::EntityRowBatch,
     channel::{channel, EntityReceiver, EntitySender},
-    record::{EntityEmbeddingRecord, EntityRelationRecord, EntitySnapshotRecord},
+    record::{
+        EntityEmbeddingRecord, EntityPropertyRestrictionRecord, EntityRelationRecord,
+        EntitySnapshotRecord,
+    },
     table::{
         EntityEditionRow, EntityEmbeddingRow, EntityIdRow, EntityLinkEdgeRow,
-        EntityTemporalMetadataRow,
+        EntityPropertyRestrictionRow, EntityTemporalMetadataRow,
     },
 };
@@ -4,6 +4,7 @@ use graph_types::{
         entity::{EntityId, EntityMetadata, EntityProperties, EntityUuid},
         link::LinkData,
     },
+    owned_by_id::OwnedById,
     Embedding,
 };
 use serde::{Deserialize, Serialize};
@@ -35,3 +36,11 @@ pub struct EntityEmbeddingRecord {
     pub updated_at_transaction_time: Timestamp<TransactionTime>,
     pub updated_at_decision_time: Timestamp<DecisionTime>,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityPropertyRestrictionRecord {
+    pub web_id: OwnedById,
+    pub entity_uuid: EntityUuid,
+    pub property_type_base_url: BaseUrl,
+}
@@ -77,3 +77,11 @@ pub struct EntityEmbeddingRow {
     pub updated_at_transaction_time: Timestamp<TransactionTime>,
     pub updated_at_decision_time: Timestamp<DecisionTime>,
 }
+
+#[derive(Debug, ToSql)]
+#[postgres(name = "restricted_entity_properties")]
+pub struct EntityPropertyRestrictionRow {
+    pub web_id: OwnedById,
+    pub entity_uuid: EntityUuid,
+    pub property_type_base_url: String,
+}
@@ -57,9 +57,10 @@ use type_system::url::{BaseUrl, VersionedUrl};
 
 use crate::{
     snapshot::{
-        entity::{EntityEmbeddingRecord, EntitySnapshotRecord},
+        entity::{EntityEmbeddingRecord, EntityPropertyRestrictionRecord, EntitySnapshotRecord},
         ontology::{
             DataTypeEmbeddingRecord, EntityTypeEmbeddingRecord, PropertyTypeEmbeddingRecord,
+            PropertyTypeRestrictionRecord,
         },
         restore::SnapshotRecordBatch,
     },
@@ -108,10 +109,12 @@ pub enum SnapshotEntry {
     DataTypeEmbedding(DataTypeEmbeddingRecord),
     PropertyType(PropertyTypeSnapshotRecord),
     PropertyTypeEmbedding(PropertyTypeEmbeddingRecord),
+    PropertyTypeRestriction(PropertyTypeRestrictionRecord),
     EntityType(EntityTypeSnapshotRecord),
     EntityTypeEmbedding(EntityTypeEmbeddingRecord),
     Entity(EntitySnapshotRecord),
     EntityEmbedding(EntityEmbeddingRecord),
+    EntityPropertyRestriction(EntityPropertyRestrictionRecord),
     Relation(AuthorizationRelation),
 }
 
@@ -206,6 +209,12 @@ impl SnapshotEntry {
                     }
                 }
             }
+            Self::PropertyTypeRestriction(restriction) => {
+                context.push_body(format!(
+                    "property type restriction: {}",
+                    restriction.property_type_base_url
+                ));
+            }
             Self::EntityTypeEmbedding(embedding) => {
                 context.push_body(format!(
                     "entity type embedding: {}",
@@ -229,6 +238,12 @@ impl SnapshotEntry {
                     }
                 }
             }
+            Self::EntityPropertyRestriction(restriction) => {
+                context.push_body(format!(
+                    "entity property restriction: {} on {}",
+                    restriction.property_type_base_url, restriction.entity_uuid
+                ));
+            }
         });
     }
 }
@@ -521,6 +536,61 @@ where
             }))
     }
 
+    async fn create_property_type_restriction_stream(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<SnapshotEntry, SnapshotDumpError>> + Send,
+        SnapshotDumpError,
+    > {
+        Ok(self
+            .acquire()
+            .await
+            .change_context(SnapshotDumpError::Query)?
+            .as_client()
+            .query_raw(
+                "SELECT property_type_base_url FROM restricted_property_types",
+                [] as [&(dyn ToSql + Sync); 0],
+            )
+            .await
+            .change_context(SnapshotDumpError::Query)?
+            .map(|result| result.change_context(SnapshotDumpError::Query))
+            .map_ok(|row| {
+                SnapshotEntry::PropertyTypeRestriction(PropertyTypeRestrictionRecord {
+                    property_type_base_url: BaseUrl::new(row.get(0))
+                        .expect("Invalid base URL returned from Postgres"),
+                })
+            }))
+    }
+
+    async fn create_entity_property_restriction_stream(
+        &self,
+    ) -> Result<
+        impl Stream<Item = Result<SnapshotEntry, SnapshotDumpError>> + Send,
+        SnapshotDumpError,
+    > {
+        Ok(self
+            .acquire()
+            .await
+            .change_context(SnapshotDumpError::Query)?
+            .as_client()
+            .query_raw(
+                "SELECT web_id, entity_uuid, property_type_base_url
+                 FROM restricted_entity_properties",
+                [] as [&(dyn ToSql + Sync); 0],
+            )
+            .await
+            .change_context(SnapshotDumpError::Query)?
+            .map(|result| result.change_context(SnapshotDumpError::Query))
+            .map_ok(|row| {
+                SnapshotEntry::EntityPropertyRestriction(EntityPropertyRestrictionRecord {
+                    web_id: OwnedById::new(row.get(0)),
+                    entity_uuid: row.get(1),
+                    property_type_base_url: BaseUrl::new(row.get(2))
+                        .expect("Invalid base URL returned from Postgres"),
+                })
+            }))
+    }
+
     /// Reads the snapshot from the store into the given sink.
     ///
     /// The sink is expected to be a `futures::Sink` that can be used to write the snapshot entries
@@ -677,6 +747,12 @@ where
                     .forward(snapshot_record_tx.clone()),
             );
 
+            scope.spawn(
+                self.create_property_type_restriction_stream()
+                    .try_flatten_stream()
+                    .forward(snapshot_record_tx.clone()),
+            );
+
             scope.spawn(
                 self.create_entity_type_embedding_stream()
                     .try_flatten_stream()
@@ -689,6 +765,12 @@ where
                     .forward(snapshot_record_tx.clone()),
             );
 
+            scope.spawn(
+                self.create_entity_property_restriction_stream()
+                    .try_flatten_stream()
+                    .forward(snapshot_record_tx.clone()),
+            );
+
             scope.spawn(
                 authorization_api
                     .read_relations::<(EntityUuid, EntityRelationAndSubject)>(
@@ -15,10 +15,10 @@ pub use self::{
     record::{
         DataTypeEmbeddingRecord, DataTypeSnapshotRecord, EntityTypeEmbeddingRecord,
         EntityTypeSnapshotRecord, OntologyTypeSnapshotRecord, PropertyTypeEmbeddingRecord,
-        PropertyTypeSnapshotRecord,
+        PropertyTypeRestrictionRecord, PropertyTypeSnapshotRecord,
     },
     table::{
         DataTypeEmbeddingRow, EntityTypeEmbeddingRow, OntologyExternalMetadataRow, OntologyIdRow,
-        OntologyOwnedMetadataRow, PropertyTypeEmbeddingRow,
+        OntologyOwnedMetadataRow, PropertyTypeEmbeddingRow, PropertyTypeRestrictionRow,
     },
 };
@@ -15,7 +15,7 @@ use crate::{
                 PropertyTypeConstrainsPropertiesOnRow, PropertyTypeConstrainsValuesOnRow,
                 PropertyTypeRow,
             },
-            PropertyTypeEmbeddingRow,
+            PropertyTypeEmbeddingRow, PropertyTypeRestrictionRow,
         },
         WriteBatch,
     },
@@ -28,6 +28,7 @@ pub enum PropertyTypeRowBatch {
     ConstrainsProperties(Vec<PropertyTypeConstrainsPropertiesOnRow>),
     Relations(HashMap<PropertyTypeId, Vec<PropertyTypeRelationAndSubject>>),
     Embeddings(Vec<PropertyTypeEmbeddingRow>),
+    Restrictions(Vec<PropertyTypeRestrictionRow>),
 }
 
 #[async_trait]
@@ -57,6 +58,10 @@ impl<C: AsClient> WriteBatch<C> for PropertyTypeRowBatch {
                     CREATE TEMPORARY TABLE property_type_embeddings_tmp
                         (LIKE property_type_embeddings INCLUDING ALL)
                         ON COMMIT DROP;
+
+                    CREATE TEMPORARY TABLE restricted_property_types_tmp
+                        (LIKE restricted_property_types INCLUDING ALL)
+                        ON COMMIT DROP;
                 ",
             )
             .await
@@ -65,6 +70,7 @@ impl<C: AsClient> WriteBatch<C> for PropertyTypeRowBatch {
         Ok(())
     }
 
+    #[expect(clippy::too_many_lines)]
     async fn write(
         self,
         postgres_client: &PostgresStore<C>,
@@ -155,6 +161,23 @@ impl<C: AsClient> WriteBatch<C> for PropertyTypeRowBatch {
                     tracing::info!("Read {} property type embeddings", rows.len());
                 }
             }
+            Self::Restrictions(restrictions) => {
+                let rows = client
+                    .query(
+                        "
+                            INSERT INTO restricted_property_types_tmp
+                            SELECT DISTINCT * FROM UNNEST($1::restricted_property_types[])
+                            ON CONFLICT DO NOTHING
+                            RETURNING 1;
+                        ",
+                        &[&restrictions],
+                    )
+                    .await
+                    .change_context(InsertionError)?;
+                if !rows.is_empty() {
+                    tracing::info!("Read {} property type restrictions", rows.len());
+                }
+            }
         }
         Ok(())
     }
@@ -194,6 +217,9 @@ impl<C: AsClient> WriteBatch<C> for PropertyTypeRowBatch {
 
                     INSERT INTO property_type_embeddings
                         SELECT * FROM property_type_embeddings_tmp;
+
+                    INSERT INTO restricted_property_types
+                        SELECT * FROM restricted_property_types_tmp;
                 ",
             )
             .await
@@ -21,7 +21,8 @@ use crate::snapshot::{
             PropertyTypeConstrainsPropertiesOnRow, PropertyTypeConstrainsValuesOnRow,
             PropertyTypeRow,
         },
-        OntologyTypeMetadataSender, PropertyTypeEmbeddingRow, PropertyTypeSnapshotRecord,
+        OntologyTypeMetadataSender, PropertyTypeEmbeddingRow, PropertyTypeRestrictionRow,
+        PropertyTypeSnapshotRecord,
     },
     SnapshotRestoreError,
 };
@@ -201,6 +202,7 @@ pub fn property_type_channel(
     chunk_size: usize,
     metadata_sender: OntologyTypeMetadataSender,
     embedding_rx: Receiver<PropertyTypeEmbeddingRow>,
+    restriction_rx: Receiver<PropertyTypeRestrictionRow>,
 ) -> (PropertyTypeSender, PropertyTypeReceiver) {
     let (schema_tx, schema_rx) = mpsc::channel(chunk_size);
     let (constrains_values_tx, constrains_values_rx) = mpsc::channel(chunk_size);
@@ -247,6 +249,10 @@ pub fn property_type_channel(
                     .ready_chunks(chunk_size)
                     .map(PropertyTypeRowBatch::Embeddings)
                     .boxed(),
+                restriction_rx
+                    .ready_chunks(chunk_size)
+                    .map(PropertyTypeRowBatch::Restrictions)
+                    .boxed(),
             ]),
         },
     )
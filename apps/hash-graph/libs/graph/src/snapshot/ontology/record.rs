@@ -4,7 +4,10 @@ use authorization::schema::{
 use graph_types::{ontology::OntologyType, Embedding};
 use serde::{Deserialize, Serialize};
 use temporal_versioning::{Timestamp, TransactionTime};
-use type_system::{url::VersionedUrl, DataType, EntityType, PropertyType};
+use type_system::{
+    url::{BaseUrl, VersionedUrl},
+    DataType, EntityType, PropertyType,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(
@@ -43,6 +46,12 @@ pub struct PropertyTypeEmbeddingRecord {
     pub updated_at_transaction_time: Timestamp<TransactionTime>,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropertyTypeRestrictionRecord {
+    pub property_type_base_url: BaseUrl,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EntityTypeEmbeddingRecord {
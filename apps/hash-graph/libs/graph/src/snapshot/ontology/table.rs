@@ -79,6 +79,12 @@ pub struct PropertyTypeEmbeddingRow {
     pub updated_at_transaction_time: Timestamp<TransactionTime>,
 }
 
+#[derive(Debug, ToSql)]
+#[postgres(name = "restricted_property_types")]
+pub struct PropertyTypeRestrictionRow {
+    pub property_type_base_url: String,
+}
+
 #[derive(Debug, ToSql)]
 #[postgres(name = "property_type_constrains_properties_on_tmp")]
 pub struct PropertyTypeConstrainsPropertiesOnRow {
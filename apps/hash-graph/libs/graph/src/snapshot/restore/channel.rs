@@ -14,10 +14,10 @@ use futures::{
 use graph_types::knowledge::entity::EntityUuid;
 
 use crate::snapshot::{
-    entity::{self, EntityEmbeddingRow, EntitySender},
+    entity::{self, EntityEmbeddingRow, EntityPropertyRestrictionRow, EntitySender},
     ontology::{
         self, DataTypeEmbeddingRow, DataTypeSender, EntityTypeEmbeddingRow, EntityTypeSender,
-        PropertyTypeEmbeddingRow, PropertyTypeSender,
+        PropertyTypeEmbeddingRow, PropertyTypeRestrictionRow, PropertyTypeSender,
     },
     owner,
     owner::{Owner, OwnerSender},
@@ -36,11 +36,13 @@ pub struct SnapshotRecordSender {
     data_type_embedding: Sender<DataTypeEmbeddingRow>,
     property_type: PropertyTypeSender,
     property_type_embedding: Sender<PropertyTypeEmbeddingRow>,
+    property_type_restriction: Sender<PropertyTypeRestrictionRow>,
     entity_type: EntityTypeSender,
     entity_type_embedding: Sender<EntityTypeEmbeddingRow>,
     entity: EntitySender,
     entity_relation: Sender<(EntityUuid, EntityRelationAndSubject)>,
     entity_embedding: Sender<EntityEmbeddingRow>,
+    entity_property_restriction: Sender<EntityPropertyRestrictionRow>,
 }
 
 impl Sink<SnapshotEntry> for SnapshotRecordSender {
@@ -67,6 +69,9 @@ impl Sink<SnapshotEntry> for SnapshotRecordSender {
         ready!(self.property_type_embedding.poll_ready_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not poll property type embedding sender")?;
+        ready!(self.property_type_restriction.poll_ready_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not poll property type restriction sender")?;
         ready!(self.entity_type.poll_ready_unpin(cx))
             .attach_printable("could not poll entity type sender")?;
         ready!(self.entity_type_embedding.poll_ready_unpin(cx))
@@ -80,6 +85,9 @@ impl Sink<SnapshotEntry> for SnapshotRecordSender {
         ready!(self.entity_embedding.poll_ready_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not poll entity embedding sender")?;
+        ready!(self.entity_property_restriction.poll_ready_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not poll entity property restriction sender")?;
 
         Poll::Ready(Ok(()))
     }
@@ -130,6 +138,13 @@ impl Sink<SnapshotEntry> for SnapshotRecordSender {
                 })
                 .change_context(SnapshotRestoreError::Read)
                 .attach_printable("could not send property type embedding"),
+            SnapshotEntry::PropertyTypeRestriction(restriction) => self
+                .property_type_restriction
+                .start_send_unpin(PropertyTypeRestrictionRow {
+                    property_type_base_url: restriction.property_type_base_url.to_string(),
+                })
+                .change_context(SnapshotRestoreError::Read)
+                .attach_printable("could not send property type restriction"),
             SnapshotEntry::EntityType(entity_type) => self
                 .entity_type
                 .start_send_unpin(entity_type)
@@ -168,6 +183,15 @@ impl Sink<SnapshotEntry> for SnapshotRecordSender {
                 })
                 .change_context(SnapshotRestoreError::Read)
                 .attach_printable("could not send entity embedding"),
+            SnapshotEntry::EntityPropertyRestriction(restriction) => self
+                .entity_property_restriction
+                .start_send_unpin(EntityPropertyRestrictionRow {
+                    web_id: restriction.web_id,
+                    entity_uuid: restriction.entity_uuid,
+                    property_type_base_url: restriction.property_type_base_url.to_string(),
+                })
+                .change_context(SnapshotRestoreError::Read)
+                .attach_printable("could not send entity property restriction"),
         }
     }
 
@@ -192,6 +216,9 @@ impl Sink<SnapshotEntry> for SnapshotRecordSender {
         ready!(self.property_type_embedding.poll_flush_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not flush property type embedding sender")?;
+        ready!(self.property_type_restriction.poll_flush_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not flush property type restriction sender")?;
         ready!(self.entity_type.poll_flush_unpin(cx))
             .attach_printable("could not flush entity type sender")?;
         ready!(self.entity_type_embedding.poll_flush_unpin(cx))
@@ -205,6 +232,9 @@ impl Sink<SnapshotEntry> for SnapshotRecordSender {
         ready!(self.entity_embedding.poll_flush_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not flush entity embedding sender")?;
+        ready!(self.entity_property_restriction.poll_flush_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not flush entity property restriction sender")?;
 
         Poll::Ready(Ok(()))
     }
@@ -230,6 +260,9 @@ impl Sink<SnapshotEntry> for SnapshotRecordSender {
         ready!(self.property_type_embedding.poll_close_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not close property type embedding sender")?;
+        ready!(self.property_type_restriction.poll_close_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not close property type restriction sender")?;
         ready!(self.entity_type.poll_close_unpin(cx))
             .attach_printable("could not close entity type sender")?;
         ready!(self.entity_type_embedding.poll_close_unpin(cx))
@@ -243,6 +276,9 @@ impl Sink<SnapshotEntry> for SnapshotRecordSender {
         ready!(self.entity_embedding.poll_close_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not close entity embedding sender")?;
+        ready!(self.entity_property_restriction.poll_close_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not close entity property restriction sender")?;
 
         Poll::Ready(Ok(()))
     }
@@ -279,18 +315,26 @@ pub fn channel(
         data_type_embedding_rx,
     );
     let (property_type_embedding_tx, property_type_embedding_rx) = mpsc::channel(chunk_size);
+    let (property_type_restriction_tx, property_type_restriction_rx) = mpsc::channel(chunk_size);
     let (property_type_tx, property_type_rx) = ontology::property_type_channel(
         chunk_size,
         ontology_metadata_tx.clone(),
         property_type_embedding_rx,
+        property_type_restriction_rx,
     );
     let (entity_type_embedding_tx, entity_type_embedding_rx) = mpsc::channel(chunk_size);
     let (entity_type_tx, entity_type_rx) =
         ontology::entity_type_channel(chunk_size, ontology_metadata_tx, entity_type_embedding_rx);
     let (entity_relation_tx, entity_relation_rx) = mpsc::channel(chunk_size);
     let (entity_embedding_tx, entity_embedding_rx) = mpsc::channel(chunk_size);
-    let (entity_tx, entity_rx) =
-        entity::channel(chunk_size, entity_relation_rx, entity_embedding_rx);
+    let (entity_property_restriction_tx, entity_property_restriction_rx) =
+        mpsc::channel(chunk_size);
+    let (entity_tx, entity_rx) = entity::channel(
+        chunk_size,
+        entity_relation_rx,
+        entity_embedding_rx,
+        entity_property_restriction_rx,
+    );
 
     (
         SnapshotRecordSender {
@@ -301,11 +345,13 @@ pub fn channel(
             data_type_embedding: data_type_embedding_tx,
             property_type: property_type_tx,
             property_type_embedding: property_type_embedding_tx,
+            property_type_restriction: property_type_restriction_tx,
             entity_type: entity_type_tx,
             entity_type_embedding: entity_type_embedding_tx,
             entity: entity_tx,
             entity_relation: entity_relation_tx,
             entity_embedding: entity_embedding_tx,
+            entity_property_restriction: entity_property_restriction_tx,
         },
         SnapshotRecordReceiver {
             stream: select_all(vec![
@@ -5,9 +5,10 @@ use graph_types::{
     account::{AccountGroupId, AccountId},
     owned_by_id::OwnedById,
 };
+use postgres_types::{FromSql, ToSql};
 use serde::{Deserialize, Serialize};
 
-use crate::store::{InsertionError, QueryError};
+use crate::store::{error::DeletionError, InsertionError, QueryError};
 
 fn random_account_id() -> AccountId {
     AccountId::new(uuid::Uuid::new_v4())
@@ -41,6 +42,36 @@ pub struct InsertWebIdParams {
     pub owner: WebOwnerSubject,
 }
 
+/// The requests a service account is allowed to perform.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize, ToSql, FromSql)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+#[postgres(name = "service_account_scope")]
+pub enum ServiceAccountScope {
+    /// Only requests which don't modify the graph are allowed.
+    #[postgres(name = "read")]
+    Read,
+    /// All requests are allowed.
+    #[postgres(name = "write")]
+    Write,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct InsertServiceAccountParams {
+    #[serde(default = "random_account_id")]
+    pub account_id: AccountId,
+    pub scope: ServiceAccountScope,
+}
+
+/// An account which is used by another service to authenticate against the graph.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ServiceAccount {
+    pub account_id: AccountId,
+    pub scope: ServiceAccountScope,
+}
+
 /// Describes the API of a store implementation for accounts.
 #[async_trait]
 pub trait AccountStore {
@@ -90,4 +121,42 @@ pub trait AccountStore {
         &self,
         owned_by_id: OwnedById,
     ) -> Result<WebOwnerSubject, QueryError>;
+
+    /// Inserts a service account, which authenticates with a token hashing to `token_hash`.
+    ///
+    /// The service account is an account on its own, so it does not have any permissions until
+    /// they are granted to it.
+    ///
+    /// # Errors
+    ///
+    /// - if insertion failed, e.g. because the [`AccountId`] or the token already exists.
+    async fn insert_service_account(
+        &mut self,
+        actor_id: AccountId,
+        params: InsertServiceAccountParams,
+        token_hash: &[u8],
+    ) -> Result<(), InsertionError>;
+
+    /// Returns the service account which authenticates with a token hashing to `token_hash`.
+    ///
+    /// # Errors
+    ///
+    /// - if reading the service account failed
+    async fn get_service_account(
+        &self,
+        token_hash: &[u8],
+    ) -> Result<Option<ServiceAccount>, QueryError>;
+
+    /// Revokes the token of the specified service account.
+    ///
+    /// The account itself is kept, so relationships referring to it stay valid. Checking whether
+    /// the actor is allowed to revoke the service account is up to the caller.
+    ///
+    /// # Errors
+    ///
+    /// - if the service account does not exist, [`StatusCode::NotFound`] is attached
+    /// - if deleting the token failed
+    ///
+    /// [`StatusCode::NotFound`]: hash_status::StatusCode::NotFound
+    async fn revoke_service_account(&mut self, account_id: AccountId) -> Result<(), DeletionError>;
 }
@@ -39,19 +39,25 @@ use type_system::{
 use crate::{
     ontology::domain_validator::DomainValidator,
     store::{
-        account::{InsertAccountGroupIdParams, InsertAccountIdParams, InsertWebIdParams},
+        account::{
+            InsertAccountGroupIdParams, InsertAccountIdParams, InsertServiceAccountParams,
+            InsertWebIdParams, ServiceAccount,
+        },
         crud::{QueryResult, Read, ReadPaginated, Sorting},
+        error::DeletionError,
         knowledge::{
-            CreateEntityParams, EntityQueryCursor, GetEntityParams, UpdateEntityEmbeddingsParams,
-            UpdateEntityParams, ValidateEntityError, ValidateEntityParams,
+            CreateEntityParams, EntityQueryCursor, GetEntityParams,
+            SetEntityPropertyRestrictionParams, UpdateEntityEmbeddingsParams, UpdateEntityParams,
+            ValidateEntityError, ValidateEntityParams,
         },
         ontology::{
             ArchiveDataTypeParams, ArchiveEntityTypeParams, ArchivePropertyTypeParams,
             CreateDataTypeParams, CreateEntityTypeParams, CreatePropertyTypeParams,
             GetDataTypesParams, GetEntityTypesParams, GetPropertyTypesParams,
-            UnarchiveDataTypeParams, UnarchiveEntityTypeParams, UnarchivePropertyTypeParams,
-            UpdateDataTypeEmbeddingParams, UpdateDataTypesParams, UpdateEntityTypeEmbeddingParams,
-            UpdateEntityTypesParams, UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
+            SetPropertyTypeRestrictionParams, UnarchiveDataTypeParams, UnarchiveEntityTypeParams,
+            UnarchivePropertyTypeParams, UpdateDataTypeEmbeddingParams, UpdateDataTypesParams,
+            UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
+            UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
         },
         query::{Filter, OntologyQueryPath},
         AccountStore, ConflictBehavior, DataTypeStore, EntityStore, EntityTypeStore,
@@ -388,7 +394,19 @@ where
                     .fetch_ontology_types(context::current(), ontology_urls)
                     .await
                     .change_context(StoreError)?
-                    .change_context(StoreError)?
+                    .map_err(|remote| {
+                        remote.into_report().map_or_else(
+                            // `into_report` only fails if the type fetcher did not send any
+                            // frames, the fetcher server always sends at least one, even if
+                            // serializing fails.
+                            || {
+                                Report::new(StoreError).attach_printable(
+                                    "the type fetcher returned an error report without any frames",
+                                )
+                            },
+                            |report| report.change_context(StoreError),
+                        )
+                    })?
             };
 
             for (ontology_type, fetched_at) in ontology_types {
@@ -857,6 +875,28 @@ where
     ) -> Result<WebOwnerSubject, QueryError> {
         self.store.identify_owned_by_id(owned_by_id).await
     }
+
+    async fn insert_service_account(
+        &mut self,
+        actor_id: AccountId,
+        params: InsertServiceAccountParams,
+        token_hash: &[u8],
+    ) -> Result<(), InsertionError> {
+        self.store
+            .insert_service_account(actor_id, params, token_hash)
+            .await
+    }
+
+    async fn get_service_account(
+        &self,
+        token_hash: &[u8],
+    ) -> Result<Option<ServiceAccount>, QueryError> {
+        self.store.get_service_account(token_hash).await
+    }
+
+    async fn revoke_service_account(&mut self, account_id: AccountId) -> Result<(), DeletionError> {
+        self.store.revoke_service_account(account_id).await
+    }
 }
 
 impl<S, A> DataTypeStore for FetchingStore<S, A>
@@ -1089,6 +1129,17 @@ where
             .await
     }
 
+    async fn set_property_type_restriction<Au: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut Au,
+        params: SetPropertyTypeRestrictionParams<'_>,
+    ) -> Result<(), UpdateError> {
+        self.store
+            .set_property_type_restriction(actor_id, authorization_api, params)
+            .await
+    }
+
     async fn update_property_type_embeddings<Au: AuthorizationApi + Send + Sync>(
         &mut self,
         actor_id: AccountId,
@@ -1335,6 +1386,17 @@ where
             .await
     }
 
+    async fn set_entity_property_restriction<Au: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut Au,
+        params: SetEntityPropertyRestrictionParams,
+    ) -> Result<(), UpdateError> {
+        self.store
+            .set_entity_property_restriction(actor_id, authorization_api, params)
+            .await
+    }
+
     async fn update_entity_embeddings<Au: AuthorizationApi + Send + Sync>(
         &mut self,
         actor_id: AccountId,
@@ -13,7 +13,10 @@ use graph_types::{
 use serde::{Deserialize, Serialize};
 use temporal_client::TemporalClient;
 use temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
-use type_system::{url::VersionedUrl, EntityType};
+use type_system::{
+    url::{BaseUrl, VersionedUrl},
+    EntityType,
+};
 #[cfg(feature = "utoipa")]
 use utoipa::{
     openapi,
@@ -236,6 +239,15 @@ pub struct UpdateEntityEmbeddingsParams<'e> {
     pub reset: bool,
 }
 
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SetEntityPropertyRestrictionParams {
+    pub entity_id: EntityId,
+    pub property_type_base_url: BaseUrl,
+    pub restricted: bool,
+}
+
 /// Describes the API of a store implementation for [Entities].
 ///
 /// [Entities]: Entity
@@ -309,9 +321,14 @@ pub trait EntityStore: crud::ReadPaginated<Entity> {
 
     /// Get the [`Subgraph`]s specified by the [`StructuralQuery`].
     ///
+    /// Restricted properties are removed from the returned entities unless the actor is permitted
+    /// to view them. Entities are not returned if the query filters or sorts by one of their
+    /// restricted properties the actor is not permitted to view.
+    ///
     /// # Errors
     ///
     /// - if the requested [`Entity`] doesn't exist
+    /// - if the query refers to a restricted property of linked entities
     ///
     /// [`StructuralQuery`]: crate::subgraph::query::StructuralQuery
     fn get_entity<A: AuthorizationApi + Sync>(
@@ -325,6 +342,9 @@ pub trait EntityStore: crud::ReadPaginated<Entity> {
 
     /// Update an existing [`Entity`].
     ///
+    /// If the actor is not permitted to view the restricted properties of the [`Entity`], the
+    /// restricted properties which are missing in the update are kept.
+    ///
     /// # Errors
     ///
     /// - if the [`Entity`] doesn't exist
@@ -343,6 +363,22 @@ pub trait EntityStore: crud::ReadPaginated<Entity> {
         params: UpdateEntityParams,
     ) -> impl Future<Output = Result<EntityMetadata, Report<UpdateError>>> + Send;
 
+    /// Marks a property of an existing [`Entity`] as restricted or unrestricted.
+    ///
+    /// Restricted properties are redacted when reading the [`Entity`] unless the actor is
+    /// permitted to view them.
+    ///
+    /// # Errors
+    ///
+    /// - if the [`Entity`] or the property type doesn't exist
+    /// - if the actor does not have full access to the [`Entity`]
+    fn set_entity_property_restriction<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        params: SetEntityPropertyRestrictionParams,
+    ) -> impl Future<Output = Result<(), Report<UpdateError>>> + Send;
+
     fn update_entity_embeddings<A: AuthorizationApi + Send + Sync>(
         &mut self,
         actor_id: AccountId,
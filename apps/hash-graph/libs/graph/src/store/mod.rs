@@ -34,7 +34,10 @@ pub use self::{
     migration::{Migration, MigrationState, StoreMigration},
     ontology::{DataTypeStore, EntityTypeStore, PropertyTypeStore},
     pool::StorePool,
-    postgres::{AsClient, PostgresStore, PostgresStorePool},
+    postgres::{
+        AsClient, AuthorizationDiscrepancies, ExpiredRelationships, PostgresStore,
+        PostgresStorePool, RelationshipBackfill, RelationshipBackfills, RelationshipDiscrepancies,
+    },
     record::{QueryRecord, SubgraphRecord},
     validation::{StoreCache, StoreProvider},
 };
@@ -252,6 +252,15 @@ pub struct UnarchivePropertyTypeParams<'a> {
     pub property_type_id: Cow<'a, VersionedUrl>,
 }
 
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SetPropertyTypeRestrictionParams<'a> {
+    #[serde(borrow)]
+    pub property_type_id: Cow<'a, VersionedUrl>,
+    pub restricted: bool,
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -369,6 +378,22 @@ pub trait PropertyTypeStore {
         params: UnarchivePropertyTypeParams<'_>,
     ) -> impl Future<Output = Result<OntologyTemporalMetadata, UpdateError>> + Send;
 
+    /// Marks the properties of an existing [`PropertyType`] as restricted or unrestricted.
+    ///
+    /// The restriction applies to all versions of the [`PropertyType`]. Restricted properties
+    /// are redacted when reading entities unless the actor is permitted to view them.
+    ///
+    /// # Errors
+    ///
+    /// - if the [`PropertyType`] doesn't exist.
+    /// - if the actor is not permitted to update the [`PropertyType`].
+    fn set_property_type_restriction<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        params: SetPropertyTypeRestrictionParams<'_>,
+    ) -> impl Future<Output = Result<(), UpdateError>> + Send;
+
     fn update_property_type_embeddings<A: AuthorizationApi + Send + Sync>(
         &mut self,
         actor_id: AccountId,
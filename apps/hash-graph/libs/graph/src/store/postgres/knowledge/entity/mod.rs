@@ -5,6 +5,7 @@ use std::{
     borrow::Cow,
     collections::{HashMap, HashSet},
     iter::once,
+    mem,
 };
 
 use authorization::{
@@ -51,14 +52,15 @@ use crate::{
         error::{DeletionError, EntityDoesNotExist, RaceConditionOnUpdate},
         knowledge::{
             CreateEntityParams, EntityQueryCursor, EntityQuerySorting, EntityValidationType,
-            GetEntityParams, UpdateEntityEmbeddingsParams, UpdateEntityParams, ValidateEntityError,
-            ValidateEntityParams,
+            GetEntityParams, SetEntityPropertyRestrictionParams, UpdateEntityEmbeddingsParams,
+            UpdateEntityParams, ValidateEntityError, ValidateEntityParams,
         },
         postgres::{
-            knowledge::entity::read::EntityEdgeTraversalData, query::ReferenceTable,
+            knowledge::entity::read::{EntityEdgeTraversalData, PropertyReferences},
+            query::ReferenceTable,
             TraversalContext,
         },
-        query::{Filter, FilterExpression, Parameter},
+        query::{Filter, FilterExpression, Parameter, ParameterList},
         validation::StoreProvider,
         AsClient, EntityStore, InsertionError, PostgresStore, QueryError, StoreCache,
         SubgraphRecord, UpdateError,
@@ -75,6 +77,15 @@ use crate::{
     },
 };
 
+/// The maximum number of viewable entities which are added to the filter of an entity query.
+///
+/// Public entities are viewable by everyone, so the number of viewable entities grows with the
+/// graph. If an actor can view more entities, the pages of the query are checked instead.
+///
+/// The limit is kept small, so an exceeded lookup is cheap compared to checking the pages. After
+/// the limit was exceeded, the authorization API may skip the lookup for a while.
+const MAX_FILTERED_VIEWABLE_ENTITIES: usize = 1_000;
+
 impl<C: AsClient> PostgresStore<C> {
     /// Internal method to read an [`Entity`] into a [`TraversalContext`].
     ///
@@ -290,6 +301,7 @@ impl<C: AsClient> PostgresStore<C> {
                     DELETE FROM entity_editions;
                     DELETE FROM entity_embeddings;
                     DELETE FROM entity_drafts;
+                    DELETE FROM restricted_entity_properties;
                     DELETE FROM entity_ids;
                 ",
             )
@@ -916,6 +928,43 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
         let temporal_axes = unresolved_temporal_axes.clone().resolve();
         let time_axis = temporal_axes.variable_time_axis();
 
+        let property_references = PropertyReferences::new(&query.filter, &params.sorting);
+        self.reject_restricted_linked_properties(&property_references)
+            .await?;
+
+        let (permitted_ids, lookup_zookie) = authorization_api
+            .lookup_entities(
+                actor_id,
+                EntityPermission::View,
+                MAX_FILTERED_VIEWABLE_ENTITIES,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        // The permitted entities are part of the filter, so the limit and the cursor are applied to
+        // the entities the actor is allowed to view. If the lookup is not restricted, e.g. if
+        // authorization is disabled or the actor can view too many entities, every page is checked
+        // instead.
+        let check_pages = permitted_ids.is_none();
+        if let Some(permitted_ids) = permitted_ids {
+            let filter = mem::replace(&mut query.filter, Filter::All(Vec::new()));
+            query.filter = Filter::All(vec![
+                filter,
+                Filter::In(
+                    FilterExpression::Path(EntityQueryPath::Uuid),
+                    ParameterList::Uuid(Cow::Owned(
+                        permitted_ids
+                            .into_iter()
+                            .map(EntityUuid::into_uuid)
+                            .collect(),
+                    )),
+                ),
+            ]);
+        }
+
+        // Entities hidden by restricted properties are removed from a page, so further pages are
+        // read until the limit is reached.
         let mut root_entities = Vec::new();
 
         let (latest_zookie, last) = loop {
@@ -952,26 +1001,54 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
                 .map(|(entity, _)| entity.metadata.record_id.entity_id)
                 .collect::<HashSet<_>>();
 
-            let (permissions, zookie) = authorization_api
-                .check_entities_permission(
-                    actor_id,
-                    EntityPermission::View,
-                    filtered_ids,
-                    Consistency::FullyConsistent,
+            let (permitted_ids, zookie) = if check_pages {
+                let (permissions, zookie) = authorization_api
+                    .check_entities_permission(
+                        actor_id,
+                        EntityPermission::View,
+                        filtered_ids,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(QueryError)?;
+
+                let permitted_ids = permissions
+                    .into_iter()
+                    .filter_map(|(entity_id, has_permission)| has_permission.then_some(entity_id))
+                    .collect::<HashSet<_>>();
+                (permitted_ids, zookie)
+            } else {
+                (
+                    filtered_ids
+                        .into_iter()
+                        .map(|entity_id| entity_id.entity_uuid)
+                        .collect(),
+                    lookup_zookie.clone(),
                 )
-                .await
-                .change_context(QueryError)?;
+            };
 
-            let permitted_ids = permissions
-                .into_iter()
-                .filter_map(|(entity_id, has_permission)| has_permission.then_some(entity_id))
-                .collect::<HashSet<_>>();
+            // Entities which are filtered or sorted by a restricted property the actor is not
+            // allowed to view are not returned, as this would reveal the restricted value.
+            let hidden_ids = self
+                .find_hidden_property_references(
+                    entities
+                        .iter()
+                        .map(|(entity, _)| entity.metadata.record_id.entity_id)
+                        .filter(|entity_id| permitted_ids.contains(&entity_id.entity_uuid))
+                        .collect(),
+                    &property_references,
+                    actor_id,
+                    authorization_api,
+                    &zookie,
+                )
+                .await?;
 
             root_entities.extend(
                 entities
                     .into_iter()
                     .filter(|(entity, _)| {
-                        permitted_ids.contains(&entity.metadata.record_id.entity_id.entity_uuid)
+                        let entity_uuid = entity.metadata.record_id.entity_id.entity_uuid;
+                        permitted_ids.contains(&entity_uuid) && !hidden_ids.contains(&entity_uuid)
                     })
                     .take(params.limit.unwrap_or(usize::MAX) - root_entities.len()),
             );
@@ -1042,6 +1119,14 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             .read_traversed_vertices(self, &mut subgraph, query.include_drafts)
             .await?;
 
+        self.redact_restricted_properties(
+            &mut subgraph,
+            actor_id,
+            authorization_api,
+            &latest_zookie,
+        )
+        .await?;
+
         Ok((subgraph, last))
     }
 
@@ -1132,6 +1217,16 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
             .draft_id
             .is_some();
 
+        transaction
+            .restore_restricted_properties(
+                actor_id,
+                authorization_api,
+                &previous_entity,
+                &mut params.properties,
+            )
+            .await
+            .change_context(UpdateError)?;
+
         let (edition_id, closed_schema) = transaction
             .insert_entity_edition(
                 EditionCreatedById::new(actor_id),
@@ -1296,6 +1391,58 @@ impl<C: AsClient> EntityStore for PostgresStore<C> {
         Ok(entity_metadata)
     }
 
+    #[tracing::instrument(level = "info", skip(self, authorization_api))]
+    async fn set_entity_property_restriction<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        params: SetEntityPropertyRestrictionParams,
+    ) -> Result<(), UpdateError> {
+        authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::FullAccess,
+                params.entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
+
+        let query = if params.restricted {
+            "
+                INSERT INTO restricted_entity_properties (
+                    web_id,
+                    entity_uuid,
+                    property_type_base_url
+                ) VALUES ($1, $2, $3)
+                ON CONFLICT DO NOTHING;
+            "
+        } else {
+            "
+                DELETE FROM restricted_entity_properties
+                WHERE web_id = $1
+                  AND entity_uuid = $2
+                  AND property_type_base_url = $3;
+            "
+        };
+
+        self.as_client()
+            .query(
+                query,
+                &[
+                    &params.entity_id.owned_by_id,
+                    &params.entity_id.entity_uuid,
+                    &params.property_type_base_url.as_str(),
+                ],
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "info", skip(self, params))]
     async fn update_entity_embeddings<A: AuthorizationApi + Send + Sync>(
         &mut self,
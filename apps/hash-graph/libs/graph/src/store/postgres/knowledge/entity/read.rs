@@ -1,10 +1,22 @@
-use std::{borrow::Cow, mem::swap};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    fmt,
+    mem::swap,
+};
 
-use error_stack::{Result, ResultExt};
+use authorization::{
+    schema::EntityPermission,
+    zanzibar::{Consistency, Zookie},
+    AuthorizationApi,
+};
+use error_stack::{ensure, Context, Report, Result, ResultExt};
 use graph_types::{
-    knowledge::entity::{EntityEditionId, EntityId, EntityUuid},
+    account::AccountId,
+    knowledge::entity::{Entity, EntityEditionId, EntityId, EntityProperties, EntityUuid},
     owned_by_id::OwnedById,
 };
+use hash_status::StatusCode;
 use temporal_versioning::{
     LeftClosedTemporalInterval, RightBoundedTemporalInterval, TemporalTagged, TimeAxis, Timestamp,
 };
@@ -12,20 +24,223 @@ use tokio_postgres::GenericClient;
 use type_system::url::BaseUrl;
 
 use crate::{
+    knowledge::EntityQueryPath,
     store::{
         postgres::{
             ontology::OntologyId,
             query::{ForeignKeyReference, ReferenceTable, Table, Transpile},
         },
-        AsClient, PostgresStore, QueryError,
+        query::{Filter, PathToken},
+        AsClient, EntityQuerySorting, PostgresStore, QueryError,
     },
     subgraph::{
         edges::{EdgeDirection, GraphResolveDepths},
         identifier::{EntityTypeVertexId, EntityVertexId},
         temporal_axes::{PinnedAxis, VariableAxis},
+        Subgraph,
     },
 };
 
+/// The globally restricted property types and the properties restricted per entity.
+type RestrictedProperties = (HashSet<BaseUrl>, HashMap<EntityUuid, HashSet<BaseUrl>>);
+
+/// Properties of entities which are referenced by the filter or the sorting of a query.
+#[derive(Debug, Default)]
+struct ReferencedProperties {
+    /// Set if the whole properties object or the embedding of the entities is referenced.
+    all: bool,
+    base_urls: HashSet<BaseUrl>,
+}
+
+impl ReferencedProperties {
+    fn insert(&mut self, path: &EntityQueryPath<'_>) {
+        match path {
+            EntityQueryPath::Properties(None) | EntityQueryPath::Embedding => self.all = true,
+            EntityQueryPath::Properties(Some(path)) => {
+                // The keys of the properties are base URLs, including the keys of nested property
+                // objects, other fields don't refer to a property.
+                for token in path.path_tokens() {
+                    if let PathToken::Field(field) = token {
+                        if let Ok(base_url) = BaseUrl::new(field.as_ref().to_owned()) {
+                            self.base_urls.insert(base_url);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        !self.all && self.base_urls.is_empty()
+    }
+
+    fn contains(&self, base_url: &BaseUrl) -> bool {
+        self.all || self.base_urls.contains(base_url)
+    }
+}
+
+/// Returns the base URLs of the properties which are restricted for an entity.
+fn restricted_base_urls<'u>(
+    restricted_property_types: &'u HashSet<BaseUrl>,
+    restricted_entity_properties: Option<&'u HashSet<BaseUrl>>,
+) -> HashSet<&'u str> {
+    restricted_property_types
+        .iter()
+        .chain(restricted_entity_properties.into_iter().flatten())
+        .map(BaseUrl::as_str)
+        .collect()
+}
+
+/// Returns whether `value` contains one of the `restricted` properties in a nested property
+/// object.
+fn contains_restricted_property(value: &serde_json::Value, restricted: &HashSet<&str>) -> bool {
+    match value {
+        serde_json::Value::Object(object) => object.iter().any(|(key, value)| {
+            restricted.contains(key.as_str()) || contains_restricted_property(value, restricted)
+        }),
+        serde_json::Value::Array(values) => values
+            .iter()
+            .any(|value| contains_restricted_property(value, restricted)),
+        _ => false,
+    }
+}
+
+/// Removes the `restricted` properties from all property objects nested in `value`.
+fn remove_restricted_properties(value: &mut serde_json::Value, restricted: &HashSet<&str>) {
+    match value {
+        serde_json::Value::Object(object) => {
+            object.retain(|key, _| !restricted.contains(key.as_str()));
+            for value in object.values_mut() {
+                remove_restricted_properties(value, restricted);
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                remove_restricted_properties(value, restricted);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// An array containing restricted properties was modified, so the restricted properties can't be
+/// assigned to its elements anymore.
+#[derive(Debug)]
+struct RestrictedArrayModified;
+
+impl fmt::Display for RestrictedArrayModified {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(
+            "an array containing restricted properties can only be modified with the permission \
+             to view the restricted properties",
+        )
+    }
+}
+
+impl Context for RestrictedArrayModified {}
+
+/// Returns `value` without the `restricted` properties nested in it.
+fn without_restricted_properties(
+    value: &serde_json::Value,
+    restricted: &HashSet<&str>,
+) -> serde_json::Value {
+    let mut value = value.clone();
+    remove_restricted_properties(&mut value, restricted);
+    value
+}
+
+/// Adds the `restricted` properties of the property objects nested in `previous` which are missing
+/// in the corresponding property objects nested in `value`.
+///
+/// Array elements don't have an identity, so the elements of an array containing restricted
+/// properties must not be added, removed, or reordered. Elements containing restricted properties
+/// must not be changed either, apart from the restricted properties themselves. Otherwise, the
+/// restricted properties would be assigned to the wrong elements or dropped.
+///
+/// Returns whether a property was added.
+fn restore_nested_restricted_properties(
+    previous: &serde_json::Value,
+    value: &mut serde_json::Value,
+    restricted: &HashSet<&str>,
+) -> Result<bool, RestrictedArrayModified> {
+    let mut restored = false;
+    match (previous, value) {
+        (serde_json::Value::Object(previous), serde_json::Value::Object(object)) => {
+            for (key, previous_value) in previous {
+                if let Some(value) = object.get_mut(key) {
+                    restored |=
+                        restore_nested_restricted_properties(previous_value, value, restricted)?;
+                } else if restricted.contains(key.as_str()) {
+                    object.insert(key.clone(), previous_value.clone());
+                    restored = true;
+                }
+            }
+        }
+        (serde_json::Value::Array(previous), serde_json::Value::Array(values))
+            if previous
+                .iter()
+                .any(|previous_value| contains_restricted_property(previous_value, restricted)) =>
+        {
+            ensure!(
+                previous.len() == values.len(),
+                Report::new(RestrictedArrayModified)
+                    .attach_printable("elements were added or removed")
+            );
+            for (index, (previous_value, value)) in previous.iter().zip(values).enumerate() {
+                ensure!(
+                    !contains_restricted_property(previous_value, restricted)
+                        || without_restricted_properties(previous_value, restricted)
+                            == without_restricted_properties(value, restricted),
+                    Report::new(RestrictedArrayModified)
+                        .attach_printable(format!("element {index} was changed or moved"))
+                );
+                restored |=
+                    restore_nested_restricted_properties(previous_value, value, restricted)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(restored)
+}
+
+/// The properties which are referenced by the filter or the sorting of an entity query.
+#[derive(Debug, Default)]
+pub(crate) struct PropertyReferences {
+    /// Properties of the queried entities.
+    queried: ReferencedProperties,
+    /// Properties of entities linked to the queried entities.
+    linked: ReferencedProperties,
+}
+
+impl PropertyReferences {
+    pub(crate) fn new(filter: &Filter<'_, Entity>, sorting: &EntityQuerySorting<'_>) -> Self {
+        let mut references = Self::default();
+        for path in filter.paths() {
+            references.insert(path);
+        }
+        for record in &sorting.paths {
+            references.insert(&record.path);
+        }
+        references
+    }
+
+    fn insert(&mut self, path: &EntityQueryPath<'_>) {
+        if let EntityQueryPath::EntityEdge { path, .. } = path {
+            let mut path = path.as_ref();
+            while let EntityQueryPath::EntityEdge {
+                path: linked_path, ..
+            } = path
+            {
+                path = linked_path;
+            }
+            self.linked.insert(path);
+        } else {
+            self.queried.insert(path);
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct EntityEdgeTraversalData {
     owned_by_ids: Vec<OwnedById>,
@@ -283,4 +498,347 @@ impl<C: AsClient> PostgresStore<C> {
                 )
             }))
     }
+
+    /// Reads the restricted properties of the given entities.
+    ///
+    /// A property is restricted for an entity if its property type is restricted globally or if
+    /// it was restricted on the entity itself.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) async fn read_restricted_properties(
+        &self,
+        entity_ids: &[(OwnedById, EntityUuid)],
+    ) -> Result<RestrictedProperties, QueryError> {
+        let restricted_property_types = self
+            .client
+            .as_client()
+            .query(
+                "
+                    SELECT property_type_base_url
+                    FROM restricted_property_types;
+                ",
+                &[],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| BaseUrl::new(row.get(0)).change_context(QueryError))
+            .collect::<Result<_, _>>()?;
+
+        let (owned_by_ids, entity_uuids): (Vec<_>, Vec<_>) = entity_ids.iter().copied().unzip();
+        let mut restricted_entity_properties = HashMap::<_, HashSet<_>>::new();
+        for row in self
+            .client
+            .as_client()
+            .query(
+                "
+                    SELECT restricted.entity_uuid, restricted.property_type_base_url
+                    FROM unnest($1::uuid[], $2::uuid[]) AS filter(web_id, entity_uuid)
+                    JOIN restricted_entity_properties AS restricted
+                      ON restricted.web_id = filter.web_id
+                     AND restricted.entity_uuid = filter.entity_uuid;
+                ",
+                &[&owned_by_ids, &entity_uuids],
+            )
+            .await
+            .change_context(QueryError)?
+        {
+            restricted_entity_properties
+                .entry(row.get(0))
+                .or_default()
+                .insert(BaseUrl::new(row.get(1)).change_context(QueryError)?);
+        }
+
+        Ok((restricted_property_types, restricted_entity_properties))
+    }
+
+    /// Rejects queries which refer to restricted properties of linked entities.
+    ///
+    /// The linked entities are only known after the query was executed, so it cannot be checked
+    /// beforehand if the actor is allowed to view the compared values.
+    #[tracing::instrument(level = "trace", skip(self))]
+    pub(crate) async fn reject_restricted_linked_properties(
+        &self,
+        references: &PropertyReferences,
+    ) -> Result<(), QueryError> {
+        if references.linked.is_empty() {
+            return Ok(());
+        }
+
+        for row in self
+            .client
+            .as_client()
+            .query(
+                "
+                    SELECT property_type_base_url FROM restricted_property_types
+                    UNION
+                    SELECT property_type_base_url FROM restricted_entity_properties;
+                ",
+                &[],
+            )
+            .await
+            .change_context(QueryError)?
+        {
+            let base_url = BaseUrl::new(row.get(0)).change_context(QueryError)?;
+            if references.linked.contains(&base_url) {
+                return Err(Report::new(QueryError)
+                    .attach(StatusCode::InvalidArgument)
+                    .attach_printable(format!(
+                        "The restricted property `{base_url}` of linked entities cannot be used \
+                         in a query"
+                    )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns the entities for which the `references` include a restricted property the actor is
+    /// not allowed to view.
+    ///
+    /// These entities have to be removed from the result, as otherwise the restricted values could
+    /// be inferred from whether and in which order the entities are returned.
+    #[tracing::instrument(level = "trace", skip(self, authorization_api, zookie))]
+    pub(crate) async fn find_hidden_property_references<A: AuthorizationApi + Sync>(
+        &self,
+        entity_ids: Vec<EntityId>,
+        references: &PropertyReferences,
+        actor_id: AccountId,
+        authorization_api: &A,
+        zookie: &Zookie<'static>,
+    ) -> Result<HashSet<EntityUuid>, QueryError> {
+        if references.queried.is_empty() || entity_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let (restricted_property_types, restricted_entity_properties) = self
+            .read_restricted_properties(
+                &entity_ids
+                    .iter()
+                    .map(|entity_id| (entity_id.owned_by_id, entity_id.entity_uuid))
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+        let references_restricted_type = restricted_property_types
+            .iter()
+            .any(|base_url| references.queried.contains(base_url));
+        let restricted_ids = entity_ids
+            .into_iter()
+            .filter(|entity_id| {
+                references_restricted_type
+                    || restricted_entity_properties
+                        .get(&entity_id.entity_uuid)
+                        .is_some_and(|properties| {
+                            properties
+                                .iter()
+                                .any(|base_url| references.queried.contains(base_url))
+                        })
+            })
+            .collect::<Vec<_>>();
+        if restricted_ids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let (permissions, _) = authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::ViewRestrictedProperties,
+                restricted_ids.iter().copied(),
+                Consistency::AtExactSnapshot(zookie),
+            )
+            .await
+            .change_context(QueryError)?;
+
+        Ok(restricted_ids
+            .into_iter()
+            .map(|entity_id| entity_id.entity_uuid)
+            .filter(|entity_uuid| !permissions.get(entity_uuid).copied().unwrap_or(false))
+            .collect())
+    }
+
+    /// Adds the restricted properties of `previous_entity` which are missing in `properties` if
+    /// the actor does not have [`EntityPermission::ViewRestrictedProperties`].
+    ///
+    /// The restricted properties are redacted when the actor reads the entity, so an update based
+    /// on the read entity would remove them otherwise. This includes restricted properties in
+    /// nested property objects. Restricted properties which are passed in `properties` still
+    /// replace the previous values.
+    ///
+    /// # Errors
+    ///
+    /// - [`PermissionDenied`] if the actor does not have
+    ///   [`EntityPermission::ViewRestrictedProperties`] and added, removed, reordered, or changed
+    ///   the elements of an array containing restricted properties, as they cannot be restored in
+    ///   that case
+    ///
+    /// [`PermissionDenied`]: StatusCode::PermissionDenied
+    #[tracing::instrument(
+        level = "trace",
+        skip(self, authorization_api, previous_entity, properties)
+    )]
+    pub(crate) async fn restore_restricted_properties<A: AuthorizationApi + Sync>(
+        &self,
+        actor_id: AccountId,
+        authorization_api: &A,
+        previous_entity: &Entity,
+        properties: &mut EntityProperties,
+    ) -> Result<(), QueryError> {
+        let entity_id = previous_entity.metadata.record_id.entity_id;
+        let (restricted_property_types, restricted_entity_properties) = self
+            .read_restricted_properties(&[(entity_id.owned_by_id, entity_id.entity_uuid)])
+            .await?;
+        let restricted = restricted_base_urls(
+            &restricted_property_types,
+            restricted_entity_properties.get(&entity_id.entity_uuid),
+        );
+
+        let mut restored_properties = properties.clone();
+        let mut restored = false;
+        let mut modified_array = None;
+        for (base_url, previous_value) in previous_entity.properties.properties() {
+            if let Some(mut value) = restored_properties.remove(base_url) {
+                match restore_nested_restricted_properties(previous_value, &mut value, &restricted)
+                {
+                    Ok(nested_restored) => restored |= nested_restored,
+                    Err(report) => {
+                        let report = report.attach_printable(base_url.clone());
+                        match &mut modified_array {
+                            None => modified_array = Some(report),
+                            Some(modified_array) => modified_array.extend_one(report),
+                        }
+                    }
+                }
+                restored_properties.insert(base_url.clone(), value);
+            } else if restricted.contains(base_url.as_str()) {
+                restored_properties.insert(base_url.clone(), previous_value.clone());
+                restored = true;
+            }
+        }
+        if !restored && modified_array.is_none() {
+            return Ok(());
+        }
+
+        let has_permission = authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::ViewRestrictedProperties,
+                entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?
+            .has_permission;
+        if has_permission {
+            return Ok(());
+        }
+        if let Some(report) = modified_array {
+            return Err(report
+                .attach(StatusCode::PermissionDenied)
+                .change_context(QueryError));
+        }
+
+        *properties = restored_properties;
+        Ok(())
+    }
+
+    /// Removes the restricted properties from all entities in the `subgraph` for which the actor
+    /// does not have [`EntityPermission::ViewRestrictedProperties`].
+    ///
+    /// Restricted properties are removed from nested property objects as well. The entities
+    /// themselves are still returned.
+    #[tracing::instrument(level = "trace", skip(self, subgraph, authorization_api, zookie))]
+    pub(crate) async fn redact_restricted_properties<A: AuthorizationApi + Sync>(
+        &self,
+        subgraph: &mut Subgraph,
+        actor_id: AccountId,
+        authorization_api: &A,
+        zookie: &Zookie<'static>,
+    ) -> Result<(), QueryError> {
+        let entity_ids = subgraph
+            .vertices
+            .entities
+            .keys()
+            .map(|vertex_id| (vertex_id.base_id.owned_by_id, vertex_id.base_id.entity_uuid))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        if entity_ids.is_empty() {
+            return Ok(());
+        }
+
+        let (restricted_property_types, restricted_entity_properties) =
+            self.read_restricted_properties(&entity_ids).await?;
+
+        let redactions = subgraph
+            .vertices
+            .entities
+            .iter()
+            .filter(|(vertex_id, entity)| {
+                let restricted = restricted_base_urls(
+                    &restricted_property_types,
+                    restricted_entity_properties.get(&vertex_id.base_id.entity_uuid),
+                );
+                entity
+                    .properties
+                    .properties()
+                    .iter()
+                    .any(|(base_url, value)| {
+                        restricted.contains(base_url.as_str())
+                            || contains_restricted_property(value, &restricted)
+                    })
+            })
+            .map(|(vertex_id, _)| *vertex_id)
+            .collect::<Vec<_>>();
+        if redactions.is_empty() {
+            return Ok(());
+        }
+
+        #[expect(clippy::needless_collect, reason = "Higher ranked lifetime error")]
+        let permissions = authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::ViewRestrictedProperties,
+                redactions
+                    .iter()
+                    .map(|vertex_id| vertex_id.base_id)
+                    .collect::<HashSet<_>>(),
+                Consistency::AtExactSnapshot(zookie),
+            )
+            .await
+            .change_context(QueryError)?
+            .0;
+
+        for vertex_id in redactions {
+            if permissions
+                .get(&vertex_id.base_id.entity_uuid)
+                .copied()
+                .unwrap_or(false)
+            {
+                continue;
+            }
+
+            let Some(entity) = subgraph.vertices.entities.get_mut(&vertex_id) else {
+                continue;
+            };
+            let restricted = restricted_base_urls(
+                &restricted_property_types,
+                restricted_entity_properties.get(&vertex_id.base_id.entity_uuid),
+            );
+            let base_urls = entity
+                .properties
+                .properties()
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>();
+            for base_url in base_urls {
+                if let Some(mut value) = entity.properties.remove(&base_url) {
+                    if !restricted.contains(base_url.as_str()) {
+                        remove_restricted_properties(&mut value, &restricted);
+                        entity.properties.insert(base_url, value);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -1,6 +1,12 @@
 use async_trait::async_trait;
-use error_stack::{Result, ResultExt};
-use tokio_postgres::Client;
+use authorization::{
+    backend::ZanzibarBackend,
+    migration::{schema_hash, SchemaMigration, SCHEMA_MIGRATIONS},
+};
+use error_stack::{Report, Result, ResultExt};
+use futures::future::BoxFuture;
+use time::OffsetDateTime;
+use tokio_postgres::{Client, GenericClient, Transaction};
 
 use super::{AsClient, PostgresStore};
 use crate::store::{
@@ -58,6 +64,412 @@ impl<C: AsClient<Client = Client>> StoreMigration for PostgresStore<C> {
     }
 }
 
+/// Creates the relationships which are required for existing data when a schema migration
+/// introduces a new relation.
+///
+/// The backfill runs inside the migration transaction after the schema was imported, so the data is
+/// read while holding the lock and the migration is only recorded if the backfill succeeded.
+/// Otherwise, the migration is applied again on the next run, so backfills have to be idempotent,
+/// e.g. by touching the relationships.
+pub type RelationshipBackfill<A> = for<'t, 'c> fn(
+    &'t PostgresStore<Transaction<'c>>,
+    &'t mut A,
+) -> BoxFuture<'t, Result<(), MigrationError>>;
+
+/// The [`RelationshipBackfill`]s by the version of the schema migration they belong to.
+pub struct RelationshipBackfills<A> {
+    backfills: Vec<(u32, RelationshipBackfill<A>)>,
+}
+
+impl<A> RelationshipBackfills<A> {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            backfills: Vec::new(),
+        }
+    }
+
+    /// Runs `backfill` when the schema migration with the specified `version` is applied.
+    ///
+    /// # Panics
+    ///
+    /// - if no schema migration with the specified `version` exists
+    /// - if a backfill was already registered for the specified `version`
+    #[must_use]
+    pub fn register(mut self, version: u32, backfill: RelationshipBackfill<A>) -> Self {
+        assert!(
+            SCHEMA_MIGRATIONS
+                .iter()
+                .any(|migration| migration.version() == version),
+            "authorization schema migration v{version} does not exist"
+        );
+        assert!(
+            self.get(version).is_none(),
+            "a backfill for authorization schema migration v{version} is already registered"
+        );
+
+        self.backfills.push((version, backfill));
+        self
+    }
+
+    fn get(&self, version: u32) -> Option<RelationshipBackfill<A>> {
+        self.backfills
+            .iter()
+            .find(|(backfill_version, _)| *backfill_version == version)
+            .map(|(_, backfill)| *backfill)
+    }
+}
+
+impl<A> Default for RelationshipBackfills<A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An authorization schema migration as recorded in the `authorization_schema_migrations` table.
+struct AppliedSchemaMigration {
+    version: u32,
+    name: String,
+    hash: String,
+    deployed_hash: String,
+}
+
+/// Formats a schema hash as it's stored in the database.
+fn format_hash(hash: u64) -> String {
+    format!("{hash:016x}")
+}
+
+/// Ensures that the `applied` migrations are the first migrations known to this version of the
+/// Graph and that they were not modified since they were applied.
+fn verify_applied_schema_migrations(
+    applied: &[AppliedSchemaMigration],
+) -> Result<(), MigrationError> {
+    if applied.len() > SCHEMA_MIGRATIONS.len() {
+        return Err(Report::new(MigrationError).attach_printable(format!(
+            "{} authorization schema migrations were applied, but only {} are known, the \
+             authorization schema was migrated by a newer version of the Graph",
+            applied.len(),
+            SCHEMA_MIGRATIONS.len()
+        )));
+    }
+
+    for (applied, expected) in applied.iter().zip(SCHEMA_MIGRATIONS) {
+        if applied.version != expected.version() || applied.hash != format_hash(expected.hash()) {
+            return Err(Report::new(MigrationError).attach_printable(format!(
+                "authorization schema migration v{}__{} does not match the applied migration \
+                 v{}__{}, migrations must not be modified after they were applied",
+                expected.version(),
+                expected.name(),
+                applied.version,
+                applied.name
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the hash of the schema which is currently deployed to the authorization backend.
+///
+/// The authorization backend may normalize the schema on import, so the exported schema is hashed
+/// instead of the migration file.
+async fn deployed_schema_hash<A>(authorization_api: &A) -> Result<String, MigrationError>
+where
+    A: ZanzibarBackend + Sync,
+{
+    let schema = authorization_api
+        .export_schema()
+        .await
+        .change_context(MigrationError)
+        .attach_printable("could not read the deployed authorization schema")?
+        .schema;
+
+    Ok(format_hash(schema_hash(&schema)))
+}
+
+impl<C: AsClient> PostgresStore<C> {
+    async fn applied_schema_migrations(
+        &self,
+    ) -> Result<Vec<AppliedSchemaMigration>, MigrationError> {
+        self.as_client()
+            .query(
+                "
+                    SELECT version, name, hash, deployed_hash
+                    FROM authorization_schema_migrations
+                    ORDER BY version;
+                ",
+                &[],
+            )
+            .await
+            .change_context(MigrationError)?
+            .into_iter()
+            .map(|row| {
+                Ok(AppliedSchemaMigration {
+                    version: u32::try_from(row.get::<_, i64>(0)).change_context(MigrationError)?,
+                    name: row.get(1),
+                    hash: row.get(2),
+                    deployed_hash: row.get(3),
+                })
+            })
+            .collect()
+    }
+
+    /// Starts a transaction which holds a lock on the `authorization_schema_migrations` table.
+    ///
+    /// The lock prevents concurrent runs of the migrations from importing schemas in between
+    /// reading the applied migrations and recording a migration.
+    async fn lock_schema_migrations(
+        &mut self,
+    ) -> Result<PostgresStore<Transaction<'_>>, MigrationError> {
+        let transaction = self.transaction().await.change_context(MigrationError)?;
+        transaction
+            .as_client()
+            .batch_execute("LOCK TABLE authorization_schema_migrations IN EXCLUSIVE MODE;")
+            .await
+            .change_context(MigrationError)?;
+        Ok(transaction)
+    }
+
+    /// Imports the schema of the last applied migration again if the deployed schema differs from
+    /// it, e.g. because it was modified manually.
+    ///
+    /// Pending migrations replace the deployed schema anyway, so this does nothing if not all
+    /// migrations are applied. Restoring the last applied schema could also fail if a previous run
+    /// imported a newer schema but failed to record it.
+    async fn restore_authorization_schema<A>(
+        &mut self,
+        authorization_api: &mut A,
+    ) -> Result<(), MigrationError>
+    where
+        A: ZanzibarBackend + Send + Sync,
+    {
+        let transaction = self.lock_schema_migrations().await?;
+        let applied = transaction.applied_schema_migrations().await?;
+        verify_applied_schema_migrations(&applied)?;
+
+        if applied.len() != SCHEMA_MIGRATIONS.len() {
+            return Ok(());
+        }
+        let Some((last_applied, migration)) = applied.last().zip(SCHEMA_MIGRATIONS.last()) else {
+            return Ok(());
+        };
+        if deployed_schema_hash(authorization_api).await? == last_applied.deployed_hash {
+            return Ok(());
+        }
+
+        tracing::warn!(
+            version = last_applied.version,
+            name = %last_applied.name,
+            "Deployed authorization schema differs from the applied migration, restoring it"
+        );
+        authorization_api
+            .import_schema(migration.schema())
+            .await
+            .change_context(MigrationError)?;
+        let deployed_hash = deployed_schema_hash(authorization_api).await?;
+        transaction
+            .as_client()
+            .query(
+                "
+                    UPDATE authorization_schema_migrations
+                    SET deployed_hash = $2
+                    WHERE version = $1;
+                ",
+                &[&i64::from(migration.version()), &deployed_hash],
+            )
+            .await
+            .change_context(MigrationError)?;
+
+        transaction.commit().await.change_context(MigrationError)
+    }
+
+    /// Applies the next authorization schema migration which was not applied yet.
+    ///
+    /// The schema is imported, the relationships are backfilled and the migration is recorded while
+    /// holding the lock on the `authorization_schema_migrations` table. Importing a schema can't be
+    /// rolled back, so if backfilling or recording the migration fails, the schema is imported
+    /// again on the next run. This is idempotent as every migration contains the full schema.
+    ///
+    /// Returns `None` if all migrations are applied.
+    async fn apply_next_authorization_migration<A>(
+        &mut self,
+        authorization_api: &mut A,
+        backfills: &RelationshipBackfills<A>,
+    ) -> Result<Option<Migration>, MigrationError>
+    where
+        A: ZanzibarBackend + Send + Sync,
+    {
+        let transaction = self.lock_schema_migrations().await?;
+        let applied = transaction.applied_schema_migrations().await?;
+        verify_applied_schema_migrations(&applied)?;
+
+        let Some(migration) = SCHEMA_MIGRATIONS.get(applied.len()) else {
+            return Ok(None);
+        };
+
+        authorization_api
+            .import_schema(migration.schema())
+            .await
+            .change_context(MigrationError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "could not import authorization schema v{}__{}",
+                    migration.version(),
+                    migration.name()
+                )
+            })?;
+        if let Some(backfill) = backfills.get(migration.version()) {
+            backfill(&transaction, authorization_api)
+                .await
+                .attach_printable_lazy(|| {
+                    format!(
+                        "could not backfill relationships for authorization schema v{}__{}",
+                        migration.version(),
+                        migration.name()
+                    )
+                })?;
+        }
+
+        let deployed_hash = deployed_schema_hash(authorization_api).await?;
+        transaction
+            .as_client()
+            .query(
+                "
+                    INSERT INTO authorization_schema_migrations (
+                        version,
+                        name,
+                        hash,
+                        deployed_hash
+                    ) VALUES ($1, $2, $3, $4);
+                ",
+                &[
+                    &i64::from(migration.version()),
+                    &migration.name(),
+                    &format_hash(migration.hash()),
+                    &deployed_hash,
+                ],
+            )
+            .await
+            .change_context(MigrationError)?;
+        transaction.commit().await.change_context(MigrationError)?;
+
+        tracing::info!(
+            version = migration.version(),
+            name = migration.name(),
+            "Applied authorization schema migration"
+        );
+        Ok(Some(Migration::new(
+            format!("v{}__{}", migration.version(), migration.name()),
+            MigrationState::Applied {
+                applied_at_utc: OffsetDateTime::now_utc().unix_timestamp(),
+            },
+            migration.hash(),
+        )))
+    }
+
+    /// Applies the authorization schema migrations which were not applied yet.
+    ///
+    /// If all migrations are applied but the deployed schema differs from the schema of the last
+    /// migration, e.g. because it was modified manually, the schema is imported again.
+    ///
+    /// Returns the migrations which were applied.
+    ///
+    /// # Errors
+    ///
+    /// - if the applied migrations don't match the migrations known to this version of the Graph
+    /// - if importing a schema, backfilling relationships or recording a migration failed
+    pub async fn run_authorization_migrations<A>(
+        &mut self,
+        authorization_api: &mut A,
+    ) -> Result<Vec<Migration>, MigrationError>
+    where
+        A: ZanzibarBackend + Send + Sync,
+    {
+        // Backfills are registered by the version of the migration introducing the relation,
+        // e.g. `.register(4, backfill_entity_viewers)`. No migration requires a backfill yet.
+        self.run_authorization_migrations_with_backfills(
+            authorization_api,
+            &RelationshipBackfills::new(),
+        )
+        .await
+    }
+
+    /// Applies the authorization schema migrations which were not applied yet and runs the
+    /// `backfills` of the applied migrations.
+    ///
+    /// See [`run_authorization_migrations`] for details.
+    ///
+    /// # Errors
+    ///
+    /// - if the applied migrations don't match the migrations known to this version of the Graph
+    /// - if importing a schema, backfilling relationships or recording a migration failed
+    ///
+    /// [`run_authorization_migrations`]: Self::run_authorization_migrations
+    #[tracing::instrument(level = "info", skip(self, authorization_api, backfills))]
+    pub async fn run_authorization_migrations_with_backfills<A>(
+        &mut self,
+        authorization_api: &mut A,
+        backfills: &RelationshipBackfills<A>,
+    ) -> Result<Vec<Migration>, MigrationError>
+    where
+        A: ZanzibarBackend + Send + Sync,
+    {
+        self.restore_authorization_schema(authorization_api).await?;
+
+        let mut migrations = Vec::new();
+        while let Some(migration) = self
+            .apply_next_authorization_migration(authorization_api, backfills)
+            .await?
+        {
+            migrations.push(migration);
+        }
+
+        Ok(migrations)
+    }
+
+    /// Ensures that all authorization schema migrations are applied and the deployed schema was
+    /// not modified since.
+    ///
+    /// # Errors
+    ///
+    /// - if a migration is not applied yet
+    /// - if the applied migrations don't match the migrations known to this version of the Graph
+    /// - if the deployed schema differs from the schema of the last migration
+    #[tracing::instrument(level = "info", skip(self, authorization_api))]
+    pub async fn check_authorization_schema<A>(
+        &self,
+        authorization_api: &A,
+    ) -> Result<(), MigrationError>
+    where
+        A: ZanzibarBackend + Sync,
+    {
+        let applied = self.applied_schema_migrations().await?;
+        verify_applied_schema_migrations(&applied)?;
+
+        let latest = SchemaMigration::latest();
+        let Some(last_applied) = applied
+            .last()
+            .filter(|migration| migration.version == latest.version())
+        else {
+            return Err(Report::new(MigrationError).attach_printable(format!(
+                "authorization schema migration v{}__{} is not applied, run the `migrate` command \
+                 first",
+                latest.version(),
+                latest.name()
+            )));
+        };
+
+        if deployed_schema_hash(authorization_api).await? != last_applied.deployed_hash {
+            return Err(Report::new(MigrationError).attach_printable(
+                "the deployed authorization schema differs from the expected schema, run the \
+                 `migrate` command to restore it",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
 impl From<&refinery::Migration> for Migration {
     fn from(value: &refinery::Migration) -> Self {
         let state = value
@@ -75,3 +487,60 @@ impl From<&refinery::Migration> for Migration {
         Self::new(name, state, value.checksum())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn applied(migration: &SchemaMigration) -> AppliedSchemaMigration {
+        AppliedSchemaMigration {
+            version: migration.version(),
+            name: migration.name().to_owned(),
+            hash: format_hash(migration.hash()),
+            deployed_hash: format_hash(0),
+        }
+    }
+
+    #[test]
+    fn applied_migrations_are_verified() {
+        verify_applied_schema_migrations(&[]).expect("no migrations are applied");
+
+        let all_applied = SCHEMA_MIGRATIONS.iter().map(applied).collect::<Vec<_>>();
+        verify_applied_schema_migrations(&all_applied).expect("all migrations are applied");
+        verify_applied_schema_migrations(&all_applied[..1])
+            .expect("the first migration is applied");
+    }
+
+    #[test]
+    fn unknown_migration_is_rejected() {
+        let mut migrations = SCHEMA_MIGRATIONS.iter().map(applied).collect::<Vec<_>>();
+        migrations.push(AppliedSchemaMigration {
+            version: SchemaMigration::latest().version() + 1,
+            name: "unknown".to_owned(),
+            hash: format_hash(0),
+            deployed_hash: format_hash(0),
+        });
+
+        _ = verify_applied_schema_migrations(&migrations)
+            .expect_err("migration was applied by a newer version");
+    }
+
+    #[test]
+    fn modified_migration_is_rejected() {
+        let mut migrations = SCHEMA_MIGRATIONS.iter().map(applied).collect::<Vec<_>>();
+        migrations[0].hash = format_hash(SCHEMA_MIGRATIONS[0].hash().wrapping_add(1));
+
+        _ = verify_applied_schema_migrations(&migrations).expect_err("migration was modified");
+    }
+
+    #[test]
+    fn skipped_migration_is_rejected() {
+        let migrations = SCHEMA_MIGRATIONS
+            .iter()
+            .skip(1)
+            .map(applied)
+            .collect::<Vec<_>>();
+
+        _ = verify_applied_schema_migrations(&migrations).expect_err("first migration is missing");
+    }
+}
@@ -5,6 +5,7 @@ mod ontology;
 mod migration;
 mod pool;
 mod query;
+mod reconcile;
 mod traversal_context;
 
 use std::fmt::Debug;
@@ -32,6 +33,7 @@ use graph_types::{
     },
     owned_by_id::OwnedById,
 };
+use hash_status::StatusCode;
 use postgres_types::Json;
 use serde::Serialize;
 use temporal_versioning::{DecisionTime, LeftClosedTemporalInterval, Timestamp, TransactionTime};
@@ -45,12 +47,17 @@ use type_system::{
 };
 
 pub use self::{
+    migration::{RelationshipBackfill, RelationshipBackfills},
     pool::{AsClient, PostgresStorePool},
     query::CursorField,
+    reconcile::{AuthorizationDiscrepancies, ExpiredRelationships, RelationshipDiscrepancies},
     traversal_context::TraversalContext,
 };
 use crate::store::{
-    account::{InsertAccountGroupIdParams, InsertAccountIdParams, InsertWebIdParams},
+    account::{
+        InsertAccountGroupIdParams, InsertAccountIdParams, InsertServiceAccountParams,
+        InsertWebIdParams, ServiceAccount,
+    },
     error::{
         DeletionError, OntologyTypeIsNotOwned, OntologyVersionDoesNotExist,
         VersionedUrlAlreadyExists,
@@ -1359,6 +1366,7 @@ impl<C: AsClient> AccountStore for PostgresStore<C> {
                 AccountGroupRelationAndSubject::Administrator {
                     subject: AccountGroupAdministratorSubject::Account { id: actor_id },
                     level: 0,
+                    expires_at: None,
                 },
             )])
             .await
@@ -1372,6 +1380,7 @@ impl<C: AsClient> AccountStore for PostgresStore<C> {
                     AccountGroupRelationAndSubject::Administrator {
                         subject: AccountGroupAdministratorSubject::Account { id: actor_id },
                         level: 0,
+                        expires_at: None,
                     },
                 )])
                 .await
@@ -1433,6 +1442,7 @@ impl<C: AsClient> AccountStore for PostgresStore<C> {
                         set: WebSubjectSet::Member,
                     },
                     level: 0,
+                    expires_at: None,
                 },
                 WebRelationAndSubject::EntityEditor {
                     subject: WebEntityEditorSubject::AccountGroup {
@@ -1440,6 +1450,7 @@ impl<C: AsClient> AccountStore for PostgresStore<C> {
                         set: WebSubjectSet::Member,
                     },
                     level: 0,
+                    expires_at: None,
                 },
                 // TODO: Add ontology type creators
             ]);
@@ -1523,6 +1534,85 @@ impl<C: AsClient> AccountStore for PostgresStore<C> {
                 .attach_printable(owned_by_id)),
         }
     }
+
+    #[tracing::instrument(level = "info", skip(self, token_hash))]
+    async fn insert_service_account(
+        &mut self,
+        actor_id: AccountId,
+        params: InsertServiceAccountParams,
+        token_hash: &[u8],
+    ) -> Result<(), InsertionError> {
+        let transaction = self.transaction().await.change_context(InsertionError)?;
+
+        transaction
+            .as_client()
+            .query(
+                "INSERT INTO accounts (account_id) VALUES ($1);",
+                &[&params.account_id],
+            )
+            .await
+            .change_context(InsertionError)
+            .attach_printable(params.account_id)?;
+
+        transaction
+            .as_client()
+            .query(
+                "
+                    INSERT INTO service_accounts (account_id, token_hash, scope, created_by_id)
+                    VALUES ($1, $2, $3, $4);
+                ",
+                &[&params.account_id, &token_hash, &params.scope, &actor_id],
+            )
+            .await
+            .change_context(InsertionError)
+            .attach_printable(params.account_id)?;
+
+        transaction.commit().await.change_context(InsertionError)
+    }
+
+    #[tracing::instrument(level = "info", skip(self, token_hash))]
+    async fn get_service_account(
+        &self,
+        token_hash: &[u8],
+    ) -> Result<Option<ServiceAccount>, QueryError> {
+        Ok(self
+            .as_client()
+            .query_opt(
+                "
+                    SELECT account_id, scope
+                    FROM service_accounts
+                    WHERE token_hash = $1;
+                ",
+                &[&token_hash],
+            )
+            .await
+            .change_context(QueryError)?
+            .map(|row| ServiceAccount {
+                account_id: row.get(0),
+                scope: row.get(1),
+            }))
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn revoke_service_account(&mut self, account_id: AccountId) -> Result<(), DeletionError> {
+        let deleted = self
+            .as_client()
+            .execute(
+                "DELETE FROM service_accounts WHERE account_id = $1;",
+                &[&account_id],
+            )
+            .await
+            .change_context(DeletionError)?;
+
+        if deleted == 0 {
+            Err(Report::new(DeletionError)
+                .attach(StatusCode::NotFound)
+                .attach_printable("Service account does not exist")
+                .attach_printable(account_id))
+        } else {
+            Ok(())
+        }
+    }
 }
 
 impl<C: AsClient> PostgresStore<C> {
@@ -1537,6 +1627,11 @@ impl<C: AsClient> PostgresStore<C> {
             .simple_query("DELETE FROM webs;")
             .await
             .change_context(DeletionError)?;
+        self.as_client()
+            .client()
+            .simple_query("DELETE FROM service_accounts;")
+            .await
+            .change_context(DeletionError)?;
         self.as_client()
             .client()
             .simple_query("DELETE FROM accounts;")
@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     collections::{HashMap, HashSet},
     iter::once,
 };
@@ -379,7 +380,7 @@ impl<C: AsClient> PostgresStore<C> {
             .read_closed_schemas(
                 &Filter::In(
                     FilterExpression::Path(EntityTypeQueryPath::OntologyId),
-                    ParameterList::Uuid(&parent_entity_type_ids),
+                    ParameterList::Uuid(Cow::Borrowed(&parent_entity_type_ids)),
                 ),
                 Some(
                     &QueryTemporalAxesUnresolved::DecisionTime {
@@ -38,8 +38,8 @@ use crate::{
         error::DeletionError,
         ontology::{
             ArchivePropertyTypeParams, CreatePropertyTypeParams, GetPropertyTypesParams,
-            UnarchivePropertyTypeParams, UpdatePropertyTypeEmbeddingParams,
-            UpdatePropertyTypesParams,
+            SetPropertyTypeRestrictionParams, UnarchivePropertyTypeParams,
+            UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
         },
         postgres::{
             crud::QueryRecordDecode,
@@ -238,6 +238,8 @@ impl<C: AsClient> PostgresStore<C> {
                     DELETE FROM property_type_embeddings;
                     DELETE FROM property_type_constrains_properties_on;
                     DELETE FROM property_type_constrains_values_on;
+                    DELETE FROM restricted_entity_properties;
+                    DELETE FROM restricted_property_types;
                 ",
             )
             .await
@@ -670,6 +672,48 @@ impl<C: AsClient> PropertyTypeStore for PostgresStore<C> {
             .await
     }
 
+    #[tracing::instrument(level = "info", skip(self, authorization_api))]
+    async fn set_property_type_restriction<A: AuthorizationApi + Send + Sync>(
+        &mut self,
+        actor_id: AccountId,
+        authorization_api: &mut A,
+        params: SetPropertyTypeRestrictionParams<'_>,
+    ) -> Result<(), UpdateError> {
+        let property_type_id = PropertyTypeId::from_url(&params.property_type_id);
+        authorization_api
+            .check_property_type_permission(
+                actor_id,
+                PropertyTypePermission::Update,
+                property_type_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)
+            .attach_printable(property_type_id.into_uuid())?;
+
+        let query = if params.restricted {
+            "
+                INSERT INTO restricted_property_types (property_type_base_url)
+                VALUES ($1)
+                ON CONFLICT DO NOTHING;
+            "
+        } else {
+            "
+                DELETE FROM restricted_property_types
+                WHERE property_type_base_url = $1;
+            "
+        };
+
+        self.as_client()
+            .query(query, &[&params.property_type_id.base_url.as_str()])
+            .await
+            .change_context(UpdateError)?;
+
+        Ok(())
+    }
+
     #[tracing::instrument(level = "info", skip(self, params))]
     async fn update_property_type_embeddings<A: AuthorizationApi + Send + Sync>(
         &mut self,
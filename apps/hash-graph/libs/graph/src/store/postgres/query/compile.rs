@@ -1,4 +1,5 @@
 use std::{
+    borrow::Cow,
     collections::{HashMap, HashSet},
     iter::once,
 };
@@ -817,7 +818,10 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
     ) -> (Expression, ParameterType) {
         let parameter_type = match parameters {
             ParameterList::Uuid(uuids) => {
-                self.artifacts.parameters.push(uuids);
+                match uuids {
+                    Cow::Borrowed(uuids) => self.artifacts.parameters.push(uuids),
+                    Cow::Owned(uuids) => self.artifacts.parameters.push(uuids),
+                }
                 ParameterType::Uuid
             }
         };
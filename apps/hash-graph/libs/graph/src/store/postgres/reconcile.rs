@@ -0,0 +1,735 @@
+//! Reconciliation of the relationships in the authorization backend with the data in Postgres.
+//!
+//! Relationships are written to the authorization backend separately from the Postgres
+//! transaction, so a failure between the two leaves them out of sync. This module finds resources
+//! without an owner, owner relationships to the wrong web, and relationships of resources which
+//! don't exist anymore.
+//!
+//! Relationships with an expiration time stop granting permissions once they expired, but they
+//! are not removed from the authorization backend. This module also finds expired relationships, so
+//! they can be deleted when repairing.
+
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use authorization::{
+    backend::{ReadError, ZanzibarBackend},
+    schema::{
+        AccountGroupNamespace, AccountGroupRelationAndSubject, DataTypeId, DataTypeNamespace,
+        DataTypeOwnerSubject, DataTypeRelationAndSubject, EntityNamespace, EntityOwnerSubject,
+        EntityRelationAndSubject, EntityTypeId, EntityTypeNamespace, EntityTypeOwnerSubject,
+        EntityTypeRelationAndSubject, PropertyTypeId, PropertyTypeNamespace,
+        PropertyTypeOwnerSubject, PropertyTypeRelationAndSubject, WebNamespace,
+        WebRelationAndSubject,
+    },
+    zanzibar::{
+        types::{Relationship, RelationshipFilter, Resource, ResourceFilter},
+        Consistency,
+    },
+};
+use error_stack::{Result, ResultExt};
+use futures::TryStreamExt;
+use graph_types::{account::AccountGroupId, knowledge::entity::EntityUuid, owned_by_id::OwnedById};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tokio_postgres::GenericClient;
+use uuid::Uuid;
+
+use crate::store::{AsClient, PostgresStore, QueryError, UpdateError};
+
+/// The number of relationships which are modified in a single request.
+const REPAIR_BATCH_SIZE: usize = 1000;
+
+/// Reads all relationships of the resources matched by `resource` from the authorization backend.
+async fn read_relationships<A, T>(
+    authorization_api: &A,
+    resource: ResourceFilter<impl Serialize + Send + Sync, impl Serialize + Send + Sync>,
+) -> Result<Vec<T>, ReadError>
+where
+    A: ZanzibarBackend + Sync,
+    for<'de> T: Relationship<
+            Resource: Resource<Kind: Deserialize<'de>, Id: Deserialize<'de>>,
+            Relation: Deserialize<'de>,
+            Subject: Resource<Kind: Deserialize<'de>, Id: Deserialize<'de>>,
+            SubjectSet: Deserialize<'de>,
+        > + Send,
+{
+    authorization_api
+        .read_relations::<T>(
+            RelationshipFilter::from_resource(resource),
+            Consistency::FullyConsistent,
+        )
+        .await?
+        .try_collect()
+        .await
+}
+
+/// Returns the `expired` relationships which are still stored unchanged in the authorization
+/// backend.
+///
+/// A relationship which was written again with a new expiration time since it was found is left
+/// out, as deleting it would revoke the renewed relationship.
+async fn read_unchanged<A, R, S>(
+    authorization_api: &A,
+    expired: &[(R, S)],
+) -> Result<Vec<(R, S)>, ReadError>
+where
+    A: ZanzibarBackend + Sync,
+    R: Resource<Kind: Serialize + Send + Sync, Id: Serialize + Send + Sync>
+        + Copy
+        + Eq
+        + Hash
+        + Send
+        + Sync,
+    S: Copy + PartialEq + Send + Sync,
+    for<'de> (R, S): Relationship<
+            Resource: Resource<Kind: Deserialize<'de>, Id: Deserialize<'de>>,
+            Relation: Deserialize<'de>,
+            Subject: Resource<Kind: Deserialize<'de>, Id: Deserialize<'de>>,
+            SubjectSet: Deserialize<'de>,
+        > + Send,
+{
+    let resources = expired
+        .iter()
+        .map(|(resource, _)| *resource)
+        .collect::<HashSet<_>>();
+
+    let mut unchanged = Vec::new();
+    for resource in resources {
+        let relationships =
+            read_relationships::<_, (R, S)>(authorization_api, ResourceFilter::from(resource))
+                .await?;
+        unchanged.extend(
+            expired
+                .iter()
+                .filter(|relationship| {
+                    relationship.0 == resource && relationships.contains(relationship)
+                })
+                .copied(),
+        );
+    }
+
+    Ok(unchanged)
+}
+
+/// Deletes `relationships` from the authorization backend in batches.
+async fn delete_relationships<A, R>(
+    authorization_api: &mut A,
+    relationships: &[R],
+) -> Result<(), UpdateError>
+where
+    A: ZanzibarBackend + Send,
+    R: Relationship<
+            Resource: Resource<Kind: Serialize, Id: Serialize>,
+            Relation: Serialize,
+            Subject: Resource<Kind: Serialize, Id: Serialize>,
+            SubjectSet: Serialize,
+        > + Copy
+        + Send
+        + Sync,
+{
+    for relationships in relationships.chunks(REPAIR_BATCH_SIZE) {
+        authorization_api
+            .delete_relationships(relationships.iter().copied())
+            .await
+            .change_context(UpdateError)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the `relationships` which don't grant any permission anymore at `now`.
+fn find_expired<R: Relationship + Copy>(relationships: &[R], now: OffsetDateTime) -> Vec<R> {
+    relationships
+        .iter()
+        .filter(|relationship| {
+            relationship
+                .to_parts()
+                .expires_at
+                .is_some_and(|expires_at| expires_at <= now)
+        })
+        .copied()
+        .collect()
+}
+
+/// Discrepancies between Postgres and the authorization backend for one kind of resource.
+#[derive(Debug)]
+pub struct RelationshipDiscrepancies<R, S> {
+    /// Owner relationships of resources in Postgres which are missing in the authorization
+    /// backend.
+    pub missing: Vec<(R, S)>,
+    /// Owner relationships in the authorization backend which refer to a different web than the
+    /// one owning the resource in Postgres.
+    pub wrong_owner: Vec<(R, S)>,
+    /// Relationships in the authorization backend for resources which don't exist in Postgres.
+    pub dangling: Vec<(R, S)>,
+}
+
+impl<R, S> RelationshipDiscrepancies<R, S> {
+    /// Compares the `resources` stored in Postgres with the `relationships` read from the
+    /// authorization backend.
+    ///
+    /// `resources` maps every resource to the web owning it, external resources don't have an
+    /// owner. `owner` returns the relationship which is expected for the owning web and `is_owner`
+    /// returns whether a relationship is an owner relationship.
+    fn find(
+        resources: &HashMap<R, Option<OwnedById>>,
+        relationships: Vec<(R, S)>,
+        owner: impl Fn(OwnedById) -> S,
+        is_owner: impl Fn(&S) -> bool,
+    ) -> Self
+    where
+        R: Copy + Eq + Hash,
+        S: PartialEq,
+    {
+        let mut owned_resources = HashSet::new();
+        let mut wrong_owner = Vec::new();
+        let mut dangling = Vec::new();
+        for (resource, relation) in relationships {
+            match resources.get(&resource) {
+                None => dangling.push((resource, relation)),
+                Some(Some(owned_by_id)) if relation == owner(*owned_by_id) => {
+                    owned_resources.insert(resource);
+                }
+                Some(Some(_)) if is_owner(&relation) => wrong_owner.push((resource, relation)),
+                Some(_) => {}
+            }
+        }
+
+        let missing = resources
+            .iter()
+            .filter(|(resource, _)| !owned_resources.contains(*resource))
+            .filter_map(|(resource, owned_by_id)| Some((*resource, owner((*owned_by_id)?))))
+            .collect();
+
+        Self {
+            missing,
+            wrong_owner,
+            dangling,
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.wrong_owner.is_empty() && self.dangling.is_empty()
+    }
+
+    /// Creates the missing owner relationships and deletes the owner relationships to the wrong
+    /// web and the dangling relationships.
+    ///
+    /// Dangling relationships of resources in `existing` are kept, as these resources were created
+    /// after the discrepancies were found.
+    async fn repair<A>(
+        &self,
+        authorization_api: &mut A,
+        existing: &HashSet<R>,
+    ) -> Result<(), UpdateError>
+    where
+        A: ZanzibarBackend + Send,
+        R: Copy + Eq + Hash + Send + Sync,
+        S: Copy + Send + Sync,
+        (R, S): Relationship<
+                Resource: Resource<Kind: Serialize, Id: Serialize>,
+                Relation: Serialize,
+                Subject: Resource<Kind: Serialize, Id: Serialize>,
+                SubjectSet: Serialize,
+            >,
+    {
+        for relationships in self.missing.chunks(REPAIR_BATCH_SIZE) {
+            authorization_api
+                .touch_relationships(relationships.iter().copied())
+                .await
+                .change_context(UpdateError)?;
+        }
+        delete_relationships(authorization_api, &self.wrong_owner).await?;
+        let dangling = self
+            .dangling
+            .iter()
+            .filter(|(resource, _)| !existing.contains(resource))
+            .copied()
+            .collect::<Vec<_>>();
+        delete_relationships(authorization_api, &dangling).await
+    }
+}
+
+/// Relationships in the authorization backend which expired.
+///
+/// Relationships of entities which don't exist anymore are reported as dangling instead.
+#[derive(Debug)]
+pub struct ExpiredRelationships {
+    pub entities: Vec<(EntityUuid, EntityRelationAndSubject)>,
+    pub webs: Vec<(OwnedById, WebRelationAndSubject)>,
+    pub account_groups: Vec<(AccountGroupId, AccountGroupRelationAndSubject)>,
+}
+
+impl ExpiredRelationships {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty() && self.webs.is_empty() && self.account_groups.is_empty()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entities.len() + self.webs.len() + self.account_groups.len()
+    }
+}
+
+/// Discrepancies between Postgres and the authorization backend.
+#[derive(Debug)]
+pub struct AuthorizationDiscrepancies {
+    pub entities: RelationshipDiscrepancies<EntityUuid, EntityRelationAndSubject>,
+    pub entity_types: RelationshipDiscrepancies<EntityTypeId, EntityTypeRelationAndSubject>,
+    pub property_types: RelationshipDiscrepancies<PropertyTypeId, PropertyTypeRelationAndSubject>,
+    pub data_types: RelationshipDiscrepancies<DataTypeId, DataTypeRelationAndSubject>,
+    pub expired: ExpiredRelationships,
+}
+
+impl AuthorizationDiscrepancies {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+            && self.entity_types.is_empty()
+            && self.property_types.is_empty()
+            && self.data_types.is_empty()
+            && self.expired.is_empty()
+    }
+}
+
+impl<C: AsClient> PostgresStore<C> {
+    /// Reads the web owning each ontology type stored in `table`.
+    async fn read_ontology_owners(
+        &self,
+        table: &'static str,
+    ) -> Result<HashMap<Uuid, Option<OwnedById>>, QueryError> {
+        let query = format!(
+            "
+                SELECT {table}.ontology_id, ontology_owned_metadata.web_id
+                FROM {table}
+                LEFT JOIN ontology_owned_metadata USING (ontology_id);
+            "
+        );
+
+        Ok(self
+            .as_client()
+            .query(query.as_str(), &[])
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| (row.get(0), row.get(1)))
+            .collect())
+    }
+
+    /// Returns the resources with dangling relationships which exist in `table` by now.
+    async fn read_existing_resources<R, S>(
+        &self,
+        table: &'static str,
+        column: &'static str,
+        discrepancies: &RelationshipDiscrepancies<R, S>,
+        into_uuid: impl Fn(R) -> Uuid + Send,
+        from_uuid: impl Fn(Uuid) -> R + Send,
+    ) -> Result<HashSet<R>, QueryError>
+    where
+        R: Copy + Eq + Hash + Send + Sync,
+        S: Sync,
+    {
+        let uuids = discrepancies
+            .dangling
+            .iter()
+            .map(|(resource, _)| into_uuid(*resource))
+            .collect::<Vec<_>>();
+        if uuids.is_empty() {
+            return Ok(HashSet::new());
+        }
+
+        let query = format!("SELECT {column} FROM {table} WHERE {column} = ANY($1);");
+        Ok(self
+            .as_client()
+            .query(query.as_str(), &[&uuids])
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| from_uuid(row.get(0)))
+            .collect())
+    }
+
+    /// Compares the entities and ontology types with the relationships in the authorization
+    /// backend and finds the relationships which expired.
+    ///
+    /// Postgres is read before the authorization backend. Resources which are created while this
+    /// runs may therefore be reported as dangling, which is accounted for when repairing the
+    /// discrepancies.
+    ///
+    /// # Errors
+    ///
+    /// - if reading from Postgres or the authorization backend failed
+    #[tracing::instrument(level = "info", skip(self, authorization_api))]
+    pub async fn find_authorization_discrepancies<A>(
+        &self,
+        authorization_api: &A,
+    ) -> Result<AuthorizationDiscrepancies, QueryError>
+    where
+        A: ZanzibarBackend + Sync,
+    {
+        let now = OffsetDateTime::now_utc();
+        let entities = self
+            .as_client()
+            .query("SELECT entity_uuid, web_id FROM entity_ids;", &[])
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| (row.get(0), Some(row.get(1))))
+            .collect::<HashMap<EntityUuid, Option<OwnedById>>>();
+        let entity_types: HashMap<_, _> = self
+            .read_ontology_owners("entity_types")
+            .await?
+            .into_iter()
+            .map(|(id, owner)| (EntityTypeId::new(id), owner))
+            .collect();
+        let property_types: HashMap<_, _> = self
+            .read_ontology_owners("property_types")
+            .await?
+            .into_iter()
+            .map(|(id, owner)| (PropertyTypeId::new(id), owner))
+            .collect();
+        let data_types: HashMap<_, _> = self
+            .read_ontology_owners("data_types")
+            .await?
+            .into_iter()
+            .map(|(id, owner)| (DataTypeId::new(id), owner))
+            .collect();
+
+        let entity_relationships: Vec<(EntityUuid, EntityRelationAndSubject)> = read_relationships(
+            authorization_api,
+            ResourceFilter::from_kind(EntityNamespace::Entity),
+        )
+        .await
+        .change_context(QueryError)?;
+        let entity_type_relationships: Vec<(EntityTypeId, EntityTypeRelationAndSubject)> =
+            read_relationships(
+                authorization_api,
+                ResourceFilter::from_kind(EntityTypeNamespace::EntityType),
+            )
+            .await
+            .change_context(QueryError)?;
+        let property_type_relationships: Vec<(PropertyTypeId, PropertyTypeRelationAndSubject)> =
+            read_relationships(
+                authorization_api,
+                ResourceFilter::from_kind(PropertyTypeNamespace::PropertyType),
+            )
+            .await
+            .change_context(QueryError)?;
+        let data_type_relationships: Vec<(DataTypeId, DataTypeRelationAndSubject)> =
+            read_relationships(
+                authorization_api,
+                ResourceFilter::from_kind(DataTypeNamespace::DataType),
+            )
+            .await
+            .change_context(QueryError)?;
+        let web_relationships: Vec<(OwnedById, WebRelationAndSubject)> = read_relationships(
+            authorization_api,
+            ResourceFilter::from_kind(WebNamespace::Web),
+        )
+        .await
+        .change_context(QueryError)?;
+        let account_group_relationships: Vec<(AccountGroupId, AccountGroupRelationAndSubject)> =
+            read_relationships(
+                authorization_api,
+                ResourceFilter::from_kind(AccountGroupNamespace::AccountGroup),
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let expired = ExpiredRelationships {
+            entities: find_expired(&entity_relationships, now)
+                .into_iter()
+                .filter(|(entity_uuid, _)| entities.contains_key(entity_uuid))
+                .collect(),
+            webs: find_expired(&web_relationships, now),
+            account_groups: find_expired(&account_group_relationships, now),
+        };
+
+        Ok(AuthorizationDiscrepancies {
+            entities: RelationshipDiscrepancies::find(
+                &entities,
+                entity_relationships,
+                |id| EntityRelationAndSubject::Owner {
+                    subject: EntityOwnerSubject::Web { id },
+                    level: 0,
+                },
+                |relation| matches!(relation, EntityRelationAndSubject::Owner { .. }),
+            ),
+            entity_types: RelationshipDiscrepancies::find(
+                &entity_types,
+                entity_type_relationships,
+                |id| EntityTypeRelationAndSubject::Owner {
+                    subject: EntityTypeOwnerSubject::Web { id },
+                    level: 0,
+                },
+                |relation| matches!(relation, EntityTypeRelationAndSubject::Owner { .. }),
+            ),
+            property_types: RelationshipDiscrepancies::find(
+                &property_types,
+                property_type_relationships,
+                |id| PropertyTypeRelationAndSubject::Owner {
+                    subject: PropertyTypeOwnerSubject::Web { id },
+                    level: 0,
+                },
+                |relation| matches!(relation, PropertyTypeRelationAndSubject::Owner { .. }),
+            ),
+            data_types: RelationshipDiscrepancies::find(
+                &data_types,
+                data_type_relationships,
+                |id| DataTypeRelationAndSubject::Owner {
+                    subject: DataTypeOwnerSubject::Web { id },
+                    level: 0,
+                },
+                |relation| matches!(relation, DataTypeRelationAndSubject::Owner { .. }),
+            ),
+            expired,
+        })
+    }
+
+    /// Creates the missing owner relationships and deletes the dangling and expired relationships.
+    ///
+    /// Before deleting relationships, Postgres is read again to keep the relationships of resources
+    /// which were created since the discrepancies were found. Relationships are written before the
+    /// transaction creating a resource is committed, so relationships of resources which are being
+    /// created while repairing may still be deleted.
+    ///
+    /// Expired relationships are read again from the authorization backend and only deleted if
+    /// they still have the same expiration time, so relationships which were renewed since the
+    /// discrepancies were found are kept.
+    ///
+    /// # Errors
+    ///
+    /// - if reading from Postgres or modifying the relationships in the authorization backend
+    ///   failed
+    #[tracing::instrument(level = "info", skip(self, discrepancies, authorization_api))]
+    pub async fn repair_authorization_discrepancies<A>(
+        &self,
+        discrepancies: &AuthorizationDiscrepancies,
+        authorization_api: &mut A,
+    ) -> Result<(), UpdateError>
+    where
+        A: ZanzibarBackend + Send + Sync,
+    {
+        let entities = self
+            .read_existing_resources(
+                "entity_ids",
+                "entity_uuid",
+                &discrepancies.entities,
+                EntityUuid::into_uuid,
+                EntityUuid::new,
+            )
+            .await
+            .change_context(UpdateError)?;
+        discrepancies
+            .entities
+            .repair(authorization_api, &entities)
+            .await?;
+
+        let entity_types = self
+            .read_existing_resources(
+                "entity_types",
+                "ontology_id",
+                &discrepancies.entity_types,
+                EntityTypeId::into_uuid,
+                EntityTypeId::new,
+            )
+            .await
+            .change_context(UpdateError)?;
+        discrepancies
+            .entity_types
+            .repair(authorization_api, &entity_types)
+            .await?;
+
+        let property_types = self
+            .read_existing_resources(
+                "property_types",
+                "ontology_id",
+                &discrepancies.property_types,
+                PropertyTypeId::into_uuid,
+                PropertyTypeId::new,
+            )
+            .await
+            .change_context(UpdateError)?;
+        discrepancies
+            .property_types
+            .repair(authorization_api, &property_types)
+            .await?;
+
+        let data_types = self
+            .read_existing_resources(
+                "data_types",
+                "ontology_id",
+                &discrepancies.data_types,
+                DataTypeId::into_uuid,
+                DataTypeId::new,
+            )
+            .await
+            .change_context(UpdateError)?;
+        discrepancies
+            .data_types
+            .repair(authorization_api, &data_types)
+            .await?;
+
+        let expired_entities = read_unchanged(authorization_api, &discrepancies.expired.entities)
+            .await
+            .change_context(UpdateError)?;
+        delete_relationships(authorization_api, &expired_entities).await?;
+        let expired_webs = read_unchanged(authorization_api, &discrepancies.expired.webs)
+            .await
+            .change_context(UpdateError)?;
+        delete_relationships(authorization_api, &expired_webs).await?;
+        let expired_account_groups =
+            read_unchanged(authorization_api, &discrepancies.expired.account_groups)
+                .await
+                .change_context(UpdateError)?;
+        delete_relationships(authorization_api, &expired_account_groups).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use authorization::schema::EntityViewerSubject;
+    use time::Duration;
+
+    use super::*;
+
+    const fn entity(id: u128) -> EntityUuid {
+        EntityUuid::new(Uuid::from_u128(id))
+    }
+
+    const fn web(id: u128) -> OwnedById {
+        OwnedById::new(Uuid::from_u128(id))
+    }
+
+    const fn owner(id: OwnedById) -> EntityRelationAndSubject {
+        EntityRelationAndSubject::Owner {
+            subject: EntityOwnerSubject::Web { id },
+            level: 0,
+        }
+    }
+
+    const fn public_viewer() -> EntityRelationAndSubject {
+        EntityRelationAndSubject::Viewer {
+            subject: EntityViewerSubject::Public,
+            level: 0,
+            expires_at: None,
+        }
+    }
+
+    const fn expiring_viewer(expires_at: OffsetDateTime) -> EntityRelationAndSubject {
+        EntityRelationAndSubject::Viewer {
+            subject: EntityViewerSubject::Public,
+            level: 0,
+            expires_at: Some(expires_at),
+        }
+    }
+
+    fn find(
+        resources: &[(EntityUuid, Option<OwnedById>)],
+        relationships: Vec<(EntityUuid, EntityRelationAndSubject)>,
+    ) -> RelationshipDiscrepancies<EntityUuid, EntityRelationAndSubject> {
+        RelationshipDiscrepancies::find(
+            &resources.iter().copied().collect(),
+            relationships,
+            owner,
+            |relation| matches!(relation, EntityRelationAndSubject::Owner { .. }),
+        )
+    }
+
+    #[test]
+    fn consistent() {
+        let discrepancies = find(
+            &[(entity(1), Some(web(1))), (entity(2), Some(web(2)))],
+            vec![
+                (entity(1), owner(web(1))),
+                (entity(1), public_viewer()),
+                (entity(2), owner(web(2))),
+            ],
+        );
+
+        assert!(discrepancies.is_empty(), "{discrepancies:?}");
+    }
+
+    #[test]
+    fn missing_owner() {
+        let discrepancies = find(
+            &[(entity(1), Some(web(1))), (entity(2), Some(web(2)))],
+            vec![(entity(1), owner(web(1))), (entity(2), public_viewer())],
+        );
+
+        assert_eq!(discrepancies.missing, [(entity(2), owner(web(2)))]);
+        assert!(discrepancies.wrong_owner.is_empty());
+        assert!(discrepancies.dangling.is_empty());
+    }
+
+    #[test]
+    fn dangling_relationships() {
+        let discrepancies = find(
+            &[(entity(1), Some(web(1)))],
+            vec![
+                (entity(1), owner(web(1))),
+                (entity(2), owner(web(2))),
+                (entity(2), public_viewer()),
+            ],
+        );
+
+        assert!(discrepancies.missing.is_empty());
+        assert!(discrepancies.wrong_owner.is_empty());
+        assert_eq!(
+            discrepancies.dangling,
+            [(entity(2), owner(web(2))), (entity(2), public_viewer())]
+        );
+    }
+
+    #[test]
+    fn external_resource() {
+        let discrepancies = find(
+            &[(entity(1), None), (entity(2), None)],
+            vec![(entity(2), public_viewer())],
+        );
+
+        assert!(discrepancies.is_empty(), "{discrepancies:?}");
+    }
+
+    #[test]
+    fn wrong_owner() {
+        let discrepancies = find(
+            &[(entity(1), Some(web(1)))],
+            vec![(entity(1), owner(web(2))), (entity(1), public_viewer())],
+        );
+
+        assert_eq!(discrepancies.missing, [(entity(1), owner(web(1)))]);
+        assert_eq!(discrepancies.wrong_owner, [(entity(1), owner(web(2)))]);
+        assert!(
+            discrepancies.dangling.is_empty(),
+            "the relationship refers to an existing resource"
+        );
+    }
+
+    #[test]
+    fn expired_relationships() {
+        let now = OffsetDateTime::now_utc();
+        let relationships = [
+            (entity(1), owner(web(1))),
+            (entity(1), public_viewer()),
+            (entity(2), expiring_viewer(now - Duration::hours(1))),
+            (entity(3), expiring_viewer(now)),
+            (entity(4), expiring_viewer(now + Duration::hours(1))),
+        ];
+
+        assert_eq!(
+            find_expired(&relationships, now),
+            [
+                (entity(2), expiring_viewer(now - Duration::hours(1))),
+                (entity(3), expiring_viewer(now)),
+            ]
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use std::{borrow::Cow, fmt, mem, str::FromStr};
 
 use derivative::Derivative;
-use error_stack::{bail, Context, Report, ResultExt};
+use error_stack::{bail, Context, Report, ResultExt, TryReportIteratorExt};
 use graph_types::{
     knowledge::entity::{Entity, EntityId},
     ontology::OntologyTypeVersion,
@@ -128,7 +128,10 @@ where
     pub fn convert_parameters(&mut self) -> Result<(), Report<ParameterConversionError>> {
         match self {
             Self::All(filters) | Self::Any(filters) => {
-                filters.iter_mut().try_for_each(Self::convert_parameters)?;
+                filters
+                    .iter_mut()
+                    .map(Self::convert_parameters)
+                    .try_collect_reports::<()>()?;
             }
             Self::Not(filter) => filter.convert_parameters()?,
             Self::Equal(lhs, rhs) | Self::NotEqual(lhs, rhs) => match (lhs, rhs) {
@@ -180,6 +183,46 @@ where
     }
 }
 
+impl<'p, R: QueryRecord + ?Sized> Filter<'p, R> {
+    /// Returns the paths which are compared in this filter.
+    #[must_use]
+    pub fn paths(&self) -> Vec<&R::QueryPath<'p>> {
+        let mut paths = Vec::new();
+        self.collect_paths(&mut paths);
+        paths
+    }
+
+    fn collect_paths<'f>(&'f self, paths: &mut Vec<&'f R::QueryPath<'p>>) {
+        match self {
+            Self::All(filters) | Self::Any(filters) => {
+                for filter in filters {
+                    filter.collect_paths(paths);
+                }
+            }
+            Self::Not(filter) => filter.collect_paths(paths),
+            Self::Equal(lhs, rhs) | Self::NotEqual(lhs, rhs) => paths.extend(
+                [lhs, rhs]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(FilterExpression::path),
+            ),
+            Self::CosineDistance(lhs, rhs, max) => {
+                paths.extend(
+                    [lhs, rhs, max]
+                        .into_iter()
+                        .filter_map(FilterExpression::path),
+                );
+            }
+            Self::In(lhs, _) => paths.extend(lhs.path()),
+            Self::StartsWith(lhs, rhs)
+            | Self::EndsWith(lhs, rhs)
+            | Self::ContainsSegment(lhs, rhs) => {
+                paths.extend([lhs, rhs].into_iter().filter_map(FilterExpression::path));
+            }
+        }
+    }
+}
+
 /// A leaf value in a [`Filter`].
 #[derive(Derivative, Deserialize)]
 #[derivative(
@@ -195,6 +238,15 @@ pub enum FilterExpression<'p, R: QueryRecord + ?Sized> {
     Parameter(Parameter<'p>),
 }
 
+impl<'p, R: QueryRecord + ?Sized> FilterExpression<'p, R> {
+    const fn path(&self) -> Option<&R::QueryPath<'p>> {
+        match self {
+            Self::Path(path) => Some(path),
+            Self::Parameter(_) => None,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Deserialize)]
 #[serde(untagged)]
 pub enum Parameter<'p> {
@@ -212,9 +264,9 @@ pub enum Parameter<'p> {
     Timestamp(Timestamp<()>),
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParameterList<'p> {
-    Uuid(&'p [Uuid]),
+    Uuid(Cow<'p, [Uuid]>),
 }
 
 impl<'p> Parameter<'p> {
@@ -460,7 +512,10 @@ mod tests {
     use serde_json::json;
 
     use super::*;
-    use crate::ontology::DataTypeQueryPath;
+    use crate::{
+        ontology::DataTypeQueryPath,
+        subgraph::edges::{EdgeDirection, KnowledgeGraphEdgeKind},
+    };
 
     fn test_filter_representation<'de, R>(actual: &Filter<'de, R>, expected: &'de serde_json::Value)
     where
@@ -572,4 +627,33 @@ mod tests {
             &expected,
         );
     }
+
+    #[test]
+    fn paths() {
+        let filter = Filter::<Entity>::deserialize(json!({
+          "all": [
+            { "equal": [
+              { "path": ["uuid"] },
+              { "parameter": Uuid::nil() }
+            ]},
+            { "not": { "startsWith": [
+              { "parameter": "Alice" },
+              { "path": ["leftEntity", "properties"] }
+            ]}}
+          ]
+        }))
+        .expect("Could not deserialize filter");
+
+        assert_eq!(
+            filter.paths(),
+            [
+                &EntityQueryPath::Uuid,
+                &EntityQueryPath::EntityEdge {
+                    edge_kind: KnowledgeGraphEdgeKind::HasLeftEntity,
+                    path: Box::new(EntityQueryPath::Properties(None)),
+                    direction: EdgeDirection::Outgoing,
+                },
+            ]
+        );
+    }
 }
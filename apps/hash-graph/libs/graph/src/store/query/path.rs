@@ -37,6 +37,11 @@ impl<'p> JsonPath<'p> {
         Ok(())
     }
 
+    #[must_use]
+    pub fn path_tokens(&self) -> &[PathToken<'p>] {
+        &self.path
+    }
+
     #[must_use]
     pub fn into_owned(self) -> JsonPath<'static> {
         JsonPath {
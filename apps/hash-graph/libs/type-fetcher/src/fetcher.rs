@@ -1,15 +1,14 @@
 use std::{error::Error, fmt};
 
+use error_stack::RemoteReport;
 use serde::{Deserialize, Serialize};
 use time::OffsetDateTime;
 use type_system::{url::VersionedUrl, DataType, EntityType, PropertyType};
 
-// We would really like to use error-stack for this. It's not possible because
-// we need Serialize and Deserialize for `Report`
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug)]
 pub enum FetcherError {
-    NetworkError(String),
-    SerializationError(String),
+    NetworkError,
+    SerializationError,
 }
 
 impl Error for FetcherError {}
@@ -19,9 +18,8 @@ impl fmt::Display for FetcherError {
         fmt.write_str("the type fetcher encountered an error during execution: ")?;
 
         match self {
-            Self::NetworkError(message) | Self::SerializationError(message) => {
-                fmt.write_str(message)
-            }
+            Self::NetworkError => fmt.write_str("could not fetch the ontology type"),
+            Self::SerializationError => fmt.write_str("could not deserialize the ontology type"),
         }
     }
 }
@@ -37,7 +35,9 @@ pub enum FetchedOntologyType {
 #[tarpc::service]
 pub trait Fetcher {
     /// Fetch a list of ontology types identified by their [`VersionedUrl]` and returns them.
+    ///
+    /// On failure, the `Report<FetcherError>` is returned as [`RemoteReport`].
     async fn fetch_ontology_types(
         ontology_type_urls: Vec<VersionedUrl>,
-    ) -> Result<Vec<(FetchedOntologyType, OffsetDateTime)>, FetcherError>;
+    ) -> Result<Vec<(FetchedOntologyType, OffsetDateTime)>, RemoteReport>;
 }
@@ -1,5 +1,6 @@
 use std::time::Duration;
 
+use error_stack::{RemoteReport, Report, SerializedFrame};
 use futures::{stream, StreamExt, TryStreamExt};
 use reqwest::{
     header::{ACCEPT, USER_AGENT},
@@ -22,7 +23,7 @@ impl Fetcher for FetchServer {
         self,
         _context: Context,
         ontology_type_urls: Vec<VersionedUrl>,
-    ) -> Result<Vec<(FetchedOntologyType, OffsetDateTime)>, FetcherError> {
+    ) -> Result<Vec<(FetchedOntologyType, OffsetDateTime)>, RemoteReport> {
         let client = Client::new();
         stream::iter(ontology_type_urls)
             .map(|url| {
@@ -37,22 +38,37 @@ impl Fetcher for FetchServer {
                         .await
                         .map_err(|err| {
                             tracing::error!(error=?err, %url, "Could not fetch ontology type");
-                            FetcherError::NetworkError(format!("Error fetching {url}: {err:?}"))
+                            Report::new(err)
+                                .change_context(FetcherError::NetworkError)
+                                .attach_printable(url.clone())
                         })?
                         .json::<FetchedOntologyType>()
                         .await
                         .map_err(|err| {
                             tracing::error!(error=?err, %url, "Could not deserialize response");
-                            FetcherError::SerializationError(format!(
-                                "Error deserializing {url}: {err:?}"
-                            ))
+                            Report::new(err)
+                                .change_context(FetcherError::SerializationError)
+                                .attach_printable(url.clone())
                         })?;
 
-                    Ok::<_, FetcherError>((ontology_type, OffsetDateTime::now_utc()))
+                    Ok::<_, Report<FetcherError>>((ontology_type, OffsetDateTime::now_utc()))
                 }
             })
             .buffer_unordered(self.buffer_size)
             .try_collect()
             .await
+            .map_err(|report| {
+                RemoteReport::try_from(&report).unwrap_or_else(|error| {
+                    tracing::error!(?error, ?report, "Could not serialize report");
+                    // The contexts are still returned, so the caller knows what failed.
+                    RemoteReport::new(vec![SerializedFrame {
+                        context: format!("{report:#}"),
+                        value: None,
+                        location: None,
+                        attachments: Vec::new(),
+                        sources: Vec::new(),
+                    }])
+                })
+            })
     }
 }
@@ -0,0 +1,8 @@
+CREATE TYPE service_account_scope AS ENUM ('read', 'write');
+
+CREATE TABLE "service_accounts" (
+    "account_id" uuid PRIMARY KEY REFERENCES "accounts",
+    "token_hash" bytea NOT NULL UNIQUE,
+    "scope" service_account_scope NOT NULL,
+    "created_by_id" uuid NOT NULL REFERENCES "accounts"
+);
@@ -0,0 +1,7 @@
+CREATE TABLE "authorization_schema_migrations" (
+    "version" bigint PRIMARY KEY,
+    "name" text NOT NULL,
+    "hash" text NOT NULL,
+    "deployed_hash" text NOT NULL,
+    "applied_at" timestamp with time zone NOT NULL DEFAULT now()
+);
@@ -0,0 +1,11 @@
+CREATE TABLE "restricted_property_types" (
+    "property_type_base_url" TEXT PRIMARY KEY REFERENCES "base_urls"
+);
+
+CREATE TABLE "restricted_entity_properties" (
+    "web_id"                 UUID NOT NULL,
+    "entity_uuid"            UUID NOT NULL,
+    "property_type_base_url" TEXT NOT NULL REFERENCES "base_urls",
+    FOREIGN KEY ("web_id", "entity_uuid") REFERENCES "entity_ids",
+    PRIMARY KEY ("web_id", "entity_uuid", "property_type_base_url")
+);
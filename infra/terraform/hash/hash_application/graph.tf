@@ -202,6 +202,9 @@ locals {
       [
         { name = "HASH_GRAPH_API_HOST", value = "0.0.0.0" },
         { name = "HASH_GRAPH_API_PORT", value = tostring(local.graph_container_port) },
+        # The Graph is only reachable through the API, which passes the authenticated actor in the
+        # `X-Authenticated-User-Actor-Id` header.
+        { name = "HASH_GRAPH_TRUST_ACTOR_ID_HEADER", value = "true" },
         { name = "HASH_GRAPH_TYPE_FETCHER_HOST", value = local.type_fetcher_container_port_dns },
         { name = "HASH_GRAPH_TYPE_FETCHER_PORT", value = tostring(local.type_fetcher_container_port) },
         { name = "HASH_SPICEDB_HOST", value = "http://${local.spicedb_container_http_port_dns}" },
@@ -9,7 +9,8 @@ use graph_types::{
 
 use crate::{
     backend::{
-        CheckError, CheckResponse, ModifyRelationError, ModifyRelationshipOperation, ReadError,
+        CheckError, CheckResponse, ExplainPermissionResponse, ModifyRelationError,
+        ModifyRelationshipOperation, ReadError,
     },
     schema::{
         AccountGroupPermission, AccountGroupRelationAndSubject, DataTypeId, DataTypePermission,
@@ -126,6 +127,16 @@ pub trait AuthorizationApi {
         > + Send,
     ) -> impl Future<Output = Result<Zookie<'static>, ModifyRelationError>> + Send;
 
+    /// Checks if the actor has the specified permission to the entity and returns how the result
+    /// was computed.
+    fn explain_entity_permission(
+        &self,
+        actor: AccountId,
+        permission: EntityPermission,
+        entity: EntityId,
+        consistency: Consistency<'_>,
+    ) -> impl Future<Output = Result<ExplainPermissionResponse, CheckError>> + Send;
+
     fn check_entities_permission(
         &self,
         actor: AccountId,
@@ -140,6 +151,27 @@ pub trait AuthorizationApi {
         consistency: Consistency<'static>,
     ) -> impl Future<Output = Result<Vec<EntityRelationAndSubject>, ReadError>> + Send;
 
+    /// Returns the entities the actor has the specified permission to.
+    ///
+    /// `None` is returned if the permission is not restricted to a subset of entities, e.g. if
+    /// authorization is disabled, or if the actor has the permission to more than `limit`
+    /// entities. The lookup stops once more than `limit` entities were found. Implementations may
+    /// remember an exceeded limit for a short time and return `None` without looking up the
+    /// entities again.
+    ///
+    /// The returned entities can be added to the filter of a query, so pagination is applied to
+    /// the permitted entities only. If `None` is returned, the entities have to be checked with
+    /// [`check_entities_permission`] instead.
+    ///
+    /// [`check_entities_permission`]: Self::check_entities_permission
+    fn lookup_entities(
+        &self,
+        actor: AccountId,
+        permission: EntityPermission,
+        limit: usize,
+        consistency: Consistency<'_>,
+    ) -> impl Future<Output = Result<(Option<Vec<EntityUuid>>, Zookie<'static>), CheckError>> + Send;
+
     ////////////////////////////////////////////////////////////////////////////
     // Entity type authorization
     ////////////////////////////////////////////////////////////////////////////
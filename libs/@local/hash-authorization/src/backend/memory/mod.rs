@@ -0,0 +1,1178 @@
+mod model;
+mod schema;
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    error::Error,
+    fmt,
+    sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use error_stack::{Report, ResultExt};
+use futures::{stream, Stream};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use self::{
+    model::{Caveat, CaveatContext, ObjectReference, SubjectReference},
+    schema::{Expression, Member, Schema},
+};
+use crate::{
+    backend::{
+        spicedb::serde as spicedb_serde, BulkCheckItem, BulkCheckResponse, CheckError,
+        CheckResponse, DeleteRelationshipError, DeleteRelationshipResponse,
+        ExplainPermissionResponse, ExportSchemaError, ExportSchemaResponse, ImportSchemaError,
+        ImportSchemaResponse, LookupResourcesResponse, ModifyRelationshipError,
+        ModifyRelationshipOperation, ModifyRelationshipResponse, PermissionTrace, ReadError,
+        ZanzibarBackend,
+    },
+    zanzibar::{
+        types::{Relationship, RelationshipFilter, Resource, Subject},
+        Consistency, Permission, Zookie,
+    },
+};
+
+/// The maximum depth of nested relations and permissions which are followed when checking a
+/// permission, which is the same as the default of `SpiceDB`.
+const MAX_DEPTH: u8 = 50;
+
+#[derive(Debug)]
+enum RequestError {
+    Conversion,
+    InvalidSchema,
+    MissingSchema,
+    SchemaMismatch,
+    InvalidRelationship(model::Relationship),
+    RelationshipExists(model::Relationship),
+    DuplicateUpdate(model::Relationship),
+    CaveatEvaluation,
+    MaxDepthExceeded,
+}
+
+impl fmt::Display for RequestError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Conversion => {
+                fmt.write_str("the request could not be converted into relationships")
+            }
+            Self::InvalidSchema => fmt.write_str("the schema is not valid"),
+            Self::MissingSchema => fmt.write_str("no schema has been imported"),
+            Self::SchemaMismatch => fmt.write_str("the request does not match the schema"),
+            Self::InvalidRelationship(relationship) => {
+                write!(fmt, "`{relationship}` is not valid for the schema")
+            }
+            Self::RelationshipExists(relationship) => {
+                write!(fmt, "`{relationship}` already exists")
+            }
+            Self::DuplicateUpdate(relationship) => {
+                write!(fmt, "`{relationship}` is updated more than once")
+            }
+            Self::CaveatEvaluation => {
+                fmt.write_str("the caveat of a relationship could not be evaluated")
+            }
+            Self::MaxDepthExceeded => write!(
+                fmt,
+                "the maximum depth of {MAX_DEPTH} was exceeded while checking the permission"
+            ),
+        }
+    }
+}
+
+impl Error for RequestError {}
+
+#[derive(Debug)]
+struct ImportedSchema {
+    source: String,
+    schema: Schema,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    schema: Option<ImportedSchema>,
+    relationships:
+        BTreeMap<ObjectReference, BTreeMap<String, BTreeMap<SubjectReference, Option<Caveat>>>>,
+    revision: u64,
+}
+
+impl State {
+    fn zookie(&self) -> Zookie<'static> {
+        Zookie::new(self.revision.to_string())
+    }
+
+    fn schema(&self) -> Result<&Schema, Report<RequestError>> {
+        self.schema
+            .as_ref()
+            .map(|imported| &imported.schema)
+            .ok_or_else(|| Report::new(RequestError::MissingSchema))
+    }
+
+    fn relationships(
+        &self,
+    ) -> impl Iterator<Item = (&ObjectReference, &str, &SubjectReference, Option<&Caveat>)> + '_
+    {
+        self.relationships
+            .iter()
+            .flat_map(|(resource, relations)| {
+                relations
+                    .iter()
+                    .map(move |(relation, subjects)| (resource, relation, subjects))
+            })
+            .flat_map(|(resource, relation, subjects)| {
+                subjects.iter().map(move |(subject, caveat)| {
+                    (resource, relation.as_str(), subject, caveat.as_ref())
+                })
+            })
+    }
+
+    fn subjects<'s>(
+        &'s self,
+        resource: &ObjectReference,
+        relation: &str,
+    ) -> impl Iterator<Item = (&'s SubjectReference, Option<&'s Caveat>)> {
+        self.relationships
+            .get(resource)
+            .and_then(|relations| relations.get(relation))
+            .into_iter()
+            .flatten()
+            .map(|(subject, caveat)| (subject, caveat.as_ref()))
+    }
+
+    fn contains(&self, relationship: &model::Relationship) -> bool {
+        self.subjects(&relationship.resource, &relationship.relation)
+            .any(|(subject, _)| *subject == relationship.subject)
+    }
+
+    fn import_schema(&mut self, source: &str) -> Result<(), Report<RequestError>> {
+        let schema = Schema::parse(source).change_context(RequestError::InvalidSchema)?;
+
+        // Existing relationships must stay valid, e.g. a relation cannot be removed while
+        // relationships still use it.
+        for (resource, relation, subject, caveat) in self.relationships() {
+            schema
+                .validate_relationship(resource, relation, subject, caveat)
+                .change_context_lazy(|| {
+                    RequestError::InvalidRelationship(model::Relationship {
+                        resource: resource.clone(),
+                        relation: relation.to_owned(),
+                        subject: subject.clone(),
+                        optional_caveat: caveat.cloned(),
+                    })
+                })?;
+        }
+
+        self.schema = Some(ImportedSchema {
+            source: source.to_owned(),
+            schema,
+        });
+        self.revision += 1;
+        Ok(())
+    }
+
+    /// Applies all updates or none of them if any update fails.
+    fn modify(
+        &mut self,
+        updates: Vec<(ModifyRelationshipOperation, model::Relationship)>,
+    ) -> Result<(), Report<RequestError>> {
+        let schema = self.schema()?;
+        let mut seen = HashSet::new();
+        for (operation, relationship) in &updates {
+            if !seen.insert((
+                &relationship.resource,
+                &relationship.relation,
+                &relationship.subject,
+            )) {
+                return Err(Report::new(RequestError::DuplicateUpdate(
+                    relationship.clone(),
+                )));
+            }
+
+            schema
+                .validate_relationship(
+                    &relationship.resource,
+                    &relationship.relation,
+                    &relationship.subject,
+                    relationship.optional_caveat.as_ref(),
+                )
+                .change_context_lazy(|| RequestError::InvalidRelationship(relationship.clone()))?;
+
+            if *operation == ModifyRelationshipOperation::Create && self.contains(relationship) {
+                return Err(Report::new(RequestError::RelationshipExists(
+                    relationship.clone(),
+                )));
+            }
+        }
+
+        for (operation, relationship) in updates {
+            match operation {
+                ModifyRelationshipOperation::Create | ModifyRelationshipOperation::Touch => {
+                    self.relationships
+                        .entry(relationship.resource)
+                        .or_default()
+                        .entry(relationship.relation)
+                        .or_default()
+                        .insert(relationship.subject, relationship.optional_caveat);
+                }
+                ModifyRelationshipOperation::Delete => self.remove(&relationship),
+            }
+        }
+
+        self.revision += 1;
+        Ok(())
+    }
+
+    fn remove(&mut self, relationship: &model::Relationship) {
+        let Some(relations) = self.relationships.get_mut(&relationship.resource) else {
+            return;
+        };
+        if let Some(subjects) = relations.get_mut(&relationship.relation) {
+            subjects.remove(&relationship.subject);
+            if subjects.is_empty() {
+                relations.remove(&relationship.relation);
+            }
+        }
+        if relations.is_empty() {
+            self.relationships.remove(&relationship.resource);
+        }
+    }
+
+    fn delete(
+        &mut self,
+        mut predicate: impl FnMut(&ObjectReference, &str, &SubjectReference) -> bool,
+    ) {
+        self.relationships.retain(|resource, relations| {
+            relations.retain(|relation, subjects| {
+                subjects.retain(|subject, _| !predicate(resource, relation, subject));
+                !subjects.is_empty()
+            });
+            !relations.is_empty()
+        });
+    }
+
+    /// Returns if the caveat of a relationship is satisfied for the `context` of the request.
+    ///
+    /// Relationships without a caveat are always satisfied.
+    fn is_satisfied(
+        &self,
+        caveat: Option<&Caveat>,
+        context: &CaveatContext,
+    ) -> Result<bool, Report<RequestError>> {
+        let Some(caveat) = caveat else {
+            return Ok(true);
+        };
+
+        self.schema()?
+            .caveat(&caveat.caveat_name)
+            .and_then(|definition| definition.evaluate(&caveat.context, context))
+            .change_context(RequestError::CaveatEvaluation)
+    }
+
+    /// Returns if `subject` has the relation or permission `name` to `resource`.
+    fn check(
+        &self,
+        resource: &ObjectReference,
+        name: &str,
+        subject: &SubjectReference,
+        context: &CaveatContext,
+        depth: u8,
+    ) -> Result<bool, Report<RequestError>> {
+        self.trace(resource, name, subject, context, depth)
+            .map(|trace| trace.has_permission)
+    }
+
+    /// Evaluates if `subject` has the relation or permission `name` to `resource`.
+    ///
+    /// The evaluation stops as soon as the result is known, so the returned trace only contains
+    /// the relations and permissions which were evaluated up to that point.
+    fn trace(
+        &self,
+        resource: &ObjectReference,
+        name: &str,
+        subject: &SubjectReference,
+        context: &CaveatContext,
+        depth: u8,
+    ) -> Result<PermissionTrace, Report<RequestError>> {
+        let depth = depth
+            .checked_sub(1)
+            .ok_or_else(|| Report::new(RequestError::MaxDepthExceeded))?;
+
+        let mut trace = PermissionTrace {
+            resource: resource.to_string(),
+            relation: name.to_owned(),
+            has_permission: false,
+            sub_traces: Vec::new(),
+        };
+
+        // A subject set always contains itself
+        if subject.object == *resource && subject.optional_relation.as_deref() == Some(name) {
+            trace.has_permission = true;
+            return Ok(trace);
+        }
+
+        let member = self
+            .schema()?
+            .member(&resource.object_type, name)
+            .change_context(RequestError::SchemaMismatch)?;
+
+        match member {
+            Member::Relation(_) => {
+                for (candidate, caveat) in self.subjects(resource, name) {
+                    if !self.is_satisfied(caveat, context)? {
+                        continue;
+                    }
+
+                    trace.has_permission = match &candidate.optional_relation {
+                        None if candidate.object.is_wildcard() => {
+                            subject.optional_relation.is_none()
+                                && candidate.object.object_type == subject.object.object_type
+                        }
+                        None => candidate == subject,
+                        Some(_) if candidate == subject => true,
+                        Some(relation) => {
+                            let sub_trace =
+                                self.trace(&candidate.object, relation, subject, context, depth)?;
+                            let has_permission = sub_trace.has_permission;
+                            trace.sub_traces.push(sub_trace);
+                            has_permission
+                        }
+                    };
+                    if trace.has_permission {
+                        break;
+                    }
+                }
+            }
+            Member::Permission(expression) => {
+                trace.has_permission = self.evaluate(
+                    resource,
+                    expression,
+                    subject,
+                    context,
+                    depth,
+                    &mut trace.sub_traces,
+                )?;
+            }
+        }
+
+        Ok(trace)
+    }
+
+    /// Returns all resources of type `object_type` to which `subject` has the relation or
+    /// permission `name`.
+    ///
+    /// Only resources which are part of a relationship are considered as they are the only ones
+    /// which can be granted a permission.
+    fn lookup<'s>(
+        &'s self,
+        object_type: &str,
+        name: &str,
+        subject: &SubjectReference,
+        context: &CaveatContext,
+    ) -> Result<Vec<&'s ObjectReference>, Report<RequestError>> {
+        let mut resources = Vec::new();
+        for resource in self.relationships.keys() {
+            if resource.object_type == object_type
+                && self.check(resource, name, subject, context, MAX_DEPTH)?
+            {
+                resources.push(resource);
+            }
+        }
+        Ok(resources)
+    }
+
+    fn evaluate(
+        &self,
+        resource: &ObjectReference,
+        expression: &Expression,
+        subject: &SubjectReference,
+        context: &CaveatContext,
+        depth: u8,
+        traces: &mut Vec<PermissionTrace>,
+    ) -> Result<bool, Report<RequestError>> {
+        match expression {
+            Expression::Nil => Ok(false),
+            Expression::Reference(name) => {
+                let trace = self.trace(resource, name, subject, context, depth)?;
+                let has_permission = trace.has_permission;
+                traces.push(trace);
+                Ok(has_permission)
+            }
+            Expression::Arrow {
+                relation,
+                permission,
+            } => {
+                let schema = self.schema()?;
+                for (candidate, caveat) in self.subjects(resource, relation) {
+                    // The permission is only evaluated on subjects where it is defined
+                    let is_defined = schema
+                        .definition(&candidate.object.object_type)
+                        .change_context(RequestError::SchemaMismatch)?
+                        .member(permission)
+                        .is_some();
+                    if !is_defined
+                        || candidate.object.is_wildcard()
+                        || !self.is_satisfied(caveat, context)?
+                    {
+                        continue;
+                    }
+
+                    let trace =
+                        self.trace(&candidate.object, permission, subject, context, depth)?;
+                    let has_permission = trace.has_permission;
+                    traces.push(trace);
+                    if has_permission {
+                        return Ok(true);
+                    }
+                }
+                Ok(false)
+            }
+            Expression::Union(lhs, rhs) => Ok(self
+                .evaluate(resource, lhs, subject, context, depth, traces)?
+                || self.evaluate(resource, rhs, subject, context, depth, traces)?),
+            Expression::Intersection(lhs, rhs) => Ok(self
+                .evaluate(resource, lhs, subject, context, depth, traces)?
+                && self.evaluate(resource, rhs, subject, context, depth, traces)?),
+            Expression::Exclusion(lhs, rhs) => Ok(self
+                .evaluate(resource, lhs, subject, context, depth, traces)?
+                && !self.evaluate(resource, rhs, subject, context, depth, traces)?),
+        }
+    }
+}
+
+/// A [`ZanzibarBackend`] which stores the schema and relationships in memory and evaluates
+/// permissions in-process.
+///
+/// This is meant for local development and hermetic tests, where running `SpiceDB` is not
+/// desired. Only a subset of the schema language is supported, see [`import_schema`] for details.
+/// Clones of the backend share the same state.
+///
+/// Like for `SpiceDB`, permissions are checked with the current time passed as `now` to caveats.
+///
+/// Every request is evaluated against the latest state, so all [`Consistency`] levels behave like
+/// [`Consistency::FullyConsistent`].
+///
+/// [`import_schema`]: ZanzibarBackend::import_schema
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryBackend {
+    state: Arc<RwLock<State>>,
+}
+
+impl InMemoryBackend {
+    /// Creates a new backend without a schema and relationships.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // The state is only modified after all updates are validated, so it's consistent even if
+    // another thread panicked while holding the lock.
+    fn read(&self) -> RwLockReadGuard<'_, State> {
+        self.state.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, State> {
+        self.state.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn object_reference<O>(resource: &O) -> Result<ObjectReference, Report<RequestError>>
+where
+    O: Resource<Kind: Serialize, Id: Serialize>,
+{
+    spicedb_serde::resource::serialize(resource, serde_json::value::Serializer)
+        .and_then(ObjectReference::deserialize)
+        .change_context(RequestError::Conversion)
+}
+
+fn subject_reference<S>(subject: &S) -> Result<SubjectReference, Report<RequestError>>
+where
+    S: Subject<Resource: Resource<Kind: Serialize, Id: Serialize>, Relation: Serialize>,
+{
+    spicedb_serde::subject::serialize(subject, serde_json::value::Serializer)
+        .and_then(SubjectReference::deserialize)
+        .change_context(RequestError::Conversion)
+}
+
+/// The context passed to caveats when checking a permission.
+fn request_context() -> Result<CaveatContext, Report<RequestError>> {
+    let now = OffsetDateTime::now_utc()
+        .format(&Rfc3339)
+        .change_context(RequestError::Conversion)?;
+
+    Ok(CaveatContext::from_iter([(
+        "now".to_owned(),
+        serde_json::Value::String(now),
+    )]))
+}
+
+fn relation_name(relation: &impl Serialize) -> Result<String, Report<RequestError>> {
+    serde_json::to_value(relation)
+        .and_then(String::deserialize)
+        .change_context(RequestError::Conversion)
+}
+
+impl ZanzibarBackend for InMemoryBackend {
+    /// Parses the schema and replaces the current schema with it.
+    ///
+    /// Caveats may only compare two of their parameters. Relationships which are not valid for the
+    /// new schema prevent the schema from being imported.
+    async fn import_schema(
+        &mut self,
+        schema: &str,
+    ) -> Result<ImportSchemaResponse, Report<ImportSchemaError>> {
+        let mut state = self.write();
+        state
+            .import_schema(schema)
+            .change_context(ImportSchemaError)?;
+
+        Ok(ImportSchemaResponse {
+            written_at: state.zookie(),
+        })
+    }
+
+    async fn export_schema(&self) -> Result<ExportSchemaResponse, Report<ExportSchemaError>> {
+        let state = self.read();
+        let schema = state
+            .schema
+            .as_ref()
+            .ok_or_else(|| Report::new(RequestError::MissingSchema))
+            .change_context(ExportSchemaError)?;
+
+        Ok(ExportSchemaResponse {
+            schema: schema.source.clone(),
+            read_at: state.zookie(),
+        })
+    }
+
+    async fn modify_relationships<T>(
+        &mut self,
+        relationships: impl IntoIterator<Item = (ModifyRelationshipOperation, T), IntoIter: Send> + Send,
+    ) -> Result<ModifyRelationshipResponse, Report<ModifyRelationshipError>>
+    where
+        T: Relationship<
+                Resource: Resource<Kind: Serialize, Id: Serialize>,
+                Relation: Serialize,
+                Subject: Resource<Kind: Serialize, Id: Serialize>,
+                SubjectSet: Serialize,
+            > + Send
+            + Sync,
+    {
+        let updates = relationships
+            .into_iter()
+            .map(|(operation, relationship)| {
+                spicedb_serde::relationship::serialize(&relationship, serde_json::value::Serializer)
+                    .and_then(model::Relationship::deserialize)
+                    .map(|relationship| (operation, relationship))
+                    .change_context(RequestError::Conversion)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .change_context(ModifyRelationshipError)?;
+
+        let mut state = self.write();
+        state
+            .modify(updates)
+            .change_context(ModifyRelationshipError)?;
+
+        Ok(ModifyRelationshipResponse {
+            written_at: state.zookie(),
+        })
+    }
+
+    async fn check_permission<O, R, S>(
+        &self,
+        resource: &O,
+        permission: &R,
+        subject: &S,
+        _: Consistency<'_>,
+    ) -> Result<CheckResponse, Report<CheckError>>
+    where
+        O: Resource<Kind: Serialize, Id: Serialize> + Sync,
+        R: Serialize + Permission<O> + Sync,
+        S: Subject<Resource: Resource<Kind: Serialize, Id: Serialize>, Relation: Serialize> + Sync,
+    {
+        let resource = object_reference(resource).change_context(CheckError)?;
+        let permission = relation_name(permission).change_context(CheckError)?;
+        let subject = subject_reference(subject).change_context(CheckError)?;
+        let context = request_context().change_context(CheckError)?;
+
+        let state = self.read();
+        Ok(CheckResponse {
+            has_permission: state
+                .check(&resource, &permission, &subject, &context, MAX_DEPTH)
+                .change_context(CheckError)?,
+            checked_at: state.zookie(),
+        })
+    }
+
+    async fn check_permissions<O, R, S>(
+        &self,
+        relationships: impl IntoIterator<Item = (O, R, S)> + Send,
+        _: Consistency<'_>,
+    ) -> Result<
+        BulkCheckResponse<impl IntoIterator<Item = BulkCheckItem<O, R, S>>>,
+        Report<CheckError>,
+    >
+    where
+        O: Resource<Kind: Serialize + DeserializeOwned, Id: Serialize + DeserializeOwned>
+            + Send
+            + Sync,
+        R: Serialize + DeserializeOwned + Permission<O> + Send + Sync,
+        S: Subject<
+                Resource: Resource<
+                    Kind: Serialize + DeserializeOwned,
+                    Id: Serialize + DeserializeOwned,
+                >,
+                Relation: Serialize + DeserializeOwned,
+            > + Send
+            + Sync,
+    {
+        let context = request_context().change_context(CheckError)?;
+        let state = self.read();
+        let permissions = relationships
+            .into_iter()
+            .map(|(resource, permission, subject)| {
+                let has_permission = state.check(
+                    &object_reference(&resource)?,
+                    &relation_name(&permission)?,
+                    &subject_reference(&subject)?,
+                    &context,
+                    MAX_DEPTH,
+                )?;
+
+                Ok(BulkCheckItem {
+                    resource,
+                    permission,
+                    subject,
+                    has_permission: Ok(has_permission),
+                })
+            })
+            .collect::<Result<Vec<_>, Report<RequestError>>>()
+            .change_context(CheckError)?;
+
+        Ok(BulkCheckResponse {
+            permissions,
+            checked_at: state.zookie(),
+        })
+    }
+
+    async fn explain_permission<O, R, S>(
+        &self,
+        resource: &O,
+        permission: &R,
+        subject: &S,
+        _: Consistency<'_>,
+    ) -> Result<ExplainPermissionResponse, Report<CheckError>>
+    where
+        O: Resource<Kind: Serialize, Id: Serialize> + Sync,
+        R: Serialize + Permission<O> + Sync,
+        S: Subject<Resource: Resource<Kind: Serialize, Id: Serialize>, Relation: Serialize> + Sync,
+    {
+        let resource = object_reference(resource).change_context(CheckError)?;
+        let permission = relation_name(permission).change_context(CheckError)?;
+        let subject = subject_reference(subject).change_context(CheckError)?;
+        let context = request_context().change_context(CheckError)?;
+
+        let state = self.read();
+        let trace = state
+            .trace(&resource, &permission, &subject, &context, MAX_DEPTH)
+            .change_context(CheckError)?;
+        Ok(ExplainPermissionResponse {
+            has_permission: trace.has_permission,
+            trace: Some(trace),
+            checked_at: state.zookie(),
+        })
+    }
+
+    async fn lookup_resources<O, R, S>(
+        &self,
+        subject: &S,
+        permission: &R,
+        resource_kind: &O::Kind,
+        limit: Option<usize>,
+        _: Consistency<'_>,
+    ) -> Result<LookupResourcesResponse<O::Id>, Report<CheckError>>
+    where
+        O: Resource<Kind: Serialize + Sync, Id: DeserializeOwned + Send>,
+        R: Serialize + Permission<O> + Sync,
+        S: Subject<Resource: Resource<Kind: Serialize, Id: Serialize>, Relation: Serialize> + Sync,
+    {
+        let object_type = serde_json::to_value(resource_kind)
+            .and_then(String::deserialize)
+            .change_context(RequestError::Conversion)
+            .change_context(CheckError)?;
+        let permission = relation_name(permission).change_context(CheckError)?;
+        let subject = subject_reference(subject).change_context(CheckError)?;
+        let context = request_context().change_context(CheckError)?;
+
+        let state = self.read();
+        let resources = state
+            .lookup(&object_type, &permission, &subject, &context)
+            .change_context(CheckError)?
+            .into_iter()
+            .take(limit.unwrap_or(usize::MAX))
+            .map(|resource| {
+                serde_json::from_value(serde_json::Value::String(resource.object_id.clone()))
+                    .change_context(RequestError::Conversion)
+            })
+            .collect::<Result<_, _>>()
+            .change_context(CheckError)?;
+
+        Ok(LookupResourcesResponse {
+            resources: Some(resources),
+            looked_up_at: state.zookie(),
+        })
+    }
+
+    async fn read_relations<R>(
+        &self,
+        filter: RelationshipFilter<
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+        >,
+        _: Consistency<'_>,
+    ) -> Result<impl Stream<Item = Result<R, Report<ReadError>>> + Send, Report<ReadError>>
+    where
+        for<'de> R: Relationship<
+                Resource: Resource<Kind: Deserialize<'de>, Id: Deserialize<'de>>,
+                Relation: Deserialize<'de>,
+                Subject: Resource<Kind: Deserialize<'de>, Id: Deserialize<'de>>,
+                SubjectSet: Deserialize<'de>,
+            > + Send,
+    {
+        let filter =
+            spicedb_serde::relationship_filter::serialize(&filter, serde_json::value::Serializer)
+                .and_then(model::RelationshipFilter::deserialize)
+                .change_context(RequestError::Conversion)
+                .change_context(ReadError)?;
+
+        let relationships = self
+            .read()
+            .relationships()
+            .filter(|(resource, relation, subject, _)| filter.matches(resource, relation, subject))
+            .map(|(resource, relation, subject, caveat)| {
+                serde_json::to_value(model::Relationship {
+                    resource: resource.clone(),
+                    relation: relation.to_owned(),
+                    subject: subject.clone(),
+                    optional_caveat: caveat.cloned(),
+                })
+                .and_then(spicedb_serde::relationship::deserialize)
+                .change_context(RequestError::Conversion)
+                .change_context(ReadError)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(stream::iter(relationships))
+    }
+
+    async fn delete_relations(
+        &mut self,
+        filter: RelationshipFilter<
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+            impl Serialize + Send + Sync,
+        >,
+    ) -> Result<DeleteRelationshipResponse, Report<DeleteRelationshipError>> {
+        let filter =
+            spicedb_serde::relationship_filter::serialize(&filter, serde_json::value::Serializer)
+                .and_then(model::RelationshipFilter::deserialize)
+                .change_context(RequestError::Conversion)
+                .change_context(DeleteRelationshipError)?;
+
+        let mut state = self.write();
+        state.delete(|resource, relation, subject| filter.matches(resource, relation, subject));
+        state.revision += 1;
+
+        Ok(DeleteRelationshipResponse {
+            deleted_at: state.zookie(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const SCHEMA: &str = "
+        definition user {}
+
+        definition group {
+            relation member: user | group#member
+        }
+
+        caveat not_expired(now timestamp, expires_at timestamp) {
+            now < expires_at
+        }
+
+        definition folder {
+            relation owner: user
+            relation viewer: user | user:* | group#member
+            permission view = owner + viewer
+        }
+
+        definition document {
+            relation parent: folder | document
+            relation owner: user | user with not_expired
+            relation viewer: user | group#member
+            relation banned: user
+
+            permission view = owner + viewer + parent->view
+            permission edit = owner & viewer
+            permission comment = view - banned
+            permission nothing = nil
+        }
+    ";
+
+    fn object(object_type: &str, object_id: &str) -> ObjectReference {
+        ObjectReference {
+            object_type: object_type.to_owned(),
+            object_id: object_id.to_owned(),
+        }
+    }
+
+    fn user(name: &str) -> SubjectReference {
+        SubjectReference {
+            object: object("user", name),
+            optional_relation: None,
+        }
+    }
+
+    fn members(group: &str) -> SubjectReference {
+        SubjectReference {
+            object: object("group", group),
+            optional_relation: Some("member".to_owned()),
+        }
+    }
+
+    fn relationship(
+        resource: ObjectReference,
+        relation: &str,
+        subject: SubjectReference,
+    ) -> model::Relationship {
+        model::Relationship {
+            resource,
+            relation: relation.to_owned(),
+            subject,
+            optional_caveat: None,
+        }
+    }
+
+    fn state(relationships: impl IntoIterator<Item = model::Relationship>) -> State {
+        let mut state = State::default();
+        state.import_schema(SCHEMA).expect("schema should be valid");
+        state
+            .modify(
+                relationships
+                    .into_iter()
+                    .map(|relationship| (ModifyRelationshipOperation::Create, relationship))
+                    .collect(),
+            )
+            .expect("relationships should be valid");
+        state
+    }
+
+    #[track_caller]
+    fn check(
+        state: &State,
+        resource: &ObjectReference,
+        name: &str,
+        subject: &SubjectReference,
+    ) -> bool {
+        state
+            .check(resource, name, subject, &CaveatContext::new(), MAX_DEPTH)
+            .expect("permission should be checked")
+    }
+
+    #[test]
+    fn direct_relation() {
+        let document = object("document", "a");
+        let state = state([relationship(document.clone(), "owner", user("alice"))]);
+
+        assert!(check(&state, &document, "owner", &user("alice")));
+        assert!(check(&state, &document, "view", &user("alice")));
+        assert!(!check(&state, &document, "owner", &user("bob")));
+        assert!(!check(
+            &state,
+            &object("document", "b"),
+            "owner",
+            &user("alice")
+        ));
+    }
+
+    #[test]
+    fn subject_set() {
+        let document = object("document", "a");
+        let state = state([
+            relationship(document.clone(), "viewer", members("admins")),
+            relationship(object("group", "admins"), "member", members("owners")),
+            relationship(object("group", "owners"), "member", user("alice")),
+        ]);
+
+        assert!(check(&state, &document, "viewer", &user("alice")));
+        assert!(check(&state, &document, "viewer", &members("owners")));
+        assert!(!check(&state, &document, "viewer", &user("bob")));
+    }
+
+    #[test]
+    fn wildcard() {
+        let folder = object("folder", "public");
+        let state = state([relationship(folder.clone(), "viewer", user("*"))]);
+
+        assert!(check(&state, &folder, "view", &user("alice")));
+        assert!(!check(&state, &folder, "view", &members("admins")));
+        assert!(!check(
+            &state,
+            &object("folder", "private"),
+            "view",
+            &user("alice")
+        ));
+    }
+
+    #[test]
+    fn arrow() {
+        let folder = object("folder", "a");
+        let document = object("document", "a");
+        let nested = object("document", "b");
+        let state = state([
+            relationship(folder.clone(), "owner", user("alice")),
+            relationship(
+                document.clone(),
+                "parent",
+                SubjectReference {
+                    object: folder,
+                    optional_relation: None,
+                },
+            ),
+            relationship(
+                nested.clone(),
+                "parent",
+                SubjectReference {
+                    object: document.clone(),
+                    optional_relation: None,
+                },
+            ),
+        ]);
+
+        assert!(check(&state, &document, "view", &user("alice")));
+        assert!(check(&state, &nested, "view", &user("alice")));
+        assert!(!check(&state, &document, "view", &user("bob")));
+        // The arrow is only followed for the permission, not for the relation
+        assert!(!check(&state, &document, "owner", &user("alice")));
+    }
+
+    #[test]
+    fn arrow_skips_subjects_without_the_permission() {
+        let mut state = State::default();
+        state
+            .import_schema(
+                "definition user {}
+                definition group { relation member: user }
+                definition folder { relation owner: user\n permission view = owner }
+                definition document {
+                    relation parent: folder | group
+                    permission view = parent->view
+                }",
+            )
+            .expect("schema should be valid");
+
+        let document = object("document", "a");
+        state
+            .modify(vec![
+                (
+                    ModifyRelationshipOperation::Create,
+                    relationship(
+                        document.clone(),
+                        "parent",
+                        SubjectReference {
+                            object: object("group", "a"),
+                            optional_relation: None,
+                        },
+                    ),
+                ),
+                (
+                    ModifyRelationshipOperation::Create,
+                    relationship(object("group", "a"), "member", user("alice")),
+                ),
+            ])
+            .expect("relationships should be valid");
+
+        assert!(!check(&state, &document, "view", &user("alice")));
+    }
+
+    #[test]
+    fn intersection() {
+        let document = object("document", "a");
+        let state = state([
+            relationship(document.clone(), "owner", user("alice")),
+            relationship(document.clone(), "viewer", user("alice")),
+            relationship(document.clone(), "owner", user("bob")),
+            relationship(document.clone(), "viewer", user("charlie")),
+        ]);
+
+        assert!(check(&state, &document, "edit", &user("alice")));
+        assert!(!check(&state, &document, "edit", &user("bob")));
+        assert!(!check(&state, &document, "edit", &user("charlie")));
+        assert!(!check(&state, &document, "edit", &user("dave")));
+    }
+
+    #[test]
+    fn exclusion() {
+        let document = object("document", "a");
+        let state = state([
+            relationship(document.clone(), "viewer", user("alice")),
+            relationship(document.clone(), "viewer", user("bob")),
+            relationship(document.clone(), "banned", user("bob")),
+            relationship(document.clone(), "banned", user("charlie")),
+        ]);
+
+        assert!(check(&state, &document, "comment", &user("alice")));
+        assert!(!check(&state, &document, "comment", &user("bob")));
+        assert!(!check(&state, &document, "comment", &user("charlie")));
+    }
+
+    #[test]
+    fn exclusion_through_arrow() {
+        let folder = object("folder", "a");
+        let document = object("document", "a");
+        let state = state([
+            relationship(folder.clone(), "viewer", members("team")),
+            relationship(object("group", "team"), "member", user("alice")),
+            relationship(object("group", "team"), "member", user("bob")),
+            relationship(
+                document.clone(),
+                "parent",
+                SubjectReference {
+                    object: folder,
+                    optional_relation: None,
+                },
+            ),
+            relationship(document.clone(), "banned", user("bob")),
+        ]);
+
+        assert!(check(&state, &document, "comment", &user("alice")));
+        assert!(!check(&state, &document, "comment", &user("bob")));
+    }
+
+    #[test]
+    fn nil() {
+        let document = object("document", "a");
+        let state = state([relationship(document.clone(), "owner", user("alice"))]);
+
+        assert!(!check(&state, &document, "nothing", &user("alice")));
+    }
+
+    #[test]
+    fn caveat() {
+        let document = object("document", "a");
+        let state = state([model::Relationship {
+            optional_caveat: Some(Caveat {
+                caveat_name: "not_expired".to_owned(),
+                context: CaveatContext::from_iter([(
+                    "expires_at".to_owned(),
+                    json!("2024-01-01T00:00:00Z"),
+                )]),
+            }),
+            ..relationship(document.clone(), "owner", user("alice"))
+        }]);
+
+        let check_at = |now: &str| {
+            let context = CaveatContext::from_iter([("now".to_owned(), json!(now))]);
+            state
+                .check(&document, "view", &user("alice"), &context, MAX_DEPTH)
+                .expect("permission should be checked")
+        };
+
+        assert!(check_at("2023-12-31T23:59:59Z"));
+        assert!(!check_at("2024-01-01T00:00:00Z"));
+        let report = state
+            .check(
+                &document,
+                "view",
+                &user("alice"),
+                &CaveatContext::new(),
+                MAX_DEPTH,
+            )
+            .expect_err("`now` should be required by the caveat");
+        assert!(matches!(
+            report.current_context(),
+            RequestError::CaveatEvaluation
+        ));
+    }
+
+    #[test]
+    fn trace() {
+        let document = object("document", "a");
+        let state = state([
+            relationship(document.clone(), "viewer", members("team")),
+            relationship(object("group", "team"), "member", user("alice")),
+        ]);
+
+        let trace = state
+            .trace(
+                &document,
+                "view",
+                &user("alice"),
+                &CaveatContext::new(),
+                MAX_DEPTH,
+            )
+            .expect("permission should be traced");
+
+        assert!(trace.has_permission);
+        assert_eq!(trace.resource, "document:a");
+        // `owner` is evaluated first and does not grant the permission
+        assert_eq!(
+            trace
+                .sub_traces
+                .iter()
+                .map(|trace| (trace.relation.as_str(), trace.has_permission))
+                .collect::<Vec<_>>(),
+            [("owner", false), ("viewer", true)]
+        );
+        assert_eq!(trace.sub_traces[1].sub_traces[0].resource, "group:team");
+    }
+
+    #[test]
+    fn lookup() {
+        let state = state([
+            relationship(object("document", "a"), "owner", user("alice")),
+            relationship(object("document", "b"), "viewer", user("alice")),
+            relationship(object("document", "b"), "banned", user("alice")),
+            relationship(object("document", "c"), "owner", user("bob")),
+        ]);
+
+        let resources = state
+            .lookup("document", "comment", &user("alice"), &CaveatContext::new())
+            .expect("resources should be looked up");
+
+        assert_eq!(resources, [&object("document", "a")]);
+    }
+
+    #[test]
+    fn max_depth() {
+        let state = state([
+            relationship(object("group", "a"), "member", members("b")),
+            relationship(object("group", "b"), "member", members("a")),
+        ]);
+
+        let report = state
+            .check(
+                &object("group", "a"),
+                "member",
+                &user("alice"),
+                &CaveatContext::new(),
+                MAX_DEPTH,
+            )
+            .expect_err("the cycle should exceed the maximum depth");
+        assert!(matches!(
+            report.current_context(),
+            RequestError::MaxDepthExceeded
+        ));
+    }
+}
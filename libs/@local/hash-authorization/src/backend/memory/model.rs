@@ -0,0 +1,207 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// The identifier of an object, e.g. `graph/account:ab09…`.
+///
+/// Objects are stored in the same representation as they are sent to `SpiceDB`, i.e. both, the
+/// type and the id are strings.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ObjectReference {
+    pub(crate) object_type: String,
+    pub(crate) object_id: String,
+}
+
+impl ObjectReference {
+    /// Returns if this object refers to all objects of its type.
+    pub(crate) fn is_wildcard(&self) -> bool {
+        self.object_id == "*"
+    }
+}
+
+impl fmt::Display for ObjectReference {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}:{}", self.object_type, self.object_id)
+    }
+}
+
+/// An object, or the set of subjects having a relation to an object.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubjectReference {
+    pub(crate) object: ObjectReference,
+    pub(crate) optional_relation: Option<String>,
+}
+
+impl fmt::Display for SubjectReference {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.object, fmt)?;
+        if let Some(relation) = &self.optional_relation {
+            write!(fmt, "#{relation}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The values of caveat parameters by their name.
+pub(crate) type CaveatContext = serde_json::Map<String, serde_json::Value>;
+
+/// A caveat of a relationship and the values of its parameters known when writing it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Caveat {
+    pub(crate) caveat_name: String,
+    #[serde(default)]
+    pub(crate) context: CaveatContext,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct Relationship {
+    pub(crate) resource: ObjectReference,
+    pub(crate) relation: String,
+    pub(crate) subject: SubjectReference,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) optional_caveat: Option<Caveat>,
+}
+
+impl fmt::Display for Relationship {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}#{}@{}", self.resource, self.relation, self.subject)?;
+        if let Some(caveat) = &self.optional_caveat {
+            write!(fmt, "[{}]", caveat.caveat_name)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubjectRelationFilter {
+    relation: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SubjectFilter {
+    subject_type: String,
+    optional_subject_id: Option<String>,
+    optional_relation: Option<SubjectRelationFilter>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct RelationshipFilter {
+    resource_type: String,
+    optional_resource_id: Option<String>,
+    optional_relation: Option<String>,
+    optional_subject_filter: Option<SubjectFilter>,
+}
+
+impl RelationshipFilter {
+    pub(crate) fn matches(
+        &self,
+        resource: &ObjectReference,
+        relation: &str,
+        subject: &SubjectReference,
+    ) -> bool {
+        if resource.object_type != self.resource_type
+            || self
+                .optional_resource_id
+                .as_ref()
+                .is_some_and(|id| *id != resource.object_id)
+            || self
+                .optional_relation
+                .as_ref()
+                .is_some_and(|filter| filter != relation)
+        {
+            return false;
+        }
+
+        let Some(filter) = &self.optional_subject_filter else {
+            return true;
+        };
+
+        // An empty relation filter only matches subjects without a relation
+        subject.object.object_type == filter.subject_type
+            && filter
+                .optional_subject_id
+                .as_ref()
+                .map_or(true, |id| *id == subject.object.object_id)
+            && filter.optional_relation.as_ref().map_or(true, |filter| {
+                subject.optional_relation.as_deref().unwrap_or_default() == filter.relation
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn filter(filter: serde_json::Value) -> RelationshipFilter {
+        serde_json::from_value(filter).expect("filter should be valid")
+    }
+
+    fn subject(object_type: &str, object_id: &str, relation: Option<&str>) -> SubjectReference {
+        SubjectReference {
+            object: ObjectReference {
+                object_type: object_type.to_owned(),
+                object_id: object_id.to_owned(),
+            },
+            optional_relation: relation.map(str::to_owned),
+        }
+    }
+
+    #[test]
+    fn relationship_filter() {
+        let document = ObjectReference {
+            object_type: "document".to_owned(),
+            object_id: "a".to_owned(),
+        };
+        let alice = subject("user", "alice", None);
+        let members = subject("group", "a", Some("member"));
+
+        assert!(filter(json!({ "resourceType": "document" })).matches(&document, "owner", &alice));
+        assert!(!filter(json!({ "resourceType": "folder" })).matches(&document, "owner", &alice));
+        assert!(
+            filter(json!({ "resourceType": "document", "optionalResourceId": "a" }))
+                .matches(&document, "owner", &alice)
+        );
+        assert!(
+            !filter(json!({ "resourceType": "document", "optionalResourceId": "b" }))
+                .matches(&document, "owner", &alice)
+        );
+        assert!(
+            !filter(json!({ "resourceType": "document", "optionalRelation": "viewer" }))
+                .matches(&document, "owner", &alice)
+        );
+
+        let by_subject = filter(json!({
+            "resourceType": "document",
+            "optionalSubjectFilter": { "subjectType": "user", "optionalSubjectId": "alice" }
+        }));
+        assert!(by_subject.matches(&document, "owner", &alice));
+        assert!(!by_subject.matches(&document, "owner", &subject("user", "bob", None)));
+        assert!(!by_subject.matches(&document, "viewer", &members));
+
+        // An empty relation filter only matches subjects without a relation
+        let without_relation = filter(json!({
+            "resourceType": "document",
+            "optionalSubjectFilter": { "subjectType": "group", "optionalRelation": { "relation": "" } }
+        }));
+        assert!(!without_relation.matches(&document, "viewer", &members));
+        assert!(without_relation.matches(&document, "viewer", &subject("group", "a", None)));
+
+        let with_relation = filter(json!({
+            "resourceType": "document",
+            "optionalSubjectFilter": {
+                "subjectType": "group",
+                "optionalRelation": { "relation": "member" }
+            }
+        }));
+        assert!(with_relation.matches(&document, "viewer", &members));
+    }
+}
@@ -0,0 +1,1241 @@
+//! Parsing and evaluation model of schemas written in the `SpiceDB` schema language.
+//!
+//! Only a subset of the language is supported, which are definitions containing relations and
+//! permissions, and caveats. Permissions may use unions (`+`), intersections (`&`), exclusions
+//! (`-`), arrows (`->`), `nil` and parentheses. Different operators have to be separated by
+//! parentheses, so the precedence of the operators never matters. Caveats may take `int` and
+//! `timestamp` parameters and their expression has to be a single comparison of two parameters.
+
+use std::{cmp::Ordering, collections::HashMap, error::Error, fmt, iter::Peekable};
+
+use error_stack::Report;
+use serde_json::Value;
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
+
+use crate::backend::memory::model::{self, CaveatContext, ObjectReference, SubjectReference};
+
+#[derive(Debug)]
+pub(crate) enum SchemaError {
+    Syntax {
+        line: usize,
+        expected: &'static str,
+        found: String,
+    },
+    Unsupported {
+        line: usize,
+        feature: String,
+    },
+    MixedOperators {
+        line: usize,
+    },
+    DuplicateDefinition(String),
+    DuplicateCaveat(String),
+    DuplicateParameter {
+        caveat: String,
+        name: String,
+    },
+    DuplicateMember {
+        definition: String,
+        name: String,
+    },
+    UnknownDefinition(String),
+    UnknownCaveat(String),
+    UnknownParameter {
+        caveat: String,
+        name: String,
+    },
+    IncomparableParameters {
+        caveat: String,
+    },
+    MissingContext {
+        caveat: String,
+        parameter: String,
+    },
+    InvalidContext {
+        caveat: String,
+        parameter: String,
+    },
+    UnknownMember {
+        definition: String,
+        name: String,
+    },
+    ArrowOnPermission {
+        definition: String,
+        name: String,
+    },
+    NotARelation {
+        definition: String,
+        name: String,
+    },
+    SubjectNotAllowed,
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax {
+                line,
+                expected,
+                found,
+            } => write!(fmt, "line {line}: expected {expected}, found {found}"),
+            Self::Unsupported { line, feature } => {
+                write!(fmt, "line {line}: `{feature}` is not supported")
+            }
+            Self::MixedOperators { line } => write!(
+                fmt,
+                "line {line}: different operators have to be separated by parentheses"
+            ),
+            Self::DuplicateDefinition(definition) => {
+                write!(fmt, "`{definition}` is defined more than once")
+            }
+            Self::DuplicateCaveat(caveat) => {
+                write!(fmt, "caveat `{caveat}` is defined more than once")
+            }
+            Self::DuplicateParameter { caveat, name } => {
+                write!(
+                    fmt,
+                    "`{name}` is defined more than once in caveat `{caveat}`"
+                )
+            }
+            Self::DuplicateMember { definition, name } => {
+                write!(fmt, "`{name}` is defined more than once in `{definition}`")
+            }
+            Self::UnknownDefinition(definition) => {
+                write!(fmt, "`{definition}` is not defined")
+            }
+            Self::UnknownCaveat(caveat) => write!(fmt, "caveat `{caveat}` is not defined"),
+            Self::UnknownParameter { caveat, name } => {
+                write!(fmt, "caveat `{caveat}` does not have a parameter `{name}`")
+            }
+            Self::IncomparableParameters { caveat } => write!(
+                fmt,
+                "the parameters compared in caveat `{caveat}` do not have the same type"
+            ),
+            Self::MissingContext { caveat, parameter } => write!(
+                fmt,
+                "parameter `{parameter}` of caveat `{caveat}` was not provided"
+            ),
+            Self::InvalidContext { caveat, parameter } => write!(
+                fmt,
+                "the value of parameter `{parameter}` of caveat `{caveat}` does not match its type"
+            ),
+            Self::UnknownMember { definition, name } => {
+                write!(
+                    fmt,
+                    "`{definition}` does not have a relation or permission `{name}`"
+                )
+            }
+            Self::ArrowOnPermission { definition, name } => write!(
+                fmt,
+                "arrows can only be used on relations, but `{name}` is a permission in \
+                 `{definition}`"
+            ),
+            Self::NotARelation { definition, name } => {
+                write!(
+                    fmt,
+                    "`{name}` is a permission in `{definition}`, not a relation"
+                )
+            }
+            Self::SubjectNotAllowed => fmt.write_str("the subject is not allowed for the relation"),
+        }
+    }
+}
+
+impl Error for SchemaError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token<'s> {
+    Identifier(&'s str),
+    Symbol(&'static str),
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(identifier) => write!(fmt, "`{identifier}`"),
+            Self::Symbol(symbol) => write!(fmt, "`{symbol}`"),
+        }
+    }
+}
+
+// Symbols which are a prefix of another symbol have to be listed after it
+const SYMBOLS: [&str; 20] = [
+    "->", "<=", ">=", "==", "!=", "{", "}", "(", ")", ":", ",", "|", "#", "*", "+", "&", "-", "=",
+    "<", ">",
+];
+
+/// Splits the source into tokens and the line they appear in.
+fn tokenize(source: &str) -> Result<Vec<(Token<'_>, usize)>, Report<SchemaError>> {
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut rest = source;
+
+    loop {
+        let trimmed = rest.trim_start_matches(|char: char| char.is_whitespace() || char == ';');
+        line += rest
+            .split_at(rest.len() - trimmed.len())
+            .0
+            .matches('\n')
+            .count();
+        rest = trimmed;
+
+        if rest.is_empty() {
+            return Ok(tokens);
+        } else if let Some(comment) = rest.strip_prefix("//") {
+            rest = comment.find('\n').map_or("", |end| comment.split_at(end).1);
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            let (comment, tail) = comment.split_once("*/").ok_or_else(|| {
+                Report::new(SchemaError::Syntax {
+                    line,
+                    expected: "`*/`",
+                    found: "end of schema".to_owned(),
+                })
+            })?;
+            line += comment.matches('\n').count();
+            rest = tail;
+        } else if rest.starts_with(|char: char| char.is_ascii_alphanumeric() || char == '_') {
+            let (identifier, tail) = rest.split_at(
+                rest.find(|char: char| {
+                    !(char.is_ascii_alphanumeric() || char == '_' || char == '/')
+                })
+                .unwrap_or(rest.len()),
+            );
+            tokens.push((Token::Identifier(identifier), line));
+            rest = tail;
+        } else if let Some((symbol, tail)) = SYMBOLS
+            .iter()
+            .find_map(|symbol| rest.strip_prefix(symbol).map(|tail| (*symbol, tail)))
+        {
+            tokens.push((Token::Symbol(symbol), line));
+            rest = tail;
+        } else {
+            return Err(Report::new(SchemaError::Unsupported {
+                line,
+                feature: rest.chars().next().unwrap_or_default().to_string(),
+            }));
+        }
+    }
+}
+
+/// A subject type, which is allowed for a relation.
+#[derive(Debug)]
+pub(crate) enum AllowedSubject {
+    /// `namespace`
+    Object(String),
+    /// `namespace:*`
+    Wildcard(String),
+    /// `namespace#relation`
+    Set { kind: String, relation: String },
+}
+
+impl AllowedSubject {
+    fn matches(&self, subject: &SubjectReference) -> bool {
+        let object = &subject.object;
+        match (self, &subject.optional_relation) {
+            (Self::Object(kind), None) => *kind == object.object_type && !object.is_wildcard(),
+            (Self::Wildcard(kind), None) => *kind == object.object_type && object.is_wildcard(),
+            (Self::Set { kind, relation }, Some(subject_relation)) => {
+                *kind == object.object_type && relation == subject_relation && !object.is_wildcard()
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A subject type with an optional caveat, which is allowed for a relation.
+#[derive(Debug)]
+pub(crate) struct AllowedType {
+    subject: AllowedSubject,
+    /// `with caveat`
+    caveat: Option<String>,
+}
+
+impl AllowedType {
+    fn matches(&self, subject: &SubjectReference, caveat: Option<&str>) -> bool {
+        self.caveat.as_deref() == caveat && self.subject.matches(subject)
+    }
+}
+
+/// The type of a caveat parameter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum ParameterType {
+    Int,
+    Timestamp,
+}
+
+impl ParameterType {
+    fn parse(self, value: &Value) -> Option<ParameterValue> {
+        match self {
+            Self::Int => value.as_i64().map(ParameterValue::Int),
+            Self::Timestamp => value
+                .as_str()
+                .and_then(|value| OffsetDateTime::parse(value, &Rfc3339).ok())
+                .map(ParameterValue::Timestamp),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum ParameterValue {
+    Int(i64),
+    Timestamp(OffsetDateTime),
+}
+
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl Comparison {
+    const fn holds(self, ordering: Ordering) -> bool {
+        match self {
+            Self::Less => ordering.is_lt(),
+            Self::LessOrEqual => ordering.is_le(),
+            Self::Greater => ordering.is_gt(),
+            Self::GreaterOrEqual => ordering.is_ge(),
+            Self::Equal => ordering.is_eq(),
+            Self::NotEqual => ordering.is_ne(),
+        }
+    }
+}
+
+/// A caveat, which is a condition on a relationship evaluated when checking a permission.
+#[derive(Debug)]
+pub(crate) struct Caveat {
+    name: String,
+    parameters: HashMap<String, ParameterType>,
+    /// `lhs <comparison> rhs`
+    lhs: String,
+    comparison: Comparison,
+    rhs: String,
+}
+
+impl Caveat {
+    fn parameter(&self, name: &str) -> Result<ParameterType, Report<SchemaError>> {
+        self.parameters.get(name).copied().ok_or_else(|| {
+            Report::new(SchemaError::UnknownParameter {
+                caveat: self.name.clone(),
+                name: name.to_owned(),
+            })
+        })
+    }
+
+    fn value(
+        &self,
+        parameter: &str,
+        context: &CaveatContext,
+    ) -> Result<ParameterValue, Report<SchemaError>> {
+        let value = context.get(parameter).ok_or_else(|| {
+            Report::new(SchemaError::MissingContext {
+                caveat: self.name.clone(),
+                parameter: parameter.to_owned(),
+            })
+        })?;
+        self.parameter(parameter)?.parse(value).ok_or_else(|| {
+            Report::new(SchemaError::InvalidContext {
+                caveat: self.name.clone(),
+                parameter: parameter.to_owned(),
+            })
+        })
+    }
+
+    /// Validates the context stored on a relationship, which may provide a subset of the
+    /// parameters.
+    fn validate_context(&self, context: &CaveatContext) -> Result<(), Report<SchemaError>> {
+        context
+            .keys()
+            .try_for_each(|parameter| self.value(parameter, context).map(|_| ()))
+    }
+
+    /// Evaluates the caveat, where the context of the relationship takes precedence over the
+    /// context of the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a parameter is not provided by either context or does not match its
+    /// type.
+    pub(crate) fn evaluate(
+        &self,
+        relationship_context: &CaveatContext,
+        request_context: &CaveatContext,
+    ) -> Result<bool, Report<SchemaError>> {
+        let value = |parameter: &str| {
+            if relationship_context.contains_key(parameter) {
+                self.value(parameter, relationship_context)
+            } else {
+                self.value(parameter, request_context)
+            }
+        };
+
+        Ok(self
+            .comparison
+            .holds(value(&self.lhs)?.cmp(&value(&self.rhs)?)))
+    }
+}
+
+/// The expression of a permission.
+#[derive(Debug)]
+pub(crate) enum Expression {
+    /// `nil`, which never matches.
+    Nil,
+    /// `relation`, which refers to a relation or a permission of the same definition.
+    Reference(String),
+    /// `relation->permission`, which follows `relation` to the subjects and evaluates
+    /// `permission` on them.
+    Arrow {
+        relation: String,
+        permission: String,
+    },
+    /// `lhs + rhs`
+    Union(Box<Self>, Box<Self>),
+    /// `lhs & rhs`
+    Intersection(Box<Self>, Box<Self>),
+    /// `lhs - rhs`
+    Exclusion(Box<Self>, Box<Self>),
+}
+
+/// A relation or permission of a [`Definition`].
+#[derive(Debug)]
+pub(crate) enum Member {
+    Relation(Vec<AllowedType>),
+    Permission(Expression),
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Definition {
+    members: HashMap<String, Member>,
+}
+
+impl Definition {
+    pub(crate) fn member(&self, name: &str) -> Option<&Member> {
+        self.members.get(name)
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Schema {
+    definitions: HashMap<String, Definition>,
+    caveats: HashMap<String, Caveat>,
+}
+
+struct Parser<'s, I: Iterator<Item = (Token<'s>, usize)>> {
+    tokens: Peekable<I>,
+    line: usize,
+}
+
+impl<'s, I> Parser<'s, I>
+where
+    I: Iterator<Item = (Token<'s>, usize)>,
+{
+    fn next(&mut self, expected: &'static str) -> Result<Token<'s>, Report<SchemaError>> {
+        match self.tokens.next() {
+            Some((token, line)) => {
+                self.line = line;
+                Ok(token)
+            }
+            None => Err(Report::new(SchemaError::Syntax {
+                line: self.line,
+                expected,
+                found: "end of schema".to_owned(),
+            })),
+        }
+    }
+
+    fn unexpected(&self, expected: &'static str, found: &Token<'_>) -> Report<SchemaError> {
+        Report::new(SchemaError::Syntax {
+            line: self.line,
+            expected,
+            found: found.to_string(),
+        })
+    }
+
+    fn identifier(&mut self, expected: &'static str) -> Result<&'s str, Report<SchemaError>> {
+        match self.next(expected)? {
+            Token::Identifier(identifier) => Ok(identifier),
+            token @ Token::Symbol(_) => Err(self.unexpected(expected, &token)),
+        }
+    }
+
+    fn symbol(&mut self, symbol: &'static str) -> Result<(), Report<SchemaError>> {
+        let token = self.next(symbol)?;
+        if token == Token::Symbol(symbol) {
+            Ok(())
+        } else {
+            Err(self.unexpected(symbol, &token))
+        }
+    }
+
+    fn next_if_symbol(&mut self, symbol: &'static str) -> bool {
+        self.tokens
+            .next_if(|(token, _)| *token == Token::Symbol(symbol))
+            .is_some()
+    }
+
+    fn schema(&mut self) -> Result<Schema, Report<SchemaError>> {
+        let mut schema = Schema::default();
+
+        while self.tokens.peek().is_some() {
+            match self.identifier("`definition` or `caveat`")? {
+                "definition" => {
+                    let name = self.identifier("definition name")?;
+                    let definition = self.definition(name)?;
+                    if schema
+                        .definitions
+                        .insert(name.to_owned(), definition)
+                        .is_some()
+                    {
+                        return Err(Report::new(SchemaError::DuplicateDefinition(
+                            name.to_owned(),
+                        )));
+                    }
+                }
+                "caveat" => {
+                    let caveat = self.caveat()?;
+                    if let Some(caveat) = schema.caveats.insert(caveat.name.clone(), caveat) {
+                        return Err(Report::new(SchemaError::DuplicateCaveat(caveat.name)));
+                    }
+                }
+                keyword => {
+                    return Err(Report::new(SchemaError::Unsupported {
+                        line: self.line,
+                        feature: keyword.to_owned(),
+                    }));
+                }
+            }
+        }
+
+        Ok(schema)
+    }
+
+    fn caveat(&mut self) -> Result<Caveat, Report<SchemaError>> {
+        let name = self.identifier("caveat name")?.to_owned();
+
+        let mut parameters = HashMap::new();
+        self.symbol("(")?;
+        loop {
+            let parameter = self.identifier("parameter name")?;
+            let parameter_type = match self.identifier("parameter type")? {
+                "int" => ParameterType::Int,
+                "timestamp" => ParameterType::Timestamp,
+                parameter_type => {
+                    return Err(Report::new(SchemaError::Unsupported {
+                        line: self.line,
+                        feature: parameter_type.to_owned(),
+                    }));
+                }
+            };
+            if parameters
+                .insert(parameter.to_owned(), parameter_type)
+                .is_some()
+            {
+                return Err(Report::new(SchemaError::DuplicateParameter {
+                    caveat: name,
+                    name: parameter.to_owned(),
+                }));
+            }
+            if !self.next_if_symbol(",") {
+                break;
+            }
+        }
+        self.symbol(")")?;
+
+        self.symbol("{")?;
+        let lhs = self.identifier("parameter name")?.to_owned();
+        let comparison = match self.next("comparison")? {
+            Token::Symbol("<") => Comparison::Less,
+            Token::Symbol("<=") => Comparison::LessOrEqual,
+            Token::Symbol(">") => Comparison::Greater,
+            Token::Symbol(">=") => Comparison::GreaterOrEqual,
+            Token::Symbol("==") => Comparison::Equal,
+            Token::Symbol("!=") => Comparison::NotEqual,
+            token => return Err(self.unexpected("comparison", &token)),
+        };
+        let rhs = self.identifier("parameter name")?.to_owned();
+        self.symbol("}")?;
+
+        Ok(Caveat {
+            name,
+            parameters,
+            lhs,
+            comparison,
+            rhs,
+        })
+    }
+
+    fn definition(&mut self, name: &str) -> Result<Definition, Report<SchemaError>> {
+        let mut definition = Definition::default();
+
+        self.symbol("{")?;
+        while !self.next_if_symbol("}") {
+            let (member_name, member) = match self.identifier("`relation` or `permission`")? {
+                "relation" => {
+                    let member_name = self.identifier("relation name")?;
+                    self.symbol(":")?;
+                    let mut allowed_types = vec![self.allowed_type()?];
+                    while self.next_if_symbol("|") {
+                        allowed_types.push(self.allowed_type()?);
+                    }
+                    (member_name, Member::Relation(allowed_types))
+                }
+                "permission" => {
+                    let member_name = self.identifier("permission name")?;
+                    self.symbol("=")?;
+                    (member_name, Member::Permission(self.expression()?))
+                }
+                keyword => {
+                    return Err(Report::new(SchemaError::Unsupported {
+                        line: self.line,
+                        feature: keyword.to_owned(),
+                    }));
+                }
+            };
+
+            if definition
+                .members
+                .insert(member_name.to_owned(), member)
+                .is_some()
+            {
+                return Err(Report::new(SchemaError::DuplicateMember {
+                    definition: name.to_owned(),
+                    name: member_name.to_owned(),
+                }));
+            }
+        }
+
+        Ok(definition)
+    }
+
+    fn allowed_type(&mut self) -> Result<AllowedType, Report<SchemaError>> {
+        let kind = self.identifier("subject type")?.to_owned();
+
+        let subject = if self.next_if_symbol("#") {
+            AllowedSubject::Set {
+                kind,
+                relation: self.identifier("relation name")?.to_owned(),
+            }
+        } else if self.next_if_symbol(":") {
+            self.symbol("*")?;
+            AllowedSubject::Wildcard(kind)
+        } else {
+            AllowedSubject::Object(kind)
+        };
+
+        let caveat = self
+            .tokens
+            .next_if(|(token, _)| *token == Token::Identifier("with"))
+            .map(|_| self.identifier("caveat name").map(str::to_owned))
+            .transpose()?;
+
+        Ok(AllowedType { subject, caveat })
+    }
+
+    /// Parses binary operators, which are left-associative.
+    ///
+    /// `SpiceDB` gives the operators different precedences, so mixing them without parentheses is
+    /// rejected instead of risking a different interpretation.
+    fn expression(&mut self) -> Result<Expression, Report<SchemaError>> {
+        let mut expression = self.term()?;
+        let mut previous_symbol = None;
+
+        loop {
+            let Some(symbol) = ["+", "&", "-"]
+                .into_iter()
+                .find(|&symbol| self.next_if_symbol(symbol))
+            else {
+                return Ok(expression);
+            };
+            if previous_symbol.is_some_and(|previous_symbol| previous_symbol != symbol) {
+                return Err(Report::new(SchemaError::MixedOperators { line: self.line }));
+            }
+            previous_symbol = Some(symbol);
+
+            let operator = match symbol {
+                "+" => Expression::Union,
+                "&" => Expression::Intersection,
+                _ => Expression::Exclusion,
+            };
+            expression = operator(Box::new(expression), Box::new(self.term()?));
+        }
+    }
+
+    fn term(&mut self) -> Result<Expression, Report<SchemaError>> {
+        match self.next("expression")? {
+            Token::Symbol("(") => {
+                let expression = self.expression()?;
+                self.symbol(")")?;
+                Ok(expression)
+            }
+            Token::Identifier("nil") => Ok(Expression::Nil),
+            Token::Identifier(relation) => {
+                if self.next_if_symbol("->") {
+                    Ok(Expression::Arrow {
+                        relation: relation.to_owned(),
+                        permission: self.identifier("permission name")?.to_owned(),
+                    })
+                } else {
+                    Ok(Expression::Reference(relation.to_owned()))
+                }
+            }
+            token @ Token::Symbol(_) => Err(self.unexpected("expression", &token)),
+        }
+    }
+}
+
+impl Schema {
+    /// Parses and validates a schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema is not valid or uses unsupported features.
+    pub(crate) fn parse(source: &str) -> Result<Self, Report<SchemaError>> {
+        let schema = Parser {
+            tokens: tokenize(source)?.into_iter().peekable(),
+            line: 1,
+        }
+        .schema()?;
+
+        for caveat in schema.caveats.values() {
+            if caveat.parameter(&caveat.lhs)? != caveat.parameter(&caveat.rhs)? {
+                return Err(Report::new(SchemaError::IncomparableParameters {
+                    caveat: caveat.name.clone(),
+                }));
+            }
+        }
+
+        for (name, definition) in &schema.definitions {
+            for member in definition.members.values() {
+                match member {
+                    Member::Relation(allowed_types) => {
+                        for allowed_type in allowed_types {
+                            schema.validate_allowed_type(allowed_type)?;
+                        }
+                    }
+                    Member::Permission(expression) => {
+                        Self::validate_expression(name, definition, expression)?;
+                    }
+                }
+            }
+        }
+
+        Ok(schema)
+    }
+
+    pub(crate) fn definition(&self, name: &str) -> Result<&Definition, Report<SchemaError>> {
+        self.definitions
+            .get(name)
+            .ok_or_else(|| Report::new(SchemaError::UnknownDefinition(name.to_owned())))
+    }
+
+    pub(crate) fn member(
+        &self,
+        definition: &str,
+        name: &str,
+    ) -> Result<&Member, Report<SchemaError>> {
+        self.definition(definition)?.member(name).ok_or_else(|| {
+            Report::new(SchemaError::UnknownMember {
+                definition: definition.to_owned(),
+                name: name.to_owned(),
+            })
+        })
+    }
+
+    pub(crate) fn caveat(&self, name: &str) -> Result<&Caveat, Report<SchemaError>> {
+        self.caveats
+            .get(name)
+            .ok_or_else(|| Report::new(SchemaError::UnknownCaveat(name.to_owned())))
+    }
+
+    fn validate_allowed_type(&self, allowed_type: &AllowedType) -> Result<(), Report<SchemaError>> {
+        if let Some(caveat) = &allowed_type.caveat {
+            self.caveat(caveat)?;
+        }
+        match &allowed_type.subject {
+            AllowedSubject::Object(kind) | AllowedSubject::Wildcard(kind) => {
+                self.definition(kind).map(|_| ())
+            }
+            AllowedSubject::Set { kind, relation } => self.member(kind, relation).map(|_| ()),
+        }
+    }
+
+    fn validate_expression(
+        name: &str,
+        definition: &Definition,
+        expression: &Expression,
+    ) -> Result<(), Report<SchemaError>> {
+        let member = |member: &str| {
+            definition.member(member).ok_or_else(|| {
+                Report::new(SchemaError::UnknownMember {
+                    definition: name.to_owned(),
+                    name: member.to_owned(),
+                })
+            })
+        };
+
+        match expression {
+            Expression::Nil => Ok(()),
+            Expression::Reference(reference) => member(reference).map(|_| ()),
+            Expression::Arrow { relation, .. } => match member(relation)? {
+                Member::Relation(_) => Ok(()),
+                Member::Permission(_) => Err(Report::new(SchemaError::ArrowOnPermission {
+                    definition: name.to_owned(),
+                    name: relation.clone(),
+                })),
+            },
+            Expression::Union(lhs, rhs)
+            | Expression::Intersection(lhs, rhs)
+            | Expression::Exclusion(lhs, rhs) => {
+                Self::validate_expression(name, definition, lhs)?;
+                Self::validate_expression(name, definition, rhs)
+            }
+        }
+    }
+
+    /// Validates that a relationship can be written for this schema.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the relation is not defined for the resource, the subject is not
+    /// allowed for the relation with the caveat, or the context does not match the caveat.
+    pub(crate) fn validate_relationship(
+        &self,
+        resource: &ObjectReference,
+        relation: &str,
+        subject: &SubjectReference,
+        caveat: Option<&model::Caveat>,
+    ) -> Result<(), Report<SchemaError>> {
+        if let Some(caveat) = caveat {
+            self.caveat(&caveat.caveat_name)?
+                .validate_context(&caveat.context)?;
+        }
+
+        let caveat_name = caveat.map(|caveat| caveat.caveat_name.as_str());
+        match self.member(&resource.object_type, relation)? {
+            Member::Relation(allowed_types)
+                if allowed_types
+                    .iter()
+                    .any(|allowed_type| allowed_type.matches(subject, caveat_name)) =>
+            {
+                Ok(())
+            }
+            Member::Relation(_) => Err(Report::new(SchemaError::SubjectNotAllowed)),
+            Member::Permission(_) => Err(Report::new(SchemaError::NotARelation {
+                definition: resource.object_type.clone(),
+                name: relation.to_owned(),
+            })),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const SCHEMA: &str = "
+        /* A user of the system */
+        definition user {}
+
+        // A group of users
+        definition group {
+            relation member: user | group#member;
+            permission view = member;
+        }
+
+        caveat not_expired(now timestamp, expires_at timestamp) {
+            now < expires_at
+        }
+
+        definition document {
+            relation owner: user | user with not_expired
+            relation viewer: user | user:* | group#member
+            relation parent: document
+            relation banned: user
+
+            permission edit = owner
+            permission view = (owner + viewer + parent->view) - banned
+            permission both = owner & viewer
+            permission nothing = nil
+        }
+    ";
+
+    fn schema() -> Schema {
+        Schema::parse(SCHEMA).expect("schema should be valid")
+    }
+
+    #[track_caller]
+    fn parse_error(source: &str) -> Report<SchemaError> {
+        Schema::parse(source).expect_err("schema should not be valid")
+    }
+
+    fn object(object_type: &str, object_id: &str) -> ObjectReference {
+        ObjectReference {
+            object_type: object_type.to_owned(),
+            object_id: object_id.to_owned(),
+        }
+    }
+
+    fn subject(object_type: &str, object_id: &str, relation: Option<&str>) -> SubjectReference {
+        SubjectReference {
+            object: object(object_type, object_id),
+            optional_relation: relation.map(str::to_owned),
+        }
+    }
+
+    fn context(value: Value) -> CaveatContext {
+        match value {
+            Value::Object(context) => context,
+            _ => panic!("context should be an object"),
+        }
+    }
+
+    fn permission<'s>(schema: &'s Schema, definition: &str, name: &str) -> &'s Expression {
+        match schema.member(definition, name) {
+            Ok(Member::Permission(expression)) => expression,
+            member => panic!("`{definition}#{name}` should be a permission: {member:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_schema() {
+        let schema = schema();
+
+        assert!(matches!(
+            schema.member("group", "member"),
+            Ok(Member::Relation(allowed_types)) if allowed_types.len() == 2
+        ));
+        assert!(matches!(
+            schema.member("document", "viewer"),
+            Ok(Member::Relation(allowed_types)) if allowed_types.len() == 3
+        ));
+        assert!(matches!(
+            permission(&schema, "document", "nothing"),
+            Expression::Nil
+        ));
+        assert!(matches!(
+            permission(&schema, "document", "both"),
+            Expression::Intersection(lhs, rhs)
+                if matches!(**lhs, Expression::Reference(ref name) if name == "owner")
+                    && matches!(**rhs, Expression::Reference(ref name) if name == "viewer")
+        ));
+        schema
+            .caveat("not_expired")
+            .expect("caveat should be defined");
+    }
+
+    #[test]
+    fn parse_nested_expression() {
+        let schema = schema();
+
+        assert!(matches!(
+            permission(&schema, "document", "view"),
+            Expression::Exclusion(lhs, rhs)
+                if matches!(**lhs, Expression::Union(_, ref arrow)
+                    if matches!(**arrow, Expression::Arrow { ref relation, ref permission }
+                        if relation == "parent" && permission == "view"))
+                    && matches!(**rhs, Expression::Reference(ref name) if name == "banned")
+        ));
+    }
+
+    #[test]
+    fn operators_are_left_associative() {
+        let schema = Schema::parse(
+            "definition user {}
+            definition document {
+                relation a: user
+                relation b: user
+                relation c: user
+                permission flat = a - b - c
+                permission nested = a + (b - c)
+            }",
+        )
+        .expect("schema should be valid");
+
+        assert!(matches!(
+            permission(&schema, "document", "flat"),
+            Expression::Exclusion(lhs, rhs)
+                if matches!(**lhs, Expression::Exclusion(..))
+                    && matches!(**rhs, Expression::Reference(ref name) if name == "c")
+        ));
+        assert!(matches!(
+            permission(&schema, "document", "nested"),
+            Expression::Union(_, rhs) if matches!(**rhs, Expression::Exclusion(..))
+        ));
+    }
+
+    #[test]
+    fn mixed_operators() {
+        for expression in ["a + b - c", "a - b + c", "a & b + c", "(a + b) & c - a"] {
+            assert!(matches!(
+                parse_error(&format!(
+                    "definition user {{}}\ndefinition document {{\n  relation a: user\n  relation \
+                     b: user\n  relation c: user\n  permission view = {expression}\n}}"
+                ))
+                .current_context(),
+                SchemaError::MixedOperators { line: 6 }
+            ));
+        }
+    }
+
+    #[test]
+    fn unterminated_comment() {
+        assert!(matches!(
+            parse_error("definition user {}\n/* comment").current_context(),
+            SchemaError::Syntax {
+                line: 2,
+                expected: "`*/`",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn unexpected_end() {
+        assert!(matches!(
+            parse_error("definition user {\n    relation owner: user").current_context(),
+            SchemaError::Syntax { line: 2, expected: "`relation` or `permission`", found }
+                if found == "end of schema"
+        ));
+        assert!(matches!(
+            parse_error("definition user {}\ndefinition document { permission view =")
+                .current_context(),
+            SchemaError::Syntax {
+                line: 2,
+                expected: "expression",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn unexpected_token() {
+        assert!(matches!(
+            parse_error("definition {}").current_context(),
+            SchemaError::Syntax { line: 1, expected: "definition name", found } if found == "`{`"
+        ));
+        assert!(matches!(
+            parse_error("definition user {}\ndefinition document {\n  relation owner user\n}")
+                .current_context(),
+            SchemaError::Syntax { line: 3, expected: ":", found } if found == "`user`"
+        ));
+        assert!(matches!(
+            parse_error("definition document { permission view = + }").current_context(),
+            SchemaError::Syntax { expected: "expression", found, .. } if found == "`+`"
+        ));
+        assert!(matches!(
+            parse_error("definition user {}\ndefinition document { relation a: user:foo }")
+                .current_context(),
+            SchemaError::Syntax { expected: "*", found, .. } if found == "`foo`"
+        ));
+        assert!(matches!(
+            parse_error("caveat c(a int, b int) { a = b }").current_context(),
+            SchemaError::Syntax {
+                expected: "comparison",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn unsupported_features() {
+        assert!(matches!(
+            parse_error("use expiration\ndefinition user {}").current_context(),
+            SchemaError::Unsupported { line: 1, feature } if feature == "use"
+        ));
+        assert!(matches!(
+            parse_error("definition user {\n  relation self: user\n  \
+                         permission view = self.all(self)\n}")
+            .current_context(),
+            SchemaError::Unsupported { line: 3, feature } if feature == "."
+        ));
+        assert!(matches!(
+            parse_error("caveat c(a string, b string) { a == b }").current_context(),
+            SchemaError::Unsupported { feature, .. } if feature == "string"
+        ));
+        assert!(matches!(
+            parse_error("definition user { relation a: user }\ndefinition group { nested }")
+                .current_context(),
+            SchemaError::Unsupported { line: 2, feature } if feature == "nested"
+        ));
+    }
+
+    #[test]
+    fn duplicates() {
+        assert!(matches!(
+            parse_error("definition user {}\ndefinition user {}").current_context(),
+            SchemaError::DuplicateDefinition(name) if name == "user"
+        ));
+        assert!(matches!(
+            parse_error("definition user { relation a: user\n permission a = nil }")
+                .current_context(),
+            SchemaError::DuplicateMember { definition, name }
+                if definition == "user" && name == "a"
+        ));
+        assert!(matches!(
+            parse_error("caveat c(a int, b int) { a == b }\ncaveat c(a int, b int) { a != b }")
+                .current_context(),
+            SchemaError::DuplicateCaveat(name) if name == "c"
+        ));
+        assert!(matches!(
+            parse_error("caveat c(a int, a int) { a == a }").current_context(),
+            SchemaError::DuplicateParameter { caveat, name } if caveat == "c" && name == "a"
+        ));
+    }
+
+    #[test]
+    fn unknown_references() {
+        assert!(matches!(
+            parse_error("definition document { relation owner: user }").current_context(),
+            SchemaError::UnknownDefinition(name) if name == "user"
+        ));
+        assert!(matches!(
+            parse_error("definition user {}\ndefinition document { relation a: user#member }")
+                .current_context(),
+            SchemaError::UnknownMember { definition, name }
+                if definition == "user" && name == "member"
+        ));
+        assert!(matches!(
+            parse_error("definition document { permission view = owner }").current_context(),
+            SchemaError::UnknownMember { definition, name }
+                if definition == "document" && name == "owner"
+        ));
+        assert!(matches!(
+            parse_error("definition user {}\ndefinition document { relation a: user with c }")
+                .current_context(),
+            SchemaError::UnknownCaveat(name) if name == "c"
+        ));
+        assert!(matches!(
+            parse_error("caveat c(a int) { a == b }").current_context(),
+            SchemaError::UnknownParameter { caveat, name } if caveat == "c" && name == "b"
+        ));
+    }
+
+    #[test]
+    fn invalid_semantics() {
+        assert!(matches!(
+            parse_error(
+                "definition document { permission edit = nil\n permission view = edit->view }"
+            )
+            .current_context(),
+            SchemaError::ArrowOnPermission { definition, name }
+                if definition == "document" && name == "edit"
+        ));
+        assert!(matches!(
+            parse_error("caveat c(a int, b timestamp) { a < b }").current_context(),
+            SchemaError::IncomparableParameters { caveat } if caveat == "c"
+        ));
+    }
+
+    #[test]
+    fn validate_relationship() {
+        let schema = schema();
+        let document = object("document", "a");
+        let caveat = model::Caveat {
+            caveat_name: "not_expired".to_owned(),
+            context: context(json!({ "expires_at": "2024-01-01T00:00:00Z" })),
+        };
+
+        for (relation, subject, caveat) in [
+            ("owner", subject("user", "alice", None), None),
+            ("owner", subject("user", "alice", None), Some(&caveat)),
+            ("viewer", subject("user", "*", None), None),
+            ("viewer", subject("group", "a", Some("member")), None),
+        ] {
+            schema
+                .validate_relationship(&document, relation, &subject, caveat)
+                .expect("relationship should be valid");
+        }
+
+        for (relation, subject, caveat) in [
+            ("owner", subject("user", "*", None), None),
+            ("owner", subject("group", "a", None), None),
+            ("viewer", subject("user", "alice", None), Some(&caveat)),
+            ("viewer", subject("group", "a", Some("view")), None),
+        ] {
+            assert!(matches!(
+                schema
+                    .validate_relationship(&document, relation, &subject, caveat)
+                    .expect_err("subject should not be allowed")
+                    .current_context(),
+                SchemaError::SubjectNotAllowed
+            ));
+        }
+
+        assert!(matches!(
+            schema
+                .validate_relationship(&document, "view", &subject("user", "alice", None), None)
+                .expect_err("permissions cannot be written")
+                .current_context(),
+            SchemaError::NotARelation { name, .. } if name == "view"
+        ));
+        assert!(matches!(
+            schema
+                .validate_relationship(&document, "owner", &subject("user", "alice", None), Some(
+                    &model::Caveat {
+                        caveat_name: "not_expired".to_owned(),
+                        context: context(json!({ "expires_at": 1 })),
+                    }
+                ))
+                .expect_err("context should not match the caveat")
+                .current_context(),
+            SchemaError::InvalidContext { parameter, .. } if parameter == "expires_at"
+        ));
+    }
+
+    #[test]
+    fn evaluate_caveat() {
+        let schema = schema();
+        let caveat = schema.caveat("not_expired").expect("caveat should exist");
+        let relationship = context(json!({ "expires_at": "2024-01-01T00:00:00Z" }));
+
+        assert!(
+            caveat
+                .evaluate(
+                    &relationship,
+                    &context(json!({ "now": "2023-12-31T23:59:59Z" }))
+                )
+                .expect("caveat should be evaluated")
+        );
+        assert!(
+            !caveat
+                .evaluate(
+                    &relationship,
+                    &context(json!({ "now": "2024-01-01T00:00:00Z" }))
+                )
+                .expect("caveat should be evaluated")
+        );
+
+        // The context of the relationship takes precedence
+        assert!(
+            !caveat
+                .evaluate(
+                    &context(json!({
+                        "now": "2025-01-01T00:00:00Z",
+                        "expires_at": "2024-01-01T00:00:00Z"
+                    })),
+                    &context(json!({ "now": "2023-01-01T00:00:00Z" }))
+                )
+                .expect("caveat should be evaluated")
+        );
+
+        assert!(matches!(
+            caveat
+                .evaluate(&relationship, &CaveatContext::new())
+                .expect_err("`now` should be missing")
+                .current_context(),
+            SchemaError::MissingContext { parameter, .. } if parameter == "now"
+        ));
+        assert!(matches!(
+            caveat
+                .evaluate(&relationship, &context(json!({ "now": "yesterday" })))
+                .expect_err("`now` should not be a timestamp")
+                .current_context(),
+            SchemaError::InvalidContext { parameter, .. } if parameter == "now"
+        ));
+    }
+}
@@ -1,3 +1,4 @@
+mod memory;
 mod spicedb;
 
 use core::{fmt, iter::repeat};
@@ -7,7 +8,10 @@ use error_stack::Report;
 use futures::{stream, Stream};
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-pub use self::spicedb::{RpcError, SpiceDbOpenApi};
+pub use self::{
+    memory::InMemoryBackend,
+    spicedb::{RpcError, SpiceDbOpenApi},
+};
 use crate::{
     zanzibar::{
         types::{Relationship, RelationshipFilter, Resource, Subject},
@@ -196,6 +200,46 @@ pub trait ZanzibarBackend {
             > + Send
             + Sync;
 
+    /// Checks if the [`Subject`] has the specified [`Permission`] to a [`Resource`] and explains
+    /// how the result was computed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the check could not be performed.
+    fn explain_permission<O, R, S>(
+        &self,
+        resource: &O,
+        permission: &R,
+        subject: &S,
+        consistency: Consistency<'_>,
+    ) -> impl Future<Output = Result<ExplainPermissionResponse, Report<CheckError>>> + Send
+    where
+        O: Resource<Kind: Serialize, Id: Serialize> + Sync,
+        R: Serialize + Permission<O> + Sync,
+        S: Subject<Resource: Resource<Kind: Serialize, Id: Serialize>, Relation: Serialize> + Sync;
+
+    /// Returns the IDs of all [`Resource`]s of the given kind for which the [`Subject`] has the
+    /// specified [`Permission`].
+    ///
+    /// If `limit` is specified, at most `limit` IDs are returned and the lookup stops as soon as
+    /// the limit is reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lookup could not be performed.
+    fn lookup_resources<O, R, S>(
+        &self,
+        subject: &S,
+        permission: &R,
+        resource_kind: &O::Kind,
+        limit: Option<usize>,
+        consistency: Consistency<'_>,
+    ) -> impl Future<Output = Result<LookupResourcesResponse<O::Id>, Report<CheckError>>> + Send
+    where
+        O: Resource<Kind: Serialize + Sync, Id: DeserializeOwned + Send>,
+        R: Serialize + Permission<O> + Sync,
+        S: Subject<Resource: Resource<Kind: Serialize, Id: Serialize>, Relation: Serialize> + Sync;
+
     /// Returns the list of all relations matching the filter.
     ///
     /// # Errors
@@ -304,6 +348,44 @@ impl ZanzibarBackend for NoAuthorization {
         })
     }
 
+    async fn explain_permission<O, R, S>(
+        &self,
+        _: &O,
+        _: &R,
+        _: &S,
+        _: Consistency<'_>,
+    ) -> Result<ExplainPermissionResponse, Report<CheckError>>
+    where
+        O: Sync,
+        R: Sync,
+        S: Sync,
+    {
+        Ok(ExplainPermissionResponse {
+            has_permission: true,
+            trace: None,
+            checked_at: Zookie::empty(),
+        })
+    }
+
+    async fn lookup_resources<O, R, S>(
+        &self,
+        _: &S,
+        _: &R,
+        _: &O::Kind,
+        _: Option<usize>,
+        _: Consistency<'_>,
+    ) -> Result<LookupResourcesResponse<O::Id>, Report<CheckError>>
+    where
+        O: Resource<Kind: Sync>,
+        R: Sync,
+        S: Sync,
+    {
+        Ok(LookupResourcesResponse {
+            resources: None,
+            looked_up_at: Zookie::empty(),
+        })
+    }
+
     async fn read_relations<R>(
         &self,
         _: RelationshipFilter<
@@ -461,8 +543,53 @@ pub struct BulkCheckItem<R, P, S> {
     pub has_permission: Result<bool, RpcError>,
 }
 
-/// Error returned from [`ZanzibarBackend::check_permission`] and
-/// [`ZanzibarBackend::check_permission`].
+/// Return value for [`ZanzibarBackend::explain_permission`].
+#[derive(Debug)]
+#[must_use]
+pub struct ExplainPermissionResponse {
+    /// If the subject has the specified permission to the [`Resource`].
+    pub has_permission: bool,
+    /// The evaluation of the permission, if the backend provided one.
+    pub trace: Option<PermissionTrace>,
+    /// A token to determine the time at which the check was performed.
+    pub checked_at: Zookie<'static>,
+}
+
+/// The evaluation of a relation or permission of a resource for a subject.
+///
+/// A relation is granted if the subject is directly related to the resource, in which case no
+/// sub-traces are present, or if it's part of a subject set. Permissions are computed from the
+/// sub-traces, e.g. a relationship path from the subject to the resource.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PermissionTrace {
+    /// The resource in the form of `type:id`, e.g. `graph/entity:ab09…`.
+    pub resource: String,
+    /// The relation or permission which was evaluated.
+    pub relation: String,
+    /// If the subject has the relation or permission to the resource.
+    pub has_permission: bool,
+    /// The evaluated relations and permissions this result was computed from.
+    pub sub_traces: Vec<PermissionTrace>,
+}
+
+/// Return value for [`ZanzibarBackend::lookup_resources`].
+#[derive(Debug)]
+#[must_use]
+pub struct LookupResourcesResponse<I> {
+    /// The IDs of the resources the subject has the specified permission to.
+    ///
+    /// `None` if the permission is not restricted to a subset of resources, e.g. if authorization
+    /// is disabled.
+    pub resources: Option<Vec<I>>,
+    /// A token to determine the time at which the lookup was performed.
+    pub looked_up_at: Zookie<'static>,
+}
+
+/// Error returned from [`ZanzibarBackend::check_permission`],
+/// [`ZanzibarBackend::check_permissions`], [`ZanzibarBackend::explain_permission`], and
+/// [`ZanzibarBackend::lookup_resources`].
 #[derive(Debug)]
 pub struct CheckError;
 
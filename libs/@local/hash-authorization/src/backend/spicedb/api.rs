@@ -11,13 +11,14 @@ use crate::{
     backend::{
         spicedb::model::{self, Permissionship, RpcError},
         BulkCheckItem, BulkCheckResponse, CheckError, CheckResponse, DeleteRelationshipError,
-        DeleteRelationshipResponse, ExportSchemaError, ExportSchemaResponse, ImportSchemaError,
-        ImportSchemaResponse, ModifyRelationshipError, ModifyRelationshipOperation,
-        ModifyRelationshipResponse, ReadError, SpiceDbOpenApi, ZanzibarBackend,
+        DeleteRelationshipResponse, ExplainPermissionResponse, ExportSchemaError,
+        ExportSchemaResponse, ImportSchemaError, ImportSchemaResponse, LookupResourcesResponse,
+        ModifyRelationshipError, ModifyRelationshipOperation, ModifyRelationshipResponse,
+        PermissionTrace, ReadError, SpiceDbOpenApi, ZanzibarBackend,
     },
     zanzibar::{
         types::{Relationship, RelationshipFilter, Resource, Subject},
-        Consistency, Permission,
+        Consistency, Permission, Zookie,
     },
 };
 
@@ -330,6 +331,7 @@ impl ZanzibarBackend for SpiceDbOpenApi {
             permission: &'t R,
             #[serde(with = "super::serde::subject_ref")]
             subject: &'t S,
+            context: model::CheckContext,
         }
 
         #[derive(Deserialize)]
@@ -344,6 +346,7 @@ impl ZanzibarBackend for SpiceDbOpenApi {
             resource,
             permission,
             subject,
+            context: model::CheckContext::now(),
         };
 
         let response: RequestResponse = self
@@ -420,6 +423,7 @@ impl ZanzibarBackend for SpiceDbOpenApi {
             permission: R,
             #[serde(with = "super::serde::subject")]
             subject: S,
+            context: model::CheckContext,
         }
 
         #[derive(Deserialize)]
@@ -464,6 +468,7 @@ impl ZanzibarBackend for SpiceDbOpenApi {
             response: Response,
         }
 
+        let context = model::CheckContext::now();
         let request = BulkCheckPermissionRequest::<O, R, S> {
             consistency: consistency.into(),
             items: relationships
@@ -473,6 +478,7 @@ impl ZanzibarBackend for SpiceDbOpenApi {
                         resource,
                         permission,
                         subject,
+                        context,
                     },
                 )
                 .collect(),
@@ -497,6 +503,203 @@ impl ZanzibarBackend for SpiceDbOpenApi {
         })
     }
 
+    #[expect(
+        clippy::missing_errors_doc,
+        reason = "False positive, documented on trait"
+    )]
+    async fn explain_permission<O, R, S>(
+        &self,
+        resource: &O,
+        permission: &R,
+        subject: &S,
+        consistency: Consistency<'_>,
+    ) -> Result<ExplainPermissionResponse, Report<CheckError>>
+    where
+        O: Resource<Kind: Serialize, Id: Serialize> + Sync,
+        R: Serialize + Permission<O> + Sync,
+        S: Subject<Resource: Resource<Kind: Serialize, Id: Serialize>, Relation: Serialize> + Sync,
+    {
+        #[derive(Serialize)]
+        #[serde(
+            rename_all = "camelCase",
+            bound = "
+                O: Resource<Kind: Serialize, Id: Serialize>,
+                R: Serialize,
+                S: Subject<Resource: Resource<Kind: Serialize, Id: Serialize>, Relation: \
+                     Serialize>"
+        )]
+        struct RequestBody<'t, O, R, S> {
+            consistency: model::Consistency<'t>,
+            #[serde(with = "super::serde::resource_ref")]
+            resource: &'t O,
+            permission: &'t R,
+            #[serde(with = "super::serde::subject_ref")]
+            subject: &'t S,
+            context: model::CheckContext,
+            with_tracing: bool,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ObjectReference {
+            object_type: String,
+            object_id: String,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct SubProblems {
+            traces: Vec<CheckDebugTrace>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct CheckDebugTrace {
+            resource: ObjectReference,
+            permission: String,
+            result: Permissionship,
+            sub_problems: Option<SubProblems>,
+        }
+
+        fn permission_trace(trace: CheckDebugTrace) -> PermissionTrace {
+            PermissionTrace {
+                resource: format!(
+                    "{}:{}",
+                    trace.resource.object_type, trace.resource.object_id
+                ),
+                relation: trace.permission,
+                has_permission: matches!(trace.result, Permissionship::HasPermission),
+                sub_traces: trace
+                    .sub_problems
+                    .map(|sub_problems| {
+                        sub_problems
+                            .traces
+                            .into_iter()
+                            .map(permission_trace)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            }
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct DebugInformation {
+            check: CheckDebugTrace,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct RequestResponse {
+            checked_at: model::ZedToken,
+            permissionship: Permissionship,
+            debug_trace: Option<DebugInformation>,
+        }
+
+        let request = RequestBody::<O, R, S> {
+            consistency: consistency.into(),
+            resource,
+            permission,
+            subject,
+            context: model::CheckContext::now(),
+            with_tracing: true,
+        };
+
+        let response: RequestResponse = self
+            .call("/v1/permissions/check", &request)
+            .await
+            .change_context(CheckError)?;
+
+        Ok(ExplainPermissionResponse {
+            has_permission: response.permissionship.into(),
+            trace: response
+                .debug_trace
+                .map(|debug_trace| permission_trace(debug_trace.check)),
+            checked_at: response.checked_at.token,
+        })
+    }
+
+    #[expect(
+        clippy::missing_errors_doc,
+        reason = "False positive, documented on trait"
+    )]
+    async fn lookup_resources<O, R, S>(
+        &self,
+        subject: &S,
+        permission: &R,
+        resource_kind: &O::Kind,
+        limit: Option<usize>,
+        consistency: Consistency<'_>,
+    ) -> Result<LookupResourcesResponse<O::Id>, Report<CheckError>>
+    where
+        O: Resource<Kind: Serialize + Sync, Id: DeserializeOwned + Send>,
+        R: Serialize + Permission<O> + Sync,
+        S: Subject<Resource: Resource<Kind: Serialize, Id: Serialize>, Relation: Serialize> + Sync,
+    {
+        #[derive(Serialize)]
+        #[serde(
+            rename_all = "camelCase",
+            bound = "
+                K: Serialize,
+                R: Serialize,
+                S: Subject<Resource: Resource<Kind: Serialize, Id: Serialize>, Relation: \
+                     Serialize>"
+        )]
+        struct LookupResourcesRequest<'t, K, R, S> {
+            consistency: model::Consistency<'t>,
+            resource_object_type: &'t K,
+            permission: &'t R,
+            #[serde(with = "super::serde::subject_ref")]
+            subject: &'t S,
+            context: model::CheckContext,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            optional_limit: Option<u32>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase", bound = "I: Deserialize<'de>")]
+        struct LookupResourcesResponseItem<I> {
+            looked_up_at: model::ZedToken,
+            resource_object_id: I,
+        }
+
+        let request = LookupResourcesRequest {
+            consistency: consistency.into(),
+            resource_object_type: resource_kind,
+            permission,
+            subject,
+            context: model::CheckContext::now(),
+            optional_limit: limit.map(|limit| u32::try_from(limit).unwrap_or(u32::MAX)),
+        };
+
+        // The stream is dropped after `limit` items, so the lookup also stops if the server does
+        // not support the limit.
+        let items = self
+            .stream::<LookupResourcesResponseItem<O::Id>>("/v1/permissions/resources", &request)
+            .await
+            .change_context(CheckError)?
+            .take(limit.unwrap_or(usize::MAX))
+            .map_err(|error| error.change_context(CheckError))
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        // All items are looked up at the same revision. If no resource was found, there is no
+        // revision to refer to.
+        let looked_up_at = items
+            .first()
+            .map_or_else(Zookie::empty, |item| item.looked_up_at.token.clone());
+
+        Ok(LookupResourcesResponse {
+            resources: Some(
+                items
+                    .into_iter()
+                    .map(|item| item.resource_object_id)
+                    .collect(),
+            ),
+            looked_up_at,
+        })
+    }
+
     #[expect(
         clippy::missing_errors_doc,
         reason = "False positive, documented on trait"
@@ -1,6 +1,7 @@
 use std::{collections::HashMap, error::Error, fmt};
 
 use serde::{ser::SerializeStruct, Deserialize, Serialize, Serializer};
+use time::OffsetDateTime;
 
 use crate::{backend::ModifyRelationshipOperation, zanzibar};
 
@@ -106,6 +107,24 @@ pub(crate) struct ContextualizedCaveat<'a> {
     context: HashMap<&'a str, serde_json::Value>,
 }
 
+/// The context passed to permission checks, used to evaluate caveated relationships.
+///
+/// Providing every parameter of the caveats defined in the schema guarantees that a check is never
+/// [`Permissionship::Conditional`].
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+pub(crate) struct CheckContext {
+    #[serde(with = "time::serde::rfc3339")]
+    now: OffsetDateTime,
+}
+
+impl CheckContext {
+    pub(crate) fn now() -> Self {
+        Self {
+            now: OffsetDateTime::now_utc(),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, Deserialize)]
 pub(crate) enum Permissionship {
     #[serde(rename = "PERMISSIONSHIP_NO_PERMISSION")]
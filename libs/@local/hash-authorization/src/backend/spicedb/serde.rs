@@ -190,6 +190,7 @@ where
 
 pub(crate) mod relationship {
     use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+    use time::OffsetDateTime;
 
     use crate::{
         backend::spicedb::serde::SerializedSubject,
@@ -216,6 +217,24 @@ pub(crate) mod relationship {
         #[serde(with = "super::relation")]
         relation: LeveledRelation<R>,
         subject: SerializedSubject<S, SR>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        optional_caveat: Option<SerializedCaveat>,
+    }
+
+    /// The name of the caveat in the schema which restricts a relationship to a point in time.
+    pub(crate) const EXPIRATION_CAVEAT: &str = "expiration";
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct SerializedCaveat {
+        caveat_name: String,
+        context: ExpirationContext,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct ExpirationContext {
+        #[serde(with = "time::serde::rfc3339")]
+        expires_at: OffsetDateTime,
     }
 
     pub(crate) fn serialize<T, S>(relationship: &T, serializer: S) -> Result<S::Ok, S::Error>
@@ -233,6 +252,7 @@ pub(crate) mod relationship {
             relation,
             subject,
             subject_set,
+            expires_at,
         } = relationship.to_parts();
 
         SerializedRelationship {
@@ -242,6 +262,10 @@ pub(crate) mod relationship {
                 object: subject,
                 optional_relation: subject_set,
             },
+            optional_caveat: expires_at.map(|expires_at| SerializedCaveat {
+                caveat_name: EXPIRATION_CAVEAT.to_owned(),
+                context: ExpirationContext { expires_at },
+            }),
         }
         .serialize(serializer)
     }
@@ -257,11 +281,24 @@ pub(crate) mod relationship {
         D: Deserializer<'de>,
     {
         let relationship = SerializedRelationship::deserialize(deserializer)?;
+        let expires_at = match relationship.optional_caveat {
+            Some(caveat) if caveat.caveat_name == EXPIRATION_CAVEAT => {
+                Some(caveat.context.expires_at)
+            }
+            Some(caveat) => {
+                return Err(de::Error::custom(format!(
+                    "unknown caveat `{}`",
+                    caveat.caveat_name
+                )));
+            }
+            None => None,
+        };
         T::from_parts(RelationshipParts {
             resource: relationship.resource,
             relation: relationship.relation,
             subject: relationship.subject.object,
             subject_set: relationship.subject.optional_relation,
+            expires_at,
         })
         .map_err(de::Error::custom)
     }
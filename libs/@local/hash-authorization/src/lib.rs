@@ -7,6 +7,7 @@
 )]
 
 pub mod backend;
+pub mod migration;
 pub mod schema;
 pub mod zanzibar;
 
@@ -30,7 +31,8 @@ use graph_types::{
 
 use crate::{
     backend::{
-        CheckError, CheckResponse, ModifyRelationError, ModifyRelationshipOperation, ReadError,
+        CheckError, CheckResponse, ExplainPermissionResponse, ModifyRelationError,
+        ModifyRelationshipOperation, ReadError,
     },
     schema::{AccountGroupPermission, EntityPermission, WebPermission},
     zanzibar::{Consistency, Zookie},
@@ -119,6 +121,20 @@ impl AuthorizationApi for NoAuthorization {
         })
     }
 
+    async fn explain_entity_permission(
+        &self,
+        _: AccountId,
+        _: EntityPermission,
+        _: EntityId,
+        _: Consistency<'_>,
+    ) -> Result<ExplainPermissionResponse, CheckError> {
+        Ok(ExplainPermissionResponse {
+            has_permission: true,
+            trace: None,
+            checked_at: Zookie::empty(),
+        })
+    }
+
     async fn check_entities_permission(
         &self,
         _: AccountId,
@@ -135,6 +151,16 @@ impl AuthorizationApi for NoAuthorization {
         ))
     }
 
+    async fn lookup_entities(
+        &self,
+        _: AccountId,
+        _: EntityPermission,
+        _: usize,
+        _: Consistency<'_>,
+    ) -> Result<(Option<Vec<EntityUuid>>, Zookie<'static>), CheckError> {
+        Ok((None, Zookie::empty()))
+    }
+
     async fn modify_entity_relations(
         &mut self,
         _: impl IntoIterator<
@@ -0,0 +1,93 @@
+//! Versioned migrations of the authorization schema.
+//!
+//! Every file in the `schemas` directory is named `v{version}__{name}.zed` and contains the full
+//! schema at that version. Migrations are applied in order of their version. Which migrations were
+//! applied is tracked by the Graph, which also runs the relationship backfills required when a
+//! migration introduces a new relation.
+
+use std::hash::Hasher;
+
+use fnv::FnvHasher;
+
+/// A single version of the authorization schema.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SchemaMigration {
+    version: u32,
+    name: &'static str,
+    schema: &'static str,
+}
+
+impl SchemaMigration {
+    const fn new(version: u32, name: &'static str, schema: &'static str) -> Self {
+        Self {
+            version,
+            name,
+            schema,
+        }
+    }
+
+    #[must_use]
+    pub const fn version(&self) -> u32 {
+        self.version
+    }
+
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    #[must_use]
+    pub const fn schema(&self) -> &'static str {
+        self.schema
+    }
+
+    /// The hash of the schema file, used to detect migrations which were modified after they were
+    /// applied.
+    #[must_use]
+    pub fn hash(&self) -> u64 {
+        schema_hash(self.schema)
+    }
+
+    /// Returns the most recent migration, which contains the schema the Graph expects.
+    ///
+    /// # Panics
+    ///
+    /// - if no migrations are defined, which is caught at compile time
+    #[must_use]
+    pub const fn latest() -> &'static Self {
+        match SCHEMA_MIGRATIONS.last() {
+            Some(migration) => migration,
+            None => panic!("at least one schema migration has to be defined"),
+        }
+    }
+}
+
+/// Hashes a schema using FNV-1a.
+///
+/// The hash is persisted alongside the applied migrations, so it has to be stable across releases,
+/// which is not guaranteed for the hashers in the standard library.
+#[must_use]
+pub fn schema_hash(schema: &str) -> u64 {
+    let mut hasher = FnvHasher::default();
+    hasher.write(schema.as_bytes());
+    hasher.finish()
+}
+
+/// All schema migrations ordered by their version.
+pub const SCHEMA_MIGRATIONS: &[SchemaMigration] = &[
+    SchemaMigration::new(
+        1,
+        "initial_schema",
+        include_str!("../schemas/v1__initial_schema.zed"),
+    ),
+    SchemaMigration::new(
+        2,
+        "relationship_expiration",
+        include_str!("../schemas/v2__relationship_expiration.zed"),
+    ),
+    SchemaMigration::new(
+        3,
+        "restricted_properties",
+        include_str!("../schemas/v3__restricted_properties.zed"),
+    ),
+];
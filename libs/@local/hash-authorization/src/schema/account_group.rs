@@ -2,9 +2,12 @@ use graph_types::account::{AccountGroupId, AccountId};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::zanzibar::{
-    types::{LeveledRelation, Relationship, RelationshipParts, Resource},
-    Permission, Relation,
+use crate::{
+    schema::expiration::relation_and_subject,
+    zanzibar::{
+        types::{LeveledRelation, Relationship, RelationshipParts, Resource},
+        Permission, Relation,
+    },
 };
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -117,20 +120,16 @@ pub enum AccountGroupMemberSubject {
     },
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
-#[serde(rename_all = "camelCase", tag = "relation")]
-pub enum AccountGroupRelationAndSubject {
-    Administrator {
-        subject: AccountGroupAdministratorSubject,
-        #[serde(skip)]
-        level: u8,
-    },
-    Member {
-        subject: AccountGroupMemberSubject,
-        #[serde(skip)]
-        level: u8,
-    },
+relation_and_subject! {
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+    pub enum AccountGroupRelationAndSubject {
+        Administrator {
+            subject: AccountGroupAdministratorSubject,
+            expires_at: Option<OffsetDateTime>
+        },
+        Member { subject: AccountGroupMemberSubject, expires_at: Option<OffsetDateTime> },
+    }
 }
 
 impl Relationship for (AccountGroupId, AccountGroupRelationAndSubject) {
@@ -152,6 +151,7 @@ impl Relationship for (AccountGroupId, AccountGroupRelationAndSubject) {
                             }
                         },
                         level: parts.relation.level,
+                        expires_at: parts.expires_at,
                     }
                 }
                 AccountGroupResourceRelation::Member => AccountGroupRelationAndSubject::Member {
@@ -161,6 +161,7 @@ impl Relationship for (AccountGroupId, AccountGroupRelationAndSubject) {
                         }
                     },
                     level: parts.relation.level,
+                    expires_at: parts.expires_at,
                 },
             },
         ))
@@ -171,8 +172,12 @@ impl Relationship for (AccountGroupId, AccountGroupRelationAndSubject) {
     }
 
     fn into_parts(self) -> RelationshipParts<Self> {
+        let expires_at = match self.1 {
+            AccountGroupRelationAndSubject::Administrator { expires_at, .. }
+            | AccountGroupRelationAndSubject::Member { expires_at, .. } => expires_at,
+        };
         let (relation, (subject, subject_set)) = match self.1 {
-            AccountGroupRelationAndSubject::Administrator { subject, level } => (
+            AccountGroupRelationAndSubject::Administrator { subject, level, .. } => (
                 LeveledRelation {
                     name: AccountGroupResourceRelation::Administrator,
                     level,
@@ -183,7 +188,7 @@ impl Relationship for (AccountGroupId, AccountGroupRelationAndSubject) {
                     }
                 },
             ),
-            AccountGroupRelationAndSubject::Member { subject, level } => (
+            AccountGroupRelationAndSubject::Member { subject, level, .. } => (
                 LeveledRelation {
                     name: AccountGroupResourceRelation::Member,
                     level,
@@ -200,6 +205,7 @@ impl Relationship for (AccountGroupId, AccountGroupRelationAndSubject) {
             relation,
             subject,
             subject_set,
+            expires_at,
         }
     }
 }
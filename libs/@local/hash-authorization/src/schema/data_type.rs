@@ -244,6 +244,7 @@ impl Relationship for (DataTypeId, DataTypeRelationAndSubject) {
             relation,
             subject,
             subject_set,
+            expires_at: None,
         }
     }
 }
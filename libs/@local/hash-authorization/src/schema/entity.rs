@@ -11,6 +11,7 @@ use uuid::Uuid;
 use crate::{
     schema::{
         error::{InvalidRelationship, InvalidResource},
+        expiration::relation_and_subject,
         PublicAccess,
     },
     zanzibar::{
@@ -53,17 +54,19 @@ pub enum EntityResourceRelation {
     Administrator,
     Editor,
     Viewer,
+    RestrictedPropertyViewer,
 }
 
 impl Relation<EntityUuid> for EntityResourceRelation {}
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "snake_case")]
 pub enum EntityPermission {
     FullAccess,
     Update,
     View,
+    ViewRestrictedProperties,
 }
 
 impl Permission<EntityUuid> for EntityPermission {}
@@ -252,35 +255,36 @@ pub enum EntityViewerSubject {
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
-#[serde(rename_all = "camelCase", tag = "relation")]
-pub enum EntityRelationAndSubject {
-    Setting {
-        subject: EntitySettingSubject,
-        #[serde(skip)]
-        level: u8,
-    },
-    Owner {
-        subject: EntityOwnerSubject,
-        #[serde(skip)]
-        level: u8,
-    },
-    Administrator {
-        subject: EntityAdministratorSubject,
-        #[serde(skip)]
-        level: u8,
-    },
-    Editor {
-        subject: EntityEditorSubject,
-        #[serde(skip)]
-        level: u8,
+#[serde(rename_all = "camelCase", tag = "kind", deny_unknown_fields)]
+pub enum EntityRestrictedPropertyViewerSubject {
+    Account {
+        #[serde(rename = "subjectId")]
+        id: AccountId,
     },
-    Viewer {
-        subject: EntityViewerSubject,
+    AccountGroup {
+        #[serde(rename = "subjectId")]
+        id: AccountGroupId,
         #[serde(skip)]
-        level: u8,
+        set: EntitySubjectSet,
     },
 }
 
+relation_and_subject! {
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+    pub enum EntityRelationAndSubject {
+        Setting { subject: EntitySettingSubject },
+        Owner { subject: EntityOwnerSubject },
+        Administrator { subject: EntityAdministratorSubject, expires_at: Option<OffsetDateTime> },
+        Editor { subject: EntityEditorSubject, expires_at: Option<OffsetDateTime> },
+        Viewer { subject: EntityViewerSubject, expires_at: Option<OffsetDateTime> },
+        RestrictedPropertyViewer {
+            subject: EntityRestrictedPropertyViewerSubject,
+            expires_at: Option<OffsetDateTime>
+        },
+    }
+}
+
 impl Relationship for (EntityUuid, EntityRelationAndSubject) {
     type Relation = EntityResourceRelation;
     type Resource = EntityUuid;
@@ -349,6 +353,7 @@ impl Relationship for (EntityUuid, EntityRelationAndSubject) {
                         }
                     },
                     level: parts.relation.level,
+                    expires_at: parts.expires_at,
                 },
                 EntityResourceRelation::Editor => EntityRelationAndSubject::Editor {
                     subject: match (parts.subject, parts.subject_set) {
@@ -369,6 +374,7 @@ impl Relationship for (EntityUuid, EntityRelationAndSubject) {
                         }
                     },
                     level: parts.relation.level,
+                    expires_at: parts.expires_at,
                 },
                 EntityResourceRelation::Viewer => EntityRelationAndSubject::Viewer {
                     subject: match (parts.subject, parts.subject_set) {
@@ -390,7 +396,35 @@ impl Relationship for (EntityUuid, EntityRelationAndSubject) {
                         }
                     },
                     level: parts.relation.level,
+                    expires_at: parts.expires_at,
                 },
+                EntityResourceRelation::RestrictedPropertyViewer => {
+                    EntityRelationAndSubject::RestrictedPropertyViewer {
+                        subject: match (parts.subject, parts.subject_set) {
+                            (EntitySubject::Account(id), None) => {
+                                EntityRestrictedPropertyViewerSubject::Account { id }
+                            }
+                            (EntitySubject::AccountGroup(id), Some(set)) => {
+                                EntityRestrictedPropertyViewerSubject::AccountGroup { id, set }
+                            }
+                            (EntitySubject::Account(_) | EntitySubject::AccountGroup(_), _) => {
+                                return Err(InvalidRelationship::<Self>::invalid_subject_set(
+                                    parts,
+                                ));
+                            }
+                            (
+                                EntitySubject::Web(_)
+                                | EntitySubject::Public
+                                | EntitySubject::Setting(_),
+                                _,
+                            ) => {
+                                return Err(InvalidRelationship::<Self>::invalid_subject(parts));
+                            }
+                        },
+                        level: parts.relation.level,
+                        expires_at: parts.expires_at,
+                    }
+                }
             },
         ))
     }
@@ -400,6 +434,15 @@ impl Relationship for (EntityUuid, EntityRelationAndSubject) {
     }
 
     fn into_parts(self) -> RelationshipParts<Self> {
+        let expires_at = match self.1 {
+            EntityRelationAndSubject::Administrator { expires_at, .. }
+            | EntityRelationAndSubject::Editor { expires_at, .. }
+            | EntityRelationAndSubject::Viewer { expires_at, .. }
+            | EntityRelationAndSubject::RestrictedPropertyViewer { expires_at, .. } => expires_at,
+            EntityRelationAndSubject::Setting { .. } | EntityRelationAndSubject::Owner { .. } => {
+                None
+            }
+        };
         let (relation, (subject, subject_set)) = match self.1 {
             EntityRelationAndSubject::Setting { subject, level } => (
                 LeveledRelation {
@@ -419,7 +462,7 @@ impl Relationship for (EntityUuid, EntityRelationAndSubject) {
                     EntityOwnerSubject::Web { id } => (EntitySubject::Web(id), None),
                 },
             ),
-            EntityRelationAndSubject::Administrator { subject, level } => (
+            EntityRelationAndSubject::Administrator { subject, level, .. } => (
                 LeveledRelation {
                     name: EntityResourceRelation::Administrator,
                     level,
@@ -433,7 +476,7 @@ impl Relationship for (EntityUuid, EntityRelationAndSubject) {
                     }
                 },
             ),
-            EntityRelationAndSubject::Editor { subject, level } => (
+            EntityRelationAndSubject::Editor { subject, level, .. } => (
                 LeveledRelation {
                     name: EntityResourceRelation::Editor,
                     level,
@@ -445,7 +488,7 @@ impl Relationship for (EntityUuid, EntityRelationAndSubject) {
                     }
                 },
             ),
-            EntityRelationAndSubject::Viewer { subject, level } => (
+            EntityRelationAndSubject::Viewer { subject, level, .. } => (
                 LeveledRelation {
                     name: EntityResourceRelation::Viewer,
                     level,
@@ -458,12 +501,27 @@ impl Relationship for (EntityUuid, EntityRelationAndSubject) {
                     EntityViewerSubject::Public => (EntitySubject::Public, None),
                 },
             ),
+            EntityRelationAndSubject::RestrictedPropertyViewer { subject, level, .. } => (
+                LeveledRelation {
+                    name: EntityResourceRelation::RestrictedPropertyViewer,
+                    level,
+                },
+                match subject {
+                    EntityRestrictedPropertyViewerSubject::Account { id } => {
+                        (EntitySubject::Account(id), None)
+                    }
+                    EntityRestrictedPropertyViewerSubject::AccountGroup { id, set } => {
+                        (EntitySubject::AccountGroup(id), Some(set))
+                    }
+                },
+            ),
         };
         RelationshipParts {
             resource: self.0,
             relation,
             subject,
             subject_set,
+            expires_at,
         }
     }
 }
@@ -493,6 +493,7 @@ impl Relationship for (EntityTypeId, EntityTypeRelationAndSubject) {
             relation,
             subject,
             subject_set,
+            expires_at: None,
         }
     }
 }
@@ -0,0 +1,63 @@
+//! (De)serialization of the `expiresAt` field of caveated relations.
+//!
+//! Expiration times are represented as RFC 3339 timestamps. A missing value means the relation
+//! never expires. Relations which can expire are declared with [`relation_and_subject!`], which
+//! adds the attributes required for the `expiresAt` field.
+
+use serde::{Deserializer, Serializer};
+use time::OffsetDateTime;
+
+pub(crate) fn serialize<S: Serializer>(
+    expires_at: &Option<OffsetDateTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    time::serde::rfc3339::option::serialize(expires_at, serializer)
+}
+
+pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<OffsetDateTime>, D::Error> {
+    time::serde::rfc3339::option::deserialize(deserializer)
+}
+
+/// Declares a relation enum which is tagged by `relation`.
+///
+/// Every variant gets a `subject` and a skipped `level` field. Variants which list
+/// `expires_at: Option<OffsetDateTime>` additionally get an optional `expiresAt` field, which is
+/// omitted if the relation never expires.
+macro_rules! relation_and_subject {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $variant:ident {
+                    subject: $subject:ty
+                    $(, $expires_at:ident: Option<OffsetDateTime>)?
+                }
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[serde(rename_all = "camelCase", tag = "relation")]
+        $vis enum $name {
+            $(
+                $variant {
+                    subject: $subject,
+                    #[serde(skip)]
+                    level: u8,
+                    $(
+                        #[serde(
+                            rename = "expiresAt",
+                            default,
+                            skip_serializing_if = "Option::is_none",
+                            with = "crate::schema::expiration"
+                        )]
+                        $expires_at: Option<time::OffsetDateTime>,
+                    )?
+                },
+            )*
+        }
+    };
+}
+
+pub(crate) use relation_and_subject;
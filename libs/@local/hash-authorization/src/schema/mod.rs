@@ -4,6 +4,7 @@ mod data_type;
 mod entity;
 mod entity_type;
 mod error;
+mod expiration;
 mod property_type;
 mod settings;
 mod web;
@@ -22,9 +23,9 @@ pub use self::{
     },
     entity::{
         EntityAdministratorSubject, EntityEditorSubject, EntityNamespace, EntityOwnerSubject,
-        EntityPermission, EntityRelationAndSubject, EntityResourceRelation, EntitySetting,
-        EntitySettingSubject, EntitySubject, EntitySubjectId, EntitySubjectSet,
-        EntityViewerSubject,
+        EntityPermission, EntityRelationAndSubject, EntityResourceRelation,
+        EntityRestrictedPropertyViewerSubject, EntitySetting, EntitySettingSubject, EntitySubject,
+        EntitySubjectId, EntitySubjectSet, EntityViewerSubject,
     },
     entity_type::{
         EntityTypeEditorSubject, EntityTypeId, EntityTypeInstantiatorSubject, EntityTypeNamespace,
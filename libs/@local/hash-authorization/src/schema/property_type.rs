@@ -424,6 +424,7 @@ impl Relationship for (PropertyTypeId, PropertyTypeRelationAndSubject) {
             relation,
             subject,
             subject_set,
+            expires_at: None,
         }
     }
 }
@@ -187,6 +187,7 @@ impl Relationship for (SettingName, SettingRelationAndSubject) {
             relation,
             subject,
             subject_set,
+            expires_at: None,
         }
     }
 }
@@ -10,6 +10,7 @@ use uuid::Uuid;
 use crate::{
     schema::{
         error::{InvalidRelationship, InvalidResource},
+        expiration::relation_and_subject,
         PublicAccess,
     },
     zanzibar::{
@@ -235,45 +236,18 @@ pub enum WebDataTypeViewerSubject {
     Public,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
-#[serde(rename_all = "camelCase", tag = "relation")]
-pub enum WebRelationAndSubject {
-    Owner {
-        subject: WebOwnerSubject,
-        #[serde(skip)]
-        level: u8,
-    },
-    EntityCreator {
-        subject: WebEntityCreatorSubject,
-        #[serde(skip)]
-        level: u8,
-    },
-    EntityEditor {
-        subject: WebEntityEditorSubject,
-        #[serde(skip)]
-        level: u8,
-    },
-    EntityViewer {
-        subject: WebEntityViewerSubject,
-        #[serde(skip)]
-        level: u8,
-    },
-    EntityTypeViewer {
-        subject: WebEntityTypeViewerSubject,
-        #[serde(skip)]
-        level: u8,
-    },
-    PropertyTypeViewer {
-        subject: WebPropertyTypeViewerSubject,
-        #[serde(skip)]
-        level: u8,
-    },
-    DataTypeViewer {
-        subject: WebDataTypeViewerSubject,
-        #[serde(skip)]
-        level: u8,
-    },
+relation_and_subject! {
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+    #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+    pub enum WebRelationAndSubject {
+        Owner { subject: WebOwnerSubject },
+        EntityCreator { subject: WebEntityCreatorSubject, expires_at: Option<OffsetDateTime> },
+        EntityEditor { subject: WebEntityEditorSubject, expires_at: Option<OffsetDateTime> },
+        EntityViewer { subject: WebEntityViewerSubject, expires_at: Option<OffsetDateTime> },
+        EntityTypeViewer { subject: WebEntityTypeViewerSubject },
+        PropertyTypeViewer { subject: WebPropertyTypeViewerSubject },
+        DataTypeViewer { subject: WebDataTypeViewerSubject },
+    }
 }
 
 impl Relationship for (OwnedById, WebRelationAndSubject) {
@@ -316,6 +290,7 @@ impl Relationship for (OwnedById, WebRelationAndSubject) {
                         }
                     },
                     level: parts.relation.level,
+                    expires_at: parts.expires_at,
                 },
                 WebResourceRelation::EntityEditor => WebRelationAndSubject::EntityEditor {
                     subject: match (parts.subject, parts.subject_set) {
@@ -331,6 +306,7 @@ impl Relationship for (OwnedById, WebRelationAndSubject) {
                         }
                     },
                     level: parts.relation.level,
+                    expires_at: parts.expires_at,
                 },
                 WebResourceRelation::EntityViewer => WebRelationAndSubject::EntityViewer {
                     subject: match (parts.subject, parts.subject_set) {
@@ -349,6 +325,7 @@ impl Relationship for (OwnedById, WebRelationAndSubject) {
                         }
                     },
                     level: parts.relation.level,
+                    expires_at: parts.expires_at,
                 },
                 WebResourceRelation::EntityTypeViewer => WebRelationAndSubject::EntityTypeViewer {
                     subject: match (parts.subject, parts.subject_set) {
@@ -397,6 +374,15 @@ impl Relationship for (OwnedById, WebRelationAndSubject) {
     }
 
     fn into_parts(self) -> RelationshipParts<Self> {
+        let expires_at = match self.1 {
+            WebRelationAndSubject::EntityCreator { expires_at, .. }
+            | WebRelationAndSubject::EntityEditor { expires_at, .. }
+            | WebRelationAndSubject::EntityViewer { expires_at, .. } => expires_at,
+            WebRelationAndSubject::Owner { .. }
+            | WebRelationAndSubject::EntityTypeViewer { .. }
+            | WebRelationAndSubject::PropertyTypeViewer { .. }
+            | WebRelationAndSubject::DataTypeViewer { .. } => None,
+        };
         let (relation, (subject, subject_set)) = match self.1 {
             WebRelationAndSubject::Owner { subject, level } => (
                 LeveledRelation {
@@ -408,7 +394,7 @@ impl Relationship for (OwnedById, WebRelationAndSubject) {
                     WebOwnerSubject::AccountGroup { id } => (WebSubject::AccountGroup(id), None),
                 },
             ),
-            WebRelationAndSubject::EntityCreator { subject, level } => (
+            WebRelationAndSubject::EntityCreator { subject, level, .. } => (
                 LeveledRelation {
                     name: WebResourceRelation::EntityCreator,
                     level,
@@ -420,7 +406,7 @@ impl Relationship for (OwnedById, WebRelationAndSubject) {
                     }
                 },
             ),
-            WebRelationAndSubject::EntityEditor { subject, level } => (
+            WebRelationAndSubject::EntityEditor { subject, level, .. } => (
                 LeveledRelation {
                     name: WebResourceRelation::EntityEditor,
                     level,
@@ -432,7 +418,7 @@ impl Relationship for (OwnedById, WebRelationAndSubject) {
                     }
                 },
             ),
-            WebRelationAndSubject::EntityViewer { subject, level } => (
+            WebRelationAndSubject::EntityViewer { subject, level, .. } => (
                 LeveledRelation {
                     name: WebResourceRelation::EntityViewer,
                     level,
@@ -478,6 +464,7 @@ impl Relationship for (OwnedById, WebRelationAndSubject) {
             relation,
             subject,
             subject_set,
+            expires_at,
         }
     }
 }
@@ -1,4 +1,8 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
 
 use error_stack::{Report, Result, ResultExt};
 use futures::TryStreamExt;
@@ -10,28 +14,43 @@ use graph_types::{
 
 use crate::{
     backend::{
-        CheckError, CheckResponse, ModifyRelationError, ModifyRelationshipOperation, ReadError,
-        RpcError, ZanzibarBackend,
+        CheckError, CheckResponse, ExplainPermissionResponse, ModifyRelationError,
+        ModifyRelationshipOperation, ReadError, RpcError, ZanzibarBackend,
     },
     schema::{
         AccountGroupPermission, AccountGroupRelationAndSubject, DataTypeId, DataTypePermission,
-        DataTypeRelationAndSubject, EntityPermission, EntityRelationAndSubject, EntitySetting,
-        EntityTypeId, EntityTypePermission, EntityTypeRelationAndSubject, PropertyTypeId,
-        PropertyTypePermission, PropertyTypeRelationAndSubject, SettingName,
+        DataTypeRelationAndSubject, EntityNamespace, EntityPermission, EntityRelationAndSubject,
+        EntitySetting, EntityTypeId, EntityTypePermission, EntityTypeRelationAndSubject,
+        PropertyTypeId, PropertyTypePermission, PropertyTypeRelationAndSubject, SettingName,
         SettingRelationAndSubject, SettingSubject, WebPermission, WebRelationAndSubject,
     },
     zanzibar::{types::RelationshipFilter, Consistency, Zookie},
     AuthorizationApi,
 };
 
+/// How long an exceeded limit of [`AuthorizationApi::lookup_entities`] is remembered.
+///
+/// While it's remembered, the same or a smaller limit is not looked up again for the actor and
+/// permission, so the caller doesn't pay for the lookup before falling back to checking the
+/// entities.
+const EXCEEDED_LOOKUP_CACHE_DURATION: Duration = Duration::from_secs(60);
+
+/// The largest exceeded lookup limit by actor and permission and the time it was exceeded.
+type ExceededLookups = HashMap<(AccountId, EntityPermission), (usize, Instant)>;
+
 #[derive(Debug, Clone)]
 pub struct ZanzibarClient<B> {
     backend: B,
+    /// Exceeded limits of [`AuthorizationApi::lookup_entities`], shared by clones of the client.
+    exceeded_lookups: Arc<Mutex<ExceededLookups>>,
 }
 
 impl<B> ZanzibarClient<B> {
-    pub const fn new(backend: B) -> Self {
-        Self { backend }
+    pub fn new(backend: B) -> Self {
+        Self {
+            backend,
+            exceeded_lookups: Arc::default(),
+        }
     }
 
     pub fn into_backend(self) -> B {
@@ -214,6 +233,19 @@ where
             .await
     }
 
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn explain_entity_permission(
+        &self,
+        actor: AccountId,
+        permission: EntityPermission,
+        entity: EntityId,
+        consistency: Consistency<'_>,
+    ) -> Result<ExplainPermissionResponse, CheckError> {
+        self.backend
+            .explain_permission(&entity.entity_uuid, &permission, &actor, consistency)
+            .await
+    }
+
     #[tracing::instrument(level = "info", skip(self, entities))]
     async fn check_entities_permission(
         &self,
@@ -256,6 +288,64 @@ where
             .map(|()| (permissions, response.checked_at))
     }
 
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn lookup_entities(
+        &self,
+        actor: AccountId,
+        permission: EntityPermission,
+        limit: usize,
+        consistency: Consistency<'_>,
+    ) -> Result<(Option<Vec<EntityUuid>>, Zookie<'static>), CheckError> {
+        let now = Instant::now();
+        let is_valid =
+            |exceeded_at: Instant| now.duration_since(exceeded_at) < EXCEEDED_LOOKUP_CACHE_DURATION;
+
+        let exceeded = self
+            .exceeded_lookups
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&(actor, permission))
+            .copied();
+        if let Some((exceeded_limit, exceeded_at)) = exceeded {
+            if limit <= exceeded_limit && is_valid(exceeded_at) {
+                return Ok((None, Zookie::empty()));
+            }
+        }
+
+        // One more entity is looked up to determine if the limit is exceeded.
+        let response = self
+            .backend
+            .lookup_resources::<EntityUuid, _, _>(
+                &actor,
+                &permission,
+                &EntityNamespace::Entity,
+                Some(limit.saturating_add(1)),
+                consistency,
+            )
+            .await?;
+
+        let resources = response
+            .resources
+            .filter(|resources| resources.len() <= limit);
+        if resources.is_none() {
+            let mut exceeded_lookups = self
+                .exceeded_lookups
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            exceeded_lookups.retain(|_, (_, exceeded_at)| is_valid(*exceeded_at));
+            exceeded_lookups
+                .entry((actor, permission))
+                .and_modify(|(exceeded_limit, exceeded_at)| {
+                    *exceeded_limit = (*exceeded_limit).max(limit);
+                    *exceeded_at = now;
+                })
+                .or_insert((limit, now));
+            drop(exceeded_lookups);
+        }
+
+        Ok((resources, response.looked_up_at))
+    }
+
     #[tracing::instrument(level = "info", skip(self))]
     async fn get_entity_relations(
         &self,
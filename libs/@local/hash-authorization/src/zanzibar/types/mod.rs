@@ -24,10 +24,14 @@ pub trait Permission<O: Resource> {}
 #[serde(transparent)]
 pub struct Zookie<'t>(Cow<'t, str>);
 
-impl Zookie<'_> {
+impl<'t> Zookie<'t> {
     pub(crate) const fn empty() -> Self {
         Self(Cow::Borrowed(""))
     }
+
+    pub(crate) fn new(token: impl Into<Cow<'t, str>>) -> Self {
+        Self(token.into())
+    }
 }
 
 /// Specifies the desired consistency level on a per-request basis.
@@ -1,5 +1,7 @@
 use std::error::Error;
 
+use time::OffsetDateTime;
+
 use crate::zanzibar::{
     types::{
         resource::{Resource, ResourceFilter},
@@ -59,6 +61,8 @@ pub struct RelationshipParts<R: Relationship> {
     pub relation: LeveledRelation<R::Relation>,
     pub subject: R::Subject,
     pub subject_set: Option<R::SubjectSet>,
+    /// The point in time from which on the relationship does not grant access anymore.
+    pub expires_at: Option<OffsetDateTime>,
 }
 
 pub trait Relationship: Sized {
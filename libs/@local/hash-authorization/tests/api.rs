@@ -1,7 +1,7 @@
 #![allow(unused_attributes, unreachable_pub)] // This file is used as module in other tests
 #![feature(associated_type_bounds)]
 
-use authorization::backend::{SpiceDbOpenApi, ZanzibarBackend};
+use authorization::backend::{InMemoryBackend, SpiceDbOpenApi, ZanzibarBackend};
 
 /// Connects to the `SpiceDB` instance specified by the environment variables.
 ///
@@ -24,3 +24,9 @@ pub fn connect() -> impl ZanzibarBackend {
     SpiceDbOpenApi::new(format!("{host}:{http_port}"), Some(&key))
         .expect("failed to connect to SpiceDB")
 }
+
+/// Creates a backend which evaluates permissions in-process and does not require `SpiceDB`.
+#[must_use]
+pub fn connect_in_memory() -> impl ZanzibarBackend {
+    InMemoryBackend::new()
+}
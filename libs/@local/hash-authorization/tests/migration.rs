@@ -0,0 +1,84 @@
+#![feature(associated_type_bounds)]
+
+mod api;
+
+use std::{error::Error, fs};
+
+use authorization::{
+    backend::ZanzibarBackend,
+    migration::{schema_hash, SchemaMigration, SCHEMA_MIGRATIONS},
+};
+
+#[test]
+fn migrations_match_schema_files() {
+    let mut files = fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/schemas"))
+        .expect("could not read schema directory")
+        .map(|entry| {
+            entry
+                .expect("could not read schema directory entry")
+                .file_name()
+                .into_string()
+                .expect("schema file name is not valid UTF-8")
+        })
+        .collect::<Vec<_>>();
+    files.sort_by_key(|file_name| {
+        file_name
+            .strip_prefix('v')
+            .and_then(|file_name| file_name.split_once("__"))
+            .and_then(|(version, _)| version.parse::<u32>().ok())
+    });
+
+    let expected = SCHEMA_MIGRATIONS
+        .iter()
+        .map(|migration| format!("v{}__{}.zed", migration.version(), migration.name()))
+        .collect::<Vec<_>>();
+    assert_eq!(files, expected);
+
+    for (migration, version) in SCHEMA_MIGRATIONS.iter().zip(1..) {
+        assert_eq!(migration.version(), version);
+    }
+    assert_eq!(
+        SchemaMigration::latest(),
+        SCHEMA_MIGRATIONS.last().expect("no migrations")
+    );
+}
+
+#[test]
+fn schema_hash_is_stable() {
+    // The hashes are persisted, so they must not change between releases.
+    assert_eq!(schema_hash(""), 0xCBF2_9CE4_8422_2325);
+    assert_eq!(schema_hash("foobar"), 0x8594_4171_F739_67E8);
+}
+
+async fn apply_migrations(
+    mut api: impl ZanzibarBackend + Send + Sync,
+) -> Result<(), Box<dyn Error>> {
+    for migration in SCHEMA_MIGRATIONS {
+        api.import_schema(migration.schema()).await?;
+        api.export_schema().await?;
+    }
+
+    Ok(())
+}
+
+mod spicedb {
+    use std::error::Error;
+
+    use crate::api;
+
+    #[tokio::test]
+    async fn apply_migrations() -> Result<(), Box<dyn Error>> {
+        super::apply_migrations(api::connect()).await
+    }
+}
+
+mod in_memory {
+    use std::error::Error;
+
+    use crate::api;
+
+    #[tokio::test]
+    async fn apply_migrations() -> Result<(), Box<dyn Error>> {
+        super::apply_migrations(api::connect_in_memory()).await
+    }
+}
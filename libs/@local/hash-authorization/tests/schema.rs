@@ -1,10 +1,22 @@
 #![allow(unreachable_pub)] // This file is used as module in other tests
 
-use graph_types::{account::AccountId, knowledge::entity::EntityUuid};
+use graph_types::{
+    account::{AccountGroupId, AccountId},
+    knowledge::entity::EntityUuid,
+    owned_by_id::OwnedById,
+};
 use uuid::Uuid;
 
 pub const ALICE: AccountId = AccountId::new(Uuid::from_fields(1, 0, 0, &[0; 8]));
 pub const BOB: AccountId = AccountId::new(Uuid::from_fields(2, 0, 0, &[0; 8]));
+pub const CHARLIE: AccountId = AccountId::new(Uuid::from_fields(3, 0, 0, &[0; 8]));
 
 pub const ENTITY_A: EntityUuid = EntityUuid::new(Uuid::from_fields(0, 1, 0, &[0; 8]));
 pub const ENTITY_B: EntityUuid = EntityUuid::new(Uuid::from_fields(0, 2, 0, &[0; 8]));
+pub const ENTITY_C: EntityUuid = EntityUuid::new(Uuid::from_fields(0, 3, 0, &[0; 8]));
+pub const ENTITY_D: EntityUuid = EntityUuid::new(Uuid::from_fields(0, 4, 0, &[0; 8]));
+
+pub const ACCOUNT_GROUP_A: AccountGroupId =
+    AccountGroupId::new(Uuid::from_fields(0, 0, 1, &[0; 8]));
+
+pub const WEB_A: OwnedById = OwnedById::new(Uuid::from_fields(0, 0, 2, &[0; 8]));
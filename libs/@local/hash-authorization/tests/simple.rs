@@ -4,23 +4,74 @@
 mod api;
 mod schema;
 
-use std::error::Error;
+use std::{collections::HashSet, error::Error};
 
 use authorization::{
-    backend::ZanzibarBackend,
+    backend::{ModifyRelationshipOperation, PermissionTrace, ZanzibarBackend},
+    migration::SchemaMigration,
     schema::{
-        EntityEditorSubject, EntityPermission, EntityRelationAndSubject, EntityViewerSubject,
+        AccountGroupAdministratorSubject, AccountGroupMemberSubject,
+        AccountGroupRelationAndSubject, EntityEditorSubject, EntityNamespace, EntityOwnerSubject,
+        EntityPermission, EntityRelationAndSubject, EntityRestrictedPropertyViewerSubject,
+        EntitySetting, EntitySettingSubject, EntitySubjectSet, EntityViewerSubject, SettingName,
+        SettingRelationAndSubject, SettingSubject, WebOwnerSubject, WebRelationAndSubject,
     },
-    zanzibar::Consistency,
+    zanzibar::{types::RelationshipFilter, Consistency, ZanzibarClient, Zookie},
+    AuthorizationApi,
 };
+use futures::TryStreamExt;
+use graph_types::{
+    account::AccountId,
+    knowledge::entity::{EntityId, EntityUuid},
+};
+use time::{Duration, OffsetDateTime};
 
-use crate::schema::{ALICE, BOB, ENTITY_A, ENTITY_B};
+use crate::schema::{
+    ACCOUNT_GROUP_A, ALICE, BOB, CHARLIE, ENTITY_A, ENTITY_B, ENTITY_C, ENTITY_D, WEB_A,
+};
 
-#[tokio::test]
-async fn test_schema() -> Result<(), Box<dyn Error>> {
-    let mut api = api::connect();
+/// Returns if the granted `trace` contains a granted sub-trace for `relation` on a resource of
+/// type `resource_type`.
+fn is_granted_by(trace: &PermissionTrace, resource_type: &str, relation: &str) -> bool {
+    trace.has_permission
+        && trace.sub_traces.iter().any(|sub_trace| {
+            (sub_trace.has_permission
+                && sub_trace.relation == relation
+                && sub_trace
+                    .resource
+                    .split_once(':')
+                    .is_some_and(|(kind, _)| kind == resource_type))
+                || is_granted_by(sub_trace, resource_type, relation)
+        })
+}
+
+/// Returns the entities `actor` has `permission` to.
+///
+/// The tests share a single `SpiceDB` instance, so the result may contain entities of other tests
+/// and only the entities used by the calling test should be asserted.
+async fn lookup_entities(
+    api: &(impl ZanzibarBackend + Sync),
+    actor: AccountId,
+    permission: EntityPermission,
+    token: &Zookie<'_>,
+) -> Result<HashSet<EntityUuid>, Box<dyn Error>> {
+    Ok(api
+        .lookup_resources::<EntityUuid, _, _>(
+            &actor,
+            &permission,
+            &EntityNamespace::Entity,
+            None,
+            Consistency::AtLeastAsFresh(token),
+        )
+        .await?
+        .resources
+        .ok_or("the backend should restrict the permission")?
+        .into_iter()
+        .collect())
+}
 
-    api.import_schema(include_str!("../schemas/v1__initial_schema.zed"))
+async fn test_schema(mut api: impl ZanzibarBackend + Send + Sync) -> Result<(), Box<dyn Error>> {
+    api.import_schema(SchemaMigration::latest().schema())
         .await?;
 
     api.export_schema().await?;
@@ -28,11 +79,10 @@ async fn test_schema() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-#[tokio::test]
-async fn plain_permissions() -> Result<(), Box<dyn Error>> {
-    let mut api = api::connect();
-
-    api.import_schema(include_str!("../schemas/v1__initial_schema.zed"))
+async fn plain_permissions(
+    mut api: impl ZanzibarBackend + Send + Sync,
+) -> Result<(), Box<dyn Error>> {
+    api.import_schema(SchemaMigration::latest().schema())
         .await?;
 
     let token = api
@@ -42,6 +92,7 @@ async fn plain_permissions() -> Result<(), Box<dyn Error>> {
                 EntityRelationAndSubject::Editor {
                     subject: EntityEditorSubject::Account { id: ALICE },
                     level: 0,
+                    expires_at: None,
                 },
             ),
             (
@@ -49,6 +100,7 @@ async fn plain_permissions() -> Result<(), Box<dyn Error>> {
                 EntityRelationAndSubject::Viewer {
                     subject: EntityViewerSubject::Account { id: BOB },
                     level: 0,
+                    expires_at: None,
                 },
             ),
             (
@@ -56,6 +108,7 @@ async fn plain_permissions() -> Result<(), Box<dyn Error>> {
                 EntityRelationAndSubject::Editor {
                     subject: EntityEditorSubject::Account { id: BOB },
                     level: 0,
+                    expires_at: None,
                 },
             ),
         ])
@@ -150,6 +203,7 @@ async fn plain_permissions() -> Result<(), Box<dyn Error>> {
             EntityRelationAndSubject::Viewer {
                 subject: EntityViewerSubject::Account { id: BOB },
                 level: 0,
+                expires_at: None,
             },
         )])
         .await?
@@ -168,3 +222,554 @@ async fn plain_permissions() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
+
+async fn inherited_permissions(
+    mut api: impl ZanzibarBackend + Send + Sync,
+) -> Result<(), Box<dyn Error>> {
+    api.import_schema(SchemaMigration::latest().schema())
+        .await?;
+
+    api.touch_relationships([
+        (
+            ACCOUNT_GROUP_A,
+            AccountGroupRelationAndSubject::Administrator {
+                subject: AccountGroupAdministratorSubject::Account { id: BOB },
+                level: 0,
+                expires_at: None,
+            },
+        ),
+        (
+            ACCOUNT_GROUP_A,
+            AccountGroupRelationAndSubject::Member {
+                subject: AccountGroupMemberSubject::Account { id: ALICE },
+                level: 0,
+                expires_at: None,
+            },
+        ),
+    ])
+    .await?;
+    api.touch_relationships([(
+        WEB_A,
+        WebRelationAndSubject::Owner {
+            subject: WebOwnerSubject::AccountGroup {
+                id: ACCOUNT_GROUP_A,
+            },
+            level: 0,
+        },
+    )])
+    .await?;
+    api.touch_relationships([(
+        SettingName::Entity(EntitySetting::AdministratorFromWeb),
+        SettingRelationAndSubject::Administrator {
+            subject: SettingSubject::Public,
+            level: 0,
+        },
+    )])
+    .await?;
+    let token = api
+        .touch_relationships([
+            (
+                ENTITY_C,
+                EntityRelationAndSubject::Owner {
+                    subject: EntityOwnerSubject::Web { id: WEB_A },
+                    level: 0,
+                },
+            ),
+            (
+                ENTITY_C,
+                EntityRelationAndSubject::Setting {
+                    subject: EntitySettingSubject::Setting {
+                        id: EntitySetting::AdministratorFromWeb,
+                    },
+                    level: 0,
+                },
+            ),
+            (
+                ENTITY_C,
+                EntityRelationAndSubject::Viewer {
+                    subject: EntityViewerSubject::AccountGroup {
+                        id: ACCOUNT_GROUP_A,
+                        set: EntitySubjectSet::Member,
+                    },
+                    level: 0,
+                    expires_at: None,
+                },
+            ),
+            (
+                ENTITY_D,
+                EntityRelationAndSubject::Owner {
+                    subject: EntityOwnerSubject::Web { id: WEB_A },
+                    level: 0,
+                },
+            ),
+            (
+                ENTITY_D,
+                EntityRelationAndSubject::Viewer {
+                    subject: EntityViewerSubject::Public,
+                    level: 0,
+                    expires_at: None,
+                },
+            ),
+        ])
+        .await?
+        .written_at;
+
+    // The administrator of the owning account group has full access if the setting is enabled
+    assert!(
+        api.check_permission(
+            &ENTITY_C,
+            &EntityPermission::FullAccess,
+            &BOB,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+    assert!(
+        !api.check_permission(
+            &ENTITY_D,
+            &EntityPermission::FullAccess,
+            &BOB,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+    assert!(
+        !api.check_permission(
+            &ENTITY_C,
+            &EntityPermission::FullAccess,
+            &ALICE,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+
+    // The full access is granted through the administrator of the account group owning the web
+    let explanation = api
+        .explain_permission(
+            &ENTITY_C,
+            &EntityPermission::FullAccess,
+            &BOB,
+            Consistency::AtLeastAsFresh(&token),
+        )
+        .await?;
+    assert!(explanation.has_permission);
+    if let Some(trace) = explanation.trace {
+        assert_eq!(trace.resource, format!("graph/entity:{ENTITY_C}"));
+        assert_eq!(trace.relation, "full_access");
+        assert!(is_granted_by(&trace, "graph/web", "administrator"));
+        assert!(is_granted_by(
+            &trace,
+            "graph/account_group",
+            "administrator"
+        ));
+    }
+
+    // Members of the account group are allowed to view the entity
+    assert!(
+        api.check_permission(
+            &ENTITY_C,
+            &EntityPermission::View,
+            &ALICE,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+    assert!(
+        !api.check_permission(
+            &ENTITY_C,
+            &EntityPermission::Update,
+            &ALICE,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+
+    // Public entities can be viewed by everyone
+    assert!(
+        api.check_permission(
+            &ENTITY_D,
+            &EntityPermission::View,
+            &ALICE,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+
+    // Looking up resources returns inherited and public permissions alike
+    let viewable = lookup_entities(&api, ALICE, EntityPermission::View, &token).await?;
+    assert!(viewable.contains(&ENTITY_C));
+    assert!(viewable.contains(&ENTITY_D));
+    let limited = api
+        .lookup_resources::<EntityUuid, _, _>(
+            &ALICE,
+            &EntityPermission::View,
+            &EntityNamespace::Entity,
+            Some(1),
+            Consistency::AtLeastAsFresh(&token),
+        )
+        .await?
+        .resources
+        .ok_or("the backend should restrict the permission")?;
+    assert_eq!(limited.len(), 1);
+    let full_access = lookup_entities(&api, BOB, EntityPermission::FullAccess, &token).await?;
+    assert!(full_access.contains(&ENTITY_C));
+    assert!(!full_access.contains(&ENTITY_D));
+
+    let token = api
+        .delete_relationships([(
+            ACCOUNT_GROUP_A,
+            AccountGroupRelationAndSubject::Member {
+                subject: AccountGroupMemberSubject::Account { id: ALICE },
+                level: 0,
+                expires_at: None,
+            },
+        )])
+        .await?
+        .written_at;
+
+    assert!(
+        !api.check_permission(
+            &ENTITY_C,
+            &EntityPermission::View,
+            &ALICE,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+    let viewable = lookup_entities(&api, ALICE, EntityPermission::View, &token).await?;
+    assert!(!viewable.contains(&ENTITY_C));
+    assert!(viewable.contains(&ENTITY_D));
+
+    Ok(())
+}
+
+async fn expiring_permissions(
+    mut api: impl ZanzibarBackend + Send + Sync,
+) -> Result<(), Box<dyn Error>> {
+    api.import_schema(SchemaMigration::latest().schema())
+        .await?;
+
+    // Sub-second precision is not needed and makes comparing the read relationships easier
+    let now = OffsetDateTime::now_utc().replace_nanosecond(0)?;
+    let expired_viewer = EntityRelationAndSubject::Viewer {
+        subject: EntityViewerSubject::Account { id: ALICE },
+        level: 0,
+        expires_at: Some(now - Duration::hours(1)),
+    };
+
+    api.touch_relationships([
+        (
+            ENTITY_A,
+            EntityRelationAndSubject::Viewer {
+                subject: EntityViewerSubject::Account { id: ALICE },
+                level: 0,
+                expires_at: Some(now + Duration::hours(1)),
+            },
+        ),
+        (ENTITY_B, expired_viewer),
+        (
+            ENTITY_C,
+            EntityRelationAndSubject::Editor {
+                subject: EntityEditorSubject::AccountGroup {
+                    id: ACCOUNT_GROUP_A,
+                    set: EntitySubjectSet::Member,
+                },
+                level: 0,
+                expires_at: None,
+            },
+        ),
+    ])
+    .await?;
+    let token = api
+        .touch_relationships([(
+            ACCOUNT_GROUP_A,
+            AccountGroupRelationAndSubject::Member {
+                subject: AccountGroupMemberSubject::Account { id: BOB },
+                level: 0,
+                expires_at: Some(now - Duration::hours(1)),
+            },
+        )])
+        .await?
+        .written_at;
+
+    assert!(
+        api.check_permission(
+            &ENTITY_A,
+            &EntityPermission::View,
+            &ALICE,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+    assert!(
+        !api.check_permission(
+            &ENTITY_B,
+            &EntityPermission::View,
+            &ALICE,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+    assert!(
+        !api.check_permission(
+            &ENTITY_C,
+            &EntityPermission::View,
+            &BOB,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+    let viewable = lookup_entities(&api, ALICE, EntityPermission::View, &token).await?;
+    assert!(viewable.contains(&ENTITY_A));
+    assert!(!viewable.contains(&ENTITY_B));
+
+    // Expired relationships are still stored and keep their expiration
+    let relationships = api
+        .read_relations::<(EntityUuid, EntityRelationAndSubject)>(
+            RelationshipFilter::from_resource(ENTITY_B),
+            Consistency::AtLeastAsFresh(&token),
+        )
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+    assert_eq!(relationships, [(ENTITY_B, expired_viewer)]);
+
+    Ok(())
+}
+
+async fn restricted_property_permissions(
+    mut api: impl ZanzibarBackend + Send + Sync,
+) -> Result<(), Box<dyn Error>> {
+    api.import_schema(SchemaMigration::latest().schema())
+        .await?;
+
+    let token = api
+        .touch_relationships([
+            (
+                ENTITY_A,
+                EntityRelationAndSubject::RestrictedPropertyViewer {
+                    subject: EntityRestrictedPropertyViewerSubject::Account { id: ALICE },
+                    level: 0,
+                    expires_at: None,
+                },
+            ),
+            (
+                ENTITY_A,
+                EntityRelationAndSubject::Viewer {
+                    subject: EntityViewerSubject::Account { id: BOB },
+                    level: 0,
+                    expires_at: None,
+                },
+            ),
+        ])
+        .await?
+        .written_at;
+
+    assert!(
+        api.check_permission(
+            &ENTITY_A,
+            &EntityPermission::ViewRestrictedProperties,
+            &ALICE,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+    // Viewing an entity does not grant access to its restricted properties
+    assert!(
+        api.check_permission(
+            &ENTITY_A,
+            &EntityPermission::View,
+            &BOB,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+    assert!(
+        !api.check_permission(
+            &ENTITY_A,
+            &EntityPermission::ViewRestrictedProperties,
+            &BOB,
+            Consistency::AtLeastAsFresh(&token)
+        )
+        .await?
+        .has_permission
+    );
+
+    Ok(())
+}
+
+async fn exceeded_lookup(
+    mut api: impl ZanzibarBackend + Send + Sync,
+) -> Result<(), Box<dyn Error>> {
+    api.import_schema(SchemaMigration::latest().schema())
+        .await?;
+
+    let token = api
+        .touch_relationships([
+            (
+                ENTITY_A,
+                EntityRelationAndSubject::Viewer {
+                    subject: EntityViewerSubject::Account { id: CHARLIE },
+                    level: 0,
+                    expires_at: None,
+                },
+            ),
+            (
+                ENTITY_B,
+                EntityRelationAndSubject::Viewer {
+                    subject: EntityViewerSubject::Account { id: CHARLIE },
+                    level: 0,
+                    expires_at: None,
+                },
+            ),
+        ])
+        .await?
+        .written_at;
+    let viewable = lookup_entities(&api, CHARLIE, EntityPermission::View, &token).await?;
+    let limit = viewable.len() - 1;
+
+    let mut client = ZanzibarClient::new(api);
+    let (entities, _) = client
+        .lookup_entities(
+            CHARLIE,
+            EntityPermission::View,
+            limit,
+            Consistency::FullyConsistent,
+        )
+        .await?;
+    assert!(entities.is_none(), "the limit should be exceeded");
+
+    let token = client
+        .modify_entity_relations([(
+            ModifyRelationshipOperation::Delete,
+            EntityId {
+                owned_by_id: WEB_A,
+                entity_uuid: ENTITY_B,
+                draft_id: None,
+            },
+            EntityRelationAndSubject::Viewer {
+                subject: EntityViewerSubject::Account { id: CHARLIE },
+                level: 0,
+                expires_at: None,
+            },
+        )])
+        .await?;
+
+    // The exceeded limit is remembered, so the entities are not looked up again
+    let (entities, _) = client
+        .lookup_entities(
+            CHARLIE,
+            EntityPermission::View,
+            limit,
+            Consistency::AtLeastAsFresh(&token),
+        )
+        .await?;
+    assert!(
+        entities.is_none(),
+        "the exceeded limit should be remembered"
+    );
+
+    let api = client.into_backend();
+    let viewable = lookup_entities(&api, CHARLIE, EntityPermission::View, &token).await?;
+    assert_eq!(viewable.len(), limit);
+
+    let client = ZanzibarClient::new(api);
+    let (entities, _) = client
+        .lookup_entities(
+            CHARLIE,
+            EntityPermission::View,
+            limit,
+            Consistency::AtLeastAsFresh(&token),
+        )
+        .await?;
+    assert!(
+        entities.is_some(),
+        "the limit should not be exceeded anymore"
+    );
+
+    Ok(())
+}
+
+mod spicedb {
+    use std::error::Error;
+
+    use crate::api;
+
+    #[tokio::test]
+    async fn test_schema() -> Result<(), Box<dyn Error>> {
+        super::test_schema(api::connect()).await
+    }
+
+    #[tokio::test]
+    async fn plain_permissions() -> Result<(), Box<dyn Error>> {
+        super::plain_permissions(api::connect()).await
+    }
+
+    #[tokio::test]
+    async fn inherited_permissions() -> Result<(), Box<dyn Error>> {
+        super::inherited_permissions(api::connect()).await
+    }
+
+    #[tokio::test]
+    async fn expiring_permissions() -> Result<(), Box<dyn Error>> {
+        super::expiring_permissions(api::connect()).await
+    }
+
+    #[tokio::test]
+    async fn restricted_property_permissions() -> Result<(), Box<dyn Error>> {
+        super::restricted_property_permissions(api::connect()).await
+    }
+
+    #[tokio::test]
+    async fn exceeded_lookup() -> Result<(), Box<dyn Error>> {
+        super::exceeded_lookup(api::connect()).await
+    }
+}
+
+mod in_memory {
+    use std::error::Error;
+
+    use crate::api;
+
+    #[tokio::test]
+    async fn test_schema() -> Result<(), Box<dyn Error>> {
+        super::test_schema(api::connect_in_memory()).await
+    }
+
+    #[tokio::test]
+    async fn plain_permissions() -> Result<(), Box<dyn Error>> {
+        super::plain_permissions(api::connect_in_memory()).await
+    }
+
+    #[tokio::test]
+    async fn inherited_permissions() -> Result<(), Box<dyn Error>> {
+        super::inherited_permissions(api::connect_in_memory()).await
+    }
+
+    #[tokio::test]
+    async fn expiring_permissions() -> Result<(), Box<dyn Error>> {
+        super::expiring_permissions(api::connect_in_memory()).await
+    }
+
+    #[tokio::test]
+    async fn restricted_property_permissions() -> Result<(), Box<dyn Error>> {
+        super::restricted_property_permissions(api::connect_in_memory()).await
+    }
+
+    #[tokio::test]
+    async fn exceeded_lookup() -> Result<(), Box<dyn Error>> {
+        super::exceeded_lookup(api::connect_in_memory()).await
+    }
+}
@@ -126,6 +126,21 @@ impl EntityProperties {
     pub const fn properties(&self) -> &HashMap<BaseUrl, serde_json::Value> {
         &self.0
     }
+
+    /// Sets the property identified by `base_url` and returns the previous value if it was
+    /// present.
+    pub fn insert(
+        &mut self,
+        base_url: BaseUrl,
+        value: serde_json::Value,
+    ) -> Option<serde_json::Value> {
+        self.0.insert(base_url, value)
+    }
+
+    /// Removes the property identified by `base_url` and returns its value if it was present.
+    pub fn remove(&mut self, base_url: &BaseUrl) -> Option<serde_json::Value> {
+        self.0.remove(base_url)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
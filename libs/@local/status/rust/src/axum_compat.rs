@@ -0,0 +1,122 @@
+use std::{fmt::Debug, marker::PhantomData};
+
+use axum::{
+    response::{IntoResponse, Response},
+    Json,
+};
+use error_stack::{Context, Report};
+use serde::Serialize;
+
+use crate::Status;
+
+impl<D> IntoResponse for Status<D>
+where
+    D: Send + Sync + Debug + Serialize,
+{
+    fn into_response(self) -> Response {
+        let status_code = axum::http::StatusCode::from_u16(self.code().to_http_code())
+            .expect("HASH Status code should map to a valid HTTP status code");
+
+        (status_code, Json(self)).into_response()
+    }
+}
+
+/// A [`Report`] which can be returned from an axum handler.
+///
+/// The [`Report`] is converted into a [`Status`] with contents of type `D`, see
+/// [`Status::from_report`]. As it implements `From<Report<C>>`, handlers returning
+/// `Result<_, ReportResponse<C>>` can use `?` on `Result<_, Report<C>>`.
+pub struct ReportResponse<C, D = serde_json::Value> {
+    report: Report<C>,
+    _contents: PhantomData<fn() -> D>,
+}
+
+impl<C, D> ReportResponse<C, D> {
+    pub fn into_report(self) -> Report<C> {
+        self.report
+    }
+}
+
+impl<C, D> From<Report<C>> for ReportResponse<C, D> {
+    fn from(report: Report<C>) -> Self {
+        Self {
+            report,
+            _contents: PhantomData,
+        }
+    }
+}
+
+impl<C, D> IntoResponse for ReportResponse<C, D>
+where
+    C: Context,
+    D: Clone + Send + Sync + Debug + Serialize + 'static,
+{
+    fn into_response(self) -> Response {
+        Status::<D>::from_report(&self.report).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use axum::body::to_bytes;
+    use serde_json::json;
+
+    use super::*;
+    use crate::StatusCode;
+
+    #[derive(Debug)]
+    struct NotFound;
+
+    impl fmt::Display for NotFound {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt.write_str("entity not found")
+        }
+    }
+
+    impl Context for NotFound {}
+
+    fn find_entity() -> Result<(), Report<NotFound>> {
+        Err(Report::new(NotFound)
+            .attach(StatusCode::NotFound)
+            .attach(json!({ "id": 1 })))
+    }
+
+    fn handler() -> Result<(), ReportResponse<NotFound>> {
+        find_entity()?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn report_into_response() {
+        let response = handler()
+            .expect_err("handler should return an error")
+            .into_response();
+        assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+
+        let body = to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body should be readable");
+        let status: Status<serde_json::Value> =
+            serde_json::from_slice(&body).expect("body should be a status");
+        assert_eq!(
+            status,
+            Status::new(
+                StatusCode::NotFound,
+                Some("entity not found".to_owned()),
+                vec![json!({ "id": 1 })],
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn report_without_status_code_into_response() {
+        let response =
+            ReportResponse::<_, serde_json::Value>::from(Report::new(NotFound)).into_response();
+        assert_eq!(
+            response.status(),
+            axum::http::StatusCode::INTERNAL_SERVER_ERROR
+        );
+    }
+}
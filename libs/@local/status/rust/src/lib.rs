@@ -4,10 +4,15 @@
 //! Defines a logical status and error model that is suitable for different programming
 //! environments, including REST APIs and RPC APIs.
 
+#[cfg(feature = "axum")]
+mod axum_compat;
+mod report;
 mod status_code;
 
 use std::fmt::Debug;
 
+#[cfg(feature = "axum")]
+pub use axum_compat::ReportResponse;
 use serde::{Deserialize, Serialize};
 pub use status_code::StatusCode;
 
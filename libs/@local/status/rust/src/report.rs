@@ -0,0 +1,107 @@
+use std::fmt::Debug;
+
+use error_stack::{Context, Report};
+use serde::Serialize;
+
+use crate::{Status, StatusCode};
+
+impl StatusCode {
+    /// Returns the most recent [`StatusCode`] attached to or provided by the [`Report`].
+    ///
+    /// If the [`Report`] does not contain a [`StatusCode`], [`StatusCode::Internal`] is returned.
+    #[must_use]
+    pub fn from_report<C>(report: &Report<C>) -> Self {
+        report
+            .request_ref::<Self>()
+            .next()
+            .copied()
+            .or_else(|| report.request_value::<Self>().next())
+            .unwrap_or(Self::Internal)
+    }
+}
+
+impl<D> Status<D>
+where
+    D: Clone + Send + Sync + Debug + Serialize + 'static,
+{
+    /// Creates a `Status` describing the error of a [`Report`].
+    ///
+    /// - The code is picked by [`StatusCode::from_report`].
+    /// - The message is the current context of the [`Report`].
+    /// - The contents are all attachments of type `D` and all references to `D` provided by the
+    ///   contexts of the [`Report`]. Values provided by the contexts are not collected.
+    #[must_use]
+    pub fn from_report<C>(report: &Report<C>) -> Self
+    where
+        C: Context,
+    {
+        Self::new(
+            StatusCode::from_report(report),
+            Some(report.current_context().to_string()),
+            report.request_ref::<D>().cloned().collect(),
+        )
+    }
+}
+
+impl<C, D> From<Report<C>> for Status<D>
+where
+    C: Context,
+    D: Clone + Send + Sync + Debug + Serialize + 'static,
+{
+    fn from(report: Report<C>) -> Self {
+        Self::from_report(&report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct NotFound;
+
+    impl fmt::Display for NotFound {
+        fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt.write_str("entity not found")
+        }
+    }
+
+    impl Context for NotFound {}
+
+    #[test]
+    fn status_code_defaults_to_internal() {
+        let report = Report::new(NotFound);
+        assert_eq!(StatusCode::from_report(&report), StatusCode::Internal);
+    }
+
+    #[test]
+    fn status_code_is_most_recent_attachment() {
+        let report = Report::new(NotFound)
+            .attach(StatusCode::NotFound)
+            .attach(StatusCode::PermissionDenied);
+        assert_eq!(
+            StatusCode::from_report(&report),
+            StatusCode::PermissionDenied
+        );
+    }
+
+    #[test]
+    fn status_from_report() {
+        let report = Report::new(NotFound)
+            .attach(StatusCode::NotFound)
+            .attach(String::from("first"))
+            .attach_printable("not collected")
+            .attach(String::from("second"));
+
+        assert_eq!(
+            Status::<String>::from(report),
+            Status::new(
+                StatusCode::NotFound,
+                Some("entity not found".to_owned()),
+                vec!["second".to_owned(), "first".to_owned()],
+            )
+        );
+    }
+}
@@ -5,6 +5,11 @@ pub mod logging;
 pub mod opentelemetry;
 pub mod sentry;
 
+#[doc(hidden)]
+pub mod __private {
+    pub use tracing;
+}
+
 use tracing::{level_filters::LevelFilter, warn};
 use tracing_subscriber::{
     filter::Directive,
@@ -1,5 +1,17 @@
+//! Log output to the terminal and to log files.
+//!
+//! [`Report`]s are recorded with [`report_value!`], e.g.
+//! `tracing::error!(error = hash_tracing::report_value!(report), "Could not read file")`, which
+//! records the trace and span ID of the current span alongside the report.
+//!
+//! With the `valuable` feature, the JSON formatters emit the report as nested JSON, including its
+//! contexts, locations and attachments, instead of a single `Debug` string. `tracing` only supports
+//! this when built with `RUSTFLAGS="--cfg tracing_unstable"`.
+//!
+//! [`report_value!`]: crate::report_value
+
 use std::{
-    fmt::{Display, Formatter},
+    fmt::{Debug, Display, Formatter},
     io,
     io::{IsTerminal, Stderr},
     path::{Path, PathBuf},
@@ -8,8 +20,10 @@ use std::{
 #[cfg(feature = "clap")]
 use clap::Parser;
 use error_stack::{fmt::ColorMode, Report};
-use tracing::{Event, Level, Subscriber};
+use opentelemetry::trace::TraceContextExt;
+use tracing::{Event, Level, Span, Subscriber};
 use tracing_appender::non_blocking::NonBlocking;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
 use tracing_subscriber::{
     filter::Directive,
     fmt::{
@@ -20,6 +34,125 @@ use tracing_subscriber::{
     },
     registry::LookupSpan,
 };
+#[cfg(feature = "valuable")]
+use valuable::{Fields, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit};
+
+/// Records a [`Report`] as a field of a `tracing` event.
+///
+/// The report is wrapped in a [`ReportValue`], so the trace and span ID of the current span are
+/// recorded as well. With the `valuable` feature the report is recorded as structured value,
+/// otherwise its `Debug` output is used.
+///
+/// ```rust
+/// use error_stack::Report;
+///
+/// let report = Report::new(std::io::Error::from(std::io::ErrorKind::NotFound));
+/// tracing::error!(
+///     error = hash_tracing::report_value!(report),
+///     "Could not read file"
+/// );
+/// ```
+#[macro_export]
+macro_rules! report_value {
+    ($report:expr) => {
+        $crate::__report_field!($crate::logging::ReportValue::new(&$report))
+    };
+}
+
+#[cfg(feature = "valuable")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __report_field {
+    ($value:expr) => {
+        $crate::__private::tracing::field::valuable(&$value)
+    };
+}
+
+#[cfg(not(feature = "valuable"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __report_field {
+    ($value:expr) => {
+        $crate::__private::tracing::field::debug($value)
+    };
+}
+
+#[cfg(feature = "valuable")]
+static REPORT_VALUE_FIELDS: &[NamedField<'static>] = &[
+    NamedField::new("trace_id"),
+    NamedField::new("span_id"),
+    NamedField::new("report"),
+];
+
+/// A [`Report`] together with the OpenTelemetry trace and span ID of the span it's logged in.
+///
+/// The IDs allow finding the trace of a logged report. They are only set if the current span is
+/// exported to OpenTelemetry. Use [`report_value!`] to record it in an event.
+///
+/// [`report_value!`]: crate::report_value
+pub struct ReportValue<'r, C> {
+    report: &'r Report<C>,
+    trace_id: Option<String>,
+    span_id: Option<String>,
+}
+
+impl<'r, C> ReportValue<'r, C> {
+    /// Wraps `report` and reads the trace and span ID of the current span.
+    #[must_use]
+    pub fn new(report: &'r Report<C>) -> Self {
+        let context = Span::current().context();
+        let span = context.span();
+        let span_context = span.span_context();
+        let (trace_id, span_id) = if span_context.is_valid() {
+            (
+                Some(span_context.trace_id().to_string()),
+                Some(span_context.span_id().to_string()),
+            )
+        } else {
+            (None, None)
+        };
+
+        Self {
+            report,
+            trace_id,
+            span_id,
+        }
+    }
+}
+
+impl<C> Debug for ReportValue<'_, C> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> std::fmt::Result {
+        if let (Some(trace_id), Some(span_id)) = (&self.trace_id, &self.span_id) {
+            write!(fmt, "[trace_id={trace_id} span_id={span_id}] ")?;
+        }
+        Debug::fmt(self.report, fmt)
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl<C: error_stack::Context> Valuable for ReportValue<'_, C> {
+    fn as_value(&self) -> Value<'_> {
+        Value::Structable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        visit.visit_named_fields(&NamedValues::new(
+            REPORT_VALUE_FIELDS,
+            &[
+                self.trace_id.as_deref().map_or(Value::Unit, Value::String),
+                self.span_id.as_deref().map_or(Value::Unit, Value::String),
+                self.report.as_value(),
+            ],
+        ));
+    }
+}
+
+#[cfg(feature = "valuable")]
+impl<C: error_stack::Context> Structable for ReportValue<'_, C> {
+    fn definition(&self) -> StructDef<'_> {
+        StructDef::new_static("ReportValue", Fields::Named(REPORT_VALUE_FIELDS))
+    }
+}
 
 /// Output format emitted to the terminal
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
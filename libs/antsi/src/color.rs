@@ -1,3 +1,7 @@
+use core::fmt::{self, Write};
+
+use crate::sgr::Sequence;
+
 /// Basic colors variants
 ///
 /// ## Support
@@ -149,6 +153,10 @@ impl IndexedColor {
     pub const fn new(index: u8) -> Self {
         Self(index)
     }
+
+    pub(crate) const fn index(self) -> u8 {
+        self.0
+    }
 }
 
 impl From<BasicColor> for IndexedColor {
@@ -260,6 +268,10 @@ impl RgbColor {
     pub const fn new(red: u8, green: u8, blue: u8) -> Self {
         Self { red, green, blue }
     }
+
+    pub(crate) const fn components(self) -> [u8; 3] {
+        [self.red, self.green, self.blue]
+    }
 }
 
 /// Truecolor 32-bit RGBA support
@@ -303,6 +315,11 @@ impl RgbaColor {
             alpha,
         }
     }
+
+    /// The color without its alpha channel.
+    pub(crate) const fn to_rgb(self) -> RgbColor {
+        RgbColor::new(self.red, self.green, self.blue)
+    }
 }
 
 /// Truecolor, 24-bit CMY colors
@@ -336,6 +353,10 @@ impl CmyColor {
             yellow,
         }
     }
+
+    pub(crate) const fn to_rgb(self) -> RgbColor {
+        RgbColor::new(255 - self.cyan, 255 - self.magenta, 255 - self.yellow)
+    }
 }
 
 /// Truecolor, 32-bit CMYK colors
@@ -372,6 +393,20 @@ impl CmykColor {
             black,
         }
     }
+
+    #[allow(clippy::cast_possible_truncation)]
+    pub(crate) const fn to_rgb(self) -> RgbColor {
+        // The result is at most `255 * 255 / 255` and therefore always fits into `u8`
+        const fn component(color: u8, black: u8) -> u8 {
+            ((255 - color as u16) * (255 - black as u16)).div_euclid(255) as u8
+        }
+
+        RgbColor::new(
+            component(self.cyan, self.black),
+            component(self.magenta, self.black),
+            component(self.yellow, self.black),
+        )
+    }
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -471,3 +506,108 @@ impl From<TransparentColor> for Color {
         Self::Transparent(value)
     }
 }
+
+/// The layer a [`Color`] is applied to, which determines the escape codes used.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(crate) enum ColorLayer {
+    Foreground,
+    Background,
+    // kitty + vte extension
+    #[cfg(feature = "underline-color")]
+    Underline,
+}
+
+impl ColorLayer {
+    /// Escape code for extended colors, as specified in [ISO 8613-6].
+    ///
+    /// [ISO 8613-6]: https://www.iso.org/standard/22943.html
+    const fn extended(self) -> u8 {
+        match self {
+            Self::Foreground => 38,
+            Self::Background => 48,
+            #[cfg(feature = "underline-color")]
+            Self::Underline => 58,
+        }
+    }
+
+    /// Escape code to reset the color to the default of the terminal.
+    pub(crate) const fn reset(self) -> u8 {
+        self.extended() + 1
+    }
+}
+
+impl Color {
+    /// Writes the escape code of the color to `sequence`.
+    ///
+    /// The underline color has no dedicated escape codes for basic and bright colors, these are
+    /// therefore written as their [`IndexedColor`] counterpart.
+    pub(crate) fn write_sgr<W>(
+        self,
+        layer: ColorLayer,
+        sequence: &mut Sequence<'_, W>,
+    ) -> fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        let extended = layer.extended();
+
+        match (self, layer) {
+            (Self::Basic(color), ColorLayer::Foreground) => {
+                sequence.push(30 + IndexedColor::from(color).0)
+            }
+            (Self::Basic(color), ColorLayer::Background) => {
+                sequence.push(40 + IndexedColor::from(color).0)
+            }
+            (Self::Bright(BrightColor(color)), ColorLayer::Foreground) => {
+                sequence.push(90 + IndexedColor::from(color).0)
+            }
+            (Self::Bright(BrightColor(color)), ColorLayer::Background) => {
+                sequence.push(100 + IndexedColor::from(color).0)
+            }
+            #[cfg(feature = "underline-color")]
+            (Self::Basic(color), ColorLayer::Underline) => {
+                Self::Indexed(color.into()).write_sgr(layer, sequence)
+            }
+            #[cfg(feature = "underline-color")]
+            (Self::Bright(color), ColorLayer::Underline) => {
+                Self::Indexed(color.into()).write_sgr(layer, sequence)
+            }
+            (Self::Indexed(IndexedColor(index)), _) => {
+                sequence.push(format_args!("{extended}:5:{index}"))
+            }
+            (Self::Transparent(TransparentColor), _) => sequence.push(format_args!("{extended}:1")),
+            (Self::Rgb(RgbColor { red, green, blue }), _) => {
+                sequence.push(format_args!("{extended}:2::{red}:{green}:{blue}"))
+            }
+            #[cfg(feature = "rgba")]
+            (
+                Self::Rgba(RgbaColor {
+                    red,
+                    green,
+                    blue,
+                    alpha,
+                }),
+                _,
+            ) => sequence.push(format_args!("{extended}:6::{red}:{green}:{blue}:{alpha}")),
+            (
+                Self::Cmy(CmyColor {
+                    cyan,
+                    magenta,
+                    yellow,
+                }),
+                _,
+            ) => sequence.push(format_args!("{extended}:3::{cyan}:{magenta}:{yellow}")),
+            (
+                Self::Cmyk(CmykColor {
+                    cyan,
+                    magenta,
+                    yellow,
+                    black,
+                }),
+                _,
+            ) => sequence.push(format_args!(
+                "{extended}:4::{cyan}:{magenta}:{yellow}:{black}"
+            )),
+        }
+    }
+}
@@ -1,3 +1,7 @@
+use core::fmt::{self, Write};
+
+use crate::sgr::Sequence;
+
 /// Frame around text
 ///
 /// Not well supported by any major terminal, frames are either rectangular or an oval. [ISO 6429]
@@ -53,4 +57,20 @@ impl Decorations {
     pub const fn new() -> Self {
         Self { frame: None }
     }
+
+    /// Writes the escape codes required to change the decorations to `to`.
+    pub(crate) fn write_transition<W>(self, to: Self, sequence: &mut Sequence<'_, W>) -> fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        if self.frame == to.frame {
+            return Ok(());
+        }
+
+        match to.frame {
+            Some(Frame::Framed) => sequence.push(51),
+            Some(Frame::Encircled) => sequence.push(52),
+            None => sequence.push(54),
+        }
+    }
 }
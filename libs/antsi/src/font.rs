@@ -1,3 +1,7 @@
+use core::fmt::{self, Write};
+
+use crate::sgr::Sequence;
+
 /// Font Weight
 ///
 /// ## Support
@@ -323,4 +327,107 @@ impl Font {
     pub const fn is_overline(self) -> bool {
         self.is_style(FontStyle::Overline)
     }
+
+    /// Returns the alternative font family, `None` if the primary font is used.
+    const fn alternative_family(self) -> Option<AlternativeFontFamily> {
+        match self.family {
+            Some(FontFamily::Alternative(family)) => Some(family),
+            _ => None,
+        }
+    }
+
+    const fn is_fraktur(self) -> bool {
+        matches!(self.family, Some(FontFamily::Fraktur))
+    }
+
+    /// Writes the escape codes required to change the font to `to`.
+    ///
+    /// Some attributes share a single reset escape code (e.g. italic and Fraktur are both reset
+    /// using `ESC[23m`), the attributes that are still enabled in `to` are enabled again
+    /// afterwards.
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn write_transition<W>(self, to: Self, sequence: &mut Sequence<'_, W>) -> fmt::Result
+    where
+        W: Write + ?Sized,
+    {
+        if self.weight != to.weight {
+            sequence.push_if(self.weight.is_some(), 22)?;
+
+            match to.weight {
+                Some(FontWeight::Bold) => sequence.push(1)?,
+                Some(FontWeight::Faint) => sequence.push(2)?,
+                None => {}
+            }
+        }
+
+        let italic_reset =
+            (self.is_italic() && !to.is_italic()) || (self.is_fraktur() && !to.is_fraktur());
+        sequence.push_if(italic_reset, 23)?;
+        sequence.push_if(to.is_italic() && (italic_reset || !self.is_italic()), 3)?;
+        sequence.push_if(to.is_fraktur() && (italic_reset || !self.is_fraktur()), 20)?;
+
+        if self.underline != to.underline {
+            match to.underline {
+                Some(Underline::Single) => sequence.push(4)?,
+                Some(Underline::Double) => sequence.push(21)?,
+                #[cfg(feature = "underline-variants")]
+                Some(Underline::Curly) => sequence.push("4:3")?,
+                #[cfg(feature = "underline-variants")]
+                Some(Underline::Dotted) => sequence.push("4:4")?,
+                #[cfg(feature = "underline-variants")]
+                Some(Underline::Dashed) => sequence.push("4:5")?,
+                None => sequence.push(24)?,
+            }
+        }
+
+        if self.blinking != to.blinking {
+            match to.blinking {
+                Some(Blinking::Slow) => sequence.push(5)?,
+                Some(Blinking::Fast) => sequence.push(6)?,
+                None => sequence.push(25)?,
+            }
+        }
+
+        if self.is_inverse() != to.is_inverse() {
+            sequence.push(if to.is_inverse() { 7 } else { 27 })?;
+        }
+
+        // mintty extension: over-strike is a variant of hidden and shares its reset
+        #[cfg(feature = "overstrike")]
+        let (is_overstrike, to_overstrike) = (self.is_overstrike(), to.is_overstrike());
+        #[cfg(not(feature = "overstrike"))]
+        let (is_overstrike, to_overstrike) = (false, false);
+
+        let hidden_reset =
+            (self.is_hidden() && !to.is_hidden()) || (is_overstrike && !to_overstrike);
+        sequence.push_if(hidden_reset, 28)?;
+        sequence.push_if(to.is_hidden() && (hidden_reset || !self.is_hidden()), 8)?;
+        sequence.push_if(to_overstrike && (hidden_reset || !is_overstrike), "8:7")?;
+
+        if self.is_strikethrough() != to.is_strikethrough() {
+            sequence.push(if to.is_strikethrough() { 9 } else { 29 })?;
+        }
+
+        if self.alternative_family() != to.alternative_family() {
+            match to.alternative_family() {
+                Some(AlternativeFontFamily(family)) => sequence.push(11 + family)?,
+                None => sequence.push(10)?,
+            }
+        }
+
+        if self.is_overline() != to.is_overline() {
+            sequence.push(if to.is_overline() { 53 } else { 55 })?;
+        }
+
+        #[cfg(feature = "script")]
+        if self.script != to.script {
+            match to.script {
+                Some(FontScript::Super) => sequence.push(73)?,
+                Some(FontScript::Sub) => sequence.push(74)?,
+                None => sequence.push(75)?,
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -23,6 +23,9 @@
 // future PR will add remaining documentation
 #![allow(missing_docs)]
 
+#[cfg(test)]
+extern crate alloc;
+
 #[cfg(feature = "rgba")]
 pub use color::RgbaColor;
 pub use color::{
@@ -32,10 +35,16 @@ pub use decorations::{Decorations, Frame};
 #[cfg(feature = "script")]
 pub use font::FontScript;
 pub use font::{Blinking, Font, FontFamily, FontWeight, Underline};
+pub use parse::Parser;
+pub use sgr::{Styled, Transition};
+pub use support::ColorSupport;
 
 mod color;
 mod decorations;
 mod font;
+mod parse;
+mod sgr;
+mod support;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Foreground(Color);
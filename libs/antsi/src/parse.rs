@@ -0,0 +1,591 @@
+#[cfg(feature = "script")]
+use crate::FontScript;
+#[cfg(feature = "rgba")]
+use crate::RgbaColor;
+#[cfg(feature = "underline-color")]
+use crate::UnderlineColor;
+use crate::{
+    font::AlternativeFontFamily, Background, BasicColor, Blinking, CmyColor, CmykColor, Color,
+    FontFamily, FontWeight, Foreground, Frame, IndexedColor, RgbColor, Style, Styled,
+    TransparentColor, Underline,
+};
+
+const ESCAPE: char = '\x1b';
+
+/// An escape sequence at the start of the input.
+enum Escape<'a> {
+    /// `CSI {parameters} m`
+    Sgr(&'a str),
+    /// Any other well-formed escape sequence, which does not change the style.
+    Other,
+}
+
+/// Splits the escape sequence at the start of `input` from the remaining input.
+///
+/// Returns `None` if `input` does not start with a well-formed escape sequence.
+fn split_escape(input: &str) -> Option<(Escape<'_>, &str)> {
+    let bytes = input.as_bytes();
+
+    match bytes.get(1)? {
+        // Control Sequence Introducer: `CSI {parameter bytes} {intermediate bytes} {final byte}`
+        b'[' => {
+            let parameters = bytes[2..]
+                .iter()
+                .take_while(|byte| (0x30..=0x3F).contains(*byte))
+                .count()
+                + 2;
+            let intermediates = bytes[parameters..]
+                .iter()
+                .take_while(|byte| (0x20..=0x2F).contains(*byte))
+                .count()
+                + parameters;
+            let final_byte = *bytes.get(intermediates)?;
+
+            if !(0x40..=0x7E).contains(&final_byte) {
+                return None;
+            }
+
+            // Private sequences (`?`, `<`, `=` and `>`) and intermediate bytes are not SGR
+            let escape = match bytes.get(2) {
+                Some(0x3C..=0x3F) => Escape::Other,
+                _ if final_byte == b'm' && parameters == intermediates => {
+                    Escape::Sgr(input.get(2..parameters)?)
+                }
+                _ => Escape::Other,
+            };
+
+            Some((escape, input.get(intermediates + 1..)?))
+        }
+        // Operating System Command, terminated by either `BEL` or `ST`, e.g. hyperlinks
+        b']' => {
+            let end = input.get(2..)?.find(['\x07', ESCAPE].as_slice())? + 2;
+            let terminator = input.get(end..)?;
+            let length = if terminator.starts_with("\x1b\\") {
+                2
+            } else if terminator.starts_with('\x07') {
+                1
+            } else {
+                return None;
+            };
+
+            Some((Escape::Other, input.get(end + length..)?))
+        }
+        // Any other escape sequence: `ESC {intermediate bytes} {final byte}`
+        _ => {
+            let intermediates = bytes[1..]
+                .iter()
+                .take_while(|byte| (0x20..=0x2F).contains(*byte))
+                .count()
+                + 1;
+            let final_byte = *bytes.get(intermediates)?;
+
+            if !(0x30..=0x7E).contains(&final_byte) {
+                return None;
+            }
+
+            Some((Escape::Other, input.get(intermediates + 1..)?))
+        }
+    }
+}
+
+/// Parses a single parameter element, where an empty element defaults to `0`.
+fn number(element: &str) -> Option<u8> {
+    if element.is_empty() {
+        Some(0)
+    } else {
+        element.parse().ok()
+    }
+}
+
+/// Parses the parameter elements of an [`RgbColor`] following the color mode.
+///
+/// The color space id is optional, as some terminal emulators omit it, but is never present in
+/// the legacy format.
+fn rgb_color<'a>(mut next: impl FnMut() -> Option<&'a str>, legacy: bool) -> Option<RgbColor> {
+    if legacy {
+        let [red, green, blue] = [number(next()?)?, number(next()?)?, number(next()?)?];
+
+        return Some(RgbColor::new(red, green, blue));
+    }
+
+    let mut components = [0; 4];
+    let mut length = 0;
+    while let Some(element) = next() {
+        if length == components.len() {
+            break;
+        }
+        components[length] = number(element)?;
+        length += 1;
+    }
+
+    match length {
+        3 => Some(RgbColor::new(components[0], components[1], components[2])),
+        4 => Some(RgbColor::new(components[1], components[2], components[3])),
+        _ => None,
+    }
+}
+
+/// Parses the parameter elements of an extended color (`38`, `48` and `58`).
+///
+/// `next` yields the elements following the escape code, which are either the sub-parameters
+/// (`ESC[38:2::255:0:0m`) or, for the legacy format, the following parameters
+/// (`ESC[38;2;255;0;0m`).
+fn extended_color<'a>(mut next: impl FnMut() -> Option<&'a str>, legacy: bool) -> Option<Color> {
+    let mode = number(next()?)?;
+
+    match mode {
+        1 if !legacy => Some(Color::Transparent(TransparentColor)),
+        2 => rgb_color(next, legacy).map(Color::from),
+        3 if !legacy => {
+            next()?;
+            let [cyan, magenta, yellow] = [number(next()?)?, number(next()?)?, number(next()?)?];
+
+            Some(CmyColor::new(cyan, magenta, yellow).into())
+        }
+        4 if !legacy => {
+            next()?;
+            let [cyan, magenta, yellow, black] = [
+                number(next()?)?,
+                number(next()?)?,
+                number(next()?)?,
+                number(next()?)?,
+            ];
+
+            Some(CmykColor::new(cyan, magenta, yellow, black).into())
+        }
+        5 => Some(IndexedColor::new(number(next()?)?).into()),
+        #[cfg(feature = "rgba")]
+        6 if !legacy => {
+            next()?;
+            let [red, green, blue, alpha] = [
+                number(next()?)?,
+                number(next()?)?,
+                number(next()?)?,
+                number(next()?)?,
+            ];
+
+            Some(RgbaColor::new(red, green, blue, alpha).into())
+        }
+        _ => None,
+    }
+}
+
+const fn basic_color(offset: u8) -> BasicColor {
+    match offset {
+        0 => BasicColor::Black,
+        1 => BasicColor::Red,
+        2 => BasicColor::Green,
+        3 => BasicColor::Yellow,
+        4 => BasicColor::Blue,
+        5 => BasicColor::Magenta,
+        6 => BasicColor::Cyan,
+        _ => BasicColor::White,
+    }
+}
+
+fn underline(variant: Option<&str>) -> Option<Underline> {
+    match variant.map_or(Some(1), number)? {
+        0 => None,
+        2 => Some(Underline::Double),
+        #[cfg(feature = "underline-variants")]
+        3 => Some(Underline::Curly),
+        #[cfg(feature = "underline-variants")]
+        4 => Some(Underline::Dotted),
+        #[cfg(feature = "underline-variants")]
+        5 => Some(Underline::Dashed),
+        _ => Some(Underline::Single),
+    }
+}
+
+/// Applies the parameters of a single SGR escape sequence to `style`.
+///
+/// Unknown or malformed parameters are ignored.
+#[allow(clippy::too_many_lines)]
+fn apply_parameters(style: &mut Style, parameters: &str) {
+    let mut parameters = parameters.split(';');
+
+    while let Some(parameter) = parameters.next() {
+        let mut elements = parameter.split(':');
+        let code = match elements.next().and_then(number) {
+            Some(code) => code,
+            None => continue,
+        };
+
+        // The legacy format of extended colors spans multiple parameters
+        let color = if !matches!(code, 38 | 48 | 58) {
+            None
+        } else if parameter.contains(':') {
+            extended_color(|| elements.next(), false)
+        } else {
+            extended_color(|| parameters.next(), true)
+        };
+
+        let font = &mut style.font;
+        match code {
+            0 => *style = Style::new(),
+            1 => font.weight = Some(FontWeight::Bold),
+            2 => font.weight = Some(FontWeight::Faint),
+            3 => {
+                font.set_italic(true);
+            }
+            4 => font.underline = underline(elements.next()),
+            5 => font.blinking = Some(Blinking::Slow),
+            6 => font.blinking = Some(Blinking::Fast),
+            7 => {
+                font.set_inverse(true);
+            }
+            // mintty extension: `ESC[8:7m` is over-strike
+            #[cfg(feature = "overstrike")]
+            8 if elements.next() == Some("7") => {
+                font.set_overstrike(true);
+            }
+            8 => {
+                font.set_hidden(true);
+            }
+            9 => {
+                font.set_strikethrough(true);
+            }
+            10 => {
+                if matches!(font.family, Some(FontFamily::Alternative(_))) {
+                    font.family = None;
+                }
+            }
+            11..=19 => {
+                font.family = AlternativeFontFamily::new(code - 11).map(FontFamily::Alternative);
+            }
+            20 => font.family = Some(FontFamily::Fraktur),
+            21 => font.underline = Some(Underline::Double),
+            22 => font.weight = None,
+            23 => {
+                font.set_italic(false);
+                if font.family == Some(FontFamily::Fraktur) {
+                    font.family = None;
+                }
+            }
+            24 => font.underline = None,
+            25 => font.blinking = None,
+            27 => {
+                font.set_inverse(false);
+            }
+            28 => {
+                font.set_hidden(false);
+                #[cfg(feature = "overstrike")]
+                font.set_overstrike(false);
+            }
+            29 => {
+                font.set_strikethrough(false);
+            }
+            30..=37 => style.foreground = Some(Foreground::new(basic_color(code - 30).into())),
+            38 => {
+                if let Some(color) = color {
+                    style.foreground = Some(Foreground::new(color));
+                }
+            }
+            39 => style.foreground = None,
+            40..=47 => style.background = Some(Background::new(basic_color(code - 40).into())),
+            48 => {
+                if let Some(color) = color {
+                    style.background = Some(Background::new(color));
+                }
+            }
+            49 => style.background = None,
+            51 => style.decorations.frame = Some(Frame::Framed),
+            52 => style.decorations.frame = Some(Frame::Encircled),
+            53 => {
+                font.set_overline(true);
+            }
+            54 => style.decorations.frame = None,
+            55 => {
+                font.set_overline(false);
+            }
+            #[cfg(feature = "underline-color")]
+            58 => {
+                if let Some(color) = color {
+                    style.underline_color = Some(UnderlineColor::new(color));
+                }
+            }
+            #[cfg(feature = "underline-color")]
+            59 => style.underline_color = None,
+            #[cfg(feature = "script")]
+            73 => font.script = Some(FontScript::Super),
+            #[cfg(feature = "script")]
+            74 => font.script = Some(FontScript::Sub),
+            #[cfg(feature = "script")]
+            75 => font.script = None,
+            90..=97 => {
+                style.foreground = Some(Foreground::new(basic_color(code - 90).bright().into()));
+            }
+            100..=107 => {
+                style.background = Some(Background::new(basic_color(code - 100).bright().into()));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Parser for text containing SGR escape sequences.
+///
+/// The parser splits the text into runs of text, each of which is annotated with the [`Style`]
+/// it is displayed with. The style starts out as the default style and is changed by every SGR
+/// escape sequence encountered, following the same semantics as a terminal: resets, like
+/// `ESC[0m` or `ESC[39m`, reset all or only the affected attributes, extended colors are accepted
+/// in both the `:` and the legacy `;` delimited format.
+///
+/// Any other escape sequence (like cursor movement or hyperlinks) is skipped. An `ESC`, which does
+/// not start a well-formed escape sequence, is kept as part of the text.
+///
+/// Runs are never empty, but adjacent runs may have the same style, e.g. if they are separated by
+/// an escape sequence that does not change the style.
+///
+/// The parsed runs can be used to strip the escape sequences from the text, or to render it again
+/// using [`Styled`]'s [`Display`] implementation.
+///
+/// # Example
+///
+/// ```
+/// use antsi::{BasicColor, Font, FontWeight, Foreground, Parser, Style};
+///
+/// let input = "\x1b[1;31merror\x1b[22m: unused variable\x1b[0m `x`";
+///
+/// let runs = Parser::new(input)
+///     .map(|run| (*run.value(), run.style()))
+///     .collect::<Vec<_>>();
+///
+/// let red = Style::new().with_foreground(Foreground::new(BasicColor::Red.into()));
+/// assert_eq!(
+///     runs,
+///     [
+///         (
+///             "error",
+///             red.with_font(Font::new().with_weight(FontWeight::Bold))
+///         ),
+///         (": unused variable", red),
+///         (" `x`", Style::new()),
+///     ]
+/// );
+///
+/// let stripped = Parser::new(input)
+///     .map(|run| *run.value())
+///     .collect::<String>();
+/// assert_eq!(stripped, "error: unused variable `x`");
+/// ```
+///
+/// [`Display`]: core::fmt::Display
+#[derive(Debug, Clone)]
+pub struct Parser<'a> {
+    input: &'a str,
+    style: Style,
+}
+
+impl<'a> Parser<'a> {
+    /// Creates a new parser for `input`, starting with the default style.
+    #[must_use]
+    pub const fn new(input: &'a str) -> Self {
+        Self {
+            input,
+            style: Style::new(),
+        }
+    }
+
+    /// The style after the escape sequences parsed so far.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Foreground, Parser, Style};
+    ///
+    /// let mut parser = Parser::new("warning\x1b[33m");
+    /// assert_eq!(parser.next().map(|run| *run.value()), Some("warning"));
+    /// assert_eq!(parser.next(), None);
+    ///
+    /// assert_eq!(
+    ///     parser.style(),
+    ///     Style::new().with_foreground(Foreground::new(BasicColor::Yellow.into()))
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn style(&self) -> Style {
+        self.style
+    }
+}
+
+impl<'a> Iterator for Parser<'a> {
+    type Item = Styled<&'a str>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.input.is_empty() {
+                return None;
+            }
+
+            if self.input.starts_with(ESCAPE) {
+                if let Some((escape, remaining)) = split_escape(self.input) {
+                    if let Escape::Sgr(parameters) = escape {
+                        apply_parameters(&mut self.style, parameters);
+                    }
+
+                    self.input = remaining;
+                    continue;
+                }
+            }
+
+            // A malformed escape sequence is part of the text
+            let start = usize::from(self.input.starts_with(ESCAPE));
+            let end = self
+                .input
+                .bytes()
+                .skip(start)
+                .position(|byte| byte == 0x1B)
+                .map_or(self.input.len(), |end| end + start);
+            let (text, remaining) = self.input.split_at(end);
+            self.input = remaining;
+
+            return Some(self.style.apply(text));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+    use crate::Font;
+
+    fn parse(input: &str) -> Vec<(&str, Style)> {
+        Parser::new(input)
+            .map(|run| (*run.value(), run.style()))
+            .collect()
+    }
+
+    const BOLD: Style = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(parse(""), []);
+        assert_eq!(parse("hello"), [("hello", Style::new())]);
+    }
+
+    #[test]
+    fn lone_escape() {
+        assert_eq!(
+            parse("error\x1b"),
+            [("error", Style::new()), ("\x1b", Style::new())]
+        );
+        assert_eq!(parse("\x1b\x1b[1m"), [("\x1b", Style::new())]);
+    }
+
+    #[test]
+    fn truncated_sequence() {
+        assert_eq!(parse("\x1b["), [("\x1b[", Style::new())]);
+        assert_eq!(parse("\x1b[1;3"), [("\x1b[1;3", Style::new())]);
+        assert_eq!(
+            parse("\x1b[1mbold\x1b[0"),
+            [("bold", BOLD), ("\x1b[0", BOLD)]
+        );
+        assert_eq!(
+            parse("\x1b]8;;https://hash.ai"),
+            [("\x1b]8;;https://hash.ai", Style::new())]
+        );
+    }
+
+    #[test]
+    fn malformed_sequence() {
+        // invalid final byte
+        assert_eq!(parse("\x1b[1\x07text"), [("\x1b[1\x07text", Style::new())]);
+        assert_eq!(parse("\x1b[1\u{e9}text"), [("\x1b[1\u{e9}text", Style::new())]);
+        assert_eq!(parse("\x1b\x07text"), [("\x1b\x07text", Style::new())]);
+        // the malformed sequence does not swallow the following sequence
+        assert_eq!(
+            parse("\x1b[1\x07\x1b[1mtext"),
+            [("\x1b[1\x07", Style::new()), ("text", BOLD)]
+        );
+        // OSC which is terminated by neither `BEL` nor `ST`
+        assert_eq!(
+            parse("\x1b]8;;url\x1b[1mtext"),
+            [("\x1b]8;;url", Style::new()), ("text", BOLD)]
+        );
+    }
+
+    #[test]
+    fn other_sequence() {
+        // cursor movement
+        assert_eq!(parse("\x1b[2Atext"), [("text", Style::new())]);
+        // private sequence, which hides the cursor
+        assert_eq!(parse("\x1b[?25ltext"), [("text", Style::new())]);
+        // intermediate bytes are not part of an SGR sequence
+        assert_eq!(parse("\x1b[1 mtext"), [("text", Style::new())]);
+        // character set selection
+        assert_eq!(parse("\x1b(Btext"), [("text", Style::new())]);
+        // hyperlinks, terminated by `ST` and `BEL`
+        assert_eq!(
+            parse("\x1b]8;;https://hash.ai\x1b\\link\x1b]8;;\x07"),
+            [("link", Style::new())]
+        );
+    }
+
+    #[test]
+    fn runs_are_split_at_sequences() {
+        assert_eq!(
+            parse("one\x1b[2Atwo"),
+            [("one", Style::new()), ("two", Style::new())]
+        );
+    }
+
+    #[test]
+    fn empty_parameters() {
+        assert_eq!(parse("\x1b[1m\x1b[mtext"), [("text", Style::new())]);
+        assert_eq!(parse("\x1b[;1mtext"), [("text", BOLD)]);
+        assert_eq!(parse("\x1b[1;mtext"), [("text", Style::new())]);
+    }
+
+    #[test]
+    fn unknown_parameters() {
+        // 26 and 60 - 69 are not supported, 200 is not assigned
+        assert_eq!(parse("\x1b[26;1;60;200mtext"), [("text", BOLD)]);
+        // out of range and non-numeric parameters
+        assert_eq!(parse("\x1b[1;256mtext"), [("text", BOLD)]);
+        assert_eq!(parse("\x1b[<;1mtext"), [("text", Style::new())]);
+        assert_eq!(
+            parse("\x1b[1;=;3mtext"),
+            [(
+                "text",
+                Style::new().with_font(Font::new().with_weight(FontWeight::Bold).with_italic())
+            )]
+        );
+    }
+
+    #[test]
+    fn malformed_extended_color() {
+        // truncated
+        assert_eq!(parse("\x1b[38;2;255;0mtext"), [("text", Style::new())]);
+        assert_eq!(parse("\x1b[38;5mtext"), [("text", Style::new())]);
+        assert_eq!(parse("\x1b[48:2:255mtext"), [("text", Style::new())]);
+        assert_eq!(parse("\x1b[38:3::0:0mtext"), [("text", Style::new())]);
+        // unknown color mode
+        assert_eq!(parse("\x1b[38;9;1mtext"), [("text", BOLD)]);
+        assert_eq!(parse("\x1b[38:9:1mtext"), [("text", Style::new())]);
+        // modes which only exist in the `:` delimited format
+        assert_eq!(parse("\x1b[38;1;1mtext"), [("text", BOLD)]);
+        // out of range components
+        assert_eq!(parse("\x1b[38;5;256;1mtext"), [("text", BOLD)]);
+        assert_eq!(parse("\x1b[38:2::256:0:0mtext"), [("text", Style::new())]);
+    }
+
+    #[test]
+    fn malformed_extended_color_keeps_previous_color() {
+        let red = Style::new().with_foreground(Foreground::new(BasicColor::Red.into()));
+
+        assert_eq!(parse("\x1b[31m\x1b[38;5mtext"), [("text", red)]);
+    }
+
+    #[test]
+    fn extended_color() {
+        let rgb = Style::new().with_foreground(Foreground::new(RgbColor::new(255, 0, 0).into()));
+
+        assert_eq!(parse("\x1b[38;2;255;0;0mtext"), [("text", rgb)]);
+        assert_eq!(parse("\x1b[38:2::255:0:0mtext"), [("text", rgb)]);
+        assert_eq!(parse("\x1b[38:2:255:0:0mtext"), [("text", rgb)]);
+        assert_eq!(parse("\x1b[38:2:1:255:0:0mtext"), [("text", rgb)]);
+    }
+}
@@ -0,0 +1,553 @@
+use core::fmt::{self, Display, Formatter, Write};
+
+use crate::{color::ColorLayer, Background, Color, Foreground, Style};
+
+/// A single `CSI … m` control sequence, which is being written to `W`.
+///
+/// The introducer is only written once the first parameter is pushed, an empty sequence is
+/// therefore not written at all.
+pub(crate) struct Sequence<'a, W: ?Sized> {
+    writer: &'a mut W,
+    empty: bool,
+}
+
+impl<'a, W> Sequence<'a, W>
+where
+    W: Write + ?Sized,
+{
+    pub(crate) fn new(writer: &'a mut W) -> Self {
+        Self {
+            writer,
+            empty: true,
+        }
+    }
+
+    pub(crate) fn push(&mut self, parameter: impl Display) -> fmt::Result {
+        self.writer
+            .write_str(if self.empty { "\x1b[" } else { ";" })?;
+        self.empty = false;
+
+        write!(self.writer, "{parameter}")
+    }
+
+    /// Pushes `parameter` if `condition` is met.
+    pub(crate) fn push_if(&mut self, condition: bool, parameter: impl Display) -> fmt::Result {
+        if condition {
+            self.push(parameter)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn finish(self) -> fmt::Result {
+        if self.empty {
+            Ok(())
+        } else {
+            self.writer.write_str("m")
+        }
+    }
+}
+
+fn write_color_transition<W>(
+    from: Option<Color>,
+    to: Option<Color>,
+    layer: ColorLayer,
+    sequence: &mut Sequence<'_, W>,
+) -> fmt::Result
+where
+    W: Write + ?Sized,
+{
+    if from == to {
+        return Ok(());
+    }
+
+    match to {
+        Some(color) => color.write_sgr(layer, sequence),
+        None => sequence.push(layer.reset()),
+    }
+}
+
+fn write_transition<W>(from: Style, to: Style, writer: &mut W) -> fmt::Result
+where
+    W: Write + ?Sized,
+{
+    let mut sequence = Sequence::new(writer);
+
+    from.font.write_transition(to.font, &mut sequence)?;
+    from.decorations
+        .write_transition(to.decorations, &mut sequence)?;
+
+    write_color_transition(
+        from.foreground.map(Foreground::color),
+        to.foreground.map(Foreground::color),
+        ColorLayer::Foreground,
+        &mut sequence,
+    )?;
+    write_color_transition(
+        from.background.map(Background::color),
+        to.background.map(Background::color),
+        ColorLayer::Background,
+        &mut sequence,
+    )?;
+    #[cfg(feature = "underline-color")]
+    write_color_transition(
+        from.underline_color.map(crate::UnderlineColor::color),
+        to.underline_color.map(crate::UnderlineColor::color),
+        ColorLayer::Underline,
+        &mut sequence,
+    )?;
+
+    sequence.finish()
+}
+
+/// The escape sequence to change from one [`Style`] to another.
+///
+/// Created by [`Style::transition`] and [`Style::reset`].
+///
+/// Only the attributes that differ between both styles are changed, every attribute is reset on its
+/// own instead of using the global reset `ESC[0m`. Styles can therefore be nested, the attributes
+/// of the surrounding text are left untouched when the inner style is reset. If both styles are
+/// the same, nothing is written.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Transition {
+    from: Style,
+    to: Style,
+}
+
+impl Display for Transition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_transition(self.from, self.to, f)
+    }
+}
+
+/// A value, which is displayed using a [`Style`].
+///
+/// Created by [`Style::apply`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Styled<T> {
+    style: Style,
+    value: T,
+}
+
+impl<T> Styled<T> {
+    /// The style which is applied to the value.
+    #[must_use]
+    pub const fn style(&self) -> Style {
+        self.style
+    }
+
+    /// The value which is displayed.
+    #[must_use]
+    pub const fn value(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> Display for Styled<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_transition(Style::new(), self.style, f)?;
+        Display::fmt(&self.value, f)?;
+        write_transition(self.style, Style::new(), f)
+    }
+}
+
+/// Writes the escape sequence required to enable the style on unstyled text.
+///
+/// The sequence is minimal, only the attributes that are set are written, the default style
+/// therefore results in an empty string. Extended colors are written using the `:` delimiter as
+/// specified by [ISO 8613-6].
+///
+/// # Example
+///
+/// ```
+/// use antsi::{BasicColor, Font, FontWeight, Foreground, RgbColor, Style};
+///
+/// let style = Style::new()
+///     .with_font(Font::new().with_weight(FontWeight::Bold))
+///     .with_foreground(Foreground::new(BasicColor::Red.into()));
+/// assert_eq!(style.to_string(), "\x1b[1;31m");
+///
+/// let style = Style::new().with_foreground(Foreground::new(RgbColor::new(255, 128, 0).into()));
+/// assert_eq!(style.to_string(), "\x1b[38:2::255:128:0m");
+///
+/// assert_eq!(Style::new().to_string(), "");
+/// ```
+///
+/// [ISO 8613-6]: https://www.iso.org/standard/22943.html
+impl Display for Style {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_transition(Self::new(), *self, f)
+    }
+}
+
+impl Style {
+    /// Returns the escape sequence to change from this style to `to`.
+    ///
+    /// See [`Transition`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Font, Foreground, Style};
+    ///
+    /// let error = Style::new().with_foreground(Foreground::new(BasicColor::Red.into()));
+    /// let emphasis = error.with_font(Font::new().with_italic());
+    ///
+    /// assert_eq!(error.transition(emphasis).to_string(), "\x1b[3m");
+    /// assert_eq!(emphasis.transition(error).to_string(), "\x1b[23m");
+    /// assert_eq!(error.transition(error).to_string(), "");
+    /// ```
+    #[must_use]
+    pub const fn transition(self, to: Self) -> Transition {
+        Transition { from: self, to }
+    }
+
+    /// Returns the escape sequence to reset every attribute of this style.
+    ///
+    /// This is the same as the [`transition`] to the default style.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Font, FontWeight, Foreground, Style};
+    ///
+    /// let style = Style::new()
+    ///     .with_font(Font::new().with_weight(FontWeight::Bold))
+    ///     .with_foreground(Foreground::new(BasicColor::Black.bright().into()));
+    ///
+    /// assert_eq!(style.reset().to_string(), "\x1b[22;39m");
+    /// ```
+    ///
+    /// [`transition`]: Self::transition
+    #[must_use]
+    pub const fn reset(self) -> Transition {
+        self.transition(Self::new())
+    }
+
+    /// Applies the style to `value`.
+    ///
+    /// The returned value is displayed by enabling the style, displaying `value` and resetting the
+    /// style afterwards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, Color, Font, FontWeight, Foreground, Style};
+    ///
+    /// const PANIC: Style =
+    ///     Style::new().with_foreground(Foreground::new(Color::Basic(BasicColor::Red)));
+    /// const BOLD: Style = Style::new().with_font(Font::new().with_weight(FontWeight::Bold));
+    ///
+    /// let message = format!(
+    ///     "{}",
+    ///     PANIC.apply(format_args!(
+    ///         "mainframe breach has been {}",
+    ///         BOLD.apply("detected")
+    ///     ))
+    /// );
+    ///
+    /// assert_eq!(
+    ///     message,
+    ///     "\x1b[31mmainframe breach has been \x1b[1mdetected\x1b[22m\x1b[39m"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn apply<T>(self, value: T) -> Styled<T> {
+        Styled { style: self, value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString, vec, vec::Vec};
+
+    #[cfg(feature = "script")]
+    use crate::FontScript;
+    #[cfg(feature = "rgba")]
+    use crate::RgbaColor;
+    #[cfg(feature = "underline-color")]
+    use crate::UnderlineColor;
+    use crate::{
+        font::AlternativeFontFamily, Background, BasicColor, Blinking, CmyColor, CmykColor, Color,
+        Font, FontFamily, FontWeight, Foreground, Frame, IndexedColor, Parser, RgbColor, Style,
+        TransparentColor, Underline,
+    };
+
+    /// Asserts that the style is parsed again from its own escape sequences, and that it is
+    /// completely reset afterwards.
+    #[track_caller]
+    fn assert_round_trip(style: Style) {
+        let output = style.apply("text").to_string();
+        let mut parser = Parser::new(&output);
+
+        assert_eq!(parser.next(), Some(style.apply("text")), "{output:?}");
+        assert_eq!(parser.next(), None, "{output:?}");
+        assert_eq!(parser.style(), Style::new(), "{output:?}");
+    }
+
+    /// Asserts that the transition between both styles is parsed as `to`.
+    #[track_caller]
+    fn assert_transition(from: Style, to: Style) {
+        let output = format!("{from}{}text", from.transition(to));
+
+        assert_eq!(
+            Parser::new(&output).last(),
+            Some(to.apply("text")),
+            "{output:?}"
+        );
+    }
+
+    const fn font(font: Font) -> Style {
+        Style::new().with_font(font)
+    }
+
+    const fn frame(frame: Frame) -> Style {
+        let mut style = Style::new();
+        style.decorations.frame = Some(frame);
+        style
+    }
+
+    fn colors() -> Vec<Color> {
+        let mut colors = vec![
+            TransparentColor.into(),
+            IndexedColor::new(0).into(),
+            IndexedColor::new(255).into(),
+            RgbColor::new(255, 128, 0).into(),
+            CmyColor::new(0, 128, 255).into(),
+            CmykColor::new(0, 64, 128, 255).into(),
+            #[cfg(feature = "rgba")]
+            RgbaColor::new(255, 128, 0, 64).into(),
+        ];
+
+        for basic in [
+            BasicColor::Black,
+            BasicColor::Red,
+            BasicColor::Green,
+            BasicColor::Yellow,
+            BasicColor::Blue,
+            BasicColor::Magenta,
+            BasicColor::Cyan,
+            BasicColor::White,
+        ] {
+            colors.push(basic.into());
+            colors.push(basic.bright().into());
+        }
+
+        colors
+    }
+
+    /// Every attribute on its own.
+    fn styles() -> Vec<Style> {
+        let mut styles = vec![
+            font(Font::new().with_weight(FontWeight::Bold)),
+            font(Font::new().with_weight(FontWeight::Faint)),
+            font(Font::new().with_italic()),
+            font(Font::new().with_underline(Underline::Single)),
+            font(Font::new().with_underline(Underline::Double)),
+            font(Font::new().with_blinking(Blinking::Slow)),
+            font(Font::new().with_blinking(Blinking::Fast)),
+            font(Font::new().with_inverse()),
+            font(Font::new().with_hidden()),
+            font(Font::new().with_strikethrough()),
+            font(Font::new().with_overline()),
+            font(Font::new().with_family(FontFamily::Fraktur)),
+            font(Font::new().with_family(FontFamily::Alternative(
+                AlternativeFontFamily::new(0).expect("should be a valid font family"),
+            ))),
+            frame(Frame::Framed),
+            frame(Frame::Encircled),
+        ];
+
+        for color in colors() {
+            styles.push(Style::new().with_foreground(Foreground::new(color)));
+            styles.push(Style::new().with_background(Background::new(color)));
+        }
+
+        styles
+    }
+
+    #[test]
+    fn weight() {
+        assert_round_trip(font(Font::new().with_weight(FontWeight::Bold)));
+        assert_round_trip(font(Font::new().with_weight(FontWeight::Faint)));
+    }
+
+    #[test]
+    fn italic() {
+        assert_round_trip(font(Font::new().with_italic()));
+    }
+
+    #[test]
+    fn underline() {
+        assert_round_trip(font(Font::new().with_underline(Underline::Single)));
+        assert_round_trip(font(Font::new().with_underline(Underline::Double)));
+    }
+
+    #[test]
+    #[cfg(feature = "underline-variants")]
+    fn underline_variants() {
+        assert_round_trip(font(Font::new().with_underline(Underline::Curly)));
+        assert_round_trip(font(Font::new().with_underline(Underline::Dotted)));
+        assert_round_trip(font(Font::new().with_underline(Underline::Dashed)));
+    }
+
+    #[test]
+    fn blinking() {
+        assert_round_trip(font(Font::new().with_blinking(Blinking::Slow)));
+        assert_round_trip(font(Font::new().with_blinking(Blinking::Fast)));
+    }
+
+    #[test]
+    fn inverse() {
+        assert_round_trip(font(Font::new().with_inverse()));
+    }
+
+    #[test]
+    fn hidden() {
+        assert_round_trip(font(Font::new().with_hidden()));
+    }
+
+    #[test]
+    fn strikethrough() {
+        assert_round_trip(font(Font::new().with_strikethrough()));
+    }
+
+    #[test]
+    fn overline() {
+        assert_round_trip(font(Font::new().with_overline()));
+    }
+
+    #[test]
+    #[cfg(feature = "overstrike")]
+    fn overstrike() {
+        assert_round_trip(font(Font::new().with_overstrike()));
+        assert_round_trip(font(Font::new().with_overstrike().with_hidden()));
+    }
+
+    #[test]
+    #[cfg(feature = "script")]
+    fn script() {
+        assert_round_trip(font(Font::new().with_script(FontScript::Super)));
+        assert_round_trip(font(Font::new().with_script(FontScript::Sub)));
+    }
+
+    #[test]
+    fn family() {
+        assert_round_trip(font(Font::new().with_family(FontFamily::Fraktur)));
+        assert_round_trip(font(
+            Font::new().with_family(FontFamily::Fraktur).with_italic(),
+        ));
+
+        for variant in 0..9 {
+            let family =
+                AlternativeFontFamily::new(variant).expect("should be a valid font family");
+            assert_round_trip(font(
+                Font::new().with_family(FontFamily::Alternative(family)),
+            ));
+        }
+    }
+
+    #[test]
+    fn frame_decoration() {
+        assert_round_trip(frame(Frame::Framed));
+        assert_round_trip(frame(Frame::Encircled));
+    }
+
+    #[test]
+    fn foreground() {
+        for color in colors() {
+            assert_round_trip(Style::new().with_foreground(Foreground::new(color)));
+        }
+    }
+
+    #[test]
+    fn background() {
+        for color in colors() {
+            assert_round_trip(Style::new().with_background(Background::new(color)));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "underline-color")]
+    fn underline_color() {
+        for color in colors() {
+            // There are no dedicated escape codes for basic and bright underline colors
+            let expected = match color {
+                Color::Basic(color) => IndexedColor::from(color).into(),
+                Color::Bright(color) => IndexedColor::from(color).into(),
+                color => color,
+            };
+
+            let output = Style::new()
+                .with_underline_color(UnderlineColor::new(color))
+                .apply("text")
+                .to_string();
+
+            assert_eq!(
+                Parser::new(&output).next(),
+                Some(
+                    Style::new()
+                        .with_underline_color(UnderlineColor::new(expected))
+                        .apply("text")
+                ),
+                "{output:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn combined() {
+        let font = Font::new()
+            .with_weight(FontWeight::Bold)
+            .with_italic()
+            .with_underline(Underline::Double)
+            .with_blinking(Blinking::Slow)
+            .with_inverse()
+            .with_hidden()
+            .with_strikethrough()
+            .with_overline()
+            .with_family(FontFamily::Fraktur);
+
+        let mut style = frame(Frame::Encircled)
+            .with_font(font)
+            .with_foreground(Foreground::new(RgbColor::new(255, 128, 0).into()))
+            .with_background(Background::new(BasicColor::Blue.bright().into()));
+
+        assert_round_trip(style);
+
+        style.font.family = AlternativeFontFamily::new(3).map(FontFamily::Alternative);
+        assert_round_trip(style);
+    }
+
+    #[test]
+    fn transitions() {
+        let styles = styles();
+
+        for &from in &styles {
+            for &to in &styles {
+                assert_transition(from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn transitions_of_shared_resets() {
+        let italic = font(Font::new().with_italic());
+        let fraktur = font(Font::new().with_family(FontFamily::Fraktur));
+        let both = font(Font::new().with_italic().with_family(FontFamily::Fraktur));
+
+        assert_transition(both, italic);
+        assert_transition(both, fraktur);
+        assert_transition(italic, both);
+        assert_transition(fraktur, both);
+
+        let bold = font(Font::new().with_weight(FontWeight::Bold));
+        let faint = font(Font::new().with_weight(FontWeight::Faint));
+
+        assert_transition(bold, faint);
+        assert_transition(faint, bold);
+    }
+}
@@ -0,0 +1,464 @@
+#[cfg(feature = "underline-color")]
+use crate::UnderlineColor;
+use crate::{Background, BasicColor, Color, Foreground, IndexedColor, Style};
+
+/// The colors supported by a terminal.
+///
+/// The tiers are ordered, every tier supports the colors of the tiers below it.
+///
+/// ## Detection
+///
+/// There is no reliable way to query the capabilities of a terminal, instead there are a couple of
+/// environment variables that are used as indicators, which [`ColorSupport::detect`] follows:
+///
+/// * [`NO_COLOR`], if set to a non-empty value, disables colors
+/// * `TERM` names the terminal type, `dumb` does not support any escape sequences, names ending in
+///   `256color` (like `xterm-256color`) support [`IndexedColor`], names ending in `direct` or
+///   `truecolor` support [`RgbColor`]
+/// * `COLORTERM` is set to `truecolor` or `24bit` by terminals, which support [`RgbColor`]
+///
+/// For a more complete detection (e.g. taking the operating system or CI providers into account),
+/// use a crate like [`supports-color`](https://lib.rs/crates/supports-color) and convert the result
+/// into a [`ColorSupport`].
+///
+/// [`NO_COLOR`]: https://no-color.org/
+/// [`RgbColor`]: crate::RgbColor
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ColorSupport {
+    /// Colors are not supported or not wanted
+    None,
+    /// The 8 [`BasicColor`]s and their [`BrightColor`] variants
+    ///
+    /// [`BrightColor`]: crate::BrightColor
+    Basic,
+    /// The 256 [`IndexedColor`]s
+    Indexed,
+    /// 24-bit [`RgbColor`]s
+    ///
+    /// [`RgbColor`]: crate::RgbColor
+    Truecolor,
+}
+
+impl ColorSupport {
+    /// Detects the color support from the environment.
+    ///
+    /// `antsi` is `no_std` and therefore does not access the environment itself, `var` is called
+    /// with the name of an environment variable and must return its value, if set.
+    ///
+    /// See [`ColorSupport`] for the variables used.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::ColorSupport;
+    ///
+    /// let support = |env: &[(&str, &str)]| {
+    ///     ColorSupport::detect(|name| {
+    ///         env.iter()
+    ///             .find(|(key, _)| *key == name)
+    ///             .map(|(_, value)| *value)
+    ///     })
+    /// };
+    ///
+    /// assert_eq!(support(&[("TERM", "xterm")]), ColorSupport::Basic);
+    /// assert_eq!(
+    ///     support(&[("TERM", "xterm-256color")]),
+    ///     ColorSupport::Indexed
+    /// );
+    /// assert_eq!(
+    ///     support(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]),
+    ///     ColorSupport::Truecolor
+    /// );
+    /// assert_eq!(
+    ///     support(&[("TERM", "xterm-256color"), ("NO_COLOR", "1")]),
+    ///     ColorSupport::None
+    /// );
+    /// assert_eq!(support(&[("TERM", "dumb")]), ColorSupport::None);
+    /// assert_eq!(support(&[]), ColorSupport::None);
+    ///
+    /// // Using the environment of the current process
+    /// let support = ColorSupport::detect(|name| std::env::var(name).ok());
+    /// # let _ = support;
+    /// ```
+    pub fn detect<T>(mut var: impl FnMut(&str) -> Option<T>) -> Self
+    where
+        T: AsRef<str>,
+    {
+        if var("NO_COLOR").map_or(false, |value| !value.as_ref().is_empty()) {
+            return Self::None;
+        }
+
+        let term = var("TERM");
+        let term = term.as_ref().map_or("", AsRef::as_ref);
+        if term == "dumb" {
+            return Self::None;
+        }
+
+        let color_term = var("COLORTERM");
+        let color_term = color_term.as_ref().map_or("", AsRef::as_ref);
+
+        if matches!(color_term, "truecolor" | "24bit")
+            || term.ends_with("direct")
+            || term.ends_with("truecolor")
+        {
+            Self::Truecolor
+        } else if term.ends_with("256color") {
+            Self::Indexed
+        } else if term.is_empty() && color_term.is_empty() {
+            Self::None
+        } else {
+            Self::Basic
+        }
+    }
+}
+
+/// Default colors of the xterm palette, used as reference for the [`BasicColor`]s and their bright
+/// variants, as the actual colors depend on the theme of the terminal.
+const PALETTE: [[u8; 3]; 16] = [
+    [0, 0, 0],
+    [205, 0, 0],
+    [0, 205, 0],
+    [205, 205, 0],
+    [0, 0, 238],
+    [205, 0, 205],
+    [0, 205, 205],
+    [229, 229, 229],
+    [127, 127, 127],
+    [255, 0, 0],
+    [0, 255, 0],
+    [255, 255, 0],
+    [92, 92, 255],
+    [255, 0, 255],
+    [0, 255, 255],
+    [255, 255, 255],
+];
+
+/// Levels of every component of the 6x6x6 color cube of the [`IndexedColor`]s `16` - `231`.
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn distance([red_a, green_a, blue_a]: [u8; 3], [red_b, green_b, blue_b]: [u8; 3]) -> u32 {
+    let component = |a: u8, b: u8| u32::from(a.abs_diff(b)).pow(2);
+
+    component(red_a, red_b) + component(green_a, green_b) + component(blue_a, blue_b)
+}
+
+/// Index of the cube level closest to `component`.
+const fn cube_level(component: u8) -> u8 {
+    match component {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (component - 35).div_euclid(40),
+    }
+}
+
+/// The approximate RGB value of an [`IndexedColor`].
+fn indexed_rgb(index: u8) -> [u8; 3] {
+    match index {
+        0..=15 => PALETTE[usize::from(index)],
+        16..=231 => {
+            let index = index - 16;
+
+            [
+                CUBE_LEVELS[usize::from(index.div_euclid(36))],
+                CUBE_LEVELS[usize::from(index.div_euclid(6).rem_euclid(6))],
+                CUBE_LEVELS[usize::from(index.rem_euclid(6))],
+            ]
+        }
+        _ => {
+            let gray = 8 + (index - 232) * 10;
+
+            [gray, gray, gray]
+        }
+    }
+}
+
+/// The [`IndexedColor`] closest to `rgb`, only the color cube and the grayscale are considered, as
+/// the first 16 colors depend on the theme of the terminal.
+fn nearest_indexed(rgb: [u8; 3]) -> IndexedColor {
+    let [red, green, blue] = rgb.map(cube_level);
+    let cube = 16 + 36 * red + 6 * green + blue;
+
+    let average = rgb
+        .iter()
+        .map(|&component| u32::from(component))
+        .sum::<u32>()
+        .div_euclid(3);
+    let gray = 232
+        + u8::try_from(average.saturating_sub(3).div_euclid(10)).map_or(23, |gray| gray.min(23));
+
+    if distance(rgb, indexed_rgb(gray)) < distance(rgb, indexed_rgb(cube)) {
+        IndexedColor::new(gray)
+    } else {
+        IndexedColor::new(cube)
+    }
+}
+
+/// The [`BasicColor`] or bright variant closest to `rgb`, using the default xterm palette.
+fn nearest_basic(rgb: [u8; 3]) -> Color {
+    let mut nearest = 0;
+    for (index, color) in (0..).zip(PALETTE) {
+        if distance(rgb, color) < distance(rgb, PALETTE[usize::from(nearest)]) {
+            nearest = index;
+        }
+    }
+
+    basic_from_index(nearest)
+}
+
+/// Converts one of the first 16 [`IndexedColor`]s into the equivalent [`BasicColor`].
+const fn basic_from_index(index: u8) -> Color {
+    let basic = match index.rem_euclid(8) {
+        0 => BasicColor::Black,
+        1 => BasicColor::Red,
+        2 => BasicColor::Green,
+        3 => BasicColor::Yellow,
+        4 => BasicColor::Blue,
+        5 => BasicColor::Magenta,
+        6 => BasicColor::Cyan,
+        _ => BasicColor::White,
+    };
+
+    if index < 8 {
+        Color::Basic(basic)
+    } else {
+        Color::Bright(basic.bright())
+    }
+}
+
+impl Color {
+    /// Converts the color into the closest color supported by `support`.
+    ///
+    /// Returns `None` if colors are not supported at all, in which case the default color of the
+    /// terminal should be used.
+    ///
+    /// [`RgbColor`]s are converted into the closest color of the [`IndexedColor`] color cube or
+    /// grayscale and [`IndexedColor`]s are converted into the closest [`BasicColor`] or its bright
+    /// variant, using the default colors of xterm as reference. Colors that are rarely supported,
+    /// like [`CmyColor`] or [`CmykColor`], are converted into [`RgbColor`]s first, while
+    /// [`TransparentColor`] is always replaced with the default color.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{BasicColor, CmykColor, Color, ColorSupport, IndexedColor, RgbColor};
+    ///
+    /// let orange = Color::Rgb(RgbColor::new(255, 135, 0));
+    ///
+    /// assert_eq!(orange.downsample(ColorSupport::Truecolor), Some(orange));
+    /// assert_eq!(
+    ///     orange.downsample(ColorSupport::Indexed),
+    ///     Some(Color::Indexed(IndexedColor::new(208)))
+    /// );
+    /// assert_eq!(
+    ///     orange.downsample(ColorSupport::Basic),
+    ///     Some(Color::Basic(BasicColor::Yellow))
+    /// );
+    /// assert_eq!(orange.downsample(ColorSupport::None), None);
+    ///
+    /// assert_eq!(
+    ///     Color::Cmyk(CmykColor::new(0, 255, 255, 0)).downsample(ColorSupport::Truecolor),
+    ///     Some(Color::Rgb(RgbColor::new(255, 0, 0)))
+    /// );
+    /// ```
+    ///
+    /// [`CmyColor`]: crate::CmyColor
+    /// [`CmykColor`]: crate::CmykColor
+    /// [`RgbColor`]: crate::RgbColor
+    /// [`TransparentColor`]: crate::TransparentColor
+    #[must_use]
+    pub fn downsample(self, support: ColorSupport) -> Option<Self> {
+        let rgb = match self {
+            Self::Basic(_) | Self::Bright(_) => {
+                return (support > ColorSupport::None).then_some(self);
+            }
+            Self::Indexed(color) => {
+                return match support {
+                    ColorSupport::None => None,
+                    ColorSupport::Basic if color.index() < 16 => {
+                        Some(basic_from_index(color.index()))
+                    }
+                    ColorSupport::Basic => Some(nearest_basic(indexed_rgb(color.index()))),
+                    ColorSupport::Indexed | ColorSupport::Truecolor => Some(self),
+                };
+            }
+            Self::Transparent(_) => return None,
+            Self::Rgb(color) => color,
+            #[cfg(feature = "rgba")]
+            Self::Rgba(color) => color.to_rgb(),
+            Self::Cmy(color) => color.to_rgb(),
+            Self::Cmyk(color) => color.to_rgb(),
+        };
+
+        match support {
+            ColorSupport::None => None,
+            ColorSupport::Basic => Some(nearest_basic(rgb.components())),
+            ColorSupport::Indexed => Some(Self::Indexed(nearest_indexed(rgb.components()))),
+            ColorSupport::Truecolor => Some(Self::Rgb(rgb)),
+        }
+    }
+}
+
+impl Style {
+    /// Converts every color of the style into the closest color supported by `support`.
+    ///
+    /// Colors, which are not supported at all, are removed. Use the result to render the style,
+    /// this way styled output degrades gracefully on terminals with limited support, instead of
+    /// printing escape sequences the terminal does not understand. See [`Color::downsample`] for
+    /// details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use antsi::{ColorSupport, Font, Foreground, RgbColor, Style};
+    ///
+    /// let style = Style::new()
+    ///     .with_font(Font::new().with_italic())
+    ///     .with_foreground(Foreground::new(RgbColor::new(255, 135, 0).into()));
+    ///
+    /// assert_eq!(
+    ///     style.downsample(ColorSupport::Indexed).to_string(),
+    ///     "\x1b[3;38:5:208m"
+    /// );
+    /// assert_eq!(
+    ///     style.downsample(ColorSupport::Basic).to_string(),
+    ///     "\x1b[3;33m"
+    /// );
+    /// assert_eq!(style.downsample(ColorSupport::None).to_string(), "\x1b[3m");
+    /// ```
+    #[must_use]
+    pub fn downsample(mut self, support: ColorSupport) -> Self {
+        self.foreground = self
+            .foreground
+            .and_then(|color| color.color().downsample(support))
+            .map(Foreground::new);
+        self.background = self
+            .background
+            .and_then(|color| color.color().downsample(support))
+            .map(Background::new);
+
+        #[cfg(feature = "underline-color")]
+        {
+            self.underline_color = self
+                .underline_color
+                .and_then(|color| color.color().downsample(support))
+                .map(UnderlineColor::new);
+        }
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{string::String, vec::Vec};
+
+    use super::*;
+
+    /// Detects the color support from exactly the given environment variables.
+    fn detect(env: &[(&str, &str)]) -> ColorSupport {
+        ColorSupport::detect(|name| {
+            env.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| *value)
+        })
+    }
+
+    #[test]
+    fn empty_environment() {
+        assert_eq!(detect(&[]), ColorSupport::None);
+        assert_eq!(
+            detect(&[("TERM", ""), ("COLORTERM", "")]),
+            ColorSupport::None
+        );
+    }
+
+    #[test]
+    fn no_color() {
+        assert_eq!(detect(&[("NO_COLOR", "1")]), ColorSupport::None);
+        assert_eq!(
+            detect(&[
+                ("NO_COLOR", "1"),
+                ("TERM", "xterm-256color"),
+                ("COLORTERM", "truecolor")
+            ]),
+            ColorSupport::None
+        );
+    }
+
+    #[test]
+    fn empty_no_color_is_ignored() {
+        assert_eq!(
+            detect(&[("NO_COLOR", ""), ("TERM", "xterm")]),
+            ColorSupport::Basic
+        );
+    }
+
+    #[test]
+    fn dumb_terminal() {
+        assert_eq!(detect(&[("TERM", "dumb")]), ColorSupport::None);
+        assert_eq!(
+            detect(&[("TERM", "dumb"), ("COLORTERM", "truecolor")]),
+            ColorSupport::None
+        );
+    }
+
+    #[test]
+    fn basic() {
+        assert_eq!(detect(&[("TERM", "xterm")]), ColorSupport::Basic);
+        assert_eq!(detect(&[("TERM", "vt100")]), ColorSupport::Basic);
+        assert_eq!(detect(&[("TERM", "xterm-color")]), ColorSupport::Basic);
+        assert_eq!(detect(&[("COLORTERM", "1")]), ColorSupport::Basic);
+    }
+
+    #[test]
+    fn indexed() {
+        assert_eq!(detect(&[("TERM", "xterm-256color")]), ColorSupport::Indexed);
+        assert_eq!(
+            detect(&[("TERM", "screen-256color")]),
+            ColorSupport::Indexed
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("COLORTERM", "yes")]),
+            ColorSupport::Indexed
+        );
+    }
+
+    #[test]
+    fn truecolor() {
+        assert_eq!(detect(&[("TERM", "xterm-direct")]), ColorSupport::Truecolor);
+        assert_eq!(
+            detect(&[("TERM", "konsole-truecolor")]),
+            ColorSupport::Truecolor
+        );
+        assert_eq!(
+            detect(&[("COLORTERM", "truecolor")]),
+            ColorSupport::Truecolor
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm"), ("COLORTERM", "24bit")]),
+            ColorSupport::Truecolor
+        );
+    }
+
+    #[test]
+    fn owned_values() {
+        let support = ColorSupport::detect(|name| match name {
+            "TERM" => Some(String::from("xterm-256color")),
+            _ => None::<String>,
+        });
+
+        assert_eq!(support, ColorSupport::Indexed);
+    }
+
+    #[test]
+    fn queries_only_known_variables() {
+        let mut queried = Vec::new();
+        let support = ColorSupport::detect(|name| {
+            queried.push(String::from(name));
+            None::<&str>
+        });
+
+        assert_eq!(support, ColorSupport::None);
+        assert_eq!(queried, ["NO_COLOR", "TERM", "COLORTERM"]);
+    }
+}
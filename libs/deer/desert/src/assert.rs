@@ -11,6 +11,25 @@ use similar_asserts::{assert_eq, assert_serde_eq};
 
 use crate::{deserializer::Deserializer, error::ErrorVec, token::Token};
 
+/// Assert that a report, which may have been created by any deserializer, consists of the expected
+/// errors.
+///
+/// # Panics
+///
+/// if error could not be serialized
+#[allow(clippy::std_instead_of_alloc)] // Reason: `assert_serde_eq!` uses `std`
+pub fn assert_error(error: &ErrorVec, report: Report<DeserializeError>) {
+    let received = report.export();
+    let received = to_value(received).expect("error should serialize");
+    let errors = ErrorVec::from_value(&received).expect("well-formed error object");
+
+    #[cfg(not(feature = "pretty"))]
+    assert_eq!(errors, *error);
+
+    #[cfg(feature = "pretty")]
+    assert_serde_eq!(errors, *error);
+}
+
 /// # Panics
 ///
 /// if there are any remaining tokens in the stream after deserialization
@@ -58,7 +77,6 @@ where
 /// # Panics
 ///
 /// if error could not be serialized
-#[allow(clippy::std_instead_of_alloc)] // Reason: `assert_serde_eq!` uses `std`
 pub fn assert_tokens_with_context_error<'de, T>(
     error: &ErrorVec,
     tokens: &'de [Token],
@@ -69,15 +87,7 @@ pub fn assert_tokens_with_context_error<'de, T>(
     let mut de = Deserializer::new(tokens, context);
     let received = T::deserialize(&mut de).expect_err("value of type T should fail serialization");
 
-    let received = received.export();
-    let received = to_value(received).expect("error should serialize");
-    let errors = ErrorVec::from_value(&received).expect("well-formed error object");
-
-    #[cfg(not(feature = "pretty"))]
-    assert_eq!(errors, *error);
-
-    #[cfg(feature = "pretty")]
-    assert_serde_eq!(errors, *error);
+    assert_error(error, received);
 }
 
 pub fn assert_tokens_error<'de, T>(error: &ErrorVec, tokens: &'de [Token])
@@ -87,6 +97,34 @@ where
     assert_tokens_with_context_error::<T>(error, tokens, &Context::new());
 }
 
+/// Deserialize with error recovery enabled, asserting both the value (including placeholders)
+/// and the errors that have been recovered from.
+///
+/// # Panics
+///
+/// * if the value could not be deserialized, even with recovery enabled
+/// * if there are any remaining tokens in the stream after deserialization
+/// * if the recovered errors could not be serialized
+pub fn assert_tokens_recover<'de, T>(expected: &T, error: &ErrorVec, tokens: &'de [Token])
+where
+    T: Deserialize<'de> + PartialEq + Debug,
+{
+    let mut context = Context::new();
+    context.enable_recovery();
+
+    let mut de = Deserializer::new(tokens, &context);
+    let received = T::deserialize(&mut de).expect("should deserialize with recovery enabled");
+
+    assert_eq!(de.remaining(), 0, "{} remaining tokens", de.remaining());
+    assert_eq!(received, *expected);
+
+    let received = context
+        .finish(Ok(()))
+        .expect_err("should have recovered from at least one error");
+
+    assert_error(error, received);
+}
+
 pub fn assert_tokens_any_error<'de, T>(tokens: &'de [Token]) -> Report<DeserializeError>
 where
     T: Deserialize<'de> + Debug,
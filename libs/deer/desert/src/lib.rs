@@ -12,7 +12,8 @@ pub(crate) mod tape;
 mod token;
 
 pub use assert::{
-    assert_tokens, assert_tokens_any_error, assert_tokens_deserialize, assert_tokens_error,
-    assert_tokens_with_assertion, assert_tokens_with_context, assert_tokens_with_context_error,
+    assert_error, assert_tokens, assert_tokens_any_error, assert_tokens_deserialize,
+    assert_tokens_error, assert_tokens_recover, assert_tokens_with_assertion,
+    assert_tokens_with_context, assert_tokens_with_context_error,
 };
 pub use token::Token;
@@ -1,11 +1,80 @@
 #[cfg_attr(feature = "std", allow(unused_imports))]
-use alloc::boxed::Box;
-use alloc::collections::BTreeMap;
-use core::any::{Any, TypeId};
+use alloc::{boxed::Box, vec::Vec};
+use alloc::{collections::BTreeMap, rc::Rc};
+use core::{
+    any::{Any, TypeId},
+    cell::RefCell,
+};
+
+use error_stack::{Report, Result};
+
+use crate::error::{DeserializeError, Location};
+
+#[derive(Debug, Default)]
+struct RecoveryState {
+    path: Vec<Location>,
+    errors: Option<Report<DeserializeError>>,
+}
+
+/// Handle to the recovery state of a [`Context`].
+///
+/// If recovery has not been enabled, every operation is a no-op.
+#[derive(Debug, Clone)]
+pub(crate) struct Recovery(Option<Rc<RefCell<RecoveryState>>>);
+
+impl Recovery {
+    /// Run `scope`, while `location` is the innermost location of every error recovered from.
+    ///
+    /// Errors that are returned (instead of being recovered from) are unaffected, they still need
+    /// to have the location attached.
+    pub(crate) fn scope<T>(&self, location: Location, scope: impl FnOnce() -> T) -> T {
+        let Some(state) = &self.0 else {
+            return scope();
+        };
+
+        state.borrow_mut().path.push(location);
+        let value = scope();
+        state.borrow_mut().path.pop();
+
+        value
+    }
+
+    /// Recover from an error by recording it and replacing the value with `placeholder`.
+    pub(crate) fn recover<T>(
+        &self,
+        result: Result<T, DeserializeError>,
+        placeholder: impl FnOnce() -> T,
+    ) -> Result<T, DeserializeError> {
+        let Some(state) = &self.0 else {
+            return result;
+        };
+
+        let mut error = match result {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+
+        let mut state = state.borrow_mut();
+
+        // locations are attached from the innermost to the outermost, the same way they would have
+        // been attached if the error would have bubbled up
+        for location in state.path.iter().rev() {
+            error = error.attach(location.clone());
+        }
+
+        match &mut state.errors {
+            Some(errors) => errors.extend_one(error),
+            errors => *errors = Some(error),
+        }
+
+        Ok(placeholder())
+    }
+}
 
 #[derive(Debug)]
 pub struct Context {
     inner: BTreeMap<TypeId, Box<dyn Any>>,
+    recovery: Option<Rc<RefCell<RecoveryState>>>,
 }
 
 impl Context {
@@ -13,6 +82,7 @@ impl Context {
     pub fn new() -> Self {
         Self {
             inner: BTreeMap::new(),
+            recovery: None,
         }
     }
 
@@ -51,6 +121,63 @@ impl Context {
             .get(&type_id)
             .and_then(|value| value.downcast_ref())
     }
+
+    /// Enable error recovery.
+    ///
+    /// By default, a value that fails to deserialize will propagate its error to the parent, which
+    /// in turn will (after visiting all remaining values) fail as well. If recovery is enabled,
+    /// primitives (like numbers, booleans, characters and strings) that fail to deserialize will
+    /// instead record the error, including the [`Location`] of the value, and continue with their
+    /// default value as placeholder, allowing the surrounding value to be deserialized.
+    ///
+    /// All errors that have been recovered from are returned by [`Self::finish`].
+    pub fn enable_recovery(&mut self) -> &mut Self {
+        self.recovery = Some(Rc::default());
+
+        self
+    }
+
+    /// Disable error recovery, discarding any error that has been recovered from.
+    pub fn disable_recovery(&mut self) -> &mut Self {
+        self.recovery = None;
+
+        self
+    }
+
+    #[must_use]
+    pub const fn is_recovering(&self) -> bool {
+        self.recovery.is_some()
+    }
+
+    pub(crate) fn recovery(&self) -> Recovery {
+        Recovery(self.recovery.clone())
+    }
+
+    /// Combine the result of a deserialization with every error that has been recovered from.
+    ///
+    /// Errors that have been recovered from are cleared, meaning that the context can be reused
+    /// for subsequent deserializations.
+    ///
+    /// # Errors
+    ///
+    /// The deserialization failed or has recovered from one or more errors.
+    pub fn finish<T>(&self, result: Result<T, DeserializeError>) -> Result<T, DeserializeError> {
+        let Some(errors) = self
+            .recovery
+            .as_ref()
+            .and_then(|state| state.borrow_mut().errors.take())
+        else {
+            return result;
+        };
+
+        match result {
+            Ok(_) => Err(errors),
+            Err(mut error) => {
+                error.extend_one(errors);
+                Err(error)
+            }
+        }
+    }
 }
 
 impl Default for Context {
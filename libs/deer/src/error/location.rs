@@ -3,7 +3,7 @@ use alloc::{string::String, vec::Vec};
 
 use super::ErrorProperty;
 
-#[derive(Debug, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize)]
 #[serde(tag = "type", content = "value")]
 #[serde(rename_all = "lowercase")]
 pub enum Location {
@@ -141,3 +141,22 @@ impl<const N: usize> Serialize for Properties<N> {
         map.end()
     }
 }
+
+/// Schema of a single variant of an externally tagged enum, `{"<name>": <reference>}`.
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct VariantSchema {
+    properties: Properties<1>,
+    required: [&'static str; 1],
+    additional_properties: bool,
+}
+
+impl VariantSchema {
+    pub(crate) const fn new(name: &'static str, reference: Reference) -> Self {
+        Self {
+            properties: Properties([(name, reference)]),
+            required: [name],
+            additional_properties: false,
+        }
+    }
+}
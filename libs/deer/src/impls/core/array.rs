@@ -25,6 +25,7 @@ impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<'de
     {
         let mut array = array.into_bound(N).change_context(VisitorError)?;
         let size_hint = array.size_hint();
+        let recovery = array.context().recovery();
 
         let mut result: Result<(), ArrayAccessError> = Ok(());
 
@@ -40,7 +41,7 @@ impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ArrayVisitor<'de
         let mut failed = false;
 
         loop {
-            let value = array.next::<T>();
+            let value = recovery.scope(Location::Array(index), || array.next::<T>());
 
             match value {
                 None => break,
@@ -29,8 +29,11 @@ impl<'de> Deserialize<'de> for bool {
     type Reflection = Self;
 
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
-        deserializer
+        let recovery = deserializer.context().recovery();
+        let value = deserializer
             .deserialize_bool(BoolVisitor)
-            .change_context(DeserializeError)
+            .change_context(DeserializeError);
+
+        recovery.recover(value, Self::default)
     }
 }
@@ -120,7 +120,10 @@ macro_rules! impl_num {
                     $($($method!($val :: $visit);)*)*
                 }
 
-                deserializer.$deserialize(PrimitiveVisitor).change_context(DeserializeError)
+                let recovery = deserializer.context().recovery();
+                let value = deserializer.$deserialize(PrimitiveVisitor).change_context(DeserializeError);
+
+                recovery.recover(value, Self::default)
             }
         }
     };
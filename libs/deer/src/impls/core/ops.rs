@@ -11,10 +11,9 @@ use crate::{
         ObjectAccessError, ResultExtPrivate, Variant, VisitorError,
     },
     ext::TupleExt,
-    helpers::Properties,
+    helpers::{Properties, VariantSchema},
     identifier,
     impls::UnitVariantVisitor,
-    schema::Reference,
     value::NoneDeserializer,
     ArrayAccess, Deserialize, Deserializer, Document, EnumVisitor, FieldVisitor, ObjectAccess,
     Reflection, Schema, StructVisitor,
@@ -49,17 +48,25 @@ where
     where
         D: Deserializer<'de>,
     {
+        let recovery = deserializer.context().recovery();
+
         match discriminant {
             BoundDiscriminant::Unbounded => deserializer
                 .deserialize_optional(UnitVariantVisitor)
                 .map(|()| Bound::Unbounded)
                 .attach(Location::Variant("Unbounded"))
                 .change_context(VisitorError),
-            BoundDiscriminant::Included => T::deserialize(deserializer)
+            BoundDiscriminant::Included => recovery
+                .scope(Location::Variant("Included"), || {
+                    T::deserialize(deserializer)
+                })
                 .map(Bound::Included)
                 .attach(Location::Variant("Included"))
                 .change_context(VisitorError),
-            BoundDiscriminant::Excluded => T::deserialize(deserializer)
+            BoundDiscriminant::Excluded => recovery
+                .scope(Location::Variant("Excluded"), || {
+                    T::deserialize(deserializer)
+                })
                 .map(Bound::Excluded)
                 .attach(Location::Variant("Excluded"))
                 .change_context(VisitorError),
@@ -74,21 +81,14 @@ where
     T: Reflection + ?Sized,
 {
     fn schema(doc: &mut Document) -> Schema {
-        #[derive(serde::Serialize)]
-        enum BoundOneOf {
-            Included(Reference),
-            Excluded(Reference),
-            Unbounded(Reference),
-        }
-
         // TODO: the case where "Unbounded" as a single value is possible cannot be
         //  represented right now with deer Schema capabilities
         Schema::new("object").with(
             "oneOf",
             [
-                BoundOneOf::Included(doc.add::<T>()),
-                BoundOneOf::Excluded(doc.add::<T>()),
-                BoundOneOf::Unbounded(doc.add::<<() as Deserialize>::Reflection>()),
+                VariantSchema::new("Included", doc.add::<T>()),
+                VariantSchema::new("Excluded", doc.add::<T>()),
+                VariantSchema::new("Unbounded", doc.add::<<() as Deserialize>::Reflection>()),
             ],
         )
     }
@@ -130,9 +130,12 @@ where
     where
         D: Deserializer<'de>,
     {
+        let recovery = deserializer.context().recovery();
+
         match key {
             RangeIdent::Start => {
-                let value = T::deserialize(deserializer)
+                let value = recovery
+                    .scope(Location::Field("start"), || T::deserialize(deserializer))
                     .attach(Location::Field("start"))
                     .change_context(VisitorError)?;
 
@@ -147,7 +150,8 @@ where
                 Ok(())
             }
             RangeIdent::End => {
-                let value = U::deserialize(deserializer)
+                let value = recovery
+                    .scope(Location::Field("end"), || U::deserialize(deserializer))
                     .attach(Location::Field("end"))
                     .change_context(VisitorError)?;
 
@@ -187,22 +191,25 @@ where
         A: ArrayAccess<'de>,
     {
         let mut array = array.into_bound(2).change_context(VisitorError)?;
-
-        let start = array
-            .next()
-            .unwrap_or_else(|| {
-                Deserialize::deserialize(NoneDeserializer::new(array.context()))
-                    .attach(Location::Tuple(0))
-                    .change_context(ArrayAccessError)
+        let recovery = array.context().recovery();
+
+        let start = recovery
+            .scope(Location::Tuple(0), || {
+                array.next().unwrap_or_else(|| {
+                    Deserialize::deserialize(NoneDeserializer::new(array.context()))
+                        .attach(Location::Tuple(0))
+                        .change_context(ArrayAccessError)
+                })
             })
             .attach(Location::Tuple(0));
 
-        let end = array
-            .next()
-            .unwrap_or_else(|| {
-                Deserialize::deserialize(NoneDeserializer::new(array.context()))
-                    .attach(Location::Tuple(1))
-                    .change_context(ArrayAccessError)
+        let end = recovery
+            .scope(Location::Tuple(1), || {
+                array.next().unwrap_or_else(|| {
+                    Deserialize::deserialize(NoneDeserializer::new(array.context()))
+                        .attach(Location::Tuple(1))
+                        .change_context(ArrayAccessError)
+                })
             })
             .attach(Location::Tuple(1));
 
@@ -231,9 +238,14 @@ where
             }
         }
 
+        let recovery = object.context().recovery();
+
         let start = start.map_or_else(
             || {
-                Deserialize::deserialize(NoneDeserializer::new(object.context()))
+                recovery
+                    .scope(Location::Field("start"), || {
+                        Deserialize::deserialize(NoneDeserializer::new(object.context()))
+                    })
                     .attach(Location::Field("start"))
                     .change_context(VisitorError)
             },
@@ -242,7 +254,10 @@ where
 
         let end = end.map_or_else(
             || {
-                Deserialize::deserialize(NoneDeserializer::new(object.context()))
+                recovery
+                    .scope(Location::Field("end"), || {
+                        Deserialize::deserialize(NoneDeserializer::new(object.context()))
+                    })
                     .attach(Location::Field("end"))
                     .change_context(VisitorError)
             },
@@ -28,7 +28,10 @@ impl<'de, T: Deserialize<'de>> OptionalVisitor<'de> for OptionVisitor<T> {
     where
         D: Deserializer<'de>,
     {
-        T::deserialize(deserializer)
+        let recovery = deserializer.context().recovery();
+
+        recovery
+            .scope(Location::Variant("Some"), || T::deserialize(deserializer))
             .map(Some)
             .attach(Location::Variant("Some"))
             .change_context(VisitorError)
@@ -38,12 +41,19 @@ impl<'de, T: Deserialize<'de>> OptionalVisitor<'de> for OptionVisitor<T> {
 pub struct OptionReflection<T: ?Sized>(PhantomData<fn() -> *const T>);
 
 impl<T: Reflection + ?Sized> Reflection for OptionReflection<T> {
+    /// # Schema
+    ///
+    /// ```json
+    /// {
+    ///     "type": "optional",
+    ///     "anyOf": [<ref>, {"type": "null"}]
+    /// }
+    /// ```
     fn schema(doc: &mut Document) -> Schema {
-        // TODO: an accurate reflection is not really possible right now
-        //  we need to do oneOf null/none/T and `Schema` does not support it right now
-        //  this needs to be fixed until `0.1`
-        // For now we just fallback to `T`
-        T::schema(doc)
+        Schema::new("optional").with(
+            "anyOf",
+            [doc.add::<T>(), doc.add::<<() as Deserialize>::Reflection>()],
+        )
     }
 }
 
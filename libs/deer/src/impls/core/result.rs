@@ -7,7 +7,7 @@ use crate::{
         DeserializeError, ExpectedVariant, Location, ReceivedVariant, UnknownVariantError, Variant,
         VisitorError,
     },
-    schema::Reference,
+    helpers::VariantSchema,
     Deserialize, Deserializer, Document, EnumVisitor, Reflection, Schema, Visitor,
 };
 
@@ -94,12 +94,16 @@ where
     where
         D: Deserializer<'de>,
     {
+        let recovery = deserializer.context().recovery();
+
         match discriminant {
-            ResultDiscriminant::Ok => T::deserialize(deserializer)
+            ResultDiscriminant::Ok => recovery
+                .scope(Location::Variant("Ok"), || T::deserialize(deserializer))
                 .map(Ok)
                 .attach(Location::Variant("Ok"))
                 .change_context(VisitorError),
-            ResultDiscriminant::Err => E::deserialize(deserializer)
+            ResultDiscriminant::Err => recovery
+                .scope(Location::Variant("Err"), || E::deserialize(deserializer))
                 .map(Err)
                 .attach(Location::Variant("Err"))
                 .change_context(VisitorError),
@@ -119,29 +123,20 @@ where
     /// ```json
     /// {
     ///     "type": "object",
-    ///     "additionalProperties": false,
     ///     "oneOf": [
-    ///         {"properties": {"Ok": <ref>}}
-    ///         {"properties": {"Err": <ref>}}
+    ///         {"properties": {"Ok": <ref>}, "required": ["Ok"], "additionalProperties": false}
+    ///         {"properties": {"Err": <ref>}, "required": ["Err"], "additionalProperties": false}
     ///     ]
     /// }
     /// ```
     fn schema(doc: &mut Document) -> Schema {
-        #[derive(serde::Serialize)]
-        enum Properties {
-            Ok(Reference),
-            Err(Reference),
-        }
-
-        Schema::new("object")
-            .with(
-                "oneOf",
-                [
-                    Properties::Ok(doc.add::<T>()),
-                    Properties::Err(doc.add::<E>()),
-                ],
-            )
-            .with("additionalProperties", false)
+        Schema::new("object").with(
+            "oneOf",
+            [
+                VariantSchema::new("Ok", doc.add::<T>()),
+                VariantSchema::new("Err", doc.add::<E>()),
+            ],
+        )
     }
 }
 
@@ -31,9 +31,12 @@ impl<'de: 'a, 'a> Deserialize<'de> for &'a str {
     type Reflection = str;
 
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
-        deserializer
+        let recovery = deserializer.context().recovery();
+        let value = deserializer
             .deserialize_str(StrVisitor(PhantomData))
-            .change_context(DeserializeError)
+            .change_context(DeserializeError);
+
+        recovery.recover(value, Self::default)
     }
 }
 
@@ -80,8 +83,11 @@ impl<'de> Deserialize<'de> for char {
     type Reflection = Self;
 
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, DeserializeError> {
-        deserializer
+        let recovery = deserializer.context().recovery();
+        let value = deserializer
             .deserialize_char(CharVisitor)
-            .change_context(DeserializeError)
+            .change_context(DeserializeError);
+
+        recovery.recover(value, Self::default)
     }
 }
@@ -72,10 +72,11 @@ macro_rules! impl_tuple {
             {
                 let mut array = array.into_bound($expected).change_context(VisitorError)?;
 
+                let recovery = array.context().recovery();
                 let mut length = 0;
 
                 $(
-                let $elem = match array.next() {
+                let $elem = match recovery.scope(Location::Tuple(length), || array.next()) {
                     None => {
                         return Err(Report::new(ArrayLengthError.into_error())
                             .attach(ExpectedLength::new($expected))
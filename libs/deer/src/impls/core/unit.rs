@@ -36,9 +36,12 @@ impl<'de> Deserialize<'de> for () {
     fn deserialize<D: Deserializer<'de>>(
         deserializer: D,
     ) -> error_stack::Result<Self, DeserializeError> {
-        deserializer
+        let recovery = deserializer.context().recovery();
+        let value = deserializer
             .deserialize_null(UnitVisitor)
-            .change_context(DeserializeError)
+            .change_context(DeserializeError);
+
+        recovery.recover(value, || ())
     }
 }
 
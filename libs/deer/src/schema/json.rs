@@ -0,0 +1,200 @@
+//! Conversion of a [`Document`] into a [JSON Schema (2020-12)] document.
+//!
+//! The schemas emitted through [`Reflection`] are mostly already valid JSON Schema, the only
+//! exception being a few types used by `deer`, which do not have an equivalent in JSON Schema.
+//! These are translated into the closest representation possible:
+//!
+//! * `bytes` and `binary`: a `string`, with `contentEncoding` set to `base64`
+//! * `none` (the absence of a value): `{"not": {}}`, meaning that the value must not be present
+//! * `optional`: no `type`, the value is constrained by `anyOf` to either the value or `null`
+//!
+//! [JSON Schema (2020-12)]: https://json-schema.org/draft/2020-12/json-schema-core
+//! [`Reflection`]: crate::Reflection
+
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+use crate::schema::{Document, Schema};
+
+/// The `$schema` dialect used in every document created by [`JsonSchema`].
+pub const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// The empty schema `{}`, which matches any value.
+struct Empty;
+
+impl Serialize for Empty {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_map(Some(0))?.end()
+    }
+}
+
+struct SerializeJsonSchema<'a>(&'a Schema);
+
+impl Serialize for SerializeJsonSchema<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Schema { ty, other } = self.0;
+
+        let mut map = serializer.serialize_map(None)?;
+
+        for (key, value) in other {
+            map.serialize_entry(key, value)?;
+        }
+
+        match ty.as_str() {
+            "bytes" | "binary" => {
+                map.serialize_entry("type", "string")?;
+                map.serialize_entry("contentEncoding", "base64")?;
+            }
+            "none" => map.serialize_entry("not", &Empty)?,
+            "optional" => {}
+            ty => map.serialize_entry("type", ty)?,
+        }
+
+        map.end()
+    }
+}
+
+struct SerializeDefinitions<'a>(&'a Document);
+
+impl Serialize for SerializeDefinitions<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Document {
+            schemas,
+            references,
+            ..
+        } = self.0;
+
+        let defs = references.iter().filter_map(|(key, reference)| {
+            schemas
+                .get(key)
+                .map(|schema| (reference.as_bare(), SerializeJsonSchema(schema)))
+        });
+
+        serializer.collect_map(defs)
+    }
+}
+
+/// A [`Document`] represented as a [JSON Schema (2020-12)] document.
+///
+/// Every type reflected in the document is added as an entry to `$defs`, the root of the document
+/// references the type the document has been created for.
+///
+/// Created through [`Document::json_schema`].
+///
+/// # Example
+///
+/// ```
+/// use deer::Deserialize;
+///
+/// let document = <u8 as Deserialize>::reflection();
+/// let schema = serde_json::to_value(document.json_schema()).expect("should be valid JSON");
+///
+/// assert_eq!(
+///     schema,
+///     serde_json::json!({
+///         "$schema": "https://json-schema.org/draft/2020-12/schema",
+///         "$ref": "#/$defs/0000-u8",
+///         "$defs": {
+///             "0000-u8": {
+///                 "type": "integer",
+///                 "minimum": 0,
+///                 "maximum": 255
+///             }
+///         }
+///     })
+/// );
+/// ```
+///
+/// [JSON Schema (2020-12)]: https://json-schema.org/draft/2020-12/json-schema-core
+pub struct JsonSchema<'a> {
+    document: &'a Document,
+}
+
+impl<'a> JsonSchema<'a> {
+    pub(crate) const fn new(document: &'a Document) -> Self {
+        Self { document }
+    }
+}
+
+impl Serialize for JsonSchema<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(3))?;
+
+        let id = self
+            .document
+            .references
+            .get(&self.document.id)
+            .expect("`new()` should have created a schema for the main schema");
+
+        map.serialize_entry("$schema", DIALECT)?;
+        map.serialize_entry("$ref", &id.as_path())?;
+        map.serialize_entry("$defs", &SerializeDefinitions(self.document))?;
+
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, to_value};
+
+    use crate::{schema::visitor::BinarySchema, Document, Reflection, Schema};
+
+    struct Missing;
+
+    impl Reflection for Missing {
+        fn schema(_: &mut Document) -> Schema {
+            Schema::new("none")
+        }
+    }
+
+    #[test]
+    fn binary() {
+        let document = BinarySchema::document();
+        let schema = to_value(document.json_schema()).expect("should be valid json");
+
+        assert_eq!(
+            schema,
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "$ref": "#/$defs/0000-deer::schema::visitor::BinarySchema",
+                "$defs": {
+                    "0000-deer::schema::visitor::BinarySchema": {
+                        "type": "string",
+                        "contentEncoding": "base64"
+                    }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn none() {
+        let document = Missing::document();
+        let schema = to_value(document.json_schema()).expect("should be valid json");
+
+        assert_eq!(
+            schema,
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "$ref": "#/$defs/0000-deer::schema::json::tests::Missing",
+                "$defs": {
+                    "0000-deer::schema::json::tests::Missing": {
+                        "not": {}
+                    }
+                }
+            })
+        );
+    }
+}
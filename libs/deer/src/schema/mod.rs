@@ -4,10 +4,17 @@
 use alloc::collections::BTreeMap;
 #[cfg_attr(feature = "std", allow(unused_imports))]
 use alloc::{boxed::Box, format, string::String};
-use core::any::{type_name, TypeId};
+use core::{
+    any::{type_name, TypeId},
+    fmt::Write,
+};
 
 use serde::{ser::SerializeMap, Serialize, Serializer};
 
+pub use self::json::JsonSchema;
+
+pub mod json;
+
 pub trait Reflection: 'static {
     fn schema(doc: &mut Document) -> Schema;
 
@@ -38,8 +45,8 @@ impl Serialize for SerializeSchema<'_> {
 }
 
 // TODO: most likely (in 0.2) we want to actually have a proper schema
-// TODO: this is currently completely untyped, we might want to adhere to a standard, like
-//  JSON-Schema or OpenAPI
+// TODO: this is currently completely untyped, a `Document` can be converted to JSON Schema via
+//  `Document::json_schema`, which translates the few non-standard types.
 //  The problem here mainly is: which crate to use, one can use utoipa (but that has significant
 //  overhead)  there's no real library out there that properly just provides the types
 //  necessary.
@@ -98,9 +105,48 @@ pub struct Reference {
 }
 
 impl Reference {
+    /// JSON Pointer to the definition, encoded as URI fragment.
+    ///
+    /// Type names may contain characters (like `<`, `>`, `[`, `]` or spaces) which are not allowed
+    /// in a URI fragment, these are percent-encoded.
     fn as_path(&self) -> String {
         let bare = self.as_bare();
-        format!("#/$defs/{bare}")
+        let mut path = String::with_capacity(bare.len() + 8);
+        path.push_str("#/$defs/");
+
+        for byte in bare.bytes() {
+            match byte {
+                // JSON Pointer escape sequences
+                b'~' => path.push_str("~0"),
+                b'/' => path.push_str("~1"),
+                // characters allowed in a URI fragment (RFC 3986)
+                b'A'..=b'Z'
+                | b'a'..=b'z'
+                | b'0'..=b'9'
+                | b'-'
+                | b'.'
+                | b'_'
+                | b'!'
+                | b'$'
+                | b'&'
+                | b'\''
+                | b'('
+                | b')'
+                | b'*'
+                | b'+'
+                | b','
+                | b';'
+                | b'='
+                | b':'
+                | b'@' => path.push(char::from(byte)),
+                byte => {
+                    // writing to a `String` is infallible
+                    let _ = write!(path, "%{byte:02X}");
+                }
+            }
+        }
+
+        path
     }
 
     fn as_bare(&self) -> String {
@@ -201,6 +247,14 @@ impl Document {
         self.schemas.get(type_id)
     }
 
+    /// Represent the document as [JSON Schema (2020-12)].
+    ///
+    /// [JSON Schema (2020-12)]: https://json-schema.org/draft/2020-12/json-schema-core
+    #[must_use]
+    pub const fn json_schema(&self) -> JsonSchema<'_> {
+        JsonSchema::new(self)
+    }
+
     #[must_use]
     pub fn reference<T: Reflection + ?Sized>(id: usize) -> Reference {
         Reference {
@@ -303,6 +357,7 @@ mod tests {
     use alloc::collections::BTreeMap;
     #[cfg_attr(feature = "std", allow(unused_imports))]
     use alloc::{boxed::Box, vec::Vec};
+    use core::marker::PhantomData;
 
     use serde_json::{json, to_value};
     use similar_asserts::assert_serde_eq;
@@ -339,6 +394,25 @@ mod tests {
         );
     }
 
+    struct Wrapper<T: ?Sized>(PhantomData<T>);
+
+    impl<T: Reflection + ?Sized> Reflection for Wrapper<T> {
+        fn schema(doc: &mut Document) -> Schema {
+            Schema::new("array").with("items", doc.add::<T>())
+        }
+    }
+
+    #[test]
+    fn reference_escaped() {
+        let document = Wrapper::<[u8]>::document();
+        let document = to_value(document).expect("should be valid json");
+
+        assert_eq!(
+            document["$ref"],
+            "#/$defs/0000-deer::schema::tests::Wrapper%3C%5Bu8%5D%3E"
+        );
+    }
+
     // test for self referential
     // Reason: we don't actually use them, but it is easier to visualize the schema that way
     #[allow(unused)]
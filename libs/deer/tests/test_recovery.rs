@@ -0,0 +1,159 @@
+use deer::{Context, Deserialize, Number};
+use deer_desert::{
+    assert_tokens_error, assert_tokens_recover, assert_tokens_with_context_error, error, Token,
+};
+use serde_json::json;
+
+#[test]
+fn array_recover_value() {
+    assert_tokens_recover(
+        &[1_u8, 0, 0],
+        &error!([
+            {
+                ns: "deer",
+                id: ["value"],
+                properties: {
+                    "expected": u8::reflection(),
+                    "received": 256,
+                    "location": [{"type": "array", "value": 1}]
+                }
+            },
+            {
+                ns: "deer",
+                id: ["value"],
+                properties: {
+                    "expected": u8::reflection(),
+                    "received": 300,
+                    "location": [{"type": "array", "value": 2}]
+                }
+            }
+        ]),
+        &[
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(1)),
+            Token::Number(Number::from(256)),
+            Token::Number(Number::from(300)),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn array_recover_does_not_recover_length() {
+    let mut context = Context::new();
+    context.enable_recovery();
+
+    // recovery only applies to values, structural errors are still returned
+    assert_tokens_with_context_error::<[u8; 1]>(
+        &error!([{
+            ns: "deer",
+            id: ["array", "length"],
+            properties: {
+                "expected": 1,
+                "received": 2,
+                "location": []
+            }
+        }]),
+        &[
+            Token::Array { length: Some(2) },
+            Token::Number(Number::from(0)),
+            Token::Number(Number::from(1)),
+            Token::ArrayEnd,
+        ],
+        &context,
+    );
+}
+
+#[test]
+fn tuple_recover_missing() {
+    assert_tokens_recover(
+        &(12_u8, 0_u16),
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u16::reflection(),
+                "location": [{"type": "tuple", "value": 1}]
+            }
+        }]),
+        &[
+            Token::Array { length: Some(1) },
+            Token::Number(12.into()),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+fn nested() -> [Token; 7] {
+    [
+        Token::Array { length: Some(2) },
+        Token::Array { length: Some(2) },
+        Token::Number(Number::from(1)),
+        Token::Number(Number::from(2)),
+        Token::ArrayEnd,
+        Token::Number(Number::from(3)),
+        Token::ArrayEnd,
+    ]
+}
+
+#[test]
+fn nested_recover_location() {
+    // the location of a recovered error is the same as if it would not have been recovered from
+    assert_tokens_error::<((u8, bool), u8)>(
+        &error!([{
+            ns: "deer",
+            id: ["type"],
+            properties: {
+                "expected": bool::reflection(),
+                "received": Number::reflection(),
+                "location": [{"type": "tuple", "value": 0}, {"type": "tuple", "value": 1}]
+            }
+        }]),
+        &nested(),
+    );
+
+    assert_tokens_recover(
+        &((1_u8, false), 3_u8),
+        &error!([{
+            ns: "deer",
+            id: ["type"],
+            properties: {
+                "expected": bool::reflection(),
+                "received": Number::reflection(),
+                "location": [{"type": "tuple", "value": 0}, {"type": "tuple", "value": 1}]
+            }
+        }]),
+        &nested(),
+    );
+}
+
+#[test]
+fn result_recover_variant() {
+    assert_tokens_recover(
+        &Ok::<u8, u16>(0),
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{"type": "variant", "value": "Ok"}]
+            }
+        }]),
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("Ok"),
+            Token::Number(Number::from(256)),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn finish_without_errors() {
+    let mut context = Context::new();
+    context.enable_recovery();
+
+    assert!(context.is_recovering());
+    assert_eq!(context.finish(Ok(1_u8)).expect("no errors recorded"), 1);
+}
@@ -0,0 +1,135 @@
+use core::{
+    cmp::Ordering,
+    marker::PhantomData,
+    ops::{Bound, Range},
+};
+
+use deer::Deserialize;
+use jsonschema::{Draft, JSONSchema};
+use serde_json::{json, Value};
+
+fn compile<'de, T: Deserialize<'de>>() -> JSONSchema {
+    let document = T::reflection();
+    let schema = serde_json::to_value(document.json_schema()).expect("should be valid JSON");
+
+    JSONSchema::options()
+        .with_draft(Draft::Draft202012)
+        .compile(&schema)
+        .unwrap_or_else(|error| panic!("invalid JSON Schema `{schema}`: {error}"))
+}
+
+fn assert_valid<'de, T: Deserialize<'de>>(instance: &Value) {
+    let schema = compile::<T>();
+    let result = schema.validate(instance);
+
+    if let Err(errors) = result {
+        let errors: Vec<_> = errors
+            .map(|error| format!("{}: {error}", error.instance_path))
+            .collect();
+
+        panic!("`{instance}` should be valid: {errors:#?}");
+    }
+}
+
+fn assert_invalid<'de, T: Deserialize<'de>>(instance: &Value) {
+    let schema = compile::<T>();
+
+    assert!(!schema.is_valid(instance), "`{instance}` should be invalid");
+}
+
+#[test]
+fn dialect() {
+    let schema =
+        serde_json::to_value(u8::reflection().json_schema()).expect("should be valid JSON");
+
+    assert_eq!(schema["$schema"], json!(deer::schema::json::DIALECT));
+}
+
+#[test]
+fn bool() {
+    assert_valid::<bool>(&json!(true));
+    assert_invalid::<bool>(&json!("true"));
+}
+
+#[test]
+fn integer() {
+    assert_valid::<u8>(&json!(255));
+    assert_invalid::<u8>(&json!(256));
+    assert_invalid::<u8>(&json!(-1));
+
+    assert_valid::<i16>(&json!(i16::MIN));
+    assert_invalid::<i16>(&json!(i32::from(i16::MAX) + 1));
+}
+
+#[test]
+fn string() {
+    assert_valid::<&str>(&json!("example"));
+    assert_invalid::<&str>(&json!(12));
+
+    assert_valid::<char>(&json!("a"));
+    assert_invalid::<char>(&json!("ab"));
+}
+
+#[test]
+fn unit() {
+    assert_valid::<()>(&json!(null));
+    assert_invalid::<()>(&json!(0));
+
+    assert_valid::<PhantomData<u8>>(&json!(null));
+}
+
+#[test]
+fn option() {
+    assert_valid::<Option<u8>>(&json!(12));
+    assert_valid::<Option<u8>>(&json!(null));
+    assert_invalid::<Option<u8>>(&json!(256));
+    assert_invalid::<Option<u8>>(&json!("12"));
+}
+
+#[test]
+fn array() {
+    assert_valid::<[u8; 2]>(&json!([1, 2]));
+    assert_invalid::<[u8; 2]>(&json!([1, 2, 3]));
+    assert_invalid::<[u8; 2]>(&json!([1, 256]));
+}
+
+#[test]
+fn tuple() {
+    assert_valid::<(u8, &str)>(&json!([1, "example"]));
+    assert_invalid::<(u8, &str)>(&json!(["example", 1]));
+}
+
+#[test]
+fn ordering() {
+    assert_valid::<Ordering>(&json!("Less"));
+    assert_invalid::<Ordering>(&json!("Smaller"));
+}
+
+#[test]
+fn result() {
+    assert_valid::<Result<u8, &str>>(&json!({"Ok": 12}));
+    assert_valid::<Result<u8, &str>>(&json!({"Err": "example"}));
+    assert_invalid::<Result<u8, &str>>(&json!({"Ok": "example"}));
+    assert_invalid::<Result<u8, &str>>(&json!({}));
+}
+
+#[test]
+fn bound() {
+    assert_valid::<Bound<u8>>(&json!({"Included": 12}));
+    assert_valid::<Bound<u8>>(&json!({"Excluded": 12}));
+    assert_invalid::<Bound<u8>>(&json!({"Included": 256}));
+}
+
+#[test]
+fn range() {
+    assert_valid::<Range<u8>>(&json!({"start": 1, "end": 2}));
+    assert_invalid::<Range<u8>>(&json!({"start": 1, "end": 256}));
+}
+
+#[test]
+fn nested() {
+    assert_valid::<Option<Result<[u8; 1], ()>>>(&json!({"Ok": [1]}));
+    assert_valid::<Option<Result<[u8; 1], ()>>>(&json!({"Err": null}));
+    assert_invalid::<Option<Result<[u8; 1], ()>>>(&json!({"Err": 1}));
+    assert_invalid::<Option<Result<[u8; 1], ()>>>(&json!({"Ok": [1], "Err": null}));
+}
@@ -0,0 +1,79 @@
+use alloc::vec::IntoIter;
+
+use deer::{
+    error::{ArrayAccessError, ArrayLengthError, DeserializerError},
+    Context, Deserialize,
+};
+use error_stack::{Result, ResultExt};
+
+use crate::{
+    deserializer::{Deserializer, Stack},
+    token::Node,
+};
+
+pub(crate) struct ArrayAccess<'a, 'de> {
+    deserializer: Deserializer<'a, 'de>,
+    stack: Stack,
+
+    nodes: IntoIter<Node<'de>>,
+    length: usize,
+
+    dirty: bool,
+    expected: usize,
+}
+
+impl<'a, 'de> ArrayAccess<'a, 'de> {
+    pub(crate) fn new(
+        deserializer: Deserializer<'a, 'de>,
+        nodes: Vec<Node<'de>>,
+    ) -> Result<Self, DeserializerError> {
+        let stack = deserializer.push()?;
+
+        Ok(Self {
+            deserializer,
+            stack,
+            length: nodes.len(),
+            nodes: nodes.into_iter(),
+            dirty: false,
+            expected: 0,
+        })
+    }
+}
+
+impl<'de> deer::ArrayAccess<'de> for ArrayAccess<'_, 'de> {
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        deer::Deserializer::context(&self.deserializer)
+    }
+
+    fn next<T>(&mut self) -> Option<Result<T, ArrayAccessError>>
+    where
+        T: Deserialize<'de>,
+    {
+        self.dirty = true;
+
+        let node = self.nodes.next()?;
+        self.expected += 1;
+
+        let value = T::deserialize(self.deserializer.nested(node, self.stack));
+
+        Some(value.change_context(ArrayAccessError))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.length)
+    }
+
+    fn end(self) -> Result<(), ArrayAccessError> {
+        if self.nodes.len() > 0 {
+            return Err(
+                ArrayLengthError::new(&self, self.expected).change_context(ArrayAccessError)
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,321 @@
+use core::ops::Range;
+
+use deer::{
+    error::{
+        DeserializerError, ExpectedLength, ExpectedType, ObjectLengthError, ReceivedLength,
+        ReceivedType, TypeError, Variant,
+    },
+    schema::Document,
+    value::{BorrowedStrDeserializer, NoneDeserializer},
+    Context, Deserialize, EnumVisitor, IdentifierVisitor, Number, OptionalVisitor, Reflection,
+    StructVisitor, Visitor,
+};
+use error_stack::{Report, Result, ResultExt};
+use toml_edit::ImDocument;
+
+use crate::{
+    array::ArrayAccess,
+    error::{BytesUnsupportedError, RecursionLimitError, Span},
+    object::ObjectAccess,
+    token::{Node, ValueToken},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StackLimit(usize);
+
+impl StackLimit {
+    #[must_use]
+    pub const fn new(limit: usize) -> Self {
+        Self(limit)
+    }
+
+    #[must_use]
+    pub const fn limit(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Stack {
+    limit: usize,
+    depth: usize,
+}
+
+impl Stack {
+    const fn new(limit: usize) -> Self {
+        Self { limit, depth: 0 }
+    }
+
+    /// Descend into a nested array or table, `span` is the location of the nested value.
+    pub(crate) fn push(self, span: Option<Range<usize>>) -> Result<Self, DeserializerError> {
+        let depth = self.depth + 1;
+
+        if depth >= self.limit {
+            let mut error = Report::new(RecursionLimitError.into_error());
+
+            if let Some(span) = span {
+                error = error.attach(Span::new(span));
+            }
+
+            Err(error.change_context(DeserializerError))
+        } else {
+            Ok(Self {
+                limit: self.limit,
+                depth,
+            })
+        }
+    }
+}
+
+/// Deserializer for a parsed TOML document.
+///
+/// Unlike the other `deer` deserializers, this operates on the syntax tree created by
+/// [`toml_edit`], as TOML allows tables to be defined in any order.
+#[derive(Debug, Copy, Clone)]
+pub struct Deserializer<'a, 'de> {
+    node: Node<'de>,
+
+    context: &'a Context,
+    stack: Stack,
+}
+
+impl<'a, 'de> Deserializer<'a, 'de> {
+    #[must_use]
+    pub fn new<S>(document: &'de ImDocument<S>, context: &'a Context) -> Self {
+        let limit = context
+            .request_ref::<StackLimit>()
+            .map_or(usize::MAX, |limit| limit.limit());
+
+        Self {
+            node: Node::Table(document.as_table()),
+            context,
+            stack: Stack::new(limit),
+        }
+    }
+
+    pub(crate) const fn nested(&self, node: Node<'de>, stack: Stack) -> Self {
+        Self {
+            node,
+            context: self.context,
+            stack,
+        }
+    }
+
+    /// Descend into the current node, used for arrays and tables.
+    pub(crate) fn push(&self) -> Result<Stack, DeserializerError> {
+        self.stack.push(self.node.span())
+    }
+
+    fn error_invalid_type(received: &ValueToken, expected: Document) -> Report<DeserializerError> {
+        Report::new(TypeError.into_error())
+            .attach(ExpectedType::new(expected))
+            .attach(ReceivedType::new(received.schema()))
+            .change_context(DeserializerError)
+    }
+}
+
+impl<'de> deer::Deserializer<'de> for Deserializer<'_, 'de> {
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.node.token() {
+            ValueToken::Missing => visitor.visit_none(),
+            ValueToken::String(value) => visitor.visit_borrowed_str(value),
+            ValueToken::Integer(value) => visitor.visit_number(Number::from(value)),
+            ValueToken::Float(value) => visitor.visit_number(Number::from(value)),
+            ValueToken::Bool(value) => visitor.visit_bool(value),
+            ValueToken::Datetime(value) => visitor.visit_string(value),
+            ValueToken::Array(nodes) => visitor.visit_array(ArrayAccess::new(self, nodes)?),
+            ValueToken::Table(entries) => visitor.visit_object(ObjectAccess::new(self, entries)?),
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_null<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        // TOML has no concept of `null`, the closest equivalent is a missing value
+        match self.node.token() {
+            ValueToken::Missing => visitor.visit_none().change_context(DeserializerError),
+            token => Err(Self::error_invalid_type(&token, <()>::reflection())),
+        }
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.node.token() {
+            ValueToken::Bool(value) => visitor.visit_bool(value).change_context(DeserializerError),
+            token => Err(Self::error_invalid_type(&token, bool::reflection())),
+        }
+    }
+
+    fn deserialize_number<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.node.token() {
+            ValueToken::Integer(value) => visitor.visit_number(Number::from(value)),
+            ValueToken::Float(value) => visitor.visit_number(Number::from(value)),
+            token => return Err(Self::error_invalid_type(&token, Number::reflection())),
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.node.token() {
+            ValueToken::String(value) => visitor
+                .visit_borrowed_str(value)
+                .change_context(DeserializerError),
+            ValueToken::Datetime(value) => visitor
+                .visit_string(value)
+                .change_context(DeserializerError),
+            token => Err(Self::error_invalid_type(&token, str::document())),
+        }
+    }
+
+    fn deserialize_bytes<V>(self, _: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let mut error = Report::new(BytesUnsupportedError.into_error());
+
+        if let Some(span) = self.node.span() {
+            error = error.attach(Span::new(span));
+        }
+
+        Err(error.change_context(DeserializerError))
+    }
+
+    fn deserialize_bytes_buffer<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_array<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.node.token() {
+            ValueToken::Array(nodes) => visitor
+                .visit_array(ArrayAccess::new(self, nodes)?)
+                .change_context(DeserializerError),
+            token => Err(Self::error_invalid_type(
+                &token,
+                ValueToken::Array(Vec::new()).schema(),
+            )),
+        }
+    }
+
+    fn deserialize_object<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        match self.node.token() {
+            ValueToken::Table(entries) => visitor
+                .visit_object(ObjectAccess::new(self, entries)?)
+                .change_context(DeserializerError),
+            token => Err(Self::error_invalid_type(
+                &token,
+                ValueToken::Table(Vec::new()).schema(),
+            )),
+        }
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        if matches!(self.node, Node::Item(toml_edit::Item::None)) {
+            visitor.visit_none().change_context(DeserializerError)
+        } else {
+            visitor.visit_some(self).change_context(DeserializerError)
+        }
+    }
+
+    fn deserialize_enum<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: EnumVisitor<'de>,
+    {
+        let ValueToken::Table(entries) = self.node.token() else {
+            // unit variants are represented as the discriminant
+            let discriminant = visitor
+                .visit_discriminant(self)
+                .change_context(DeserializerError)?;
+
+            return visitor
+                .visit_value(discriminant, NoneDeserializer::new(self.context))
+                .change_context(DeserializerError);
+        };
+
+        let stack = self.push()?;
+
+        let [(key, node)] = entries[..] else {
+            return Err(Report::new(ObjectLengthError.into_error())
+                .attach(ExpectedLength::new(1))
+                .attach(ReceivedLength::new(entries.len()))
+                .change_context(DeserializerError));
+        };
+
+        let discriminant = visitor
+            .visit_discriminant(BorrowedStrDeserializer::new(key, self.context))
+            .change_context(DeserializerError)?;
+
+        visitor
+            .visit_value(discriminant, self.nested(node, stack))
+            .change_context(DeserializerError)
+    }
+
+    fn deserialize_struct<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: StructVisitor<'de>,
+    {
+        match self.node.token() {
+            ValueToken::Table(entries) => visitor
+                .visit_object(ObjectAccess::new(self, entries)?)
+                .change_context(DeserializerError),
+            token => Err(Self::error_invalid_type(
+                &token,
+                ValueToken::Table(Vec::new()).schema(),
+            )),
+        }
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: IdentifierVisitor<'de>,
+    {
+        match self.node.token() {
+            ValueToken::String(value) => visitor.visit_str(value),
+            ValueToken::Integer(value) if value >= 0 => visitor.visit_u64(value.unsigned_abs()),
+            token => return Err(Self::error_invalid_type(&token, str::document())),
+        }
+        .change_context(DeserializerError)
+    }
+}
@@ -0,0 +1,127 @@
+use core::{
+    fmt::{Display, Formatter},
+    ops::Range,
+};
+
+use deer::{
+    error::{ErrorProperties, ErrorProperty, Id, Location, Namespace, Variant},
+    id,
+};
+use error_stack::Report;
+use toml_edit::TomlError;
+
+const NAMESPACE: Namespace = Namespace::new("deer-toml");
+
+#[derive(Debug)]
+pub(crate) struct RecursionLimitError;
+
+impl Display for RecursionLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        // This message is vague by design to not encourage abuse from the consumers
+        f.write_str("Recursion limit has been exceeded")
+    }
+}
+
+impl Variant for RecursionLimitError {
+    type Properties = (Location, Span);
+
+    const ID: Id = id!["recursion"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        _: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        Display::fmt(self, fmt)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct BytesUnsupportedError;
+
+impl Display for BytesUnsupportedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("TOML does not support bytes")
+    }
+}
+
+impl Variant for BytesUnsupportedError {
+    type Properties = (Location, Span);
+
+    const ID: Id = id!["bytes"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        _: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        fmt.write_str("deer-toml does not support deserialization of bytes")
+    }
+}
+
+/// Byte range in the source document.
+pub(crate) struct Span {
+    range: Range<usize>,
+}
+
+impl Span {
+    pub(crate) fn new(range: impl Into<Range<usize>>) -> Self {
+        Self {
+            range: range.into(),
+        }
+    }
+}
+
+impl ErrorProperty for Span {
+    type Value<'a> = Option<&'a Range<usize>> where Self: 'a ;
+
+    fn key() -> &'static str {
+        "span"
+    }
+
+    fn value<'a>(mut stack: impl Iterator<Item = &'a Self>) -> Self::Value<'a> {
+        stack.next().map(|Self { range }| range)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct SyntaxError(String);
+
+impl Display for SyntaxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.0.trim_end())
+    }
+}
+
+impl Variant for SyntaxError {
+    type Properties = (Location, Span);
+
+    const ID: Id = id!["syntax"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        properties: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        let span = properties.1;
+
+        if let Some(Range { start, end }) = span {
+            fmt.write_fmt(format_args!("{self} at {start}..{end}"))
+        } else {
+            Display::fmt(self, fmt)
+        }
+    }
+}
+
+pub(crate) fn convert_toml_error(error: &TomlError) -> Report<deer::error::Error> {
+    let mut report = Report::new(SyntaxError(error.message().to_owned()).into_error());
+
+    if let Some(span) = error.span() {
+        report = report.attach(Span::new(span));
+    }
+
+    report
+}
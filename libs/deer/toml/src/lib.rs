@@ -0,0 +1,52 @@
+//! `deer` deserializer for TOML.
+//!
+//! The document is parsed using [`toml_edit`], which retains the span of every value. Datetimes
+//! are deserialized as their string representation, as `deer` has no dedicated datetime type.
+//! TOML has no concept of `null`, meaning that optional values are only `None` if they are
+//! missing.
+// TODO: once more stable introduce: warning missing_docs, clippy::missing_errors_doc
+#![deny(unsafe_code)]
+mod array;
+mod deserializer;
+mod error;
+mod object;
+mod token;
+
+extern crate alloc;
+
+use deer::{
+    error::{DeserializeError, DeserializerError},
+    Context, Deserialize, DeserializeOwned,
+};
+use error_stack::Result;
+pub use toml_edit::ImDocument;
+
+pub use crate::deserializer::{Deserializer, StackLimit};
+use crate::error::convert_toml_error;
+
+/// Parse and deserialize a TOML document.
+///
+/// The document is discarded after deserialization, to borrow from the document use
+/// [`from_document`] instead.
+pub fn from_str<T>(value: &str, context: &Context) -> Result<T, DeserializeError>
+where
+    T: DeserializeOwned,
+{
+    let document = ImDocument::parse(value).map_err(|error| {
+        convert_toml_error(&error)
+            .change_context(DeserializerError)
+            .change_context(DeserializeError)
+    })?;
+
+    from_document(&document, context)
+}
+
+pub fn from_document<'de, T, S>(
+    document: &'de ImDocument<S>,
+    context: &Context,
+) -> Result<T, DeserializeError>
+where
+    T: Deserialize<'de>,
+{
+    T::deserialize(Deserializer::new(document, context))
+}
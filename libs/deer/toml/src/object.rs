@@ -0,0 +1,95 @@
+use alloc::vec::IntoIter;
+
+use deer::{
+    error::{DeserializerError, ObjectAccessError, ObjectLengthError},
+    value::BorrowedStrDeserializer,
+    Context, FieldVisitor,
+};
+use error_stack::{Result, ResultExt};
+
+use crate::{
+    deserializer::{Deserializer, Stack},
+    token::Node,
+};
+
+pub(crate) struct ObjectAccess<'a, 'de> {
+    deserializer: Deserializer<'a, 'de>,
+    stack: Stack,
+
+    entries: IntoIter<(&'de str, Node<'de>)>,
+    length: usize,
+
+    dirty: bool,
+    expected: usize,
+}
+
+impl<'a, 'de> ObjectAccess<'a, 'de> {
+    pub(crate) fn new(
+        deserializer: Deserializer<'a, 'de>,
+        entries: Vec<(&'de str, Node<'de>)>,
+    ) -> Result<Self, DeserializerError> {
+        let stack = deserializer.push()?;
+
+        Ok(Self {
+            deserializer,
+            stack,
+            length: entries.len(),
+            entries: entries.into_iter(),
+            dirty: false,
+            expected: 0,
+        })
+    }
+}
+
+impl<'de> deer::ObjectAccess<'de> for ObjectAccess<'_, 'de> {
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        deer::Deserializer::context(&self.deserializer)
+    }
+
+    fn try_field<F>(
+        &mut self,
+        visitor: F,
+    ) -> core::result::Result<Result<F::Value, ObjectAccessError>, F>
+    where
+        F: FieldVisitor<'de>,
+    {
+        self.dirty = true;
+
+        let Some((key, node)) = self.entries.next() else {
+            return Err(visitor);
+        };
+
+        self.expected += 1;
+
+        let key = visitor
+            .visit_key(BorrowedStrDeserializer::new(key, self.context()))
+            .change_context(ObjectAccessError);
+
+        // unlike streaming formats we do not need to skip the value if the key is invalid
+        let result = key.and_then(|key| {
+            visitor
+                .visit_value(key, self.deserializer.nested(node, self.stack))
+                .change_context(ObjectAccessError)
+        });
+
+        Ok(result)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.length)
+    }
+
+    fn end(self) -> Result<(), ObjectAccessError> {
+        if self.entries.len() > 0 {
+            return Err(
+                ObjectLengthError::new(&self, self.expected).change_context(ObjectAccessError)
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use core::ops::Range;
+
+use deer::{Deserialize, Document, Number, Reflection, Schema};
+use toml_edit::{Item, Table, Value};
+
+/// A node in the parsed document, this is either a table, a value or an item (a value in a
+/// non-inline table).
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum Node<'de> {
+    Item(&'de Item),
+    Value(&'de Value),
+    Table(&'de Table),
+}
+
+impl<'de> Node<'de> {
+    /// Byte range of the node in the source document.
+    ///
+    /// This is only `None` if the document has been modified after parsing.
+    pub(crate) fn span(self) -> Option<Range<usize>> {
+        match self {
+            Self::Item(item) => item.span(),
+            Self::Value(value) => value.span(),
+            Self::Table(table) => table.span(),
+        }
+    }
+
+    pub(crate) fn token(self) -> ValueToken<'de> {
+        match self {
+            Self::Item(Item::None) => ValueToken::Missing,
+            Self::Item(Item::Value(value)) => Self::Value(value).token(),
+            Self::Item(Item::Table(table)) => Self::Table(table).token(),
+            Self::Item(Item::ArrayOfTables(array)) => {
+                ValueToken::Array(array.iter().map(Self::Table).collect())
+            }
+            Self::Value(Value::String(value)) => ValueToken::String(value.value()),
+            Self::Value(Value::Integer(value)) => ValueToken::Integer(*value.value()),
+            Self::Value(Value::Float(value)) => ValueToken::Float(*value.value()),
+            Self::Value(Value::Boolean(value)) => ValueToken::Bool(*value.value()),
+            // TOML datetimes do not have an equivalent in `deer`, they are deserialized using
+            // their RFC 3339 representation instead
+            Self::Value(Value::Datetime(value)) => ValueToken::Datetime(value.value().to_string()),
+            Self::Value(Value::Array(array)) => {
+                ValueToken::Array(array.iter().map(Self::Value).collect())
+            }
+            Self::Value(Value::InlineTable(table)) => ValueToken::Table(
+                table
+                    .iter()
+                    .map(|(key, value)| (key, Self::Value(value)))
+                    .collect(),
+            ),
+            Self::Table(table) => ValueToken::Table(
+                table
+                    .iter()
+                    .map(|(key, item)| (key, Self::Item(item)))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+// This is like `toml_edit::Value`, but normalizes inline and regular tables and arrays of tables
+pub(crate) enum ValueToken<'de> {
+    /// Only occurs if an item has been explicitly removed from a document
+    Missing,
+    String(&'de str),
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+    Datetime(String),
+    Array(Vec<Node<'de>>),
+    Table(Vec<(&'de str, Node<'de>)>),
+}
+
+struct AnyObject;
+
+impl Reflection for AnyObject {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("object")
+    }
+}
+
+struct AnyArray;
+
+impl Reflection for AnyArray {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("array")
+    }
+}
+
+impl ValueToken<'_> {
+    pub(crate) fn schema(&self) -> Document {
+        match self {
+            Self::Missing => <() as Deserialize>::reflection(),
+            Self::Bool(_) => bool::reflection(),
+            Self::String(_) | Self::Datetime(_) => str::document(),
+            Self::Integer(_) | Self::Float(_) => Number::reflection(),
+            Self::Table(_) => AnyObject::document(),
+            Self::Array(_) => AnyArray::document(),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use core::fmt::Debug;
+
+use deer::{Context, DeserializeOwned};
+use deer_desert::{assert_error, assert_tokens, error::ErrorVec, Token};
+
+/// Assert that the TOML document deserializes into the same value as the equivalent tokens.
+#[allow(dead_code)] // False positive
+pub(crate) fn assert_toml<T>(expected: &T, source: &str, tokens: &[Token])
+where
+    T: DeserializeOwned + PartialEq + Debug,
+{
+    assert_tokens(expected, tokens);
+
+    let received = deer_toml::from_str::<T>(source, &Context::new()).expect("should deserialize");
+    assert_eq!(received, *expected);
+}
+
+#[allow(dead_code)] // False positive
+pub(crate) fn assert_toml_error<T>(error: &ErrorVec, source: &str, context: &Context)
+where
+    T: DeserializeOwned + Debug,
+{
+    let received =
+        deer_toml::from_str::<T>(source, context).expect_err("value of type T should fail");
+
+    assert_error(error, received);
+}
@@ -0,0 +1,185 @@
+use core::ops::{Bound, Range};
+
+use deer::{Context, Deserialize, Number};
+use deer_desert::{error, Token};
+use deer_toml::StackLimit;
+use serde_json::json;
+
+use crate::common::{assert_toml, assert_toml_error};
+
+mod common;
+
+#[test]
+fn array_ok() {
+    assert_toml(
+        &((1_u8, true)..(2_u8, false)),
+        "start = [1, true]\nend = [2, false]\n",
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("start"),
+            Token::Array { length: Some(2) },
+            Token::Number(Number::from(1)),
+            Token::Bool(true),
+            Token::ArrayEnd,
+            Token::Str("end"),
+            Token::Array { length: Some(2) },
+            Token::Number(Number::from(2)),
+            Token::Bool(false),
+            Token::ArrayEnd,
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn array_err_length() {
+    assert_toml_error::<Bound<(u8, u8)>>(
+        &error!([{
+            ns: "deer",
+            id: ["array", "length"],
+            properties: {
+                "expected": 2,
+                "received": 3,
+                "location": [{"type": "variant", "value": "Included"}]
+            }
+        }]),
+        "Included = [1, 2, 3]\n",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn table_ok() {
+    let tokens = [
+        Token::Object { length: Some(2) },
+        Token::Str("start"),
+        Token::Object { length: Some(2) },
+        Token::Str("start"),
+        Token::Number(Number::from(1)),
+        Token::Str("end"),
+        Token::Number(Number::from(2)),
+        Token::ObjectEnd,
+        Token::Str("end"),
+        Token::Object { length: Some(2) },
+        Token::Str("start"),
+        Token::Number(Number::from(3)),
+        Token::Str("end"),
+        Token::Number(Number::from(4)),
+        Token::ObjectEnd,
+        Token::ObjectEnd,
+    ];
+
+    let expected = (1_u8..2_u8)..(3_u8..4_u8);
+
+    assert_toml(
+        &expected,
+        "start = { start = 1, end = 2 }\nend = { start = 3, end = 4 }\n",
+        &tokens,
+    );
+    assert_toml(
+        &expected,
+        "[start]\nstart = 1\nend = 2\n\n[end]\nstart = 3\nend = 4\n",
+        &tokens,
+    );
+}
+
+#[test]
+fn table_err_missing() {
+    assert_toml_error::<Range<u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u8::reflection(),
+                "location": [{"type": "field", "value": "end"}]
+            }
+        }]),
+        "start = 1\n",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn array_of_tables_ok() {
+    assert_toml(
+        &((1_u8..2_u8,)..(3_u8..4_u8,)),
+        "end = [{ start = 3, end = 4 }]\n\n[[start]]\nstart = 1\nend = 2\n",
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("end"),
+            Token::Array { length: Some(1) },
+            Token::Object { length: Some(2) },
+            Token::Str("start"),
+            Token::Number(Number::from(3)),
+            Token::Str("end"),
+            Token::Number(Number::from(4)),
+            Token::ObjectEnd,
+            Token::ArrayEnd,
+            Token::Str("start"),
+            Token::Array { length: Some(1) },
+            Token::Object { length: Some(2) },
+            Token::Str("start"),
+            Token::Number(Number::from(1)),
+            Token::Str("end"),
+            Token::Number(Number::from(2)),
+            Token::ObjectEnd,
+            Token::ArrayEnd,
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn enum_ok() {
+    assert_toml(
+        &(Bound::Included(1_u8)..Bound::Unbounded),
+        "start = { Included = 1 }\nend = 'Unbounded'\n",
+        &[
+            Token::Object { length: Some(2) },
+            Token::Str("start"),
+            Token::Object { length: Some(1) },
+            Token::Str("Included"),
+            Token::Number(Number::from(1)),
+            Token::ObjectEnd,
+            Token::Str("end"),
+            Token::Str("Unbounded"),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn enum_err_length() {
+    assert_toml_error::<Bound<u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["object", "length"],
+            properties: {
+                "expected": 1,
+                "received": 2,
+                "location": []
+            }
+        }]),
+        "Included = 1\nExcluded = 2\n",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn stack_limit_err() {
+    let mut context = Context::new();
+    context.insert(StackLimit::new(2));
+
+    assert_toml_error::<Bound<(u8,)>>(
+        &error!([{
+            ns: "deer-toml",
+            id: ["recursion"],
+            properties: {
+                "span": {"start": 11, "end": 14},
+                "location": [{"type": "variant", "value": "Included"}]
+            }
+        }]),
+        "Included = [1]\n",
+        &context,
+    );
+}
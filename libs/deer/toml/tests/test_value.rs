@@ -0,0 +1,180 @@
+use core::ops::{Bound, Range};
+
+use deer::{error::ReportExt, Context, Deserialize, Number};
+use deer_desert::{assert_error, error, Token};
+use deer_toml::ImDocument;
+use serde_json::json;
+
+use crate::common::{assert_toml, assert_toml_error};
+
+mod common;
+
+const fn range(start: Token, end: Token) -> [Token; 6] {
+    [
+        Token::Object { length: Some(2) },
+        Token::Str("start"),
+        start,
+        Token::Str("end"),
+        end,
+        Token::ObjectEnd,
+    ]
+}
+
+#[test]
+fn bool_ok() {
+    assert_toml(
+        &(true..false),
+        "start = true\nend = false\n",
+        &range(Token::Bool(true), Token::Bool(false)),
+    );
+}
+
+#[test]
+fn bool_err_type() {
+    assert_toml_error::<Bound<bool>>(
+        &error!([{
+            ns: "deer",
+            id: ["type"],
+            properties: {
+                "expected": bool::reflection(),
+                "received": <&str>::reflection(),
+                "location": [{"type": "variant", "value": "Included"}]
+            }
+        }]),
+        "Included = 'true'\n",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn integer_ok() {
+    assert_toml(
+        &(-12_i8..0x1F),
+        "start = -12\nend = 0x1f\n",
+        &range(
+            Token::Number(Number::from(-12)),
+            Token::Number(Number::from(31)),
+        ),
+    );
+}
+
+#[test]
+fn integer_err_value() {
+    assert_toml_error::<Bound<u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{"type": "variant", "value": "Included"}]
+            }
+        }]),
+        "Included = 256\n",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn float_ok() {
+    assert_toml(
+        &(1.5_f64..-2e3),
+        "start = 1.5\nend = -2e3\n",
+        &range(
+            Token::Number(Number::from(1.5)),
+            Token::Number(Number::from(-2e3)),
+        ),
+    );
+}
+
+#[test]
+fn string_ok() {
+    assert_toml(
+        &('a'..'b'),
+        "start = \"a\"\nend = 'b'\n",
+        &range(Token::Char('a'), Token::Char('b')),
+    );
+}
+
+#[test]
+fn string_borrowed_ok() {
+    let document = ImDocument::parse("start = \"a\"\nend = \"b\"\n").expect("should parse");
+
+    let received: Range<&str> =
+        deer_toml::from_document(&document, &Context::new()).expect("should deserialize");
+
+    assert_eq!(received, "a".."b");
+}
+
+#[test]
+fn optional_ok() {
+    // TOML has no `null`, optional values are omitted instead
+    assert_toml(
+        &(Some(1_u8)..None),
+        "start = 1\n",
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("start"),
+            Token::Number(Number::from(1)),
+            Token::ObjectEnd,
+        ],
+    );
+}
+
+#[test]
+fn null_err_type() {
+    assert_toml_error::<Bound<()>>(
+        &error!([{
+            ns: "deer",
+            id: ["type"],
+            properties: {
+                "expected": <()>::reflection(),
+                "received": Number::reflection(),
+                "location": [{"type": "variant", "value": "Included"}]
+            }
+        }]),
+        "Included = 0\n",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn bytes_err() {
+    let document = ImDocument::parse("Included = 'a'\n").expect("should parse");
+
+    let received = deer_toml::from_document::<Bound<&[u8]>, _>(&document, &Context::new())
+        .expect_err("bytes are unsupported");
+
+    assert_error(
+        &error!([{
+            ns: "deer-toml",
+            id: ["bytes"],
+            properties: {
+                "span": {"start": 11, "end": 14},
+                "location": [{"type": "variant", "value": "Included"}]
+            }
+        }]),
+        received,
+    );
+}
+
+#[test]
+fn syntax_err() {
+    let received = deer_toml::from_str::<Range<u8>>("start = \nend = 1\n", &Context::new())
+        .expect_err("missing value should fail");
+    let received = serde_json::to_value(received.export()).expect("error should serialize");
+
+    // the end of the span depends on how far the parser reads ahead, only assert where it starts
+    let [error] = received
+        .as_array()
+        .expect("errors should be an array")
+        .as_slice()
+    else {
+        panic!("expected exactly one error, received {received}");
+    };
+
+    assert_eq!(error["namespace"], json!("deer-toml"));
+    assert_eq!(error["id"], json!(["syntax"]));
+    assert_eq!(error["properties"]["span"]["start"], json!(8));
+    assert_eq!(error["properties"]["location"], json!([]));
+}
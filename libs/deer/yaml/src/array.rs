@@ -0,0 +1,77 @@
+use deer::{
+    error::{ArrayAccessError, ArrayLengthError, DeserializerError},
+    Context, Deserialize, Deserializer as _,
+};
+use error_stack::{Result, ResultExt};
+use yaml_rust2::Event;
+
+use crate::deserializer::Deserializer;
+
+pub(crate) struct ArrayAccess<'a, 'b, 'de: 'a> {
+    deserializer: &'a mut Deserializer<'b, 'de>,
+
+    dirty: bool,
+    expected: usize,
+}
+
+impl<'a, 'b, 'de: 'a> ArrayAccess<'a, 'b, 'de> {
+    pub(crate) fn new(
+        deserializer: &'a mut Deserializer<'b, 'de>,
+    ) -> Result<Self, DeserializerError> {
+        deserializer.try_stack_push()?;
+
+        Ok(Self {
+            deserializer,
+            dirty: false,
+            expected: 0,
+        })
+    }
+}
+
+impl<'de> deer::ArrayAccess<'de> for ArrayAccess<'_, '_, 'de> {
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        self.deserializer.context()
+    }
+
+    fn next<T>(&mut self) -> Option<Result<T, ArrayAccessError>>
+    where
+        T: Deserialize<'de>,
+    {
+        self.dirty = true;
+
+        // we check for `None` here because we could be EOF, in that case we're "done", we will
+        // error out at `.end()`
+        match self.deserializer.events.peek() {
+            None | Some((Event::SequenceEnd, _)) => return None,
+            Some(_) => {}
+        }
+
+        self.expected += 1;
+
+        let value = T::deserialize(&mut *self.deserializer);
+
+        Some(value.change_context(ArrayAccessError))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        None
+    }
+
+    fn end(self) -> Result<(), ArrayAccessError> {
+        self.deserializer.stack.pop();
+
+        let result = match self.deserializer.events.peek() {
+            None => Err(self.deserializer.events.eof()),
+            Some((Event::SequenceEnd, _)) => Ok(()),
+            Some(_) => Err(ArrayLengthError::new(&self, self.expected)),
+        };
+
+        self.deserializer.skip_nested();
+
+        result.change_context(ArrayAccessError)
+    }
+}
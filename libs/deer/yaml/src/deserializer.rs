@@ -0,0 +1,424 @@
+use deer::{
+    error::{
+        DeserializerError, ExpectedLength, ExpectedType, ObjectLengthError, ReceivedType,
+        TypeError, Variant,
+    },
+    schema::Document,
+    value::NoneDeserializer,
+    Context, Deserialize, EnumVisitor, IdentifierVisitor, Number, OptionalVisitor, Reflection,
+    StructVisitor, Visitor,
+};
+use error_stack::{Report, Result, ResultExt};
+use yaml_rust2::Event;
+
+use crate::{
+    array::ArrayAccess,
+    error::{BytesUnsupportedError, Position, RecursionLimitError, SyntaxError},
+    events::Events,
+    object::ObjectAccess,
+    token::{is_null_event, Scalar, ValueToken},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct StackLimit(usize);
+
+impl StackLimit {
+    #[must_use]
+    pub const fn new(limit: usize) -> Self {
+        Self(limit)
+    }
+
+    #[must_use]
+    pub const fn limit(self) -> usize {
+        self.0
+    }
+}
+
+pub(crate) struct Stack {
+    limit: usize,
+    depth: usize,
+}
+
+impl Stack {
+    const fn new(limit: usize) -> Self {
+        Self { limit, depth: 0 }
+    }
+
+    pub(crate) fn push(&mut self, position: Position) -> Result<(), DeserializerError> {
+        self.depth += 1;
+
+        if self.depth >= self.limit {
+            Err(Report::new(RecursionLimitError.into_error())
+                .attach(position)
+                .change_context(DeserializerError))
+        } else {
+            Ok(())
+        }
+    }
+
+    pub(crate) fn pop(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+}
+
+pub struct Deserializer<'a, 'de> {
+    pub(crate) events: Events<'de>,
+
+    context: &'a Context,
+    pub(crate) stack: Stack,
+}
+
+impl<'a, 'de> Deserializer<'a, 'de> {
+    #[must_use]
+    pub fn new(value: &'de str, context: &'a Context) -> Self {
+        let limit = context
+            .request_ref::<StackLimit>()
+            .map_or(usize::MAX, |limit| limit.limit());
+
+        Self {
+            events: Events::new(value),
+            context,
+            stack: Stack::new(limit),
+        }
+    }
+
+    /// Consume the next event if it is a scalar.
+    fn next_scalar(&mut self) -> Option<Scalar> {
+        if !matches!(self.events.peek(), Some((Event::Scalar(..), _))) {
+            return None;
+        }
+
+        match self.events.next() {
+            Some(Ok((Event::Scalar(value, style, _, tag), marker))) => {
+                Some(Scalar::new(value, style, tag.as_ref(), marker))
+            }
+            // we peeked the event before, therefore we know that the next event is a scalar
+            _ => unreachable!(),
+        }
+    }
+
+    fn next_value(&mut self) -> Result<ValueToken, DeserializerError> {
+        if let Some(scalar) = self.next_scalar() {
+            return scalar.resolve().change_context(DeserializerError);
+        }
+
+        let token = match self.events.peek() {
+            None => return Err(self.events.eof().change_context(DeserializerError)),
+            Some((Event::SequenceStart(..), _)) => ValueToken::Sequence,
+            Some((Event::MappingStart(..), _)) => ValueToken::Mapping,
+            // do not consume the end event, this way the surrounding sequence or mapping is still
+            // able to finish
+            Some((_, marker)) => {
+                return Err(Report::new(SyntaxError::UnexpectedEnd.into_error())
+                    .attach(Position::from(*marker))
+                    .change_context(DeserializerError));
+            }
+        };
+
+        _ = self.events.next();
+
+        Ok(token)
+    }
+
+    /// Skip all events until the end of the current sequence or mapping, including the end event.
+    pub(crate) fn skip_nested(&mut self) {
+        let mut depth: usize = 0;
+
+        // errors are not recoverable, meaning that the stream will end after an error
+        while let Some(Ok((event, _))) = self.events.next() {
+            match event {
+                Event::SequenceStart(..) | Event::MappingStart(..) => depth += 1,
+                Event::SequenceEnd | Event::MappingEnd if depth == 0 => return,
+                Event::SequenceEnd | Event::MappingEnd => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+
+    pub(crate) fn recover(&mut self, token: &ValueToken) {
+        if matches!(token, ValueToken::Mapping | ValueToken::Sequence) {
+            self.skip_nested();
+        }
+    }
+
+    /// Skip the next value.
+    pub(crate) fn skip(&mut self) {
+        if let Some(Ok((Event::SequenceStart(..) | Event::MappingStart(..), _))) =
+            self.events.next()
+        {
+            self.skip_nested();
+        }
+    }
+
+    pub(crate) fn try_stack_push(&mut self) -> Result<(), DeserializerError> {
+        if let Err(error) = self.stack.push(self.events.position()) {
+            // we can still recover, we pop us again from the stack as we stopped before and do not
+            // commit. We still show the error, but we could continue, so we skip all events.
+            self.stack.pop();
+            self.skip_nested();
+
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    fn error_invalid_type(
+        &mut self,
+        received: &ValueToken,
+        expected: Document,
+    ) -> Report<DeserializerError> {
+        self.recover(received);
+
+        Report::new(TypeError.into_error())
+            .attach(ExpectedType::new(expected))
+            .attach(ReceivedType::new(received.schema()))
+            .change_context(DeserializerError)
+    }
+}
+
+impl<'de> deer::Deserializer<'de> for &mut Deserializer<'_, 'de> {
+    fn context(&self) -> &Context {
+        self.context
+    }
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Null => visitor.visit_null(),
+            ValueToken::Bool(value) => visitor.visit_bool(value),
+            ValueToken::String(value) => visitor.visit_string(value),
+            ValueToken::Number(value) => visitor.visit_number(value),
+            ValueToken::Mapping => visitor.visit_object(ObjectAccess::new(self)?),
+            ValueToken::Sequence => visitor.visit_array(ArrayAccess::new(self)?),
+        }
+        .change_context(DeserializerError)
+    }
+
+    fn deserialize_null<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Null => visitor.visit_null().change_context(DeserializerError),
+            token => Err(self.error_invalid_type(&token, <()>::reflection())),
+        }
+    }
+
+    fn deserialize_bool<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Bool(value) => visitor.visit_bool(value).change_context(DeserializerError),
+            token => Err(self.error_invalid_type(&token, bool::reflection())),
+        }
+    }
+
+    fn deserialize_number<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Number(value) => visitor
+                .visit_number(value)
+                .change_context(DeserializerError),
+            token => Err(self.error_invalid_type(&token, Number::reflection())),
+        }
+    }
+
+    fn deserialize_char<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_string<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_str<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        // In YAML every scalar can be interpreted as a string, e.g. `version: 1.0` should be
+        // deserializable as `"1.0"` if a string is requested.
+        if let Some(scalar) = self.next_scalar() {
+            return visitor
+                .visit_string(scalar.value)
+                .change_context(DeserializerError);
+        }
+
+        let token = self.next_value()?;
+        Err(self.error_invalid_type(&token, str::document()))
+    }
+
+    fn deserialize_bytes<V>(self, _: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let position = self.events.position();
+        self.skip();
+
+        Err(Report::new(BytesUnsupportedError.into_error())
+            .attach(position)
+            .change_context(DeserializerError))
+    }
+
+    fn deserialize_bytes_buffer<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        self.deserialize_bytes(visitor)
+    }
+
+    fn deserialize_array<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Sequence => visitor
+                .visit_array(ArrayAccess::new(self)?)
+                .change_context(DeserializerError),
+            token => Err(self.error_invalid_type(&token, ValueToken::Sequence.schema())),
+        }
+    }
+
+    fn deserialize_object<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: Visitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Mapping => visitor
+                .visit_object(ObjectAccess::new(self)?)
+                .change_context(DeserializerError),
+            token => Err(self.error_invalid_type(&token, ValueToken::Mapping.schema())),
+        }
+    }
+
+    fn deserialize_optional<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: OptionalVisitor<'de>,
+    {
+        let is_null = match self.events.peek() {
+            None => return Err(self.events.eof().change_context(DeserializerError)),
+            Some((event, _)) => is_null_event(event),
+        };
+
+        if is_null {
+            // we know the value will be `null`, therefore we can just discard the next event
+            _ = self.events.next();
+
+            visitor.visit_null().change_context(DeserializerError)
+        } else {
+            visitor.visit_some(self).change_context(DeserializerError)
+        }
+    }
+
+    fn deserialize_enum<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: EnumVisitor<'de>,
+    {
+        let is_map = match self.events.peek() {
+            Some((Event::MappingStart(..), _)) => {
+                // eat the event, so that we're at the key (that we need)
+                _ = self.events.next();
+                true
+            }
+            Some(_) => false,
+            None => return Err(self.events.eof().change_context(DeserializerError)),
+        };
+
+        let result = visitor
+            .visit_discriminant(&mut *self)
+            .change_context(DeserializerError);
+
+        if is_map && result.is_err() {
+            // the key is an error, we need to swallow the value
+            self.skip();
+        }
+
+        let discriminant = result?;
+
+        let mut value = if is_map {
+            visitor
+                .visit_value(discriminant, &mut *self)
+                .change_context(DeserializerError)
+        } else {
+            visitor
+                .visit_value(discriminant, NoneDeserializer::new(self.context))
+                .change_context(DeserializerError)
+        };
+
+        if is_map {
+            if matches!(self.events.peek(), Some((Event::MappingEnd, _))) {
+                // we can safely continue
+                _ = self.events.next();
+            } else {
+                // we have received multiple entries, error out
+                // make sure we close the mapping
+                self.skip_nested();
+
+                let error = Report::new(ObjectLengthError.into_error())
+                    .attach(ExpectedLength::new(1))
+                    .change_context(DeserializerError);
+
+                match &mut value {
+                    Err(value) => value.extend_one(error),
+                    value => *value = Err(error),
+                }
+            }
+        }
+
+        value
+    }
+
+    fn deserialize_struct<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: StructVisitor<'de>,
+    {
+        let token = self.next_value()?;
+
+        match token {
+            ValueToken::Mapping => visitor
+                .visit_object(ObjectAccess::new(self)?)
+                .change_context(DeserializerError),
+
+            token => Err(self.error_invalid_type(&token, ValueToken::Mapping.schema())),
+        }
+    }
+
+    fn deserialize_identifier<V>(self, visitor: V) -> Result<V::Value, DeserializerError>
+    where
+        V: IdentifierVisitor<'de>,
+    {
+        if let Some(scalar) = self.next_scalar() {
+            return match scalar.as_u64() {
+                Some(value) => visitor.visit_u64(value),
+                None => visitor.visit_str(&scalar.value),
+            }
+            .change_context(DeserializerError);
+        }
+
+        let token = self.next_value()?;
+        Err(self.error_invalid_type(&token, str::document()))
+    }
+}
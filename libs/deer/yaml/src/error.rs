@@ -0,0 +1,185 @@
+use core::fmt::{Display, Formatter};
+
+use deer::{
+    error::{ErrorProperties, ErrorProperty, Id, Location, Namespace, ReceivedValue, Variant},
+    id,
+};
+use error_stack::Report;
+use yaml_rust2::{scanner::Marker, ScanError};
+
+const NAMESPACE: Namespace = Namespace::new("deer-yaml");
+
+#[derive(Debug)]
+pub(crate) struct RecursionLimitError;
+
+impl Display for RecursionLimitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        // This message is vague by design to not encourage abuse from the consumers
+        f.write_str("Recursion limit has been exceeded")
+    }
+}
+
+impl Variant for RecursionLimitError {
+    type Properties = (Location, Position);
+
+    const ID: Id = id!["recursion"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        _: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        Display::fmt(self, fmt)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct BytesUnsupportedError;
+
+impl Display for BytesUnsupportedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("YAML does not support bytes")
+    }
+}
+
+impl Variant for BytesUnsupportedError {
+    type Properties = (Location, Position);
+
+    const ID: Id = id!["bytes"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        _: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        fmt.write_str("deer-yaml does not support deserialization of bytes")
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum NumberError {
+    Overflow,
+    Underflow,
+}
+
+impl Display for NumberError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Overflow => f.write_str("number too large"),
+            Self::Underflow => f.write_str("number too small"),
+        }
+    }
+}
+
+impl Variant for NumberError {
+    type Properties = (Location, Position, ReceivedValue);
+
+    const ID: Id = id!["number"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        properties: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        let position = properties.1;
+
+        if let Some(position) = position {
+            fmt.write_fmt(format_args!("{self} at {position}"))
+        } else {
+            Display::fmt(self, fmt)
+        }
+    }
+}
+
+/// Position in the source document, lines and columns are 1-indexed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize)]
+pub(crate) struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl Position {
+    pub(crate) const START: Self = Self { line: 1, column: 1 };
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let Self { line, column } = self;
+
+        f.write_fmt(format_args!("line {line} column {column}"))
+    }
+}
+
+impl From<Marker> for Position {
+    fn from(marker: Marker) -> Self {
+        Self {
+            line: marker.line(),
+            // `yaml-rust2` columns are 0-indexed, while lines are 1-indexed
+            column: marker.col() + 1,
+        }
+    }
+}
+
+impl ErrorProperty for Position {
+    type Value<'a> = Option<&'a Self> where Self: 'a ;
+
+    fn key() -> &'static str {
+        "position"
+    }
+
+    fn value<'a>(mut stack: impl Iterator<Item = &'a Self>) -> Self::Value<'a> {
+        stack.next()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum SyntaxError {
+    UnexpectedEof,
+    UnexpectedEnd,
+    RecursiveAlias,
+    AliasLimitExceeded,
+    Scanner(String),
+}
+
+impl Display for SyntaxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnexpectedEof => f.write_str("unexpected end of file"),
+            Self::UnexpectedEnd => f.write_str("unexpected end of sequence or mapping"),
+            Self::RecursiveAlias => f.write_str("alias refers to the node it is contained in"),
+            Self::AliasLimitExceeded => {
+                f.write_str("aliases expand to too many events compared to the document size")
+            }
+            Self::Scanner(info) => f.write_str(info),
+        }
+    }
+}
+
+impl Variant for SyntaxError {
+    type Properties = (Location, Position);
+
+    const ID: Id = id!["syntax"];
+    const NAMESPACE: Namespace = NAMESPACE;
+
+    fn message(
+        &self,
+        fmt: &mut Formatter,
+        properties: &<Self::Properties as ErrorProperties>::Value<'_>,
+    ) -> core::fmt::Result {
+        let position = properties.1;
+
+        if let Some(position) = position {
+            fmt.write_fmt(format_args!("{self} at {position}"))
+        } else {
+            Display::fmt(self, fmt)
+        }
+    }
+}
+
+pub(crate) fn convert_scan_error(error: &ScanError) -> Report<deer::error::Error> {
+    Report::new(SyntaxError::Scanner(error.info().to_owned()).into_error())
+        .attach(Position::from(*error.marker()))
+}
@@ -0,0 +1,248 @@
+use alloc::collections::VecDeque;
+use core::str::Chars;
+use std::collections::HashMap;
+
+use deer::error::{Error, Variant};
+use error_stack::{Report, Result};
+use yaml_rust2::{
+    parser::{Event, Parser},
+    scanner::Marker,
+};
+
+use crate::error::{convert_scan_error, Position, SyntaxError};
+
+/// The number of events aliases may expand to, relative to the number of events in the document.
+///
+/// Every alias replays all events of the node it refers to, nested aliases therefore grow
+/// exponentially ("billion laughs"), this limits the work done for a document of a given size.
+const ALIAS_EXPANSION_FACTOR: usize = 100;
+
+/// A node that has an anchor (`&name`) attached and is still being consumed.
+struct Recording {
+    anchor: usize,
+    depth: usize,
+    events: Vec<(Event, Marker)>,
+}
+
+/// Stream of events of a single YAML document.
+///
+/// Document and stream markers are skipped and aliases (`*name`) are expanded into the events of
+/// the node they refer to, meaning that consumers only ever see scalars, sequences and mappings.
+/// The events replayed for aliases are limited by [`ALIAS_EXPANSION_FACTOR`].
+pub(crate) struct Events<'de> {
+    parser: Parser<Chars<'de>>,
+
+    peeked: Option<Result<(Event, Marker), Error>>,
+    replay: VecDeque<(Event, Marker)>,
+
+    anchors: HashMap<usize, Vec<(Event, Marker)>>,
+    recordings: Vec<Recording>,
+
+    /// Number of events received from the parser
+    parsed: usize,
+    /// Number of events replayed for aliases
+    expanded: usize,
+
+    marker: Option<Marker>,
+    done: bool,
+}
+
+impl<'de> Events<'de> {
+    pub(crate) fn new(value: &'de str) -> Self {
+        Self {
+            parser: Parser::new_from_str(value),
+            peeked: None,
+            replay: VecDeque::new(),
+            anchors: HashMap::new(),
+            recordings: Vec::new(),
+            parsed: 0,
+            expanded: 0,
+            marker: None,
+            done: false,
+        }
+    }
+
+    fn expand(&mut self, anchor: usize) -> Result<(), Error> {
+        let Some(events) = self.anchors.get(&anchor) else {
+            // the parser already verified that the anchor exists, this means that we're still
+            // recording it, therefore the alias is contained in the node it refers to
+            return Err(
+                Report::new(SyntaxError::RecursiveAlias.into_error()).attach(self.position())
+            );
+        };
+
+        self.expanded += events.len();
+        if self.expanded > self.parsed.saturating_mul(ALIAS_EXPANSION_FACTOR) {
+            return Err(
+                Report::new(SyntaxError::AliasLimitExceeded.into_error()).attach(self.position())
+            );
+        }
+
+        for event in events.iter().rev() {
+            self.replay.push_front(event.clone());
+        }
+
+        Ok(())
+    }
+
+    fn pull(&mut self) -> Option<Result<(Event, Marker), Error>> {
+        loop {
+            let (event, marker) = if let Some(event) = self.replay.pop_front() {
+                event
+            } else {
+                if self.done {
+                    return None;
+                }
+
+                match self.parser.next_token() {
+                    Ok(event) => {
+                        self.parsed += 1;
+                        event
+                    }
+                    Err(error) => {
+                        // the parser is unable to recover from errors, every subsequent call would
+                        // return garbage
+                        self.done = true;
+                        return Some(Err(convert_scan_error(&error)));
+                    }
+                }
+            };
+
+            self.marker = Some(marker);
+
+            match event {
+                Event::Nothing | Event::StreamStart | Event::DocumentStart | Event::DocumentEnd => {
+                }
+                Event::StreamEnd => {
+                    self.done = true;
+                    return None;
+                }
+                Event::Alias(anchor) => {
+                    if let Err(error) = self.expand(anchor) {
+                        return Some(Err(error));
+                    }
+                }
+                event => return Some(Ok((event, marker))),
+            }
+        }
+    }
+
+    fn record(&mut self, event: &Event, marker: Marker) {
+        for recording in &mut self.recordings {
+            recording.events.push((event.clone(), marker));
+
+            match event {
+                Event::SequenceStart(..) | Event::MappingStart(..) => recording.depth += 1,
+                Event::SequenceEnd | Event::MappingEnd => recording.depth -= 1,
+                _ => {}
+            }
+        }
+
+        while let Some(recording) = self.recordings.pop() {
+            if recording.depth > 0 {
+                self.recordings.push(recording);
+                break;
+            }
+
+            self.anchors.insert(recording.anchor, recording.events);
+        }
+
+        match *event {
+            Event::Scalar(_, _, anchor, _) if anchor > 0 => {
+                self.anchors.insert(anchor, vec![(event.clone(), marker)]);
+            }
+            Event::SequenceStart(anchor, _) | Event::MappingStart(anchor, _) if anchor > 0 => {
+                self.recordings.push(Recording {
+                    anchor,
+                    depth: 1,
+                    events: vec![(event.clone(), marker)],
+                });
+            }
+            _ => {}
+        }
+    }
+
+    /// Position of the last event that has been pulled from the parser.
+    pub(crate) fn position(&self) -> Position {
+        self.marker.map_or(Position::START, Position::from)
+    }
+
+    /// Peek at the next event, `None` if the end of the document has been reached or the next
+    /// event is an error, use [`Self::eof`] to create the appropriate error.
+    pub(crate) fn peek(&mut self) -> Option<&(Event, Marker)> {
+        if self.peeked.is_none() {
+            // once the end has been reached `pull` will always return `None`, therefore it is safe
+            // to call it again
+            self.peeked = self.pull();
+        }
+
+        self.peeked.as_ref()?.as_ref().ok()
+    }
+
+    pub(crate) fn next(&mut self) -> Option<Result<(Event, Marker), Error>> {
+        let event = self.peeked.take().or_else(|| self.pull());
+
+        if let Some(Ok((event, marker))) = &event {
+            self.record(event, *marker);
+        }
+
+        event
+    }
+
+    /// Create the error for an unexpected end of the document.
+    ///
+    /// If the document ended because of a syntax error, the syntax error is returned instead.
+    pub(crate) fn eof(&mut self) -> Report<Error> {
+        if let Some(Err(_)) = &self.peeked {
+            if let Some(Err(error)) = self.peeked.take() {
+                return error;
+            }
+        }
+
+        Report::new(SyntaxError::UnexpectedEof.into_error()).attach(self.position())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::iter::once;
+
+    use super::Events;
+    use crate::error::SyntaxError;
+
+    fn is_alias_limit(value: &str) -> bool {
+        let mut events = Events::new(value);
+
+        while let Some(event) = events.next() {
+            if let Err(error) = event {
+                return matches!(
+                    error.current_context().downcast_ref::<SyntaxError>(),
+                    Some(SyntaxError::AliasLimitExceeded)
+                );
+            }
+        }
+
+        false
+    }
+
+    #[test]
+    fn alias_within_limit() {
+        assert!(!is_alias_limit("a: &a [1, 2]\nb: [*a, *a, *a]\nc: *a\n"));
+    }
+
+    #[test]
+    fn alias_limit_exceeded() {
+        // every level refers to the previous level ten times, the last level expands to 10^9
+        // scalars
+        let levels = (1..10).map(|level| {
+            let aliases = vec![format!("*l{}", level - 1); 10].join(", ");
+            format!("l{level}: &l{level} [{aliases}]")
+        });
+        let document = once(format!("l0: &l0 [{}]", ["lol"; 10].join(", ")))
+            .chain(levels)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(is_alias_limit(&document));
+    }
+}
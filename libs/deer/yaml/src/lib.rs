@@ -0,0 +1,31 @@
+//! `deer` deserializer for YAML.
+//!
+//! Scalars are resolved according to the [YAML 1.2 core schema], with the exception that every
+//! scalar can be deserialized as a string. Anchors and aliases are supported, an alias is expanded
+//! into the node it refers to. Only the first document of a stream is deserialized.
+//!
+//! [YAML 1.2 core schema]: https://yaml.org/spec/1.2.2/#1023-core-schema
+// TODO: once more stable introduce: warning missing_docs, clippy::missing_errors_doc
+#![deny(unsafe_code)]
+mod array;
+mod deserializer;
+mod error;
+mod events;
+mod object;
+mod token;
+
+extern crate alloc;
+
+use deer::{error::DeserializeError, Context, Deserialize};
+use error_stack::Result;
+
+pub use crate::deserializer::{Deserializer, StackLimit};
+
+pub fn from_str<'de, T>(value: &'de str, context: &Context) -> Result<T, DeserializeError>
+where
+    T: Deserialize<'de>,
+{
+    let mut deserializer = Deserializer::new(value, context);
+
+    T::deserialize(&mut deserializer)
+}
@@ -0,0 +1,93 @@
+use deer::{
+    error::{DeserializerError, ObjectAccessError, ObjectLengthError},
+    Context, Deserializer as _, FieldVisitor,
+};
+use error_stack::{Result, ResultExt};
+use yaml_rust2::Event;
+
+use crate::deserializer::Deserializer;
+
+pub(crate) struct ObjectAccess<'a, 'b, 'de: 'a> {
+    deserializer: &'a mut Deserializer<'b, 'de>,
+
+    dirty: bool,
+    expected: usize,
+}
+
+impl<'a, 'b, 'de: 'a> ObjectAccess<'a, 'b, 'de> {
+    pub(crate) fn new(
+        deserializer: &'a mut Deserializer<'b, 'de>,
+    ) -> Result<Self, DeserializerError> {
+        deserializer.try_stack_push()?;
+
+        Ok(Self {
+            deserializer,
+
+            dirty: false,
+            expected: 0,
+        })
+    }
+}
+
+impl<'de> deer::ObjectAccess<'de> for ObjectAccess<'_, '_, 'de> {
+    fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    fn context(&self) -> &Context {
+        self.deserializer.context()
+    }
+
+    fn try_field<F>(
+        &mut self,
+        visitor: F,
+    ) -> core::result::Result<Result<F::Value, ObjectAccessError>, F>
+    where
+        F: FieldVisitor<'de>,
+    {
+        self.dirty = true;
+
+        // we check for `None` here because we could be EOF, in that case we still error out with
+        // the visitor as we're "done".
+        match self.deserializer.events.peek() {
+            None | Some((Event::MappingEnd, _)) => return Err(visitor),
+            Some(_) => {}
+        }
+
+        self.expected += 1;
+
+        // YAML allows any value as key, it is up to the visitor to decide if the key is valid
+        let key = visitor.visit_key(&mut *self.deserializer);
+
+        let result = match key {
+            Ok(key) => visitor
+                .visit_value(key, &mut *self.deserializer)
+                .change_context(ObjectAccessError),
+            Err(error) => {
+                self.deserializer.skip(); // skip value
+
+                Err(error.change_context(ObjectAccessError))
+            }
+        };
+
+        Ok(result)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        None
+    }
+
+    fn end(self) -> Result<(), ObjectAccessError> {
+        self.deserializer.stack.pop();
+
+        let result = match self.deserializer.events.peek() {
+            None => Err(self.deserializer.events.eof()),
+            Some((Event::MappingEnd, _)) => Ok(()),
+            Some(_) => Err(ObjectLengthError::new(&self, self.expected)),
+        };
+
+        self.deserializer.skip_nested();
+
+        result.change_context(ObjectAccessError)
+    }
+}
@@ -0,0 +1,267 @@
+use deer::{
+    error::{Error, ReceivedValue, Variant},
+    Deserialize, Document, Number, Reflection, Schema,
+};
+use error_stack::{Report, Result};
+use yaml_rust2::{
+    parser::{Event, Tag},
+    scanner::{Marker, TScalarStyle},
+};
+
+use crate::error::{NumberError, Position};
+
+/// A scalar, before it has been resolved into a value.
+pub(crate) struct Scalar {
+    pub(crate) value: String,
+    plain: bool,
+    marker: Marker,
+}
+
+fn is_plain(style: TScalarStyle, tag: Option<&Tag>) -> bool {
+    // `!!str` forces a plain scalar to be a string, every other tag is resolved the same way as
+    // an untagged scalar
+    let is_str = tag.is_some_and(|tag| tag.handle == "!!" && tag.suffix == "str");
+
+    style == TScalarStyle::Plain && !is_str
+}
+
+fn is_null(value: &str) -> bool {
+    matches!(value, "" | "~" | "null" | "Null" | "NULL")
+}
+
+/// Checks if the event is a scalar that resolves to `null`, without consuming it.
+pub(crate) fn is_null_event(event: &Event) -> bool {
+    match event {
+        Event::Scalar(value, style, _, tag) => is_plain(*style, tag.as_ref()) && is_null(value),
+        _ => false,
+    }
+}
+
+impl Scalar {
+    pub(crate) fn new(
+        value: String,
+        style: TScalarStyle,
+        tag: Option<&Tag>,
+        marker: Marker,
+    ) -> Self {
+        Self {
+            value,
+            plain: is_plain(style, tag),
+            marker,
+        }
+    }
+
+    /// Interpret the scalar as an unsigned integer, used for identifiers.
+    pub(crate) fn as_u64(&self) -> Option<u64> {
+        if !self.plain {
+            return None;
+        }
+
+        match parse_integer(&self.value) {
+            Some((false, Ok(value))) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Resolve the scalar according to the [YAML 1.2 core schema].
+    ///
+    /// [YAML 1.2 core schema]: https://yaml.org/spec/1.2.2/#1023-core-schema
+    pub(crate) fn resolve(self) -> Result<ValueToken, Error> {
+        if !self.plain {
+            return Ok(ValueToken::String(self.value));
+        }
+
+        if is_null(&self.value) {
+            return Ok(ValueToken::Null);
+        }
+
+        match self.value.as_str() {
+            "true" | "True" | "TRUE" => return Ok(ValueToken::Bool(true)),
+            "false" | "False" | "FALSE" => return Ok(ValueToken::Bool(false)),
+            ".inf" | ".Inf" | ".INF" | "+.inf" | "+.Inf" | "+.INF" => {
+                return Ok(ValueToken::Number(Number::from(f64::INFINITY)));
+            }
+            "-.inf" | "-.Inf" | "-.INF" => {
+                return Ok(ValueToken::Number(Number::from(f64::NEG_INFINITY)));
+            }
+            ".nan" | ".NaN" | ".NAN" => return Ok(ValueToken::Number(Number::from(f64::NAN))),
+            _ => {}
+        }
+
+        if let Some((negative, value)) = parse_integer(&self.value) {
+            return value
+                .map_err(|error| {
+                    Report::new(error.into_error())
+                        .attach(Position::from(self.marker))
+                        .attach(ReceivedValue::new(self.value.clone()))
+                })
+                .and_then(|value| {
+                    if !negative {
+                        return Ok(Number::from(value));
+                    }
+
+                    // `i64::MIN` has no positive counterpart, therefore we need to negate the
+                    // magnitude in a wider type
+                    i64::try_from(-i128::from(value))
+                        .map(Number::from)
+                        .map_err(|_error| {
+                            Report::new(NumberError::Underflow.into_error())
+                                .attach(Position::from(self.marker))
+                                .attach(ReceivedValue::new(self.value.clone()))
+                        })
+                })
+                .map(ValueToken::Number);
+        }
+
+        if is_float(&self.value) {
+            // `is_float` ensures that the syntax is valid, `f64` saturates to infinity
+            if let Ok(value) = self.value.parse::<f64>() {
+                return Ok(ValueToken::Number(Number::from(value)));
+            }
+        }
+
+        Ok(ValueToken::String(self.value))
+    }
+}
+
+/// Parses an integer (decimal, octal with `0o` or hexadecimal with `0x` prefix).
+///
+/// Returns `None` if the value is not an integer, otherwise if the value is negative and the
+/// magnitude.
+fn parse_integer(value: &str) -> Option<(bool, core::result::Result<u64, NumberError>)> {
+    let (negative, unsigned) = value
+        .strip_prefix('-')
+        .map(|unsigned| (true, unsigned))
+        .or_else(|| value.strip_prefix('+').map(|unsigned| (false, unsigned)))
+        .unwrap_or((false, value));
+
+    let (radix, digits) = unsigned
+        .strip_prefix("0x")
+        .map(|digits| (16, digits))
+        .or_else(|| unsigned.strip_prefix("0o").map(|digits| (8, digits)))
+        .unwrap_or((10, unsigned));
+
+    // the sign is only allowed for decimal integers
+    if digits.is_empty()
+        || (radix != 10 && unsigned.len() != value.len())
+        || !digits.chars().all(|char| char.is_digit(radix))
+    {
+        return None;
+    }
+
+    let magnitude = u64::from_str_radix(digits, radix).map_err(|_error| {
+        if negative {
+            NumberError::Underflow
+        } else {
+            NumberError::Overflow
+        }
+    });
+
+    Some((negative, magnitude))
+}
+
+/// Checks if the value is a float, as defined by
+/// `[-+]?(\.[0-9]+|[0-9]+(\.[0-9]*)?)([eE][-+]?[0-9]+)?`
+fn is_float(value: &str) -> bool {
+    let value = value.strip_prefix(['-', '+']).unwrap_or(value);
+
+    let (mantissa, exponent) = value
+        .split_once(['e', 'E'])
+        .map_or((value, None), |(mantissa, exponent)| {
+            (mantissa, Some(exponent))
+        });
+
+    let is_digits = |value: &str| value.bytes().all(|byte| byte.is_ascii_digit());
+
+    let mantissa = match mantissa.split_once('.') {
+        Some((integer, fraction)) => {
+            (!integer.is_empty() || !fraction.is_empty())
+                && is_digits(integer)
+                && is_digits(fraction)
+        }
+        None => !mantissa.is_empty() && is_digits(mantissa),
+    };
+
+    let exponent = exponent.map_or(true, |exponent| {
+        let exponent = exponent.strip_prefix(['-', '+']).unwrap_or(exponent);
+
+        !exponent.is_empty() && is_digits(exponent)
+    });
+
+    mantissa && exponent
+}
+
+// This is like `yaml_rust2::Event` but does not contain syntax events like `SequenceEnd` or
+// `MappingEnd`, and scalars have already been resolved
+pub(crate) enum ValueToken {
+    Null,
+    Bool(bool),
+    String(String),
+    Number(Number),
+    Mapping,
+    Sequence,
+}
+
+struct AnyObject;
+
+impl Reflection for AnyObject {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("object")
+    }
+}
+
+struct AnyArray;
+
+impl Reflection for AnyArray {
+    fn schema(_: &mut Document) -> Schema {
+        Schema::new("array")
+    }
+}
+
+impl ValueToken {
+    pub(crate) fn schema(&self) -> Document {
+        match self {
+            Self::Null => <() as Deserialize>::reflection(),
+            Self::Bool(_) => bool::reflection(),
+            Self::String(_) => str::document(),
+            Self::Number(_) => Number::reflection(),
+            Self::Mapping => AnyObject::document(),
+            Self::Sequence => AnyArray::document(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_float, parse_integer};
+
+    #[test]
+    fn integer() {
+        assert!(matches!(parse_integer("12"), Some((false, Ok(12)))));
+        assert!(matches!(parse_integer("+12"), Some((false, Ok(12)))));
+        assert!(matches!(parse_integer("-12"), Some((true, Ok(12)))));
+        assert!(matches!(parse_integer("0x1f"), Some((false, Ok(31)))));
+        assert!(matches!(parse_integer("0o17"), Some((false, Ok(15)))));
+
+        assert!(parse_integer("-0x1f").is_none());
+        assert!(parse_integer("0x").is_none());
+        assert!(parse_integer("1_000").is_none());
+        assert!(parse_integer("").is_none());
+
+        assert!(matches!(
+            parse_integer("18446744073709551616"),
+            Some((false, Err(_)))
+        ));
+    }
+
+    #[test]
+    fn float() {
+        for value in ["1.5", "-1.5", "+.5", "1.", "1e5", "1.5E-3", "12"] {
+            assert!(is_float(value), "{value} should be a float");
+        }
+
+        for value in [".", "e5", "1e", "1.5.3", "1,5", "inf", "nan", "0x1f", ""] {
+            assert!(!is_float(value), "{value} should not be a float");
+        }
+    }
+}
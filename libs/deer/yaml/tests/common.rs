@@ -0,0 +1,27 @@
+use core::fmt::Debug;
+
+use deer::{Context, Deserialize};
+use deer_desert::{assert_error, assert_tokens, error::ErrorVec, Token};
+
+/// Assert that the YAML document deserializes into the same value as the equivalent tokens.
+#[allow(dead_code)] // False positive
+pub(crate) fn assert_yaml<'de, T>(expected: &T, source: &'de str, tokens: &'de [Token])
+where
+    T: Deserialize<'de> + PartialEq + Debug,
+{
+    assert_tokens(expected, tokens);
+
+    let received = deer_yaml::from_str::<T>(source, &Context::new()).expect("should deserialize");
+    assert_eq!(received, *expected);
+}
+
+#[allow(dead_code)] // False positive
+pub(crate) fn assert_yaml_error<'de, T>(error: &ErrorVec, source: &'de str, context: &Context)
+where
+    T: Deserialize<'de> + Debug,
+{
+    let received =
+        deer_yaml::from_str::<T>(source, context).expect_err("value of type T should fail");
+
+    assert_error(error, received);
+}
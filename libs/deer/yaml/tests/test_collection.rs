@@ -0,0 +1,254 @@
+use core::ops::{Bound, Range};
+
+use deer::{Context, Deserialize, Number};
+use deer_desert::{error, Token};
+use deer_yaml::StackLimit;
+use serde_json::json;
+
+use crate::common::{assert_yaml, assert_yaml_error};
+
+mod common;
+
+#[test]
+fn sequence_ok() {
+    let tokens = [
+        Token::Array { length: Some(3) },
+        Token::Number(Number::from(1)),
+        Token::Number(Number::from(2)),
+        Token::Number(Number::from(3)),
+        Token::ArrayEnd,
+    ];
+
+    assert_yaml(&[1_u8, 2, 3], "[1, 2, 3]", &tokens);
+    assert_yaml(&[1_u8, 2, 3], "- 1\n- 2\n- 3\n", &tokens);
+}
+
+#[test]
+fn sequence_nested_ok() {
+    assert_yaml(
+        &((1_u8, true), 'a'),
+        "- - 1\n  - true\n- a\n",
+        &[
+            Token::Array { length: Some(2) },
+            Token::Array { length: Some(2) },
+            Token::Number(Number::from(1)),
+            Token::Bool(true),
+            Token::ArrayEnd,
+            Token::Char('a'),
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn sequence_err_value() {
+    assert_yaml_error::<((u8, bool), u8)>(
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": [{"type": "tuple", "value": 0}, {"type": "tuple", "value": 0}]
+            }
+        }]),
+        "[[256, true], 1]",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn sequence_err_length() {
+    assert_yaml_error::<[u8; 2]>(
+        &error!([{
+            ns: "deer",
+            id: ["array", "length"],
+            properties: {
+                "expected": 2,
+                "received": null,
+                "location": []
+            }
+        }]),
+        "[1, 2, 3]",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn sequence_err_type_recover() {
+    // the mapping is skipped, the remaining elements are still deserialized
+    assert_yaml_error::<(u8, u8, u8)>(
+        &error!([
+            {
+                ns: "deer",
+                id: ["type"],
+                properties: {
+                    "expected": Number::reflection(),
+                    "received": {"$defs": {"0000-deer_yaml::token::AnyObject": {"type": "object"}}, "$ref": "#/$defs/0000-deer_yaml::token::AnyObject"},
+                    "location": [{"type": "tuple", "value": 1}]
+                }
+            },
+            {
+                ns: "deer",
+                id: ["value"],
+                properties: {
+                    "expected": u8::reflection(),
+                    "received": 256,
+                    "location": [{"type": "tuple", "value": 2}]
+                }
+            }
+        ]),
+        "[1, {a: [1, 2]}, 256]",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn mapping_ok() {
+    let tokens = [
+        Token::Object { length: Some(2) },
+        Token::Str("start"),
+        Token::Number(Number::from(1)),
+        Token::Str("end"),
+        Token::Number(Number::from(3)),
+        Token::ObjectEnd,
+    ];
+
+    assert_yaml(&(1_u8..3_u8), "{start: 1, end: 3}", &tokens);
+    assert_yaml(&(1_u8..3_u8), "start: 1\nend: 3\n", &tokens);
+}
+
+#[test]
+fn mapping_err_missing() {
+    assert_yaml_error::<Range<u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["value", "missing"],
+            properties: {
+                "expected": u8::reflection(),
+                "location": [{"type": "field", "value": "end"}]
+            }
+        }]),
+        "start: 1\n",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn enum_ok() {
+    assert_yaml(
+        &Bound::Included(1_u8),
+        "Included: 1",
+        &[
+            Token::Object { length: Some(1) },
+            Token::Str("Included"),
+            Token::Number(Number::from(1)),
+            Token::ObjectEnd,
+        ],
+    );
+
+    assert_yaml(
+        &Bound::<u8>::Unbounded,
+        "Unbounded",
+        &[Token::Str("Unbounded")],
+    );
+}
+
+#[test]
+fn enum_err_too_many() {
+    assert_yaml_error::<Bound<u8>>(
+        &error!([{
+            ns: "deer",
+            id: ["object", "length"],
+            properties: {
+                "expected": 1,
+                "received": null,
+                "location": []
+            }
+        }]),
+        "{Included: 1, Excluded: 2}",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn anchor_ok() {
+    assert_yaml(
+        &[1_u8, 1, 1],
+        "[&a 1, *a, *a]",
+        &[
+            Token::Array { length: Some(3) },
+            Token::Number(Number::from(1)),
+            Token::Number(Number::from(1)),
+            Token::Number(Number::from(1)),
+            Token::ArrayEnd,
+        ],
+    );
+
+    assert_yaml(
+        &[1_u8..2_u8, 1_u8..2_u8],
+        "- &range\n  start: 1\n  end: 2\n- *range\n",
+        &[
+            Token::Array { length: Some(2) },
+            Token::Object { length: Some(2) },
+            Token::Str("start"),
+            Token::Number(Number::from(1)),
+            Token::Str("end"),
+            Token::Number(Number::from(2)),
+            Token::ObjectEnd,
+            Token::Object { length: Some(2) },
+            Token::Str("start"),
+            Token::Number(Number::from(1)),
+            Token::Str("end"),
+            Token::Number(Number::from(2)),
+            Token::ObjectEnd,
+            Token::ArrayEnd,
+        ],
+    );
+}
+
+#[test]
+fn stack_limit_err() {
+    let mut context = Context::new();
+    context.insert(StackLimit::new(2));
+
+    assert_yaml_error::<[[u8; 1]; 1]>(
+        &error!([{
+            ns: "deer-yaml",
+            id: ["recursion"],
+            properties: {
+                "position": {"line": 1, "column": 2},
+                "location": [{"type": "array", "value": 0}]
+            }
+        }]),
+        "[[1]]",
+        &context,
+    );
+}
+
+#[test]
+fn anchor_err_recursive() {
+    // the alias is reported once the sequence is finished, the element itself is missing
+    assert_yaml_error::<[[u8; 1]; 1]>(
+        &error!([
+            {
+                ns: "deer",
+                id: ["value", "missing"],
+                properties: {
+                    "expected": <[u8; 1]>::reflection(),
+                    "location": [{"type": "array", "value": 0}]
+                }
+            },
+            {
+                ns: "deer-yaml",
+                id: ["syntax"],
+                properties: {
+                    "position": {"line": 1, "column": 5},
+                    "location": []
+                }
+            }
+        ]),
+        "&a [*a]",
+        &Context::new(),
+    );
+}
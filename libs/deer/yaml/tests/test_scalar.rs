@@ -0,0 +1,178 @@
+use deer::{Context, Deserialize, Number};
+use deer_desert::{error, Token};
+use serde_json::json;
+
+use crate::common::{assert_yaml, assert_yaml_error};
+
+mod common;
+
+#[test]
+fn bool_ok() {
+    for source in ["true", "True", "TRUE"] {
+        assert_yaml(&true, source, &[Token::Bool(true)]);
+    }
+
+    for source in ["false", "False", "FALSE"] {
+        assert_yaml(&false, source, &[Token::Bool(false)]);
+    }
+}
+
+#[test]
+fn bool_err_quoted() {
+    assert_yaml_error::<bool>(
+        &error!([{
+            ns: "deer",
+            id: ["type"],
+            properties: {
+                "expected": bool::reflection(),
+                "received": <&str>::reflection(),
+                "location": []
+            }
+        }]),
+        "'true'",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn integer_ok() {
+    assert_yaml(&12_u8, "12", &[Token::Number(Number::from(12))]);
+    assert_yaml(&12_u8, "+12", &[Token::Number(Number::from(12))]);
+    assert_yaml(&-12_i8, "-12", &[Token::Number(Number::from(-12))]);
+    assert_yaml(&31_u8, "0x1f", &[Token::Number(Number::from(31))]);
+    assert_yaml(&15_u8, "0o17", &[Token::Number(Number::from(15))]);
+    assert_yaml(&i8::MIN, "-128", &[Token::Number(Number::from(i8::MIN))]);
+}
+
+#[test]
+fn integer_err_value() {
+    assert_yaml_error::<u8>(
+        &error!([{
+            ns: "deer",
+            id: ["value"],
+            properties: {
+                "expected": u8::reflection(),
+                "received": 256,
+                "location": []
+            }
+        }]),
+        "256",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn integer_err_overflow() {
+    assert_yaml_error::<u64>(
+        &error!([{
+            ns: "deer-yaml",
+            id: ["number"],
+            properties: {
+                "position": {"line": 1, "column": 1},
+                "received": "18446744073709551616",
+                "location": []
+            }
+        }]),
+        "18446744073709551616",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn float_ok() {
+    assert_yaml(&1.5_f64, "1.5", &[Token::Number(Number::from(1.5))]);
+    assert_yaml(&0.5_f64, ".5", &[Token::Number(Number::from(0.5))]);
+    assert_yaml(
+        &-1500.0_f64,
+        "-1.5e3",
+        &[Token::Number(Number::from(-1500.0))],
+    );
+    assert_yaml(
+        &f64::INFINITY,
+        ".inf",
+        &[Token::Number(Number::from(f64::INFINITY))],
+    );
+    assert_yaml(
+        &f64::NEG_INFINITY,
+        "-.Inf",
+        &[Token::Number(Number::from(f64::NEG_INFINITY))],
+    );
+}
+
+#[test]
+fn null_ok() {
+    for source in ["~", "null", "Null", "NULL"] {
+        assert_yaml(&(), source, &[Token::Null]);
+    }
+}
+
+#[test]
+fn string_ok() {
+    assert_yaml(&'a', "a", &[Token::Char('a')]);
+    assert_yaml(&'a', "'a'", &[Token::Char('a')]);
+    assert_yaml(&'a', "\"a\"", &[Token::Char('a')]);
+    assert_yaml(&'a', "!!str a", &[Token::Char('a')]);
+}
+
+#[test]
+fn string_ok_plain_number() {
+    // every scalar can be deserialized as a string
+    assert_yaml(&'1', "1", &[Token::Char('1')]);
+    assert_yaml(&'~', "~", &[Token::Char('~')]);
+}
+
+#[test]
+fn number_err_quoted() {
+    assert_yaml_error::<u8>(
+        &error!([{
+            ns: "deer",
+            id: ["type"],
+            properties: {
+                "expected": Number::reflection(),
+                "received": <&str>::reflection(),
+                "location": []
+            }
+        }]),
+        "'12'",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn optional_ok() {
+    assert_yaml(&None::<u8>, "~", &[Token::Null]);
+    assert_yaml(&None::<u8>, "null", &[Token::Null]);
+    assert_yaml(&Some(12_u8), "12", &[Token::Number(Number::from(12))]);
+}
+
+#[test]
+fn syntax_err() {
+    assert_yaml_error::<u8>(
+        &error!([{
+            ns: "deer-yaml",
+            id: ["syntax"],
+            properties: {
+                "position": {"line": 1, "column": 1},
+                "location": []
+            }
+        }]),
+        "'12",
+        &Context::new(),
+    );
+}
+
+#[test]
+fn eof_err() {
+    assert_yaml_error::<u8>(
+        &error!([{
+            ns: "deer-yaml",
+            id: ["syntax"],
+            properties: {
+                "position": {"line": 1, "column": 1},
+                "location": []
+            }
+        }]),
+        "",
+        &Context::new(),
+    );
+}
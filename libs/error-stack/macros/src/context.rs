@@ -0,0 +1,160 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Data, DeriveInput, Expr, Fields, LitStr, Token,
+};
+
+/// The parsed `#[display("...", args...)]` attribute.
+struct Display {
+    format: LitStr,
+    arguments: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for Display {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let format = input.parse()?;
+
+        let arguments = if input.is_empty() {
+            Punctuated::new()
+        } else {
+            input.parse::<Token![,]>()?;
+            Punctuated::parse_terminated(input)?
+        };
+
+        Ok(Self { format, arguments })
+    }
+}
+
+impl Display {
+    fn from_attributes(attributes: &[Attribute], span: Span) -> syn::Result<Self> {
+        let mut display = None;
+
+        for attribute in attributes {
+            if !attribute.path().is_ident("display") {
+                continue;
+            }
+
+            if display.is_some() {
+                return Err(syn::Error::new(
+                    attribute.span(),
+                    "duplicate `#[display(...)]` attribute",
+                ));
+            }
+
+            display = Some(attribute.parse_args()?);
+        }
+
+        display.ok_or_else(|| {
+            syn::Error::new(
+                span,
+                "missing `#[display(\"...\")]` attribute, which is required to implement `Display`",
+            )
+        })
+    }
+}
+
+/// Rewrites positional arguments like `{0}` to the bindings of tuple fields like `{_0}`.
+fn rewrite_positional(format: &str) -> String {
+    let mut rewritten = String::with_capacity(format.len());
+    let mut chars = format.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        rewritten.push(char);
+
+        if char == '{' {
+            match chars.peek() {
+                Some('{') => rewritten.extend(chars.next()),
+                Some(next) if next.is_ascii_digit() => rewritten.push('_'),
+                _ => {}
+            }
+        }
+    }
+
+    rewritten
+}
+
+/// Creates a match arm, which destructures `pattern` and writes the formatted [`Display`].
+fn expand_arm(pattern: &TokenStream, fields: &Fields, display: Display) -> TokenStream {
+    let Display {
+        mut format,
+        arguments,
+    } = display;
+
+    let pattern = match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote!(#pattern { #(#names),* })
+        }
+        Fields::Unnamed(fields) => {
+            let bindings = (0..fields.unnamed.len()).map(|index| format_ident!("_{}", index));
+
+            if arguments.is_empty() {
+                format = LitStr::new(&rewrite_positional(&format.value()), format.span());
+            }
+
+            quote!(#pattern ( #(#bindings),* ))
+        }
+        Fields::Unit => pattern.clone(),
+    };
+
+    quote! {
+        #pattern => ::core::write!(__formatter, #format, #arguments)
+    }
+}
+
+pub(crate) fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let arms = match &input.data {
+        Data::Struct(data) => {
+            let display = Display::from_attributes(&input.attrs, ident.span())?;
+            vec![expand_arm(&quote!(Self), &data.fields, display)]
+        }
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .map(|variant| {
+                let display = Display::from_attributes(&variant.attrs, variant.ident.span())?;
+                let variant_ident = &variant.ident;
+
+                Ok(expand_arm(
+                    &quote!(Self::#variant_ident),
+                    &variant.fields,
+                    display,
+                ))
+            })
+            .collect::<syn::Result<_>>()?,
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "`Context` cannot be derived for unions",
+            ));
+        }
+    };
+
+    // An empty enum cannot be matched through a reference.
+    let scrutinee = if arms.is_empty() {
+        quote!(*self)
+    } else {
+        quote!(self)
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn fmt(&self, __formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match #scrutinee {
+                    #(#arms,)*
+                }
+            }
+        }
+
+        ::error_stack::__private::derive::impl_context!(
+            [#impl_generics] [#ident #ty_generics] [#where_clause]
+        );
+    })
+}
@@ -1 +1,150 @@
 #![doc = include_str!("../README.md")]
+
+mod context;
+mod track_report;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ItemFn};
+
+/// Derives `Display` and `Context` for a type.
+///
+/// The `Display` implementation is generated from the `#[display(...)]` attribute, which takes a
+/// format string and optionally additional format arguments, similar to [`format!`]. The fields of
+/// the struct or of the enum variant are available inside of the format string by their name, or,
+/// for tuple fields, by their index. If additional format arguments are passed, positional
+/// arguments refer to them instead, and tuple fields are available through `self`. The attribute is
+/// required on structs and on every variant of an enum.
+///
+/// If `Error` is available, i.e. if the `std` feature of `error-stack` is enabled or a nightly
+/// toolchain is used, `Error` is implemented, so the type can be used with other error handling
+/// libraries as well. Otherwise, `Context` is implemented directly. In both cases, the type has to
+/// implement `Debug`.
+///
+/// # Examples
+///
+/// ```
+/// use error_stack::Report;
+/// use error_stack_macros::Context;
+///
+/// #[derive(Debug, Context)]
+/// #[display("could not parse `{path}` at line {line}")]
+/// struct ParseConfigError {
+///     path: &'static str,
+///     line: usize,
+/// }
+///
+/// #[derive(Debug, Context)]
+/// enum ConnectionError {
+///     #[display("connection timed out after {0} seconds")]
+///     Timeout(u64),
+///     #[display("connection refused by {host}:{port}")]
+///     Refused { host: &'static str, port: u16 },
+///     #[display("connection was closed")]
+///     Closed,
+/// }
+///
+/// let report = Report::new(ParseConfigError {
+///     path: "config.toml",
+///     line: 3,
+/// });
+/// assert_eq!(
+///     report.to_string(),
+///     "could not parse `config.toml` at line 3"
+/// );
+///
+/// assert_eq!(
+///     ConnectionError::Timeout(30).to_string(),
+///     "connection timed out after 30 seconds"
+/// );
+/// assert_eq!(
+///     ConnectionError::Refused {
+///         host: "localhost",
+///         port: 5432
+///     }
+///     .to_string(),
+///     "connection refused by localhost:5432"
+/// );
+/// ```
+///
+/// Additional format arguments are passed through:
+///
+/// ```
+/// use error_stack_macros::Context;
+///
+/// #[derive(Debug, Context)]
+/// #[display("expected at most {} items, got {len}", Self::LIMIT)]
+/// struct TooManyItems {
+///     len: usize,
+/// }
+///
+/// impl TooManyItems {
+///     const LIMIT: usize = 16;
+/// }
+///
+/// assert_eq!(
+///     TooManyItems { len: 20 }.to_string(),
+///     "expected at most 16 items, got 20"
+/// );
+/// ```
+#[proc_macro_derive(Context, attributes(display))]
+pub fn derive_context(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    context::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Attaches the function name and the selected arguments to a returned [`Report`].
+///
+/// The function has to return a `Result<_, Report<_>>`, e.g. `error_stack::Result`. If it returns
+/// an error, the [`Debug`] output of each argument listed in the attribute is attached as printable
+/// attachment `argument = value`, followed by ``in function `name` ``. The arguments are only
+/// formatted if an error is returned, so they must not be moved inside of the function. `self` may
+/// be listed as well if the function takes a receiver.
+///
+/// `async` functions are supported as well, in which case the attachments are added when the
+/// returned future resolves to an error.
+///
+/// [`Report`]: https://docs.rs/error-stack/latest/error_stack/struct.Report.html
+///
+/// # Examples
+///
+/// ```
+/// use error_stack::{bail, AttachmentKind, FrameKind, Report};
+/// use error_stack_macros::{track_report, Context};
+///
+/// #[derive(Debug, Context)]
+/// #[display("user {0} does not exist")]
+/// struct UnknownUser(u32);
+///
+/// #[track_report(id)]
+/// fn find_user(id: u32, database: &[u32]) -> Result<usize, Report<UnknownUser>> {
+///     match database.iter().position(|&user| user == id) {
+///         Some(index) => Ok(index),
+///         None => bail!(UnknownUser(id)),
+///     }
+/// }
+///
+/// let report = find_user(3, &[1, 2]).expect_err("user 3 does not exist");
+///
+/// let attachments = report
+///     .frames()
+///     .filter_map(|frame| match frame.kind() {
+///         FrameKind::Attachment(AttachmentKind::Printable(attachment)) => {
+///             Some(attachment.to_string())
+///         }
+///         _ => None,
+///     })
+///     .collect::<Vec<_>>();
+/// assert_eq!(attachments, ["in function `find_user`", "id = 3"]);
+/// ```
+#[proc_macro_attribute]
+pub fn track_report(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    let arguments = parse_macro_input!(attribute as track_report::Arguments);
+    let function = parse_macro_input!(item as ItemFn);
+
+    track_report::expand(&arguments, &function)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
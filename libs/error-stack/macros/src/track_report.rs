@@ -0,0 +1,113 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    ext::IdentExt,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    FnArg, Ident, ItemFn, LitStr, Pat, ReturnType, Token,
+};
+
+/// The arguments of the function to attach, e.g. `#[track_report(id, name)]`.
+pub(crate) struct Arguments(Punctuated<Ident, Token![,]>);
+
+impl Parse for Arguments {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // `parse_any` also accepts `self`
+        Punctuated::parse_terminated_with(input, Ident::parse_any).map(Self)
+    }
+}
+
+/// Returns an error if `argument` is not an argument of `function`.
+fn ensure_argument(function: &ItemFn, argument: &Ident) -> syn::Result<()> {
+    let exists = function.sig.inputs.iter().any(|input| match input {
+        FnArg::Receiver(_) => argument == "self",
+        FnArg::Typed(typed) => match &*typed.pat {
+            Pat::Ident(pattern) => pattern.ident == *argument,
+            _ => false,
+        },
+    });
+
+    if exists {
+        Ok(())
+    } else {
+        Err(syn::Error::new(
+            argument.span(),
+            format!("`{argument}` is not an argument of this function"),
+        ))
+    }
+}
+
+pub(crate) fn expand(arguments: &Arguments, function: &ItemFn) -> syn::Result<TokenStream> {
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = function;
+
+    if let Some(constness) = sig.constness {
+        return Err(syn::Error::new(
+            constness.span,
+            "`#[track_report]` cannot be used on `const` functions",
+        ));
+    }
+
+    let return_type = match &sig.output {
+        ReturnType::Type(_, return_type) => return_type,
+        ReturnType::Default => {
+            return Err(syn::Error::new(
+                sig.span(),
+                "`#[track_report]` requires the function to return a `Result<_, Report<_>>`",
+            ));
+        }
+    };
+
+    let mut attachments = Vec::with_capacity(arguments.0.len());
+    for argument in &arguments.0 {
+        ensure_argument(function, argument)?;
+
+        let format = LitStr::new(&format!("{argument} = {{:?}}"), argument.span());
+        attachments.push(quote! {
+            .attach_printable(::error_stack::__private::derive::format!(#format, #argument))
+        });
+    }
+
+    let function_name = LitStr::new(&format!("in function `{}`", sig.ident), sig.ident.span());
+
+    // The body is evaluated inside of a closure or an `async` block, so `return` and `?` within the
+    // body behave as before. Neither of them moves the arguments unless the body does, so the
+    // tracked arguments are still available to be formatted if an error is returned.
+    let result = if sig.asyncness.is_some() {
+        quote! {
+            async {
+                // Specifies the output of the `async` block, which is required for `?`.
+                #[allow(unreachable_code)]
+                if false {
+                    let __error_stack_return: #return_type = loop {};
+                    return __error_stack_return;
+                }
+                #block
+            }
+            .await
+        }
+    } else {
+        quote! {
+            (|| -> #return_type #block)()
+        }
+    };
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis #sig {
+            #[allow(clippy::redundant_closure_call)]
+            let __error_stack_result = #result;
+
+            __error_stack_result.map_err(|__error_stack_report| {
+                __error_stack_report
+                    #(#attachments)*
+                    .attach_printable(#function_name)
+            })
+        }
+    })
+}
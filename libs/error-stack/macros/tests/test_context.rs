@@ -0,0 +1,79 @@
+use core::fmt::Debug;
+
+use error_stack::Report;
+use error_stack_macros::Context;
+
+#[derive(Debug, Context)]
+#[display("unit struct")]
+struct Unit;
+
+#[derive(Debug, Context)]
+#[display("{0} and {1:?}")]
+struct Tuple(u32, &'static str);
+
+#[derive(Debug, Context)]
+#[display("{name} is {age} years old")]
+struct Named {
+    name: &'static str,
+    age: u8,
+}
+
+#[derive(Debug, Context)]
+#[display("{} is not {1}", self.0, "zero")]
+struct Arguments(u32);
+
+#[derive(Debug, Context)]
+#[display("value: {value:?}")]
+struct Generic<T: Debug + Send + Sync + 'static> {
+    value: T,
+}
+
+#[derive(Debug, Context)]
+enum Enum {
+    #[display("unit variant")]
+    Unit,
+    #[display("tuple variant with {0}")]
+    Tuple(u32),
+    #[display("named variant with {{{value}}}")]
+    Named { value: u32 },
+}
+
+#[test]
+fn display() {
+    assert_eq!(Unit.to_string(), "unit struct");
+    assert_eq!(Tuple(1, "a").to_string(), r#"1 and "a""#);
+    assert_eq!(
+        Named {
+            name: "Alice",
+            age: 42
+        }
+        .to_string(),
+        "Alice is 42 years old"
+    );
+    assert_eq!(Arguments(1).to_string(), "1 is not zero");
+    assert_eq!(Generic { value: [1, 2] }.to_string(), "value: [1, 2]");
+}
+
+#[test]
+fn display_enum() {
+    assert_eq!(Enum::Unit.to_string(), "unit variant");
+    assert_eq!(Enum::Tuple(1).to_string(), "tuple variant with 1");
+    assert_eq!(
+        Enum::Named { value: 1 }.to_string(),
+        "named variant with {1}"
+    );
+}
+
+#[test]
+fn context() {
+    let report = Report::new(Named {
+        name: "Alice",
+        age: 42,
+    })
+    .change_context(Enum::Tuple(1))
+    .change_context(Generic { value: "generic" });
+
+    assert_eq!(report.to_string(), r#"value: "generic""#);
+    assert!(report.contains::<Named>());
+    assert!(report.contains::<Enum>());
+}
@@ -0,0 +1,179 @@
+use error_stack::{bail, AttachmentKind, FrameKind, Report, Result};
+use error_stack_macros::{track_report, Context};
+
+#[derive(Debug, Context)]
+#[display("root error")]
+struct RootError;
+
+fn printable_attachments<C>(report: &Report<C>) -> Vec<String> {
+    report
+        .frames()
+        .filter_map(|frame| match frame.kind() {
+            FrameKind::Attachment(AttachmentKind::Printable(attachment)) => {
+                Some(attachment.to_string())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[track_report]
+fn without_arguments() -> Result<(), RootError> {
+    bail!(RootError)
+}
+
+#[track_report(id, name)]
+fn with_arguments(id: u32, name: String, fail: bool) -> Result<String, RootError> {
+    if fail {
+        bail!(RootError)
+    }
+
+    Ok(format!("{id}: {name}"))
+}
+
+/// Counts how often it was formatted.
+struct Formatted<'c>(&'c core::cell::Cell<usize>);
+
+impl core::fmt::Debug for Formatted<'_> {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.set(self.0.get() + 1);
+        fmt.write_str("formatted")
+    }
+}
+
+#[track_report(argument)]
+fn formatting(argument: Formatted<'_>, untracked: String, fail: bool) -> Result<String, RootError> {
+    if fail {
+        bail!(RootError)
+    }
+
+    // untracked arguments may still be moved
+    Ok(untracked)
+}
+
+#[track_report(value)]
+fn early_return(value: Option<u32>) -> Result<u32, RootError> {
+    let value = match value {
+        Some(value) => value,
+        None => return Err(Report::new(RootError)),
+    };
+
+    Ok(value)
+}
+
+struct Database {
+    name: &'static str,
+}
+
+impl core::fmt::Debug for Database {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        fmt.write_str(self.name)
+    }
+}
+
+impl Database {
+    #[track_report(self, key)]
+    fn get(&self, key: &str) -> Result<&str, RootError> {
+        if key.is_empty() {
+            bail!(RootError)
+        }
+
+        Ok(self.name)
+    }
+}
+
+// `.await` is desugared using `IntoFuture`, which is wrongly reported as incompatible with the MSRV
+#[allow(clippy::incompatible_msrv)]
+#[track_report(id)]
+async fn asynchronous(id: u32) -> Result<u32, RootError> {
+    async {}.await;
+
+    if id == 0 {
+        bail!(RootError)
+    }
+
+    Ok(id)
+}
+
+#[test]
+fn function_name() {
+    let report = without_arguments().expect_err("should fail");
+
+    assert_eq!(
+        printable_attachments(&report),
+        ["in function `without_arguments`"]
+    );
+}
+
+#[test]
+fn arguments() {
+    assert_eq!(
+        with_arguments(1, "Alice".to_owned(), false).expect("should succeed"),
+        "1: Alice"
+    );
+
+    let report = with_arguments(1, "Alice".to_owned(), true).expect_err("should fail");
+    assert_eq!(
+        printable_attachments(&report),
+        [
+            "in function `with_arguments`",
+            r#"name = "Alice""#,
+            "id = 1"
+        ]
+    );
+}
+
+#[test]
+fn formats_only_on_error() {
+    let count = core::cell::Cell::new(0);
+
+    assert_eq!(
+        formatting(Formatted(&count), "Alice".to_owned(), false).expect("should succeed"),
+        "Alice"
+    );
+    assert_eq!(count.get(), 0);
+
+    let report = formatting(Formatted(&count), "Alice".to_owned(), true).expect_err("should fail");
+    assert_eq!(count.get(), 1);
+    assert_eq!(
+        printable_attachments(&report),
+        ["in function `formatting`", "argument = formatted"]
+    );
+}
+
+#[test]
+fn returns_early() {
+    assert_eq!(early_return(Some(1)).expect("should succeed"), 1);
+
+    let report = early_return(None).expect_err("should fail");
+    assert_eq!(
+        printable_attachments(&report),
+        ["in function `early_return`", "value = None"]
+    );
+}
+
+#[test]
+fn receiver() {
+    let database = Database { name: "users" };
+    assert_eq!(database.get("id").expect("should succeed"), "users");
+
+    let report = database.get("").expect_err("should fail");
+    assert_eq!(
+        printable_attachments(&report),
+        ["in function `get`", r#"key = """#, "self = users"]
+    );
+}
+
+#[test]
+fn async_function() {
+    assert_eq!(
+        futures::executor::block_on(asynchronous(1)).expect("should succeed"),
+        1
+    );
+
+    let report = futures::executor::block_on(asynchronous(0)).expect_err("should fail");
+    assert_eq!(
+        printable_attachments(&report),
+        ["in function `asynchronous`", "id = 0"]
+    );
+}
@@ -298,6 +298,7 @@ mod config;
 mod hook;
 mod location;
 mod r#override;
+mod render;
 
 use alloc::collections::VecDeque;
 #[cfg_attr(feature = "std", allow(unused_imports))]
@@ -322,6 +323,7 @@ pub use hook::HookContext;
 pub(crate) use hook::{install_builtin_hooks, Format, Hooks};
 #[cfg(not(any(feature = "std", feature = "hooks")))]
 use location::LocationAttachment;
+pub use render::{Compact, ContextNode, Html, Markdown, Render, Rendered};
 
 use crate::{
     fmt::{
@@ -728,7 +730,7 @@ impl FromIterator<Line> for Lines {
 /// Collect the current "stack", a stack are the current frames which only have a single
 /// source/parent.
 /// This searches until it finds a stack "split", where a frame has more than a single source.
-fn collect<'a>(root: &'a Frame, prefix: &'a [&Frame]) -> (Vec<&'a Frame>, &'a [Frame]) {
+fn collect<'a>(root: &'a Frame, prefix: &[&'a Frame]) -> (Vec<&'a Frame>, &'a [Frame]) {
     let mut stack = vec![];
     stack.extend(prefix);
     stack.push(root);
@@ -757,7 +759,7 @@ fn collect<'a>(root: &'a Frame, prefix: &'a [&Frame]) -> (Vec<&'a Frame>, &'a [F
 /// then moves it up the chain and adds it to our results.
 /// Once we reach the end all remaining items on the stack are added to the prefix pile,
 /// which will be used in next iteration.
-fn partition<'a>(stack: &'a [&'a Frame]) -> (Vec<(&'a Frame, Vec<&'a Frame>)>, Vec<&'a Frame>) {
+fn partition<'a>(stack: &[&'a Frame]) -> (Vec<(&'a Frame, Vec<&'a Frame>)>, Vec<&'a Frame>) {
     let mut result = vec![];
     let mut queue = vec![];
 
@@ -0,0 +1,524 @@
+//! Alternative output formats for [`Report`]s.
+//!
+//! Besides the [`Debug`] output, a [`Report`] can be rendered using a [`Render`] implementation
+//! via [`Report::render`]. The output is computed from the same information as the [`Debug`]
+//! output: every context together with its attachments, where attachments are formatted using the
+//! hooks installed via [`Report::install_debug_hook`] (if the `std` or `hooks` feature is
+//! enabled), and the appendix pushed by these hooks.
+//!
+//! `error-stack` provides the following renderers:
+//!
+//! - [`Compact`]: a single line, e.g. for log messages
+//! - [`Html`]: nested, collapsible `<details>` elements, e.g. for error pages
+//! - [`Markdown`]: a nested list, e.g. for bug reports
+//!
+//! [`Debug`]: core::fmt::Debug
+//! [`Report::install_debug_hook`]: crate::Report::install_debug_hook
+
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{
+    fmt::{self, Display, Formatter, Write},
+    iter::once,
+};
+
+use crate::{
+    fmt::{collect, config::Config, debug_attachments_invoke, partition, Charset, ColorMode},
+    Context, Frame, FrameKind, Report,
+};
+
+/// A context of a [`Report`] prepared for rendering.
+///
+/// Used by [`Render`] implementations.
+pub struct ContextNode<'r> {
+    context: &'r dyn Context,
+    attachments: Vec<String>,
+    opaque: usize,
+    sources: Vec<Self>,
+}
+
+impl<'r> ContextNode<'r> {
+    /// The context itself.
+    #[must_use]
+    pub fn context(&self) -> &'r dyn Context {
+        self.context
+    }
+
+    /// The formatted attachments of the context, in the same order as in the [`Debug`] output.
+    ///
+    /// [`Debug`]: core::fmt::Debug
+    #[must_use]
+    pub fn attachments(&self) -> &[String] {
+        &self.attachments
+    }
+
+    /// The number of attachments, which could not be formatted.
+    #[must_use]
+    pub const fn opaque_attachments(&self) -> usize {
+        self.opaque
+    }
+
+    /// The contexts this context has been created from.
+    #[must_use]
+    pub fn sources(&self) -> &[Self] {
+        &self.sources
+    }
+}
+
+/// Builds the nodes for `root`, analogous to `debug_frame`.
+fn nodes<'r>(root: &'r Frame, prefix: &[&'r Frame], config: &mut Config) -> Vec<ContextNode<'r>> {
+    let (stack, sources) = collect(root, prefix);
+    let (stack, prefix) = partition(&stack);
+
+    // contexts are built before their sources, so hooks are invoked in the same order as in
+    // `debug_frame`
+    let mut contexts = stack
+        .into_iter()
+        .map(|(head, mut body)| {
+            let context = match head.kind() {
+                FrameKind::Context(context) => context,
+                FrameKind::Attachment(_) => {
+                    unreachable!("the head of a partition is always a context")
+                }
+            };
+
+            // reverse all attachments, to make it more logical relative to the attachment order
+            body.reverse();
+            let (opaque, attachments) = debug_attachments_invoke(once(head).chain(body), config);
+
+            ContextNode {
+                context,
+                attachments,
+                opaque: opaque.0,
+                sources: Vec::new(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut children = sources
+        .iter()
+        .flat_map(|source| nodes(source, &prefix, config))
+        .collect();
+
+    // if there is no context, the group is "transparent" and its sources are returned directly
+    while let Some(mut context) = contexts.pop() {
+        context.sources = children;
+        children = vec![context];
+    }
+
+    children
+}
+
+/// An output format for a [`Report`].
+///
+/// The output is created from the tree of contexts, see [`ContextNode`], and the appendix, which
+/// contains dense information like backtraces.
+///
+/// # Example
+///
+/// ```rust
+/// use core::fmt::{self, Formatter};
+/// use std::io;
+///
+/// use error_stack::{
+///     fmt::{ContextNode, Render},
+///     Report,
+/// };
+///
+/// /// Renders only the root causes of a report.
+/// struct RootCauses;
+///
+/// impl Render for RootCauses {
+///     fn render(
+///         &self,
+///         nodes: &[ContextNode<'_>],
+///         _appendix: &[String],
+///         fmt: &mut Formatter<'_>,
+///     ) -> fmt::Result {
+///         for node in nodes {
+///             if node.sources().is_empty() {
+///                 writeln!(fmt, "{}", node.context())?;
+///             } else {
+///                 self.render(node.sources(), &[], fmt)?;
+///             }
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let report = Report::new(io::Error::from(io::ErrorKind::NotFound))
+///     .attach_printable("could not read config")
+///     .change_context(io::Error::from(io::ErrorKind::Other));
+///
+/// assert_eq!(report.render(RootCauses).to_string(), "entity not found\n");
+/// ```
+pub trait Render {
+    /// The [`ColorMode`] passed to the hooks via [`HookContext::color_mode`].
+    ///
+    /// [`HookContext::color_mode`]: crate::fmt::HookContext::color_mode
+    fn color_mode(&self) -> ColorMode {
+        ColorMode::None
+    }
+
+    /// The [`Charset`] passed to the hooks via [`HookContext::charset`].
+    ///
+    /// [`HookContext::charset`]: crate::fmt::HookContext::charset
+    fn charset(&self) -> Charset {
+        Charset::Utf8
+    }
+
+    /// Writes the output for the given contexts and appendix.
+    ///
+    /// `nodes` contains more than one entry if the [`Report`] has multiple current contexts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `fmt` fails.
+    fn render(
+        &self,
+        nodes: &[ContextNode<'_>],
+        appendix: &[String],
+        fmt: &mut Formatter<'_>,
+    ) -> fmt::Result;
+}
+
+/// A [`Report`] rendered using a [`Render`] implementation.
+///
+/// Created by [`Report::render`], the alternate flag (`{:#}`) is passed to the hooks via
+/// [`HookContext::alternate`].
+///
+/// [`HookContext::alternate`]: crate::fmt::HookContext::alternate
+#[must_use]
+pub struct Rendered<'r, C, R> {
+    report: &'r Report<C>,
+    renderer: R,
+}
+
+impl<C, R: Render> Display for Rendered<'_, C, R> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        let mut config = Config::new(
+            self.renderer.color_mode(),
+            self.renderer.charset(),
+            fmt.alternate(),
+        );
+
+        let nodes = self
+            .report
+            .current_frames()
+            .iter()
+            .flat_map(|frame| nodes(frame, &[], &mut config))
+            .collect::<Vec<_>>();
+
+        #[cfg(any(feature = "std", feature = "hooks"))]
+        let appendix = config.context::<Frame>().appendix();
+        #[cfg(not(any(feature = "std", feature = "hooks")))]
+        let appendix = &[];
+
+        self.renderer.render(&nodes, appendix, fmt)
+    }
+}
+
+impl<C> Report<C> {
+    /// Renders the `Report` using the given [`Render`] implementation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    ///
+    /// use error_stack::{fmt::Compact, Report};
+    ///
+    /// let report = Report::new(io::Error::from(io::ErrorKind::NotFound))
+    ///     .attach_printable("could not read config")
+    ///     .change_context(io::Error::from(io::ErrorKind::Other));
+    ///
+    /// println!("{}", report.render(Compact));
+    /// # assert!(report.render(Compact).to_string().starts_with("other error (at "));
+    /// ```
+    pub const fn render<R>(&self, renderer: R) -> Rendered<'_, C, R>
+    where
+        R: Render,
+    {
+        Rendered {
+            report: self,
+            renderer,
+        }
+    }
+}
+
+/// Renders a [`Report`] as a single line.
+///
+/// Every context is followed by its attachments in parentheses and separated from its source by
+/// `: `. If a context has multiple sources, they are wrapped in brackets and separated by `; `.
+/// Opaque attachments and the appendix are omitted.
+///
+/// ```text
+/// context B (printable C): context A (printable B): [root error (printable A); root error]
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Compact;
+
+impl Compact {
+    /// Writes `value` with every sequence of whitespace containing a newline replaced by a space.
+    fn write_line(value: &str, fmt: &mut Formatter<'_>) -> fmt::Result {
+        for (index, line) in value.lines().map(str::trim).enumerate() {
+            if index > 0 {
+                fmt.write_char(' ')?;
+            }
+            fmt.write_str(line)?;
+        }
+
+        Ok(())
+    }
+
+    fn render_node(node: &ContextNode<'_>, fmt: &mut Formatter<'_>) -> fmt::Result {
+        Self::write_line(&node.context.to_string(), fmt)?;
+
+        if !node.attachments.is_empty() {
+            fmt.write_str(" (")?;
+            for (index, attachment) in node.attachments.iter().enumerate() {
+                if index > 0 {
+                    fmt.write_str(", ")?;
+                }
+                Self::write_line(attachment, fmt)?;
+            }
+            fmt.write_char(')')?;
+        }
+
+        match node.sources.as_slice() {
+            [] => Ok(()),
+            [source] => {
+                fmt.write_str(": ")?;
+                Self::render_node(source, fmt)
+            }
+            sources => {
+                fmt.write_str(": ")?;
+                Self::render_nodes(sources, fmt)
+            }
+        }
+    }
+
+    fn render_nodes(nodes: &[ContextNode<'_>], fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_char('[')?;
+        for (index, node) in nodes.iter().enumerate() {
+            if index > 0 {
+                fmt.write_str("; ")?;
+            }
+            Self::render_node(node, fmt)?;
+        }
+        fmt.write_char(']')
+    }
+}
+
+impl Render for Compact {
+    fn render(
+        &self,
+        nodes: &[ContextNode<'_>],
+        _: &[String],
+        fmt: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        match nodes {
+            [node] => Self::render_node(node, fmt),
+            nodes => Self::render_nodes(nodes, fmt),
+        }
+    }
+}
+
+/// Escapes `&`, `<`, `>`, `"` and `'` for use in HTML.
+struct HtmlEscape<'a>(&'a str);
+
+impl Display for HtmlEscape<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        for char in self.0.chars() {
+            match char {
+                '&' => fmt.write_str("&amp;")?,
+                '<' => fmt.write_str("&lt;")?,
+                '>' => fmt.write_str("&gt;")?,
+                '"' => fmt.write_str("&quot;")?,
+                '\'' => fmt.write_str("&#39;")?,
+                char => fmt.write_char(char)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders a [`Report`] as HTML.
+///
+/// Every context is rendered as `<details>` element, which can be collapsed to hide its
+/// attachments and sources. The appendix is rendered as `<pre>` elements. The elements have
+/// classes prefixed with `error-stack-` to allow styling them.
+///
+/// ```html
+/// <div class="error-stack">
+/// <details class="error-stack-context" open>
+/// <summary>context A</summary>
+/// <ul class="error-stack-attachments">
+/// <li>at src/main.rs:10:5</li>
+/// </ul>
+/// <details class="error-stack-context" open>
+/// <summary>root error</summary>
+/// </details>
+/// </details>
+/// </div>
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Html;
+
+impl Html {
+    fn render_node(node: &ContextNode<'_>, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str("<details class=\"error-stack-context\" open>\n")?;
+        writeln!(
+            fmt,
+            "<summary>{}</summary>",
+            HtmlEscape(&node.context.to_string())
+        )?;
+
+        if !node.attachments.is_empty() || node.opaque > 0 {
+            fmt.write_str("<ul class=\"error-stack-attachments\">\n")?;
+            for attachment in &node.attachments {
+                writeln!(fmt, "<li>{}</li>", HtmlEscape(attachment))?;
+            }
+            match node.opaque {
+                0 => {}
+                1 => fmt.write_str("<li>1 additional opaque attachment</li>\n")?,
+                opaque => writeln!(fmt, "<li>{opaque} additional opaque attachments</li>")?,
+            }
+            fmt.write_str("</ul>\n")?;
+        }
+
+        for source in &node.sources {
+            Self::render_node(source, fmt)?;
+        }
+
+        fmt.write_str("</details>\n")
+    }
+}
+
+impl Render for Html {
+    fn render(
+        &self,
+        nodes: &[ContextNode<'_>],
+        appendix: &[String],
+        fmt: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        fmt.write_str("<div class=\"error-stack\">\n")?;
+        for node in nodes {
+            Self::render_node(node, fmt)?;
+        }
+        for snippet in appendix {
+            writeln!(
+                fmt,
+                "<pre class=\"error-stack-appendix\">{}</pre>",
+                HtmlEscape(snippet.trim_end_matches('\n'))
+            )?;
+        }
+        fmt.write_str("</div>")
+    }
+}
+
+/// Escapes characters with a special meaning in Markdown.
+struct MarkdownEscape<'a>(&'a str);
+
+impl Display for MarkdownEscape<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        for char in self.0.chars() {
+            if matches!(
+                char,
+                '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|'
+            ) {
+                fmt.write_char('\\')?;
+            }
+            fmt.write_char(char)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Renders a [`Report`] as Markdown.
+///
+/// The contexts are rendered as nested list, where every context is written in bold and followed
+/// by its attachments and sources. The appendix is rendered as code blocks after a horizontal rule.
+///
+/// ```markdown
+/// - **context A**
+///   - at src/main.rs:10:5
+///   - **root error**
+/// ```
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Markdown;
+
+impl Markdown {
+    /// Writes a list item, additional lines are indented to be part of the item.
+    fn write_item(value: &str, bold: bool, indent: usize, fmt: &mut Formatter<'_>) -> fmt::Result {
+        for (index, line) in value.lines().enumerate() {
+            if index == 0 {
+                write!(fmt, "{:indent$}- ", "")?;
+            } else {
+                write!(fmt, "{:indent$}  ", "")?;
+            }
+
+            if bold && !line.trim().is_empty() {
+                writeln!(fmt, "**{}**", MarkdownEscape(line))?;
+            } else {
+                writeln!(fmt, "{}", MarkdownEscape(line))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn render_node(node: &ContextNode<'_>, indent: usize, fmt: &mut Formatter<'_>) -> fmt::Result {
+        Self::write_item(&node.context.to_string(), true, indent, fmt)?;
+
+        let indent = indent + 2;
+        for attachment in &node.attachments {
+            Self::write_item(attachment, false, indent, fmt)?;
+        }
+        match node.opaque {
+            0 => {}
+            1 => Self::write_item("1 additional opaque attachment", false, indent, fmt)?,
+            opaque => Self::write_item(
+                &format!("{opaque} additional opaque attachments"),
+                false,
+                indent,
+                fmt,
+            )?,
+        }
+
+        for source in &node.sources {
+            Self::render_node(source, indent, fmt)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Render for Markdown {
+    fn render(
+        &self,
+        nodes: &[ContextNode<'_>],
+        appendix: &[String],
+        fmt: &mut Formatter<'_>,
+    ) -> fmt::Result {
+        for node in nodes {
+            Self::render_node(node, 0, fmt)?;
+        }
+
+        if !appendix.is_empty() {
+            fmt.write_str("\n---\n")?;
+            for snippet in appendix {
+                write!(fmt, "\n```text\n{}\n```\n", snippet.trim_end_matches('\n'))?;
+            }
+        }
+
+        Ok(())
+    }
+}
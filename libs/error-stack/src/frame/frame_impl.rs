@@ -14,6 +14,10 @@ pub(super) trait FrameImpl: Send + Sync + 'static {
 
     fn as_any_mut(&mut self) -> &mut dyn Any;
 
+    /// The name of the type of the context or attachment.
+    #[cfg(any(feature = "sarif", feature = "valuable"))]
+    fn type_name(&self) -> &'static str;
+
     /// Provide values which can then be requested.
     #[cfg(nightly)]
     fn provide<'a>(&'a self, request: &mut Request<'a>);
@@ -57,6 +61,11 @@ impl<C: Context> FrameImpl for ContextFrame<C> {
         &mut self.context
     }
 
+    #[cfg(any(feature = "sarif", feature = "valuable"))]
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<C>()
+    }
+
     #[cfg(nightly)]
     fn provide<'a>(&'a self, request: &mut Request<'a>) {
         Context::provide(&self.context, request);
@@ -80,6 +89,11 @@ impl<A: 'static + Send + Sync> FrameImpl for AttachmentFrame<A> {
         &mut self.attachment
     }
 
+    #[cfg(any(feature = "sarif", feature = "valuable"))]
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<A>()
+    }
+
     #[cfg(nightly)]
     fn provide<'a>(&'a self, request: &mut Request<'a>) {
         request.provide_ref(&self.attachment);
@@ -105,6 +119,11 @@ impl<A: 'static + fmt::Debug + fmt::Display + Send + Sync> FrameImpl
         &mut self.attachment
     }
 
+    #[cfg(any(feature = "sarif", feature = "valuable"))]
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<A>()
+    }
+
     #[cfg(nightly)]
     fn provide<'a>(&'a self, request: &mut Request<'a>) {
         request.provide_ref(&self.attachment);
@@ -151,6 +170,11 @@ impl FrameImpl for AnyhowContext {
         &mut self.0
     }
 
+    #[cfg(any(feature = "sarif", feature = "valuable"))]
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<anyhow::Error>()
+    }
+
     #[cfg(nightly)]
     #[inline]
     fn provide<'a>(&'a self, request: &mut Request<'a>) {
@@ -198,6 +222,11 @@ impl FrameImpl for EyreContext {
         &mut self.0
     }
 
+    #[cfg(any(feature = "sarif", feature = "valuable"))]
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<eyre::Report>()
+    }
+
     #[cfg(nightly)]
     #[inline]
     fn provide<'a>(&'a self, request: &mut Request<'a>) {
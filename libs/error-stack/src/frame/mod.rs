@@ -5,7 +5,7 @@ mod kind;
 use alloc::boxed::Box;
 #[cfg(nightly)]
 use core::error::{self, Error};
-use core::{any::TypeId, fmt};
+use core::{any::TypeId, fmt, mem};
 
 use self::frame_impl::FrameImpl;
 pub use self::kind::{AttachmentKind, FrameKind};
@@ -47,6 +47,16 @@ impl Frame {
         &mut self.sources
     }
 
+    /// Removes the sources of this `Frame` and returns them.
+    pub(crate) fn take_sources(&mut self) -> Box<[Self]> {
+        mem::take(&mut self.sources)
+    }
+
+    /// Replaces the sources of this `Frame`.
+    pub(crate) fn set_sources(&mut self, sources: Box<[Self]>) {
+        self.sources = sources;
+    }
+
     /// Returns how the `Frame` was created.
     #[must_use]
     pub fn kind(&self) -> FrameKind<'_> {
@@ -97,6 +107,12 @@ impl Frame {
         self.frame.as_any().type_id()
     }
 
+    /// Returns the name of the type of the held context or attachment by this frame.
+    #[cfg(any(feature = "sarif", feature = "valuable"))]
+    pub(crate) fn type_name(&self) -> &'static str {
+        self.frame.type_name()
+    }
+
     #[cfg(nightly)]
     pub(crate) fn as_error(&self) -> &impl Error {
         &self.frame
@@ -7,6 +7,8 @@ use crate::{
     fmt::{install_builtin_hooks, Hooks},
     Report,
 };
+#[cfg(feature = "serde")]
+use crate::{serde::SerdeHooks, Frame};
 
 #[cfg(feature = "std")]
 type RwLock<T> = std::sync::RwLock<T>;
@@ -17,6 +19,8 @@ type RwLock<T> = std::sync::RwLock<T>;
 type RwLock<T> = spin::rwlock::RwLock<T>;
 
 static FMT_HOOK: RwLock<Hooks> = RwLock::new(Hooks { inner: Vec::new() });
+#[cfg(feature = "serde")]
+static SERDE_HOOK: RwLock<SerdeHooks> = RwLock::new(SerdeHooks { inner: Vec::new() });
 
 impl Report<()> {
     /// Can be used to globally set a [`Debug`] format hook, for a specific type `T`.
@@ -236,4 +240,85 @@ impl Report<()> {
 
         closure(&hook)
     }
+
+    /// Can be used to globally register a [`Serialize`] implementation for a specific type `T`.
+    ///
+    /// Once registered, every context or attachment of type `T` will be serialized using its
+    /// [`Serialize`] implementation when the [`Report`] is serialized. Without a hook opaque
+    /// attachments are omitted and contexts as well as printable attachments are serialized using
+    /// their [`Display`] output. Registering a hook for an already registered type replaces the
+    /// previous hook.
+    ///
+    /// Contexts keep their [`Display`] output under the `context` key, the structured value is
+    /// added under the `value` key.
+    ///
+    /// [`Display`]: core::fmt::Display
+    /// [`Serialize`]: serde::Serialize
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::io::{Error, ErrorKind};
+    ///
+    /// use error_stack::{report, Report};
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct RetryAfter {
+    ///     seconds: u64,
+    /// }
+    ///
+    /// Report::install_serde_hook::<RetryAfter>();
+    ///
+    /// let report =
+    ///     report!(Error::from(ErrorKind::TimedOut)).attach(RetryAfter { seconds: 30 });
+    ///
+    /// let value = serde_json::to_value(&report).unwrap();
+    /// assert_eq!(value[0]["attachments"][0], serde_json::json!({ "seconds": 30 }));
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn install_serde_hook<T: serde::Serialize + Send + Sync + 'static>() {
+        // TODO: Use `let ... else` when MSRV is 1.65
+        #[cfg(feature = "std")]
+        let mut lock = SERDE_HOOK.write().unwrap_or_else(|_| {
+            unreachable!(
+                "Hook is poisoned. This is considered a bug and should be reported to \
+                https://github.com/hashintel/hash/issues/new/choose"
+            )
+        });
+
+        // The spin RwLock cannot panic
+        #[cfg(all(not(feature = "std"), feature = "hooks"))]
+        let mut lock = SERDE_HOOK.write();
+
+        lock.insert::<T>();
+    }
+
+    /// Returns the value of the frame as [`Serialize`] if a hook has been registered for its
+    /// type via [`install_serde_hook`].
+    ///
+    /// [`Serialize`]: serde::Serialize
+    /// [`install_serde_hook`]: Self::install_serde_hook
+    #[cfg(feature = "serde")]
+    pub(crate) fn invoke_serde_hook(frame: &Frame) -> Option<&dyn erased_serde::Serialize> {
+        // TODO: Use `let ... else` when MSRV is 1.65
+        #[cfg(feature = "std")]
+        let hooks = SERDE_HOOK.read().unwrap_or_else(|_| {
+            unreachable!(
+                "Hook is poisoned. This is considered a bug and should be reported to \
+                https://github.com/hashintel/hash/issues/new/choose"
+            )
+        });
+
+        // The spin RwLock cannot panic
+        #[cfg(all(not(feature = "std"), feature = "hooks"))]
+        let hooks = SERDE_HOOK.read();
+
+        // The hook is copied out of the lock, so that `Serialize` implementations are free to
+        // install hooks themselves
+        let hook = hooks.get(frame.type_id());
+        drop(hooks);
+
+        hook?(frame)
+    }
 }
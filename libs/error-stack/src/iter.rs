@@ -1,4 +1,6 @@
-//! Iterators over [`Frame`]s.
+//! Iterators over [`Frame`]s and extensions for iterators over [`Result`]s.
+//!
+//! [`Result`]: crate::Result
 
 #[cfg_attr(feature = "std", allow(unused_imports))]
 use alloc::{vec, vec::Vec};
@@ -10,7 +12,7 @@ use core::{
     slice::{Iter, IterMut},
 };
 
-use crate::Frame;
+use crate::{Frame, Report, Result};
 
 /// Helper function, which is used in both [`Frames`] and [`FramesMut`].
 ///
@@ -267,3 +269,204 @@ where
         fmt.debug_list().entries(self.clone()).finish()
     }
 }
+
+/// Extension trait for iterators over [`Result`]s, which collects every [`Report`] instead of
+/// stopping at the first one.
+///
+/// All failing [`Report`]s are merged into a single [`Report`] with multiple sources by using
+/// [`Report::extend_one()`]. The order of the sources corresponds to the order, in which the
+/// errors have been returned by the iterator.
+///
+/// The bounded variants keep at most `max` [`Report`]s, any further error is discarded. As at least
+/// one [`Report`] is required to represent a failure, a limit of `0` behaves like a limit of `1`.
+///
+/// [`Report::extend_one()`]: crate::Report::extend_one
+pub trait TryReportIteratorExt: Iterator + Sized {
+    /// The type of the successful values.
+    type Ok;
+
+    /// The context of the [`Report`]s.
+    type Context;
+
+    /// Collects all successful values into `A` or returns all [`Report`]s merged into one.
+    ///
+    /// Unlike collecting into a [`Result`], this does not stop at the first error but consumes the
+    /// whole iterator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the merged [`Report`] if the iterator returned at least one error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use error_stack::{iter::TryReportIteratorExt, Report};
+    ///
+    /// fn parse(value: &str) -> Result<u8, Report<std::num::ParseIntError>> {
+    ///     value.parse().map_err(Report::new)
+    /// }
+    ///
+    /// let numbers = ["1", "2", "3"]
+    ///     .into_iter()
+    ///     .map(parse)
+    ///     .try_collect_reports::<Vec<_>>()?;
+    /// assert_eq!(numbers, [1, 2, 3]);
+    ///
+    /// let report = ["1", "foo", "256"]
+    ///     .into_iter()
+    ///     .map(parse)
+    ///     .try_collect_reports::<Vec<_>>()
+    ///     .expect_err("`foo` and `256` are not valid");
+    /// assert_eq!(report.current_frames().len(), 2);
+    /// # Ok::<(), Report<std::num::ParseIntError>>(())
+    /// ```
+    fn try_collect_reports<A>(self) -> Result<A, Self::Context>
+    where
+        A: FromIterator<Self::Ok>,
+    {
+        self.try_collect_reports_bounded(usize::MAX)
+    }
+
+    /// Collects all successful values into `A` or returns up to `max` [`Report`]s merged into one.
+    ///
+    /// The iterator is not consumed any further after `max` errors have been encountered.
+    ///
+    /// # Errors
+    ///
+    /// Returns the merged [`Report`] if the iterator returned at least one error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use error_stack::{iter::TryReportIteratorExt, Report};
+    ///
+    /// fn parse(value: &str) -> Result<u8, Report<std::num::ParseIntError>> {
+    ///     value.parse().map_err(Report::new)
+    /// }
+    ///
+    /// let report = ["foo", "bar", "baz"]
+    ///     .into_iter()
+    ///     .map(parse)
+    ///     .try_collect_reports_bounded::<Vec<_>>(2)
+    ///     .expect_err("the values are not numbers");
+    /// assert_eq!(report.current_frames().len(), 2);
+    /// ```
+    fn try_collect_reports_bounded<A>(self, max: usize) -> Result<A, Self::Context>
+    where
+        A: FromIterator<Self::Ok>;
+
+    /// Splits the iterator into all successful values and all [`Report`]s merged into one.
+    ///
+    /// Unlike [`try_collect_reports()`], the successful values are kept even if an error occurred.
+    ///
+    /// [`try_collect_reports()`]: Self::try_collect_reports
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use error_stack::{iter::TryReportIteratorExt, Report};
+    ///
+    /// fn parse(value: &str) -> Result<u8, Report<std::num::ParseIntError>> {
+    ///     value.parse().map_err(Report::new)
+    /// }
+    ///
+    /// let (numbers, report) = ["1", "foo", "3"]
+    ///     .into_iter()
+    ///     .map(parse)
+    ///     .partition_reports::<Vec<_>>();
+    /// assert_eq!(numbers, [1, 3]);
+    /// assert!(report.is_some());
+    /// ```
+    fn partition_reports<A>(self) -> (A, Option<Report<Self::Context>>)
+    where
+        A: Default + Extend<Self::Ok>,
+    {
+        self.partition_reports_bounded(usize::MAX)
+    }
+
+    /// Splits the iterator into all successful values and up to `max` [`Report`]s merged into one.
+    ///
+    /// The iterator is always consumed completely, errors exceeding the limit are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use error_stack::{iter::TryReportIteratorExt, Report};
+    ///
+    /// fn parse(value: &str) -> Result<u8, Report<std::num::ParseIntError>> {
+    ///     value.parse().map_err(Report::new)
+    /// }
+    ///
+    /// let (numbers, report) = ["foo", "bar", "3"]
+    ///     .into_iter()
+    ///     .map(parse)
+    ///     .partition_reports_bounded::<Vec<_>>(1);
+    /// assert_eq!(numbers, [3]);
+    /// assert_eq!(report.expect("errors occurred").current_frames().len(), 1);
+    /// ```
+    fn partition_reports_bounded<A>(self, max: usize) -> (A, Option<Report<Self::Context>>)
+    where
+        A: Default + Extend<Self::Ok>;
+}
+
+/// Merges `report` into `merged` and increments the number of merged [`Report`]s.
+fn merge_report<C>(merged: &mut Option<Report<C>>, report: Report<C>, count: &mut usize) {
+    match merged {
+        Some(merged) => merged.extend_one(report),
+        None => *merged = Some(report),
+    }
+    *count += 1;
+}
+
+impl<I, T, C> TryReportIteratorExt for I
+where
+    I: Iterator<Item = Result<T, C>>,
+{
+    type Context = C;
+    type Ok = T;
+
+    fn try_collect_reports_bounded<A>(mut self, max: usize) -> Result<A, C>
+    where
+        A: FromIterator<T>,
+    {
+        let max = max.max(1);
+        let mut report = None;
+        let mut count = 0;
+
+        let values = core::iter::from_fn(|| {
+            while count < max {
+                match self.next()? {
+                    // Values are not needed anymore after the first error
+                    Ok(value) if report.is_none() => return Some(value),
+                    Ok(_) => {}
+                    Err(error) => merge_report(&mut report, error, &mut count),
+                }
+            }
+
+            None
+        })
+        .collect();
+
+        report.map_or(Ok(values), Err)
+    }
+
+    fn partition_reports_bounded<A>(self, max: usize) -> (A, Option<Report<C>>)
+    where
+        A: Default + Extend<T>,
+    {
+        let max = max.max(1);
+        let mut values = A::default();
+        let mut report = None;
+        let mut count = 0;
+
+        for item in self {
+            match item {
+                Ok(value) => values.extend(Some(value)),
+                Err(error) if count < max => merge_report(&mut report, error, &mut count),
+                Err(_) => {}
+            }
+        }
+
+        (values, report)
+    }
+}
@@ -439,6 +439,9 @@
 //! In addition to [`ResultExt`], this crate also comes with [`FutureExt`], which provides the same
 //! functionality for [`Future`]s.
 //!
+//! Iterators over [`Result`]s can use [`TryReportIteratorExt`] to collect all errors into a single
+//! [`Report`] instead of stopping at the first one.
+//!
 //! [`Future`]: core::future::Future
 //!
 //! ### Colored output and charset selection
@@ -459,6 +462,11 @@
 //! `hooks`        | Enables hooks on `no-std` platforms using spin locks               | disabled
 //! `anyhow`       | Provides `into_report` to convert [`anyhow::Error`] to [`Report`]  | disabled
 //! `eyre`         | Provides `into_report` to convert [`eyre::Report`] to [`Report`]   | disabled
+//! `serde`        | Enables serialization of [`Report`]                                | disabled
+//! `remote`       | Provides [`RemoteReport`] to deserialize a serialized [`Report`]   | disabled
+//! `sarif`        | Provides conversion of [`Report`] into a [SARIF] log               | disabled
+//! `valuable`     | Implements [`Valuable`] for [`Report`] to record it structurally   | disabled
+//! `macros`       | Provides `#[derive(Context)]` and the [`track_report`] attribute   | disabled
 //!
 //!
 //! [`set_debug_hook`]: Report::set_debug_hook
@@ -469,6 +477,9 @@
 //! [`Display`]: core::fmt::Display
 //! [`Debug`]: core::fmt::Debug
 //! [`SpanTrace`]: tracing_error::SpanTrace
+//! [SARIF]: https://sarifweb.azurewebsites.net
+//! [`Valuable`]: valuable::Valuable
+//! [`track_report`]: error_stack_macros::track_report
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(
     nightly,
@@ -491,6 +502,7 @@ pub mod iter;
 mod compat;
 mod frame;
 mod macros;
+mod prune;
 mod report;
 mod result;
 
@@ -500,14 +512,29 @@ mod error;
 pub mod fmt;
 #[cfg(any(feature = "std", feature = "hooks"))]
 mod hook;
+#[cfg(feature = "remote")]
+mod remote;
+#[cfg(feature = "sarif")]
+mod sarif;
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "valuable")]
+mod valuable;
+
+#[cfg(feature = "macros")]
+pub use error_stack_macros::{track_report, Context};
 
+#[cfg(feature = "remote")]
+pub use self::remote::{
+    RemoteAttachment, RemoteContext, RemoteReport, SerializedAttachment, SerializedFrame,
+    SerializedLocation,
+};
 pub use self::{
     compat::IntoReportCompat,
     context::Context,
     frame::{AttachmentKind, Frame, FrameKind},
     macros::*,
+    prune::Redacted,
     report::Report,
     result::Result,
 };
@@ -515,6 +542,7 @@ pub use self::{
 #[allow(deprecated)]
 pub use self::{
     future::FutureExt,
+    iter::TryReportIteratorExt,
     result::{IntoReport, ResultExt},
 };
 
@@ -60,12 +60,57 @@ pub mod __private {
         }
     }
 
+    /// Used by `error-stack-macros`, which cannot rely on `alloc` being available in the caller's
+    /// crate or on the caller knowing, which `Error` trait is used.
+    ///
+    /// This is a separate module as [`report!`] imports `__private::*`, which would shadow items
+    /// of the caller otherwise.
+    ///
+    /// [`report!`]: crate::report
+    pub mod derive {
+        pub use alloc::format;
+        #[cfg(nightly)]
+        pub use core::error::Error;
+        #[cfg(all(not(nightly), feature = "std"))]
+        pub use std::error::Error;
+
+        pub use crate::__impl_context as impl_context;
+    }
+
     // false-positive lint
     #[allow(unreachable_pub)]
     // Import anonymously to allow calling `__kind` but forbid implementing the tag-traits.
     pub use self::specialization::{ContextTag as _, ReportTag as _};
 }
 
+/// Implements [`Context`] for a type, which already implements `Debug` and `Display`.
+///
+/// If [`Error`] is available, it's implemented instead, so the blanket implementation of
+/// [`Context`] applies and the type can be used by other error handling libraries as well.
+///
+/// Used by `#[derive(Context)]`, the parameters are the tokens of the generics of the `impl` block,
+/// the type, and the where clause, each wrapped in brackets.
+///
+/// [`Context`]: crate::Context
+/// [`Error`]: core::error::Error
+#[doc(hidden)]
+#[macro_export]
+#[cfg(any(nightly, feature = "std"))]
+macro_rules! __impl_context {
+    ([$($impl_generics:tt)*] [$($ty:tt)*] [$($where_clause:tt)*]) => {
+        impl $($impl_generics)* $crate::__private::derive::Error for $($ty)* $($where_clause)* {}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+#[cfg(not(any(nightly, feature = "std")))]
+macro_rules! __impl_context {
+    ([$($impl_generics:tt)*] [$($ty:tt)*] [$($where_clause:tt)*]) => {
+        impl $($impl_generics)* $crate::Context for $($ty)* $($where_clause)* {}
+    };
+}
+
 /// Creates a [`Report`] from the given parameters.
 ///
 /// The parameters may either be [`Context`] or a [`Report`]. The returned [`Report`] will use the
@@ -0,0 +1,312 @@
+//! Removing information from a [`Report`], e.g. before it leaves a trust boundary.
+
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
+use core::{fmt, mem, panic::Location};
+
+use crate::{AttachmentKind, Frame, FrameKind, Report};
+
+/// Placeholder for an attachment, which has been removed by [`Report::redact`].
+///
+/// It's printed as `[redacted]`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Redacted;
+
+impl fmt::Display for Redacted {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("[redacted]")
+    }
+}
+
+fn redact_frames<T>(frames: Box<[Frame]>) -> Box<[Frame]>
+where
+    T: Send + Sync + 'static,
+{
+    frames
+        .into_vec()
+        .into_iter()
+        .map(redact_frame::<T>)
+        .collect()
+}
+
+fn redact_frame<T>(mut frame: Frame) -> Frame
+where
+    T: Send + Sync + 'static,
+{
+    let sources = redact_frames::<T>(frame.take_sources());
+
+    if matches!(frame.kind(), FrameKind::Attachment(_)) && frame.is::<T>() {
+        Frame::from_printable_attachment(Redacted, sources)
+    } else {
+        frame.set_sources(sources);
+        frame
+    }
+}
+
+fn is_location(frame: &Frame) -> bool {
+    #[cfg(feature = "remote")]
+    if frame.is::<crate::SerializedLocation>() {
+        return true;
+    }
+
+    frame.is::<Location<'static>>()
+}
+
+fn strip_location_frames(frames: Box<[Frame]>) -> Vec<Frame> {
+    frames
+        .into_vec()
+        .into_iter()
+        .flat_map(strip_location_frame)
+        .collect()
+}
+
+/// Returns the frames replacing `frame`, which are its sources if `frame` is a location.
+fn strip_location_frame(mut frame: Frame) -> Vec<Frame> {
+    let sources = strip_location_frames(frame.take_sources());
+
+    if matches!(frame.kind(), FrameKind::Attachment(_)) && is_location(&frame) {
+        sources
+    } else {
+        frame.set_sources(sources.into_boxed_slice());
+        vec![frame]
+    }
+}
+
+/// Prunes `frames`, see [`prune_frame`].
+///
+/// The returned flag is only set if all frames have been pruned.
+fn prune_frames<F>(frames: Box<[Frame]>, keep: bool, predicate: &mut F) -> (Vec<Frame>, bool)
+where
+    F: FnMut(&Location<'static>) -> bool,
+{
+    let mut removed = !frames.is_empty();
+    let mut pruned = Vec::with_capacity(frames.len());
+
+    for frame in frames.into_vec() {
+        let (frames, frame_removed) = prune_frame(frame, keep, predicate);
+        pruned.extend(frames);
+        removed &= frame_removed;
+    }
+
+    (pruned, removed)
+}
+
+/// Returns the frames replacing `frame` and if the context the attachments above belong to has
+/// been removed.
+///
+/// The location of a context is attached directly on top of it, every attachment above belongs to
+/// the context until the location of the next context follows. If `keep` is set, the next context
+/// is kept regardless of its location.
+fn prune_frame<F>(mut frame: Frame, keep: bool, predicate: &mut F) -> (Vec<Frame>, bool)
+where
+    F: FnMut(&Location<'static>) -> bool,
+{
+    let remove = !keep
+        && matches!(frame.sources(), [source] if matches!(source.kind(), FrameKind::Context(_)))
+        && frame
+            .downcast_ref::<Location<'static>>()
+            .map_or(false, &mut *predicate);
+
+    if remove {
+        let mut context = frame.take_sources().into_vec();
+        let sources = context
+            .pop()
+            .map_or_else(Box::default, |mut context| context.take_sources());
+
+        let (sources, _) = prune_frames(sources, false, predicate);
+        return (sources, true);
+    }
+
+    let is_context = matches!(frame.kind(), FrameKind::Context(_));
+    let (sources, removed) = prune_frames(frame.take_sources(), keep && !is_context, predicate);
+
+    if removed && !is_context {
+        (sources, true)
+    } else {
+        frame.set_sources(sources.into_boxed_slice());
+        (vec![frame], false)
+    }
+}
+
+/// Returns if `upper` can be removed as it repeats `lower`.
+fn is_repeated(upper: &Frame, lower: &Frame) -> bool {
+    if upper.type_id() != lower.type_id() {
+        return false;
+    }
+
+    match (upper.kind(), lower.kind()) {
+        (FrameKind::Context(upper), FrameKind::Context(lower)) => {
+            upper.to_string() == lower.to_string()
+        }
+        (
+            FrameKind::Attachment(AttachmentKind::Printable(upper)),
+            FrameKind::Attachment(AttachmentKind::Printable(lower)),
+        ) => upper.to_string() == lower.to_string(),
+        _ => false,
+    }
+}
+
+fn collapse_frames(frames: Box<[Frame]>) -> Box<[Frame]> {
+    frames.into_vec().into_iter().map(collapse_frame).collect()
+}
+
+fn collapse_frame(mut frame: Frame) -> Frame {
+    let mut sources = collapse_frames(frame.take_sources());
+
+    if let [source] = &mut *sources {
+        // A context is separated from the context below by the location of the latter
+        let lower = if matches!(frame.kind(), FrameKind::Context(_))
+            && source.is::<Location<'static>>()
+            && source.sources().len() == 1
+        {
+            &mut source.sources_mut()[0]
+        } else {
+            source
+        };
+
+        if is_repeated(&frame, lower) {
+            let lower_sources = lower.take_sources();
+            sources = lower_sources;
+        }
+    }
+
+    frame.set_sources(sources);
+    frame
+}
+
+impl<C> Report<C> {
+    /// Replaces every attachment of type `T` with [`Redacted`].
+    ///
+    /// This can be used to remove sensitive information, e.g. SQL statements or tokens, before the
+    /// `Report` is returned to a client. Contexts are not redacted.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::{fmt, io};
+    ///
+    /// use error_stack::{Redacted, Report};
+    ///
+    /// #[derive(Debug)]
+    /// struct Token(&'static str);
+    ///
+    /// impl fmt::Display for Token {
+    ///     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         write!(fmt, "token: {}", self.0)
+    ///     }
+    /// }
+    ///
+    /// let report = Report::new(io::Error::from(io::ErrorKind::PermissionDenied))
+    ///     .attach_printable(Token("secret"))
+    ///     .redact::<Token>();
+    ///
+    /// assert!(!report.contains::<Token>());
+    /// assert!(report.contains::<Redacted>());
+    /// ```
+    pub fn redact<T>(mut self) -> Self
+    where
+        T: Send + Sync + 'static,
+    {
+        let frames = mem::take(self.frames.as_mut());
+        *self.frames = redact_frames::<T>(frames.into_boxed_slice()).into_vec();
+        self
+    }
+
+    /// Removes the locations of all contexts.
+    ///
+    /// Locations contain the paths of the source files the contexts have been created in, which
+    /// should not be exposed when the `Report` leaves a trust boundary. Locations received from a
+    /// remote report are removed as well.
+    ///
+    /// As [`prune`] and [`collapse`] rely on the locations, they have to be called before.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::{io, panic::Location};
+    ///
+    /// use error_stack::Report;
+    ///
+    /// let report = Report::new(io::Error::from(io::ErrorKind::NotFound))
+    ///     .attach_printable("could not read config")
+    ///     .strip_locations();
+    ///
+    /// assert!(!report.contains::<Location<'static>>());
+    /// ```
+    ///
+    /// [`prune`]: Self::prune
+    /// [`collapse`]: Self::collapse
+    pub fn strip_locations(mut self) -> Self {
+        let frames = mem::take(self.frames.as_mut());
+        *self.frames = strip_location_frames(frames.into_boxed_slice());
+        self
+    }
+
+    /// Removes every context, which has been created at a location matching `predicate`, together
+    /// with the attachments added while it was the current context.
+    ///
+    /// This can be used to remove contexts created by dependencies, e.g. by checking if the file of
+    /// the location is inside of the Cargo registry. The current context of the `Report` is always
+    /// kept.
+    ///
+    /// The location of a context is the [`Location`] attached when creating the context, contexts
+    /// without a [`Location`] attachment are kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    ///
+    /// use error_stack::Report;
+    ///
+    /// let report = Report::new(io::Error::from(io::ErrorKind::NotFound))
+    ///     .attach_printable("could not read config")
+    ///     .change_context(io::Error::from(io::ErrorKind::Other))
+    ///     .prune(|location| location.file().contains("/.cargo/registry/"));
+    /// # assert_eq!(report.frames().filter(|frame| frame.is::<io::Error>()).count(), 2);
+    /// ```
+    ///
+    /// [`Location`]: core::panic::Location
+    pub fn prune<F>(mut self, mut predicate: F) -> Self
+    where
+        F: FnMut(&Location<'static>) -> bool,
+    {
+        let frames = mem::take(self.frames.as_mut());
+        let (frames, _) = prune_frames(frames.into_boxed_slice(), true, &mut predicate);
+        *self.frames = frames;
+        self
+    }
+
+    /// Collapses repeated frames.
+    ///
+    /// A printable attachment is removed if the attachment below is of the same type and has the
+    /// same [`Display`] output. A context is removed if the context below, only separated by its
+    /// location, is of the same type and has the same [`Display`] output. Of repeated frames, the
+    /// most recent one is kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::io;
+    ///
+    /// use error_stack::Report;
+    ///
+    /// let mut report = Report::new(io::Error::from(io::ErrorKind::TimedOut));
+    /// for _ in 0..3 {
+    ///     report = report.attach_printable("retrying");
+    /// }
+    ///
+    /// let report = report.collapse();
+    /// assert_eq!(
+    ///     report.frames().filter(|frame| frame.is::<&str>()).count(),
+    ///     1
+    /// );
+    /// ```
+    ///
+    /// [`Display`]: core::fmt::Display
+    pub fn collapse(mut self) -> Self {
+        let frames = mem::take(self.frames.as_mut());
+        *self.frames = collapse_frames(frames.into_boxed_slice()).into_vec();
+        self
+    }
+}
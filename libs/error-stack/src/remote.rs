@@ -0,0 +1,255 @@
+//! Deserialization of serialized [`Report`]s.
+//!
+//! A serialized [`Report`] can be deserialized into a [`RemoteReport`], which can then be turned
+//! back into a [`Report`] on the receiving side of a process boundary, e.g. to use it as the
+//! source of a local error.
+
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{Context, Frame, Report};
+
+/// The location of a [`Report`] frame, as it has been serialized.
+///
+/// This is the serialized form of [`core::panic::Location`], when converted back into a
+/// [`Report`] it is attached as a printable attachment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename = "Location")]
+pub struct SerializedLocation {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+impl fmt::Display for SerializedLocation {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { file, line, column } = self;
+
+        write!(fmt, "at {file}:{line}:{column}")
+    }
+}
+
+/// An attachment of a [`Report`] frame, as it has been serialized.
+///
+/// Printable attachments are serialized as their [`Display`] output, attachments with a serde hook
+/// (see [`Report::install_serde_hook`]) as their structured value. As both are indistinguishable
+/// if a structured value is a string, a structured string is deserialized as
+/// [`SerializedAttachment::Printable`].
+///
+/// [`Display`]: core::fmt::Display
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SerializedAttachment {
+    Printable(String),
+    Value(Value),
+}
+
+/// A single context of a [`Report`], including all its attachments, as it has been serialized.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SerializedFrame {
+    /// The [`Display`] output of the context.
+    ///
+    /// [`Display`]: core::fmt::Display
+    pub context: String,
+    /// The structured value of the context, only present if a serde hook has been registered for
+    /// the context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<Value>,
+    /// The location the context has been created at.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<SerializedLocation>,
+    /// All attachments leading up to the next context, starting with the attachment closest to
+    /// the context.
+    #[serde(default)]
+    pub attachments: Vec<SerializedAttachment>,
+    #[serde(default)]
+    pub sources: Vec<Self>,
+}
+
+impl SerializedFrame {
+    fn into_frame(self) -> Frame {
+        let Self {
+            context,
+            value,
+            location,
+            attachments,
+            sources,
+        } = self;
+
+        let sources = sources
+            .into_iter()
+            .map(Self::into_frame)
+            .collect::<Vec<_>>()
+            .into_boxed_slice();
+
+        let mut frame = Frame::from_context(RemoteContext { context, value }, sources);
+
+        if let Some(location) = location {
+            frame = Frame::from_printable_attachment(location, Box::new([frame]));
+        }
+
+        for attachment in attachments {
+            frame = match attachment {
+                SerializedAttachment::Printable(attachment) => {
+                    Frame::from_printable_attachment(attachment, Box::new([frame]))
+                }
+                SerializedAttachment::Value(value) => {
+                    Frame::from_printable_attachment(RemoteAttachment(value), Box::new([frame]))
+                }
+            };
+        }
+
+        frame
+    }
+}
+
+/// A context that has been received from a [`RemoteReport`].
+///
+/// The [`Display`] output is the same as the one of the original context.
+///
+/// [`Display`]: core::fmt::Display
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteContext {
+    context: String,
+    value: Option<Value>,
+}
+
+impl RemoteContext {
+    /// The [`Display`] output of the original context.
+    ///
+    /// [`Display`]: core::fmt::Display
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.context
+    }
+
+    /// The structured value of the original context, if a serde hook has been registered for it.
+    #[must_use]
+    pub const fn value(&self) -> Option<&Value> {
+        self.value.as_ref()
+    }
+}
+
+impl fmt::Display for RemoteContext {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str(&self.context)
+    }
+}
+
+impl Context for RemoteContext {}
+
+/// A structured attachment that has been received from a [`RemoteReport`].
+///
+/// The attachment is printable, it is displayed as JSON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteAttachment(pub Value);
+
+impl fmt::Display for RemoteAttachment {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, fmt)
+    }
+}
+
+/// A [`Report`] that has been received over a process boundary.
+///
+/// `RemoteReport` has the same serialized representation as [`Report`], meaning that a serialized
+/// [`Report`] can be deserialized as `RemoteReport`. Use [`into_report`] to continue working with
+/// it as [`Report`], every context will be a [`RemoteContext`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::{fmt, io};
+///
+/// use error_stack::{Context, RemoteReport, Report};
+///
+/// #[derive(Debug)]
+/// struct FetchError;
+///
+/// impl fmt::Display for FetchError {
+///     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         fmt.write_str("could not fetch the resource")
+///     }
+/// }
+///
+/// impl Context for FetchError {}
+///
+/// // on the server
+/// let report = Report::new(io::Error::from(io::ErrorKind::NotFound))
+///     .attach_printable("the file does not exist");
+/// let remote = RemoteReport::try_from(&report).unwrap();
+/// let payload = serde_json::to_string(&remote).unwrap();
+///
+/// // on the client
+/// let remote: RemoteReport = serde_json::from_str(&payload).unwrap();
+/// let report = remote.into_report().unwrap().change_context(FetchError);
+///
+/// assert_eq!(report.frames().count(), 5);
+/// ```
+///
+/// [`into_report`]: Self::into_report
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct RemoteReport {
+    frames: Vec<SerializedFrame>,
+}
+
+impl RemoteReport {
+    /// Creates a `RemoteReport` from its serialized frames.
+    #[must_use]
+    pub const fn new(frames: Vec<SerializedFrame>) -> Self {
+        Self { frames }
+    }
+
+    /// The current frames of the remote report.
+    ///
+    /// This is the equivalent of [`Report::current_frames`].
+    #[must_use]
+    pub fn frames(&self) -> &[SerializedFrame] {
+        &self.frames
+    }
+
+    /// Consumes the `RemoteReport` and returns the serialized frames.
+    #[must_use]
+    pub fn into_frames(self) -> Vec<SerializedFrame> {
+        self.frames
+    }
+
+    /// Converts the `RemoteReport` into a [`Report`].
+    ///
+    /// Contexts are converted into [`RemoteContext`], attachments and locations into printable
+    /// attachments. Structured attachments are attached as [`RemoteAttachment`]. Serializing the
+    /// resulting [`Report`] again results in the same output.
+    ///
+    /// Returns `None` if the `RemoteReport` does not contain any frames.
+    #[must_use]
+    pub fn into_report(self) -> Option<Report<RemoteContext>> {
+        if self.frames.is_empty() {
+            return None;
+        }
+
+        Some(Report::from_frames(
+            self.frames
+                .into_iter()
+                .map(SerializedFrame::into_frame)
+                .collect(),
+        ))
+    }
+}
+
+impl<C: Context> TryFrom<&Report<C>> for RemoteReport {
+    type Error = serde_json::Error;
+
+    /// Converts the [`Report`] into its serialized form.
+    ///
+    /// # Errors
+    ///
+    /// Fails if a value serialized by a serde hook cannot be represented as JSON.
+    fn try_from(report: &Report<C>) -> Result<Self, Self::Error> {
+        serde_json::to_value(report).and_then(serde_json::from_value)
+    }
+}
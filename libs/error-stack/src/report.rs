@@ -320,6 +320,19 @@ impl<C> Report<C> {
         report
     }
 
+    /// Creates a `Report` from already existing frames, no location or backtrace is captured.
+    ///
+    /// `frames` must not be empty.
+    #[cfg(feature = "serde")]
+    pub(crate) fn from_frames(frames: Vec<Frame>) -> Self {
+        debug_assert!(!frames.is_empty(), "a report requires at least one frame");
+
+        Self {
+            frames: Box::new(frames),
+            _context: PhantomData,
+        }
+    }
+
     /// Merge two [`Report`]s together
     ///
     /// This function appends the [`current_frames()`] of the other [`Report`] to the
@@ -0,0 +1,196 @@
+//! Conversion of [`Report`]s into [SARIF] logs.
+//!
+//! [SARIF]: https://sarifweb.azurewebsites.net
+
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::{format, string::String, vec, vec::Vec};
+use core::panic::Location;
+
+use sarif::schema::{
+    self, ArtifactLocation, Level, Message, PhysicalLocation, Region, ReportingDescriptor, Run,
+    SarifLog, SchemaVersion, Tool, ToolComponent,
+};
+
+#[cfg(feature = "remote")]
+use crate::remote::SerializedLocation;
+use crate::{AttachmentKind, Context, Frame, FrameKind, Report};
+
+fn physical_location(frame: &Frame) -> Option<PhysicalLocation<'static>> {
+    if let Some(location) = frame.downcast_ref::<Location<'static>>() {
+        // `Location::file` borrows from the location, not from the `'static` string it points to
+        return Some(
+            PhysicalLocation::new(ArtifactLocation::new(String::from(location.file())))
+                .with_region(Region::new(location.line()).with_start_column(location.column())),
+        );
+    }
+
+    #[cfg(feature = "remote")]
+    if let Some(SerializedLocation { file, line, column }) = frame.downcast_ref() {
+        return Some(
+            PhysicalLocation::new(ArtifactLocation::new(file.clone()))
+                .with_region(Region::new(*line).with_start_column(*column)),
+        );
+    }
+
+    None
+}
+
+/// Escapes the link text of an embedded link in a plain text message.
+fn escape_link_text(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        if matches!(char, '\\' | '[' | ']') {
+            escaped.push('\\');
+        }
+        escaped.push(char);
+    }
+
+    escaped
+}
+
+/// A context frame together with all attachments leading up to the next context.
+struct SarifContext<'a> {
+    frame: &'a Frame,
+    context: &'a dyn Context,
+    /// The attachments, starting with the attachment closest to the context.
+    attachments: Vec<&'a Frame>,
+}
+
+fn find_next<'a>(head: &[&'a Frame], mut current: &'a Frame) -> Vec<SarifContext<'a>> {
+    let mut attachments = vec![];
+    attachments.extend(head);
+
+    loop {
+        if let FrameKind::Context(context) = current.kind() {
+            attachments.reverse();
+
+            return vec![SarifContext {
+                frame: current,
+                context,
+                attachments,
+            }];
+        } else if current.sources().len() > 1 {
+            attachments.push(current);
+
+            return current
+                .sources()
+                .iter()
+                .flat_map(|source| find_next(&attachments, source))
+                .collect();
+        } else if current.sources().len() == 1 {
+            attachments.push(current);
+
+            current = &current.sources()[0];
+        } else {
+            return vec![];
+        }
+    }
+}
+
+#[derive(Default)]
+struct Converter {
+    rules: Vec<&'static str>,
+    results: Vec<schema::Result<'static>>,
+}
+
+impl Converter {
+    /// Converts the context and all its sources into results and returns the index of the result
+    /// of the context.
+    fn convert(&mut self, context: SarifContext<'_>) -> usize {
+        let SarifContext {
+            frame,
+            context,
+            attachments,
+        } = context;
+
+        let rule_id = frame.type_name();
+        if !self.rules.contains(&rule_id) {
+            self.rules.push(rule_id);
+        }
+
+        let mut result = schema::Result::new(Message::new(format!("{context}")))
+            .with_rule_id(rule_id)
+            .with_level(Level::Error);
+
+        for attachment in attachments {
+            if let Some(location) = physical_location(attachment) {
+                let location = schema::Location::new().with_physical_location(location);
+
+                if result.locations.is_empty() {
+                    result = result.with_location(location);
+                } else {
+                    result = result.with_related_location(location);
+                }
+            } else if let FrameKind::Attachment(AttachmentKind::Printable(attachment)) =
+                attachment.kind()
+            {
+                result = result.with_related_location(
+                    schema::Location::new().with_message(Message::new(format!("{attachment}"))),
+                );
+            }
+        }
+
+        let index = self.results.len();
+        self.results.push(result);
+
+        let sources = frame
+            .sources()
+            .iter()
+            .flat_map(|source| find_next(&[], source))
+            .map(|source| self.convert(source))
+            .collect::<Vec<_>>();
+
+        for source in sources {
+            let source_result = &self.results[source];
+
+            let text = source_result.message.text.as_deref().unwrap_or_default();
+            let mut location = schema::Location::new().with_message(Message::new(format!(
+                "caused by [{}](sarif:/runs/0/results/{source})",
+                escape_link_text(text)
+            )));
+            if let Some(physical_location) = source_result
+                .locations
+                .first()
+                .and_then(|location| location.physical_location.clone())
+            {
+                location = location.with_physical_location(physical_location);
+            }
+
+            self.results[index].related_locations.push(location);
+        }
+
+        index
+    }
+
+    fn into_run(self) -> Run<'static> {
+        let driver = ToolComponent::new(env!("CARGO_PKG_NAME"))
+            .with_version(env!("CARGO_PKG_VERSION"))
+            .with_rules(self.rules.into_iter().map(ReportingDescriptor::new));
+
+        Run::new(Tool::new(driver)).with_results(self.results)
+    }
+}
+
+impl<C> From<&Report<C>> for SarifLog<'static> {
+    /// Converts the [`Report`] into a SARIF log with a single run.
+    ///
+    /// Every context of the [`Report`] becomes a result, whose rule id is the name of the context
+    /// type. The location a context has been created at becomes the location of the result,
+    /// printable attachments are added as related locations with a message. Sources of a context
+    /// are converted into results as well, and are linked from the related locations of the
+    /// context they are the source of.
+    fn from(report: &Report<C>) -> Self {
+        let mut converter = Converter::default();
+
+        for context in report
+            .current_frames()
+            .iter()
+            .flat_map(|frame| find_next(&[], frame))
+        {
+            converter.convert(context);
+        }
+
+        Self::new(SchemaVersion::V2_1_0).with_run(converter.into_run())
+    }
+}
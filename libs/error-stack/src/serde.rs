@@ -1,49 +1,138 @@
 //! Implementation of general [`Report`] serialization.
 //!
-//! The value can be of any type, currently only printable attachments and context are supported, in
-//! the near future any values will be supported through the use of hooks.
+//! The value can be of any type, types that have a hook registered via
+//! [`Report::install_serde_hook`] are serialized using their [`Serialize`] implementation,
+//! printable attachments and contexts without a hook are serialized using their [`Display`] output.
+//! Opaque attachments without a hook are omitted.
 //!
 //! The serialized [`Report`] is a list of all current sources with the following output:
 //!
 //! ```json
 //! {
 //!     "context": "context display output",
+//!     "value": {}, // only present if a hook has been registered for the context
+//!     "location": { "file": "src/main.rs", "line": 1, "column": 1 }, // if captured
 //!     "attachments": ["all", "attachments", "leading", "up", "to", "this", "context"],
 //!     "sources": [] // recursive render using `frame.sources()`
 //! }
 //! ```
+//!
+//! With the `remote` feature, a serialized [`Report`] can be deserialized as [`RemoteReport`].
+//!
+//! [`Display`]: core::fmt::Display
+//! [`RemoteReport`]: crate::RemoteReport
 
 #[cfg_attr(feature = "std", allow(unused_imports))]
-use alloc::{format, vec, vec::Vec};
+use alloc::{format, string::String, vec, vec::Vec};
+#[cfg(any(feature = "std", feature = "hooks"))]
+use core::any::TypeId;
+use core::panic::Location;
 
-use serde::{ser::SerializeMap, Serialize, Serializer};
+use serde::{
+    ser::{SerializeMap, SerializeStruct},
+    Serialize, Serializer,
+};
 
+#[cfg(feature = "remote")]
+use crate::remote::{RemoteAttachment, RemoteContext, SerializedLocation};
 use crate::{AttachmentKind, Context, Frame, FrameKind, Report};
 
-struct SerializeAttachment<'a>(&'a Frame);
+#[cfg(any(feature = "std", feature = "hooks"))]
+type SerdeHook = for<'a> fn(&'a Frame) -> Option<&'a dyn erased_serde::Serialize>;
 
-impl<'a> Serialize for SerializeAttachment<'a> {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
+#[cfg(any(feature = "std", feature = "hooks"))]
+fn downcast<T: Serialize + Send + Sync + 'static>(
+    frame: &Frame,
+) -> Option<&dyn erased_serde::Serialize> {
+    frame
+        .downcast_ref::<T>()
+        .map(|value| value as &dyn erased_serde::Serialize)
+}
+
+/// Holds the list of types that have been registered via [`Report::install_serde_hook`].
+#[cfg(any(feature = "std", feature = "hooks"))]
+pub(crate) struct SerdeHooks {
+    // We use `Vec`, instead of `HashMap` or `BTreeMap`, for consistency with `Hooks`, which
+    // allows the static to be created in a `const` context.
+    pub(crate) inner: Vec<(TypeId, SerdeHook)>,
+}
+
+#[cfg(any(feature = "std", feature = "hooks"))]
+impl SerdeHooks {
+    pub(crate) fn insert<T: Serialize + Send + Sync + 'static>(&mut self) {
+        let type_id = TypeId::of::<T>();
+
+        // make sure that previous hooks of the same TypeId are deleted.
+        self.inner.retain(|(id, _)| *id != type_id);
+        self.inner.push((type_id, downcast::<T>));
+    }
+
+    pub(crate) fn get(&self, type_id: TypeId) -> Option<SerdeHook> {
+        self.inner
+            .iter()
+            .find(|(id, _)| *id == type_id)
+            .map(|(_, hook)| *hook)
+    }
+}
+
+fn invoke_hook(frame: &Frame) -> Option<&dyn erased_serde::Serialize> {
+    // values received from a remote report are serialized the same way they were received
+    #[cfg(feature = "remote")]
+    if let Some(RemoteAttachment(value)) = frame.downcast_ref() {
+        return Some(value);
+    }
+
+    #[cfg(feature = "remote")]
+    if let Some(value) = frame
+        .downcast_ref::<RemoteContext>()
+        .and_then(RemoteContext::value)
+    {
+        return Some(value);
+    }
+
+    #[cfg(any(feature = "std", feature = "hooks"))]
+    {
+        Report::invoke_serde_hook(frame)
+    }
+
+    // without a global registry there are no hooks to invoke
+    #[cfg(not(any(feature = "std", feature = "hooks")))]
     {
-        let Self(frame) = self;
+        let _ = frame;
+        None
+    }
+}
+
+enum SerializeAttachment<'a> {
+    Hook(&'a dyn erased_serde::Serialize),
+    Printable(String),
+}
+
+impl<'a> SerializeAttachment<'a> {
+    fn new(frame: &'a Frame) -> Option<Self> {
+        if let Some(value) = invoke_hook(frame) {
+            return Some(Self::Hook(value));
+        }
 
-        #[allow(clippy::match_same_arms)]
         match frame.kind() {
-            FrameKind::Context(_) => {
-                // TODO: for now `Context` is unsupported, upcoming PR will fix via hooks
-                // `SerializeAttachmentList` ensures that no context is ever serialized
-                unimplemented!()
-            }
-            FrameKind::Attachment(AttachmentKind::Opaque(_)) => {
-                // TODO: for now opaque attachments are unsupported, upcoming PR will fix that
-                // `SerializeAttachmentList` ensures that no such attachment is added
-                unimplemented!()
-            }
             FrameKind::Attachment(AttachmentKind::Printable(attachment)) => {
-                format!("{attachment}").serialize(serializer)
+                Some(Self::Printable(format!("{attachment}")))
             }
+            // opaque attachments without a hook cannot be serialized and are ignored,
+            // `find_next` ensures that no context is ever passed in
+            FrameKind::Attachment(AttachmentKind::Opaque(_)) | FrameKind::Context(_) => None,
+        }
+    }
+}
+
+impl<'a> Serialize for SerializeAttachment<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Hook(value) => value.serialize(serializer),
+            Self::Printable(attachment) => attachment.serialize(serializer),
         }
     }
 }
@@ -55,25 +144,52 @@ impl<'a, 'b> Serialize for SerializeAttachmentList<'a, 'b> {
     where
         S: Serializer,
     {
-        serializer.collect_seq(
-            self.0
-                .iter()
-                .copied()
-                .filter(|attachment| {
-                    // for now opaque attachments are ignored
-                    !matches!(
-                        attachment.kind(),
-                        FrameKind::Attachment(AttachmentKind::Opaque(_))
-                    )
-                })
-                .map(SerializeAttachment),
-        )
+        serializer.collect_seq(self.0.iter().copied().filter_map(SerializeAttachment::new))
+    }
+}
+
+enum SerializeLocation<'a> {
+    Local(&'a Location<'static>),
+    #[cfg(feature = "remote")]
+    Remote(&'a SerializedLocation),
+}
+
+impl<'a> SerializeLocation<'a> {
+    fn new(frame: &'a Frame) -> Option<Self> {
+        let location = frame.downcast_ref::<Location<'static>>().map(Self::Local);
+
+        #[cfg(feature = "remote")]
+        let location = location.or_else(|| frame.downcast_ref().map(Self::Remote));
+
+        location
+    }
+}
+
+impl<'a> Serialize for SerializeLocation<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::Local(location) => {
+                // same representation as `SerializedLocation`
+                let mut state = serializer.serialize_struct("Location", 3)?;
+                state.serialize_field("file", location.file())?;
+                state.serialize_field("line", &location.line())?;
+                state.serialize_field("column", &location.column())?;
+                state.end()
+            }
+            #[cfg(feature = "remote")]
+            Self::Remote(location) => location.serialize(serializer),
+        }
     }
 }
 
 struct SerializeContext<'a> {
     attachments: Vec<&'a Frame>,
     context: &'a dyn Context,
+    value: Option<&'a dyn erased_serde::Serialize>,
+    location: Option<SerializeLocation<'a>>,
     sources: &'a [Frame],
 }
 
@@ -84,12 +200,20 @@ impl<'a> Serialize for SerializeContext<'a> {
     {
         let Self {
             context,
+            value,
+            location,
             attachments,
             sources,
         } = self;
 
-        let mut map = serializer.serialize_map(Some(3))?;
+        let mut map = serializer.serialize_map(None)?;
         map.serialize_entry("context", &format!("{context}").as_str())?;
+        if let Some(value) = value {
+            map.serialize_entry("value", value)?;
+        }
+        if let Some(location) = location {
+            map.serialize_entry("location", location)?;
+        }
         map.serialize_entry("attachments", &SerializeAttachmentList(attachments))?;
         map.serialize_entry("sources", &SerializeSources(sources))?;
 
@@ -118,9 +242,20 @@ fn find_next<'a>(head: &[&'a Frame], mut current: &'a Frame) -> Vec<SerializeCon
             // found the context, return all attachments (reversed)
             attachments.reverse();
 
+            // the location is attached directly after the context has been created
+            let location = attachments
+                .first()
+                .copied()
+                .and_then(SerializeLocation::new);
+            if location.is_some() {
+                attachments.remove(0);
+            }
+
             return vec![SerializeContext {
                 attachments,
                 context,
+                value: invoke_hook(current),
+                location,
                 sources: current.sources(),
             }];
         } else if current.sources().len() > 1 {
@@ -0,0 +1,354 @@
+//! Structured inspection of [`Report`]s using [`valuable`].
+//!
+//! [`Report`] implements [`Valuable`], which allows recording it as a nested value, e.g. as a field
+//! of a [`tracing`] event.
+//!
+//! [`tracing`]: https://docs.rs/tracing
+
+#[cfg_attr(feature = "std", allow(unused_imports))]
+use alloc::{format, vec, vec::Vec};
+use core::panic::Location;
+
+#[cfg(feature = "spantrace")]
+use tracing_error::SpanTrace;
+use valuable::{
+    Fields, Listable, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit,
+};
+
+#[cfg(feature = "remote")]
+use crate::remote::SerializedLocation;
+use crate::{AttachmentKind, Context, Frame, FrameKind, Report};
+
+static REPORT_FIELDS: &[NamedField<'static>] =
+    &[NamedField::new("context"), NamedField::new("frames")];
+
+#[cfg(feature = "spantrace")]
+static CONTEXT_FIELDS: &[NamedField<'static>] = &[
+    NamedField::new("context"),
+    NamedField::new("type"),
+    NamedField::new("location"),
+    NamedField::new("attachments"),
+    NamedField::new("sources"),
+    NamedField::new("spans"),
+];
+
+#[cfg(not(feature = "spantrace"))]
+static CONTEXT_FIELDS: &[NamedField<'static>] = &[
+    NamedField::new("context"),
+    NamedField::new("type"),
+    NamedField::new("location"),
+    NamedField::new("attachments"),
+    NamedField::new("sources"),
+];
+
+static LOCATION_FIELDS: &[NamedField<'static>] = &[
+    NamedField::new("file"),
+    NamedField::new("line"),
+    NamedField::new("column"),
+];
+
+#[cfg(feature = "spantrace")]
+static SPAN_FIELDS: &[NamedField<'static>] = &[
+    NamedField::new("name"),
+    NamedField::new("target"),
+    NamedField::new("fields"),
+];
+
+struct ValuableLocation<'a> {
+    file: &'a str,
+    line: u32,
+    column: u32,
+}
+
+impl<'a> ValuableLocation<'a> {
+    fn new(frame: &'a Frame) -> Option<Self> {
+        if let Some(location) = frame.downcast_ref::<Location<'static>>() {
+            return Some(Self {
+                file: location.file(),
+                line: location.line(),
+                column: location.column(),
+            });
+        }
+
+        #[cfg(feature = "remote")]
+        if let Some(SerializedLocation { file, line, column }) = frame.downcast_ref() {
+            return Some(Self {
+                file,
+                line: *line,
+                column: *column,
+            });
+        }
+
+        None
+    }
+}
+
+impl Valuable for ValuableLocation<'_> {
+    fn as_value(&self) -> Value<'_> {
+        Value::Structable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        visit.visit_named_fields(&NamedValues::new(
+            LOCATION_FIELDS,
+            &[
+                Value::String(self.file),
+                Value::U32(self.line),
+                Value::U32(self.column),
+            ],
+        ));
+    }
+}
+
+impl Structable for ValuableLocation<'_> {
+    fn definition(&self) -> StructDef<'_> {
+        StructDef::new_static("Location", Fields::Named(LOCATION_FIELDS))
+    }
+}
+
+/// The printable attachments of a context, formatted when visited.
+struct ValuableAttachments<'a>(&'a [&'a Frame]);
+
+impl Valuable for ValuableAttachments<'_> {
+    fn as_value(&self) -> Value<'_> {
+        Value::Listable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        for frame in self.0 {
+            if let FrameKind::Attachment(AttachmentKind::Printable(attachment)) = frame.kind() {
+                visit.visit_value(Value::String(&format!("{attachment}")));
+            }
+        }
+    }
+}
+
+impl Listable for ValuableAttachments<'_> {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.0.len()))
+    }
+}
+
+/// The spans of all [`SpanTrace`]s attached to a context.
+#[cfg(feature = "spantrace")]
+struct ValuableSpans<'a>(&'a [&'a Frame]);
+
+#[cfg(feature = "spantrace")]
+impl Valuable for ValuableSpans<'_> {
+    fn as_value(&self) -> Value<'_> {
+        Value::Listable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        for span_trace in self
+            .0
+            .iter()
+            .filter_map(|frame| frame.downcast_ref::<SpanTrace>())
+        {
+            span_trace.with_spans(|metadata, fields| {
+                visit.visit_value(Value::Structable(&ValuableSpan {
+                    name: metadata.name(),
+                    target: metadata.target(),
+                    fields,
+                }));
+                true
+            });
+        }
+    }
+}
+
+#[cfg(feature = "spantrace")]
+impl Listable for ValuableSpans<'_> {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+#[cfg(feature = "spantrace")]
+struct ValuableSpan<'a> {
+    name: &'a str,
+    target: &'a str,
+    fields: &'a str,
+}
+
+#[cfg(feature = "spantrace")]
+impl Valuable for ValuableSpan<'_> {
+    fn as_value(&self) -> Value<'_> {
+        Value::Structable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        visit.visit_named_fields(&NamedValues::new(
+            SPAN_FIELDS,
+            &[
+                Value::String(self.name),
+                Value::String(self.target),
+                Value::String(self.fields),
+            ],
+        ));
+    }
+}
+
+#[cfg(feature = "spantrace")]
+impl Structable for ValuableSpan<'_> {
+    fn definition(&self) -> StructDef<'_> {
+        StructDef::new_static("Span", Fields::Named(SPAN_FIELDS))
+    }
+}
+
+/// A context frame together with all attachments leading up to the next context.
+struct ValuableContext<'a> {
+    frame: &'a Frame,
+    context: &'a dyn Context,
+    /// The attachments, starting with the attachment closest to the context.
+    attachments: Vec<&'a Frame>,
+}
+
+impl Valuable for ValuableContext<'_> {
+    fn as_value(&self) -> Value<'_> {
+        Value::Structable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        let Self {
+            frame,
+            context,
+            attachments,
+        } = self;
+
+        let location = attachments.first().copied().and_then(ValuableLocation::new);
+        let attachments = if location.is_some() {
+            &attachments[1..]
+        } else {
+            attachments.as_slice()
+        };
+
+        let context = format!("{context}");
+        let attachments_value = ValuableAttachments(attachments);
+        let sources = ValuableSources(frame.sources());
+
+        #[cfg(feature = "spantrace")]
+        let spans = ValuableSpans(attachments);
+
+        visit.visit_named_fields(&NamedValues::new(
+            CONTEXT_FIELDS,
+            &[
+                Value::String(&context),
+                Value::String(frame.type_name()),
+                location.as_value(),
+                Value::Listable(&attachments_value),
+                Value::Listable(&sources),
+                #[cfg(feature = "spantrace")]
+                Value::Listable(&spans),
+            ],
+        ));
+    }
+}
+
+impl Structable for ValuableContext<'_> {
+    fn definition(&self) -> StructDef<'_> {
+        StructDef::new_static("Context", Fields::Named(CONTEXT_FIELDS))
+    }
+}
+
+struct ValuableSources<'a>(&'a [Frame]);
+
+impl Valuable for ValuableSources<'_> {
+    fn as_value(&self) -> Value<'_> {
+        Value::Listable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        for context in self.0.iter().flat_map(|source| find_next(&[], source)) {
+            visit.visit_value(Value::Structable(&context));
+        }
+    }
+}
+
+impl Listable for ValuableSources<'_> {
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+fn find_next<'a>(head: &[&'a Frame], mut current: &'a Frame) -> Vec<ValuableContext<'a>> {
+    let mut attachments = vec![];
+    attachments.extend(head);
+
+    loop {
+        if let FrameKind::Context(context) = current.kind() {
+            attachments.reverse();
+
+            return vec![ValuableContext {
+                frame: current,
+                context,
+                attachments,
+            }];
+        } else if current.sources().len() > 1 {
+            attachments.push(current);
+
+            return current
+                .sources()
+                .iter()
+                .flat_map(|source| find_next(&attachments, source))
+                .collect();
+        } else if current.sources().len() == 1 {
+            attachments.push(current);
+
+            current = &current.sources()[0];
+        } else {
+            return vec![];
+        }
+    }
+}
+
+/// Records the [`Report`] as a structured value.
+///
+/// The value is a struct named `Report` with the [`Display`] output of the current context as
+/// `context` and the tree of contexts as `frames`. Every context is recorded with its [`Display`]
+/// output, its type name, the location it has been created at, its printable attachments, the
+/// spans of its [`SpanTrace`] (if the `spantrace` feature is enabled), and its sources.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io;
+///
+/// use error_stack::Report;
+/// use valuable::{Valuable, Value};
+///
+/// let report = Report::new(io::Error::from(io::ErrorKind::NotFound))
+///     .attach_printable("the file does not exist");
+///
+/// match report.as_value() {
+///     Value::Structable(value) => assert_eq!(value.definition().name(), "Report"),
+///     _ => unreachable!("reports are structable"),
+/// }
+///
+/// // with `tracing_unstable` and the `valuable` feature of `tracing`:
+/// // tracing::error!(error = report.as_value(), "could not read the config");
+/// ```
+///
+/// [`Display`]: core::fmt::Display
+/// [`SpanTrace`]: tracing_error::SpanTrace
+impl<C: Context> Valuable for Report<C> {
+    fn as_value(&self) -> Value<'_> {
+        Value::Structable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        visit.visit_named_fields(&NamedValues::new(
+            REPORT_FIELDS,
+            &[
+                Value::String(&format!("{}", self.current_context())),
+                Value::Listable(&ValuableSources(self.current_frames())),
+            ],
+        ));
+    }
+}
+
+impl<C: Context> Structable for Report<C> {
+    fn definition(&self) -> StructDef<'_> {
+        StructDef::new_static("Report", Fields::Named(REPORT_FIELDS))
+    }
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "macros")]
+#![cfg_attr(nightly, feature(error_in_core))]
+
+use error_stack::{track_report, Context, Report, Result};
+
+#[derive(Debug, Context)]
+#[display("could not parse {input:?}")]
+struct ParseError {
+    input: &'static str,
+}
+
+#[track_report(input)]
+fn parse(input: &'static str) -> Result<u32, ParseError> {
+    input
+        .parse()
+        .ok()
+        .ok_or_else(|| Report::new(ParseError { input }))
+}
+
+// The derived context implements `Error`, this fails to compile otherwise
+#[cfg(any(nightly, feature = "std"))]
+const _: () = {
+    #[cfg(nightly)]
+    use core::error::Error;
+    #[cfg(not(nightly))]
+    use std::error::Error;
+
+    const fn assert_error<T>()
+    where
+        T: Error,
+    {
+    }
+
+    assert_error::<ParseError>();
+};
+
+#[test]
+fn derive_and_track() {
+    assert_eq!(parse("42").expect("should succeed"), 42);
+
+    let report = parse("foo").expect_err("should fail");
+    assert_eq!(report.to_string(), r#"could not parse "foo""#);
+
+    let attachments = report
+        .frames()
+        .filter_map(|frame| frame.downcast_ref::<&str>().copied())
+        .collect::<Vec<_>>();
+    assert_eq!(attachments, ["in function `parse`"]);
+}
@@ -5,7 +5,7 @@ extern crate alloc;
 use core::fmt::{Display, Formatter, Write};
 
 mod common;
-use error_stack::{report, Context, Report};
+use error_stack::{report, Context, Report, TryReportIteratorExt};
 
 #[derive(Debug)]
 struct Char(char);
@@ -97,3 +97,76 @@ fn iter_mut() {
         ['A', 'B', 'C', 'D', 'E', 'F', 'G', 'H']
     );
 }
+
+/// Returns `Ok` for uppercase and `Err` for lowercase characters.
+fn uppercase(chars: &str) -> impl Iterator<Item = Result<char, Report<Char>>> + '_ {
+    chars.chars().map(|c| {
+        if c.is_uppercase() {
+            Ok(c)
+        } else {
+            Err(report!(Char(c)))
+        }
+    })
+}
+
+fn contexts(report: &Report<Char>) -> String {
+    report
+        .frames()
+        .filter_map(|frame| frame.downcast_ref::<Char>().map(|c| c.0))
+        .collect()
+}
+
+#[test]
+fn try_collect_reports() {
+    assert_eq!(
+        uppercase("ABC")
+            .try_collect_reports::<String>()
+            .expect("all characters are uppercase"),
+        "ABC"
+    );
+
+    let report = uppercase("AbCd")
+        .try_collect_reports::<String>()
+        .expect_err("not all characters are uppercase");
+    assert_eq!(contexts(&report), "bd");
+}
+
+#[test]
+fn try_collect_reports_bounded() {
+    let mut iter = uppercase("abcD");
+    let report = iter
+        .by_ref()
+        .try_collect_reports_bounded::<String>(2)
+        .expect_err("not all characters are uppercase");
+    assert_eq!(contexts(&report), "ab");
+    assert_eq!(iter.map(Result::ok).collect::<Vec<_>>(), [None, Some('D')]);
+
+    let report = uppercase("ab")
+        .try_collect_reports_bounded::<String>(0)
+        .expect_err("not all characters are uppercase");
+    assert_eq!(contexts(&report), "a");
+}
+
+#[test]
+fn partition_reports() {
+    let (values, report) = uppercase("ABC").partition_reports::<String>();
+    assert_eq!(values, "ABC");
+    assert!(report.is_none());
+
+    let (values, report) = uppercase("AbCd").partition_reports::<String>();
+    assert_eq!(values, "AC");
+    assert_eq!(
+        contexts(&report.expect("not all characters are uppercase")),
+        "bd"
+    );
+}
+
+#[test]
+fn partition_reports_bounded() {
+    let (values, report) = uppercase("aBcDe").partition_reports_bounded::<Vec<_>>(2);
+    assert_eq!(values, ['B', 'D']);
+    assert_eq!(
+        contexts(&report.expect("not all characters are uppercase")),
+        "ac"
+    );
+}
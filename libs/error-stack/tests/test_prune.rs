@@ -0,0 +1,201 @@
+#![cfg_attr(nightly, feature(error_in_core, error_generic_member_access))]
+
+mod common;
+
+#[allow(clippy::wildcard_imports)]
+use common::*;
+use error_stack::Redacted;
+
+#[derive(Debug)]
+struct Token(&'static str);
+
+impl core::fmt::Display for Token {
+    fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(fmt, "token: {}", self.0)
+    }
+}
+
+#[test]
+fn redact() {
+    let report = create_report()
+        .attach_printable(Token("secret"))
+        .attach_printable(PrintableA(0))
+        .attach(Token("secret"))
+        .change_context(ContextA(0))
+        .redact::<Token>();
+
+    assert!(!report.contains::<Token>());
+    assert_eq!(
+        report
+            .frames()
+            .filter(|frame| frame.is::<Redacted>())
+            .count(),
+        2
+    );
+    assert_eq!(
+        remove_builtin_messages(messages(&report)),
+        [
+            "context A",
+            "[redacted]",
+            "printable A",
+            "[redacted]",
+            "root error"
+        ]
+    );
+}
+
+#[test]
+fn redact_keeps_contexts() {
+    let report = create_report()
+        .change_context(ContextA(0))
+        .redact::<ContextA>();
+
+    assert!(report.contains::<ContextA>());
+    assert!(!report.contains::<Redacted>());
+    assert_eq!(report.current_context(), &ContextA(0));
+}
+
+#[test]
+fn prune() {
+    // `create_report` is defined in `common.rs`
+    let report = create_report()
+        .attach_printable(PrintableA(0))
+        .change_context(ContextA(0))
+        .attach_printable(PrintableB(0))
+        .change_context(ContextB(0))
+        .prune(|location| location.file().ends_with("common.rs"));
+
+    assert_eq!(
+        remove_builtin_messages(messages(&report)),
+        ["context B", "printable B", "context A"]
+    );
+    assert_eq!(report.current_context(), &ContextB(0));
+}
+
+#[test]
+fn prune_keeps_current_context() {
+    let report = create_report()
+        .attach_printable(PrintableA(0))
+        .change_context(ContextA(0))
+        .attach_printable(PrintableB(0))
+        .prune(|_| true);
+
+    assert_eq!(
+        remove_builtin_messages(messages(&report)),
+        ["printable B", "context A"]
+    );
+    assert_eq!(report.current_context(), &ContextA(0));
+}
+
+#[test]
+fn prune_sources() {
+    let mut report = create_report().attach_printable(PrintableA(1));
+    report.extend_one(create_report().attach_printable(PrintableA(2)));
+    let mut report = report
+        .attach_printable(PrintableB(0))
+        .change_context(ContextA(0));
+    report.extend_one(
+        create_report()
+            .attach_printable(PrintableA(3))
+            .change_context(ContextA(1)),
+    );
+
+    let report = report.prune(|location| location.file().ends_with("common.rs"));
+
+    assert_eq!(
+        remove_builtin_messages(messages(&report)),
+        ["context A", "context A"]
+    );
+    assert_eq!(report.current_frames().len(), 2);
+}
+
+#[test]
+fn collapse() {
+    let report = create_report()
+        .attach_printable("retrying")
+        .attach_printable("retrying")
+        .attach_printable("retrying")
+        .attach_printable(PrintableC(0))
+        .attach_printable(PrintableC(0))
+        .attach_printable(PrintableC(1))
+        .collapse();
+
+    assert_eq!(
+        remove_builtin_messages(messages(&report)),
+        ["printable C: 1", "printable C: 0", "retrying", "root error"]
+    );
+}
+
+#[test]
+fn collapse_contexts() {
+    let report = create_report()
+        .change_context(ContextA(0))
+        .change_context(ContextA(1))
+        .change_context(ContextB(0))
+        .change_context(ContextA(2))
+        .collapse();
+
+    assert_eq!(
+        remove_builtin_messages(messages(&report)),
+        ["context A", "context B", "context A", "root error"]
+    );
+    // the most recent context is kept
+    assert_eq!(report.current_context(), &ContextA(2));
+    assert!(
+        report
+            .frames()
+            .any(|frame| frame.downcast_ref() == Some(&ContextA(1)))
+    );
+    assert!(
+        !report
+            .frames()
+            .any(|frame| frame.downcast_ref() == Some(&ContextA(0)))
+    );
+}
+
+#[test]
+fn collapse_attachments_of_different_types() {
+    let report = create_report()
+        .attach_printable(PrintableA(0))
+        .attach_printable(PrintableB(0))
+        .attach_printable(PrintableA(0))
+        .collapse();
+
+    assert_eq!(
+        remove_builtin_messages(messages(&report)),
+        ["printable A", "printable B", "printable A", "root error"]
+    );
+}
+
+#[test]
+fn collapse_sources() {
+    let mut report = create_report().attach_printable(PrintableA(0));
+    report.extend_one(create_report().attach_printable(PrintableA(0)));
+    let report = report.attach_printable(PrintableA(0)).collapse();
+
+    // attachments with multiple sources are not collapsed
+    assert_eq!(
+        remove_builtin_messages(messages(&report)),
+        [
+            "printable A",
+            "printable A",
+            "root error",
+            "printable A",
+            "root error"
+        ]
+    );
+}
+
+#[test]
+fn strip_locations() {
+    let mut report = create_report().attach_printable(PrintableA(0));
+    report.extend_one(create_report().attach_printable(PrintableB(0)));
+    let report = report.change_context(ContextB(0)).strip_locations();
+
+    assert!(!report.contains::<core::panic::Location<'static>>());
+    assert_eq!(
+        remove_builtin_messages(messages(&report)),
+        ["context B", "printable A", "root error", "printable B", "root error"]
+    );
+    assert_eq!(report.current_context(), &ContextB(0));
+}
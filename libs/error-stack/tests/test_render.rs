@@ -0,0 +1,133 @@
+#![cfg(any(feature = "std", feature = "hooks"))]
+// can be considered safe, because we only check the output, which in itself does not use **any**
+// unsafe code.
+#![cfg(not(miri))]
+#![cfg_attr(nightly, feature(error_in_core, error_generic_member_access))]
+#![allow(clippy::std_instead_of_core)]
+
+mod common;
+
+use core::panic::Location;
+
+use common::{create_report, ContextA, ContextB, PrintableA, PrintableC};
+use error_stack::{
+    fmt::{Compact, Html, Markdown},
+    Report,
+};
+use insta::assert_snapshot;
+
+fn prepare() -> impl Drop {
+    std::env::set_var("RUST_LIB_BACKTRACE", "0");
+
+    // locations contain line numbers, which would make the snapshots brittle
+    Report::install_debug_hook::<Location<'static>>(|_, _| {});
+
+    let settings = insta::Settings::clone_current();
+
+    settings.bind_to_scope()
+}
+
+fn create_linear() -> Report<ContextB> {
+    create_report()
+        .attach_printable(PrintableA(1))
+        .change_context(ContextA(2))
+        .attach_printable("multiline\nattachment")
+        .attach(PrintableA(3))
+        .change_context(ContextB(4))
+}
+
+fn create_sources() -> Report<ContextA> {
+    let mut report = create_report().attach_printable(PrintableC(1));
+    report.extend_one(create_report().attach_printable(PrintableC(2)));
+
+    report
+        .attach_printable(PrintableC(3))
+        .change_context(ContextA(4))
+        .attach_printable(PrintableC(5))
+}
+
+#[test]
+fn compact() {
+    let _guard = prepare();
+
+    assert_snapshot!(create_linear().render(Compact).to_string());
+}
+
+#[test]
+fn compact_sources() {
+    let _guard = prepare();
+
+    assert_snapshot!(create_sources().render(Compact).to_string());
+}
+
+#[test]
+fn html() {
+    let _guard = prepare();
+
+    assert_snapshot!(create_linear().render(Html).to_string());
+}
+
+#[test]
+fn html_sources() {
+    let _guard = prepare();
+
+    assert_snapshot!(create_sources().render(Html).to_string());
+}
+
+#[test]
+fn html_escape() {
+    let _guard = prepare();
+
+    let report = create_report().attach_printable("<script>alert('error & \"stack\"')</script>");
+
+    assert_snapshot!(report.render(Html).to_string());
+}
+
+#[test]
+fn markdown() {
+    let _guard = prepare();
+
+    assert_snapshot!(create_linear().render(Markdown).to_string());
+}
+
+#[test]
+fn markdown_sources() {
+    let _guard = prepare();
+
+    assert_snapshot!(create_sources().render(Markdown).to_string());
+}
+
+#[test]
+fn markdown_escape() {
+    let _guard = prepare();
+
+    let report = create_report().attach_printable("`code` with *emphasis* and [link](url)");
+
+    assert_snapshot!(report.render(Markdown).to_string());
+}
+
+#[test]
+fn hook() {
+    #[derive(Debug)]
+    struct Suggestion(&'static str);
+
+    let _guard = prepare();
+
+    Report::install_debug_hook::<Suggestion>(|Suggestion(value), context| {
+        let idx = context.increment_counter() + 1;
+
+        context.push_body(format!("suggestion ({idx})"));
+        context.push_appendix(format!("suggestion no. {idx}\n  {value}"));
+    });
+
+    let report = create_report()
+        .attach(Suggestion("check the configuration"))
+        .change_context(ContextA(1));
+
+    assert_eq!(
+        report.render(Compact).to_string(),
+        "context A: root error (suggestion (1))"
+    );
+    assert_snapshot!("hook_html", report.render(Html).to_string());
+    assert_snapshot!("hook_markdown", report.render(Markdown).to_string());
+}
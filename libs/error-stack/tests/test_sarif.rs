@@ -0,0 +1,88 @@
+#![cfg(all(feature = "std", feature = "sarif", feature = "serde"))]
+#![cfg_attr(nightly, feature(error_in_core, error_generic_member_access))]
+
+#[cfg(feature = "remote")]
+use error_stack::RemoteReport;
+use error_stack::Report;
+use insta::assert_json_snapshot;
+use sarif::schema::SarifLog;
+
+use crate::common::{create_report, ContextA, ContextB, PrintableA, PrintableC};
+
+mod common;
+
+fn prepare() -> impl Drop {
+    let mut settings = insta::Settings::clone_current();
+    settings.add_filter(&regex::escape(env!("CARGO_PKG_VERSION")), "[version]");
+
+    settings.bind_to_scope()
+}
+
+#[test]
+fn context() {
+    let _guard = prepare();
+
+    let report = create_report()
+        .attach_printable(PrintableA(1))
+        .change_context(ContextA(2));
+
+    assert_json_snapshot!(SarifLog::from(&report));
+}
+
+#[test]
+fn multiple_sources() {
+    let _guard = prepare();
+
+    let mut a = create_report().attach_printable(PrintableC(1));
+    let b = create_report().attach_printable(PrintableC(2));
+
+    a.extend_one(b);
+
+    let a = a
+        .change_context(ContextA(3))
+        .attach_printable(PrintableC(4));
+
+    assert_json_snapshot!(SarifLog::from(&a));
+}
+
+#[test]
+fn rules_are_unique() {
+    let mut a = create_report().change_context(ContextB(1));
+    let b = create_report().change_context(ContextB(2));
+
+    a.extend_one(b);
+
+    let log = SarifLog::from(&a);
+    let run = &log.runs.expect("log should contain a run")[0];
+
+    assert_eq!(run.results.len(), 4);
+    assert_eq!(run.tool.driver.rules.len(), 2);
+    assert!(run.results.iter().all(|result| {
+        result.rule_id.as_deref().map_or(false, |rule_id| {
+            run.tool.driver.rules.iter().any(|rule| rule.id == rule_id)
+        })
+    }));
+}
+
+#[test]
+#[cfg(feature = "remote")]
+fn remote_location() {
+    let report = create_report().change_context(ContextA(1));
+    let remote: Report<_> = RemoteReport::try_from(&report)
+        .expect("report should be serializable")
+        .into_report()
+        .expect("report should not be empty");
+
+    let local = SarifLog::from(&report);
+    let remote = SarifLog::from(&remote);
+
+    let locations = |log: &SarifLog<'static>| {
+        log.runs.as_ref().expect("log should contain a run")[0]
+            .results
+            .iter()
+            .map(|result| result.locations.clone())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(locations(&local), locations(&remote));
+}
@@ -7,6 +7,11 @@
 #![cfg_attr(nightly, feature(error_in_core, error_generic_member_access))]
 #![allow(clippy::std_instead_of_core)]
 
+use core::fmt::{self, Display, Formatter};
+
+use error_stack::{Context, Report};
+#[cfg(feature = "remote")]
+use error_stack::{RemoteContext, RemoteReport};
 use insta::assert_ron_snapshot;
 
 use crate::common::{create_report, ContextA, PrintableA, PrintableC};
@@ -69,3 +74,170 @@ fn multiple_sources_at_root() {
 
     assert_ron_snapshot!(a);
 }
+
+#[test]
+fn hook_opaque_attachment() {
+    #[derive(serde::Serialize)]
+    struct RetryAfter {
+        seconds: u64,
+    }
+
+    let _guard = prepare();
+
+    Report::install_serde_hook::<RetryAfter>();
+
+    let report = create_report()
+        .attach(RetryAfter { seconds: 30 })
+        .attach_printable(PrintableA(2));
+
+    assert_ron_snapshot!(report);
+}
+
+#[test]
+fn hook_printable_attachment() {
+    #[derive(Debug, serde::Serialize)]
+    struct Code(u16);
+
+    impl Display for Code {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            write!(f, "code {}", self.0)
+        }
+    }
+
+    let _guard = prepare();
+
+    Report::install_serde_hook::<Code>();
+
+    let report = create_report().attach_printable(Code(404));
+
+    assert_ron_snapshot!(report);
+}
+
+#[test]
+fn hook_context() {
+    #[derive(Debug, serde::Serialize)]
+    struct RequestFailed {
+        method: &'static str,
+        status: u16,
+    }
+
+    impl Display for RequestFailed {
+        fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+            f.write_str("request failed")
+        }
+    }
+
+    impl Context for RequestFailed {}
+
+    let _guard = prepare();
+
+    Report::install_serde_hook::<RequestFailed>();
+
+    let report = create_report().change_context(RequestFailed {
+        method: "GET",
+        status: 502,
+    });
+
+    assert_ron_snapshot!(report);
+}
+
+#[test]
+#[cfg(feature = "remote")]
+fn remote_round_trip() {
+    #[derive(serde::Serialize)]
+    struct Attempt(u8);
+
+    let _guard = prepare();
+
+    Report::install_serde_hook::<Attempt>();
+
+    let mut report = create_report()
+        .attach_printable(PrintableA(1))
+        .attach(Attempt(3))
+        .change_context(ContextA(2));
+    report.extend_one(create_report().change_context(ContextA(4)));
+
+    let expected = serde_json::to_value(&report).expect("report should be serializable");
+
+    let remote = RemoteReport::try_from(&report).expect("report should be convertible");
+    assert_eq!(
+        serde_json::to_value(&remote).expect("remote report should be serializable"),
+        expected
+    );
+
+    let received = remote
+        .into_report()
+        .expect("remote report should not be empty");
+    assert_eq!(received.current_frames().len(), 2);
+    assert_eq!(
+        received
+            .frames()
+            .filter(|frame| frame.is::<RemoteContext>())
+            .count(),
+        4
+    );
+    assert_eq!(
+        serde_json::to_value(&received).expect("received report should be serializable"),
+        expected
+    );
+}
+
+#[test]
+#[cfg(feature = "remote")]
+fn remote_as_source() {
+    let _guard = prepare();
+
+    let remote: RemoteReport = serde_json::from_str(
+        r#"[{
+            "context": "remote error",
+            "value": {"code": 404},
+            "location": {"file": "src/server.rs", "line": 12, "column": 5},
+            "attachments": ["not found", {"retry": false}],
+            "sources": []
+        }]"#,
+    )
+    .expect("remote report should be deserializable");
+
+    let report = remote
+        .into_report()
+        .expect("remote report should not be empty")
+        .change_context(ContextA(1));
+
+    assert_ron_snapshot!(report);
+}
+
+#[test]
+#[cfg(feature = "remote")]
+fn remote_empty() {
+    let remote: RemoteReport = serde_json::from_str("[]").expect("should be deserializable");
+
+    assert!(remote.into_report().is_none());
+}
+
+#[test]
+#[cfg(feature = "remote")]
+fn remote_strip_locations() {
+    let remote: RemoteReport = serde_json::from_str(
+        r#"[{
+            "context": "remote error",
+            "location": {"file": "src/server.rs", "line": 12, "column": 5},
+            "attachments": ["not found"],
+            "sources": []
+        }]"#,
+    )
+    .expect("remote report should be deserializable");
+
+    let report = remote
+        .into_report()
+        .expect("remote report should not be empty")
+        .strip_locations();
+
+    assert_eq!(
+        serde_json::to_value(&report).expect("report should be serializable"),
+        serde_json::json!([{
+            "context": "remote error",
+            "attachments": ["not found"],
+            "sources": []
+        }])
+    );
+}
@@ -0,0 +1,114 @@
+#![cfg(all(feature = "std", feature = "spantrace", feature = "valuable"))]
+#![cfg_attr(nightly, feature(error_in_core, error_generic_member_access))]
+
+use error_stack::Result;
+use insta::assert_debug_snapshot;
+use tracing_error::ErrorLayer;
+use tracing_subscriber::layer::SubscriberExt;
+use valuable::{Valuable, Value, Visit};
+
+use crate::common::{
+    capture_error, create_error, create_report, ContextA, PrintableA, PrintableC, RootError,
+};
+
+mod common;
+
+fn install_tracing_subscriber() {
+    static ONCE: std::sync::Once = std::sync::Once::new();
+    ONCE.call_once(|| {
+        tracing::subscriber::set_global_default(
+            tracing_subscriber::Registry::default().with(ErrorLayer::default()),
+        )
+        .expect("Could not set tracing subscriber");
+    });
+}
+
+#[test]
+fn context() {
+    let report = create_report()
+        .attach_printable(PrintableA(1))
+        .change_context(ContextA(2));
+
+    assert_debug_snapshot!(report.as_value());
+}
+
+#[test]
+fn multiple_sources() {
+    let mut a = create_report().attach_printable(PrintableC(1));
+    let b = create_report().attach_printable(PrintableC(2));
+
+    a.extend_one(b);
+
+    let a = a
+        .attach_printable(PrintableC(3))
+        .change_context(ContextA(2))
+        .attach_printable(PrintableC(4));
+
+    assert_debug_snapshot!(a.as_value());
+}
+
+#[test]
+fn spans() {
+    /// Collects the names of all visited spans.
+    #[derive(Default)]
+    struct SpanNames(Vec<String>);
+
+    impl Visit for SpanNames {
+        fn visit_value(&mut self, value: Value<'_>) {
+            match value {
+                Value::Structable(structable) => structable.visit(self),
+                Value::Listable(listable) => listable.visit(self),
+                _ => {}
+            }
+        }
+
+        fn visit_named_fields(&mut self, named_values: &valuable::NamedValues<'_>) {
+            if let Some(spans) = named_values.get_by_name("spans") {
+                spans.visit(&mut SpanCollector(&mut self.0));
+            }
+            if let Some(sources) = named_values.get_by_name("sources") {
+                sources.visit(self);
+            }
+            if let Some(frames) = named_values.get_by_name("frames") {
+                frames.visit(self);
+            }
+        }
+    }
+
+    struct SpanCollector<'a>(&'a mut Vec<String>);
+
+    impl Visit for SpanCollector<'_> {
+        fn visit_value(&mut self, value: Value<'_>) {
+            match value {
+                Value::Structable(span) => span.visit(self),
+                Value::Listable(spans) => spans.visit(self),
+                _ => {}
+            }
+        }
+
+        fn visit_named_fields(&mut self, named_values: &valuable::NamedValues<'_>) {
+            if let Some(Value::String(name)) = named_values.get_by_name("name") {
+                self.0.push((*name).to_owned());
+            }
+        }
+    }
+
+    #[tracing::instrument]
+    fn func_b() -> Result<(), RootError> {
+        create_error()
+    }
+
+    #[tracing::instrument]
+    fn func_a() -> Result<(), RootError> {
+        func_b()
+    }
+
+    install_tracing_subscriber();
+
+    let report = capture_error(func_a);
+
+    let mut names = SpanNames::default();
+    report.visit(&mut names);
+
+    assert_eq!(names.0, ["func_b", "func_a"]);
+}
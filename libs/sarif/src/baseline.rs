@@ -0,0 +1,287 @@
+//! Comparing a [`SarifLog`] against the log of a previous run.
+
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    string::String,
+    vec::Vec,
+};
+
+use crate::schema::{BaselineState, Result, Run, SarifLog};
+
+/// Returns if `current` differs from `baseline` in a way relevant to a user.
+fn is_updated(current: &Result<'_>, baseline: &Result<'_>) -> bool {
+    current.kind != baseline.kind
+        || current.level != baseline.level
+        || current.message != baseline.message
+        || current.locations != baseline.locations
+}
+
+impl Run<'_> {
+    /// Sets the [`baseline_state`] of every result by comparing the run against the run of the
+    /// same tool in a baseline, e.g. the analysis of the default branch.
+    ///
+    /// Results are matched by their [`fingerprints`] or, if there are none, by their partial
+    /// fingerprint (see [`Result::compute_fingerprint`]). A matched result is either
+    /// [`Unchanged`] or [`Updated`], depending on if its kind, level, message or locations
+    /// have changed. Results of the baseline without a match are added to the run as [`Absent`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{BaselineState, Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let baseline = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_result(Result::new(Message::new("unused variable: `x`")));
+    ///
+    /// let mut run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([
+    ///     Result::new(Message::new("unused variable: `x`")),
+    ///     Result::new(Message::new("unused variable: `y`")),
+    /// ]);
+    /// run.apply_baseline(&baseline);
+    ///
+    /// let new = run
+    ///     .results
+    ///     .iter()
+    ///     .filter(|result| result.baseline_state == Some(BaselineState::New))
+    ///     .count();
+    /// assert_eq!(new, 1);
+    /// ```
+    ///
+    /// [`baseline_state`]: Result::baseline_state
+    /// [`fingerprints`]: Result::fingerprints
+    /// [`Unchanged`]: BaselineState::Unchanged
+    /// [`Updated`]: BaselineState::Updated
+    /// [`Absent`]: BaselineState::Absent
+    pub fn apply_baseline(&mut self, baseline: &Self) {
+        // Identical results are matched in order of their occurrence
+        let mut baseline_results = BTreeMap::<String, VecDeque<usize>>::new();
+        for (index, result) in baseline.results.iter().enumerate() {
+            if result.baseline_state != Some(BaselineState::Absent) {
+                baseline_results
+                    .entry(result.identity())
+                    .or_default()
+                    .push_back(index);
+            }
+        }
+
+        for result in &mut self.results {
+            let matched = baseline_results
+                .get_mut(&result.identity())
+                .and_then(VecDeque::pop_front)
+                .map(|index| &baseline.results[index]);
+
+            result.baseline_state = Some(match matched {
+                Some(baseline) if is_updated(result, baseline) => BaselineState::Updated,
+                Some(_) => BaselineState::Unchanged,
+                None => BaselineState::New,
+            });
+        }
+
+        let mut absent = baseline_results.into_values().flatten().collect::<Vec<_>>();
+        absent.sort_unstable();
+        self.results.extend(absent.into_iter().map(|index| {
+            baseline.results[index]
+                .clone()
+                .with_baseline_state(BaselineState::Absent)
+        }));
+    }
+}
+
+impl SarifLog<'_> {
+    /// Sets the [`baseline_state`] of every result by comparing each run against the run of the
+    /// same tool in `baseline`.
+    ///
+    /// Runs are matched by the name of their [driver]. If there is no matching run in the
+    /// baseline, all results are [`New`]. See [`Run::apply_baseline`] for details.
+    ///
+    /// This can be used to only report newly introduced results, e.g. to fail a CI job only if a
+    /// change introduces new lint warnings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{
+    ///     BaselineState, Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    /// };
+    ///
+    /// let clippy = || Run::new(Tool::new(ToolComponent::new("clippy")));
+    ///
+    /// let baseline = SarifLog::new(SchemaVersion::V2_1_0)
+    ///     .with_run(clippy().with_result(Result::new(Message::new("unused variable: `x`"))));
+    ///
+    /// let mut log = SarifLog::new(SchemaVersion::V2_1_0)
+    ///     .with_run(clippy().with_result(Result::new(Message::new("unused import: `std::io`"))));
+    /// log.apply_baseline(&baseline);
+    ///
+    /// let states = log.runs.unwrap()[0]
+    ///     .results
+    ///     .iter()
+    ///     .map(|result| result.baseline_state.unwrap())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(states, [BaselineState::New, BaselineState::Absent]);
+    /// ```
+    ///
+    /// [`baseline_state`]: Result::baseline_state
+    /// [driver]: crate::schema::Tool::driver
+    /// [`New`]: BaselineState::New
+    pub fn apply_baseline(&mut self, baseline: &Self) {
+        let mut baseline_runs = baseline.runs.iter().flatten().collect::<Vec<_>>();
+
+        for run in self.runs.iter_mut().flatten() {
+            let position = baseline_runs
+                .iter()
+                .position(|baseline| baseline.tool.driver.name == run.tool.driver.name);
+
+            match position {
+                Some(position) => run.apply_baseline(baseline_runs.remove(position)),
+                None => {
+                    for result in &mut run.results {
+                        result.baseline_state = Some(BaselineState::New);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use coverage_helper::test;
+
+    use crate::schema::{
+        ArtifactLocation, BaselineState, Level, Location, Message, PhysicalLocation, Region,
+        Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    fn result(message: &'static str, line: u32) -> Result<'static> {
+        Result::new(Message::new(message))
+            .with_rule_id("unused_variables")
+            .with_location(
+                Location::new().with_physical_location(
+                    PhysicalLocation::new(ArtifactLocation::new("src/main.rs"))
+                        .with_region(Region::new(line)),
+                ),
+            )
+    }
+
+    fn run(name: &'static str) -> Run<'static> {
+        Run::new(Tool::new(ToolComponent::new(name)))
+    }
+
+    fn states<'r>(run: &'r Run<'_>) -> Vec<(&'r str, BaselineState)> {
+        run.results
+            .iter()
+            .map(|result| {
+                (
+                    result
+                        .message
+                        .text
+                        .as_deref()
+                        .expect("message should have text"),
+                    result.baseline_state.expect("baseline state should be set"),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn states_of_results() {
+        let baseline = run("clippy").with_results([
+            result("unused variable: `a`", 1),
+            result("unused variable: `b`", 2),
+            result("unused variable: `c`", 3),
+            result("unused variable: `d`", 4),
+        ]);
+
+        let mut current = run("clippy").with_results([
+            result("unused variable: `a`", 1),
+            result("unused variable: `b`", 12),
+            result("unused variable: `e`", 5),
+            result("unused variable: `d`", 4).with_level(Level::Error),
+        ]);
+        current.apply_baseline(&baseline);
+
+        assert_eq!(
+            states(&current),
+            [
+                ("unused variable: `a`", BaselineState::Unchanged),
+                ("unused variable: `b`", BaselineState::Updated),
+                ("unused variable: `e`", BaselineState::New),
+                ("unused variable: `d`", BaselineState::Updated),
+                ("unused variable: `c`", BaselineState::Absent),
+            ]
+        );
+    }
+
+    #[test]
+    fn duplicated_results() {
+        let baseline = run("clippy").with_result(result("unused variable: `a`", 1));
+
+        let mut current = run("clippy").with_results([
+            result("unused variable: `a`", 1),
+            result("unused variable: `a`", 8),
+        ]);
+        current.apply_baseline(&baseline);
+
+        assert_eq!(
+            states(&current),
+            [
+                ("unused variable: `a`", BaselineState::Unchanged),
+                ("unused variable: `a`", BaselineState::New),
+            ]
+        );
+    }
+
+    #[test]
+    fn fingerprints() {
+        let baseline = run("clippy")
+            .with_result(result("unused variable: `a`", 1).with_fingerprint("stableId/v1", "1"));
+
+        let mut current = run("clippy")
+            .with_result(result("unused variable: `b`", 1).with_fingerprint("stableId/v1", "1"));
+        current.apply_baseline(&baseline);
+
+        assert_eq!(
+            states(&current),
+            [("unused variable: `b`", BaselineState::Updated)]
+        );
+    }
+
+    #[test]
+    fn absent_results_are_not_carried_over() {
+        let baseline = run("clippy").with_result(
+            result("unused variable: `a`", 1).with_baseline_state(BaselineState::Absent),
+        );
+
+        let mut current = run("clippy");
+        current.apply_baseline(&baseline);
+
+        assert!(current.results.is_empty());
+    }
+
+    #[test]
+    fn log() {
+        let baseline = SarifLog::new(SchemaVersion::V2_1_0).with_runs([
+            run("clippy").with_result(result("unused variable: `a`", 1)),
+            run("rustc").with_result(result("unused variable: `b`", 1)),
+        ]);
+
+        let mut current = SarifLog::new(SchemaVersion::V2_1_0).with_runs([
+            run("rustfmt").with_result(result("unused variable: `c`", 1)),
+            run("clippy").with_result(result("unused variable: `a`", 1)),
+        ]);
+        current.apply_baseline(&baseline);
+
+        let runs = current.runs.expect("log should have runs");
+        assert_eq!(
+            states(&runs[0]),
+            [("unused variable: `c`", BaselineState::New)]
+        );
+        assert_eq!(
+            states(&runs[1]),
+            [("unused variable: `a`", BaselineState::Unchanged)]
+        );
+    }
+}
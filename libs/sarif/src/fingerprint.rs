@@ -0,0 +1,292 @@
+//! Computing stable fingerprints of [`Result`]s.
+
+use alloc::{borrow::Cow, format, string::String};
+use core::hash::Hasher;
+
+use fnv::FnvHasher;
+
+use crate::schema::{Result, Run, SarifLog};
+
+/// Hasher used for fingerprints.
+///
+/// FNV-1a is used as it's stable across platforms and Rust versions.
+#[derive(Default)]
+struct FingerprintHasher(FnvHasher);
+
+impl FingerprintHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+
+    /// Writes a field, which is delimited so that adjacent fields cannot be confused.
+    fn write_field(&mut self, field: Option<&str>) {
+        match field {
+            Some(field) => {
+                self.write(&[1]);
+                self.write(field.as_bytes());
+                self.write(&[0]);
+            }
+            None => self.write(&[0]),
+        }
+    }
+
+    fn finish(&self) -> String {
+        format!("{:016x}", self.0.finish())
+    }
+}
+
+impl Result<'_> {
+    /// The method name under which [`compute_fingerprint`] is stored in
+    /// [`Result::partial_fingerprints`].
+    ///
+    /// [`compute_fingerprint`]: Self::compute_fingerprint
+    pub const FINGERPRINT_METHOD: &'static str = "primaryLocationHash/v1";
+
+    /// Computes a partial fingerprint of the result, which is stable across unrelated changes of
+    /// the analyzed artifacts.
+    ///
+    /// The fingerprint is derived from the rule, the message, and the artifact and the snippet of
+    /// the primary location, but not from line or column numbers. Whitespace in the snippet is
+    /// ignored. It is therefore not affected by code being moved within the artifact or being
+    /// reformatted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, Message, PhysicalLocation, Region, Result};
+    ///
+    /// let result = |line| {
+    ///     Result::new(Message::new("unused variable: `x`"))
+    ///         .with_rule_id("unused_variables")
+    ///         .with_location(
+    ///             Location::new().with_physical_location(
+    ///                 PhysicalLocation::new(ArtifactLocation::new("src/main.rs"))
+    ///                     .with_region(Region::new(line)),
+    ///             ),
+    ///         )
+    /// };
+    ///
+    /// assert_eq!(
+    ///     result(3).compute_fingerprint(),
+    ///     result(7).compute_fingerprint()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn compute_fingerprint(&self) -> String {
+        let mut hasher = FingerprintHasher::default();
+
+        hasher.write_field(self.rule_id.as_deref());
+        hasher.write_field(self.message.text.as_deref());
+        hasher.write_field(self.message.id.as_deref());
+
+        let physical_location = self
+            .locations
+            .first()
+            .and_then(|location| location.physical_location.as_ref());
+        let artifact_location = physical_location
+            .and_then(|physical_location| physical_location.artifact_location.as_ref());
+        hasher.write_field(artifact_location.and_then(|location| location.uri_base_id.as_deref()));
+        hasher.write_field(artifact_location.and_then(|location| location.uri.as_deref()));
+
+        let snippet = physical_location
+            .and_then(|physical_location| physical_location.region.as_ref())
+            .and_then(|region| region.snippet.as_ref())
+            .and_then(|snippet| snippet.text.as_deref());
+        match snippet {
+            Some(snippet) => {
+                hasher.write(&[1]);
+                for word in snippet.split_whitespace() {
+                    hasher.write(word.as_bytes());
+                    hasher.write(&[0]);
+                }
+            }
+            None => hasher.write(&[0]),
+        }
+
+        hasher.finish()
+    }
+
+    /// Stores [`compute_fingerprint`] in [`partial_fingerprints`] under
+    /// [`FINGERPRINT_METHOD`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_computed_fingerprint();
+    ///
+    /// assert!(
+    ///     result
+    ///         .partial_fingerprints
+    ///         .contains_key(Result::FINGERPRINT_METHOD)
+    /// );
+    /// ```
+    ///
+    /// [`compute_fingerprint`]: Self::compute_fingerprint
+    /// [`partial_fingerprints`]: Self::partial_fingerprints
+    /// [`FINGERPRINT_METHOD`]: Self::FINGERPRINT_METHOD
+    #[must_use]
+    pub fn with_computed_fingerprint(mut self) -> Self {
+        self.compute_partial_fingerprint();
+        self
+    }
+
+    fn compute_partial_fingerprint(&mut self) {
+        let fingerprint = self.compute_fingerprint();
+        self.partial_fingerprints.insert(
+            Cow::Borrowed(Self::FINGERPRINT_METHOD),
+            Cow::Owned(fingerprint),
+        );
+    }
+
+    /// Returns the fingerprint used to identify the result across runs.
+    ///
+    /// If the result has [`fingerprints`], the rule and these are used. Otherwise, the partial
+    /// fingerprint stored under [`FINGERPRINT_METHOD`] is used, or computed if missing.
+    ///
+    /// [`fingerprints`]: Self::fingerprints
+    /// [`FINGERPRINT_METHOD`]: Self::FINGERPRINT_METHOD
+    pub(crate) fn identity(&self) -> String {
+        if self.fingerprints.is_empty() {
+            return self
+                .partial_fingerprints
+                .get(Self::FINGERPRINT_METHOD)
+                .map_or_else(
+                    || self.compute_fingerprint(),
+                    |fingerprint| String::from(fingerprint.as_ref()),
+                );
+        }
+
+        let mut hasher = FingerprintHasher::default();
+        hasher.write_field(self.rule_id.as_deref());
+        for (method, fingerprint) in &self.fingerprints {
+            hasher.write_field(Some(method));
+            hasher.write_field(Some(fingerprint));
+        }
+        hasher.finish()
+    }
+}
+
+impl Run<'_> {
+    /// Computes the partial fingerprint of every result of the run.
+    ///
+    /// See [`Result::compute_fingerprint`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let mut run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_result(Result::new(Message::new("unused variable: `x`")));
+    /// run.compute_fingerprints();
+    ///
+    /// assert!(
+    ///     run.results[0]
+    ///         .partial_fingerprints
+    ///         .contains_key(Result::FINGERPRINT_METHOD)
+    /// );
+    /// ```
+    pub fn compute_fingerprints(&mut self) {
+        for result in &mut self.results {
+            result.compute_partial_fingerprint();
+        }
+    }
+}
+
+impl SarifLog<'_> {
+    /// Computes the partial fingerprint of every result of every run in the log.
+    ///
+    /// See [`Result::compute_fingerprint`] for details.
+    pub fn compute_fingerprints(&mut self) {
+        for run in self.runs.iter_mut().flatten() {
+            run.compute_fingerprints();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        ArtifactContent, ArtifactLocation, Location, Message, PhysicalLocation, Region, Result,
+    };
+
+    fn result(
+        rule_id: &'static str,
+        uri: &'static str,
+        region: Region<'static>,
+    ) -> Result<'static> {
+        Result::new(Message::new("mismatched types"))
+            .with_rule_id(rule_id)
+            .with_location(Location::new().with_physical_location(
+                PhysicalLocation::new(ArtifactLocation::new(uri)).with_region(region),
+            ))
+    }
+
+    #[test]
+    fn independent_of_lines() {
+        assert_eq!(
+            result("E0308", "src/main.rs", Region::new(3)).compute_fingerprint(),
+            result("E0308", "src/main.rs", Region::new(7).with_start_column(4))
+                .compute_fingerprint()
+        );
+    }
+
+    #[test]
+    fn dependent_on_rule_and_artifact() {
+        let fingerprint = result("E0308", "src/main.rs", Region::new(3)).compute_fingerprint();
+
+        assert_ne!(
+            fingerprint,
+            result("E0309", "src/main.rs", Region::new(3)).compute_fingerprint()
+        );
+        assert_ne!(
+            fingerprint,
+            result("E0308", "src/lib.rs", Region::new(3)).compute_fingerprint()
+        );
+    }
+
+    #[test]
+    fn snippet_whitespace() {
+        let snippet = |text| Region::new(3).with_snippet(ArtifactContent::new().with_text(text));
+
+        assert_eq!(
+            result("E0308", "src/main.rs", snippet("let x: i32 = \"5\";")).compute_fingerprint(),
+            result("E0308", "src/main.rs", snippet("    let x:  i32 =\n\"5\";"))
+                .compute_fingerprint()
+        );
+        assert_ne!(
+            result("E0308", "src/main.rs", snippet("let x: i32 = \"5\";")).compute_fingerprint(),
+            result("E0308", "src/main.rs", snippet("let y: i32 = \"5\";")).compute_fingerprint()
+        );
+    }
+
+    #[test]
+    fn stable() {
+        // The fingerprint is persisted in logs, so it must not change between versions
+        assert_eq!(
+            result("E0308", "src/main.rs", Region::new(3)).compute_fingerprint(),
+            "e0cda5ab9cc6ebf1"
+        );
+    }
+
+    #[test]
+    fn identity_prefers_fingerprints() {
+        let result = result("E0308", "src/main.rs", Region::new(3));
+
+        assert_eq!(
+            result.identity(),
+            result.clone().with_computed_fingerprint().identity()
+        );
+        assert_ne!(
+            result.identity(),
+            result
+                .clone()
+                .with_fingerprint("stableId/v1", "1")
+                .identity()
+        );
+    }
+}
@@ -14,5 +14,9 @@ extern crate std;
 
 pub mod schema;
 
+mod baseline;
+mod fingerprint;
+mod merge;
+
 #[cfg(feature = "serde")]
 pub(crate) mod serde;
@@ -0,0 +1,272 @@
+//! Merging multiple [`SarifLog`]s into one.
+
+use crate::schema::{
+    Artifact, ArtifactLocation, Invocation, Location, Result, Run, SarifLog, ThreadFlowLocation,
+};
+
+/// Offsets to apply to indices into the arrays of a [`Run`] when appending it to another run.
+#[derive(Copy, Clone)]
+struct Offsets {
+    artifacts: u64,
+    thread_flow_locations: u64,
+}
+
+impl Offsets {
+    fn artifact_location(self, location: &mut ArtifactLocation<'_>) {
+        if let Some(index) = &mut location.index {
+            *index += self.artifacts;
+        }
+    }
+
+    fn location(self, location: &mut Location<'_>) {
+        if let Some(location) = location
+            .physical_location
+            .as_mut()
+            .and_then(|location| location.artifact_location.as_mut())
+        {
+            self.artifact_location(location);
+        }
+    }
+
+    fn thread_flow_location(self, location: &mut ThreadFlowLocation<'_>) {
+        if let Some(index) = &mut location.index {
+            *index += self.thread_flow_locations;
+        }
+        if let Some(location) = &mut location.location {
+            self.location(location);
+        }
+    }
+
+    fn artifact(self, artifact: &mut Artifact<'_>) {
+        if let Some(index) = &mut artifact.parent_index {
+            *index += self.artifacts;
+        }
+        if let Some(location) = &mut artifact.location {
+            self.artifact_location(location);
+        }
+    }
+
+    fn invocation(self, invocation: &mut Invocation<'_>) {
+        let artifact_locations = invocation
+            .response_files
+            .iter_mut()
+            .chain(&mut invocation.executable_location)
+            .chain(&mut invocation.working_directory)
+            .chain(&mut invocation.stdin)
+            .chain(&mut invocation.stdout)
+            .chain(&mut invocation.stderr)
+            .chain(&mut invocation.stdout_stderr);
+        for location in artifact_locations {
+            self.artifact_location(location);
+        }
+
+        let notifications = invocation
+            .tool_execution_notifications
+            .iter_mut()
+            .chain(&mut invocation.tool_configuration_notifications);
+        for notification in notifications {
+            for location in &mut notification.locations {
+                self.location(location);
+            }
+        }
+    }
+
+    fn result(self, result: &mut Result<'_>) {
+        if let Some(location) = &mut result.analysis_target {
+            self.artifact_location(location);
+        }
+        for location in result
+            .locations
+            .iter_mut()
+            .chain(&mut result.related_locations)
+        {
+            self.location(location);
+        }
+        for thread_flow in result
+            .code_flows
+            .iter_mut()
+            .flat_map(|code_flow| &mut code_flow.thread_flows)
+        {
+            for location in &mut thread_flow.locations {
+                self.thread_flow_location(location);
+            }
+        }
+        for change in result
+            .fixes
+            .iter_mut()
+            .flat_map(|fix| &mut fix.artifact_changes)
+        {
+            self.artifact_location(&mut change.artifact_location);
+        }
+    }
+}
+
+/// Appends the contents of `other` to `run`, which must have been produced by the same tool.
+fn append_run<'s>(run: &mut Run<'s>, mut other: Run<'s>) {
+    let offsets = Offsets {
+        artifacts: run.artifacts.len() as u64,
+        thread_flow_locations: run.thread_flow_locations.len() as u64,
+    };
+
+    for artifact in &mut other.artifacts {
+        offsets.artifact(artifact);
+    }
+    for invocation in &mut other.invocations {
+        offsets.invocation(invocation);
+    }
+    for location in &mut other.thread_flow_locations {
+        // Thread flow locations of the run itself cannot refer to another one
+        if let Some(location) = &mut location.location {
+            offsets.location(location);
+        }
+    }
+    for result in &mut other.results {
+        offsets.result(result);
+    }
+
+    run.artifacts.append(&mut other.artifacts);
+    run.invocations.append(&mut other.invocations);
+    run.thread_flow_locations
+        .append(&mut other.thread_flow_locations);
+    run.results.append(&mut other.results);
+}
+
+impl SarifLog<'_> {
+    /// Merges the runs of `other` into this log.
+    ///
+    /// Runs produced by a tool, which is not yet part of this log, are added as separate runs.
+    /// Runs of a tool already present, i.e. with an equal [`Tool`] including its rules, are
+    /// combined into a single run. The indices of artifacts and thread flow locations are adjusted
+    /// accordingly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent};
+    ///
+    /// let clippy = || Run::new(Tool::new(ToolComponent::new("clippy")));
+    ///
+    /// let mut log = SarifLog::new(SchemaVersion::V2_1_0)
+    ///     .with_run(clippy().with_result(Result::new(Message::new("unused variable: `x`"))));
+    ///
+    /// log.merge(SarifLog::new(SchemaVersion::V2_1_0).with_runs([
+    ///     clippy().with_result(Result::new(Message::new("unused import: `std::io`"))),
+    ///     Run::new(Tool::new(ToolComponent::new("rustfmt"))),
+    /// ]));
+    ///
+    /// let runs = log.runs.unwrap();
+    /// assert_eq!(runs.len(), 2);
+    /// assert_eq!(runs[0].results.len(), 2);
+    /// ```
+    ///
+    /// [`Tool`]: crate::schema::Tool
+    pub fn merge(&mut self, other: Self) {
+        let runs = self.runs.get_or_insert_with(Default::default);
+
+        for run in other.runs.into_iter().flatten() {
+            match runs.iter_mut().find(|existing| existing.tool == run.tool) {
+                Some(existing) => append_run(existing, run),
+                None => runs.push(run),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        Artifact, ArtifactLocation, CodeFlow, Invocation, Location, Message, PhysicalLocation,
+        Result, Run, SarifLog, SchemaVersion, ThreadFlow, ThreadFlowLocation, Tool, ToolComponent,
+    };
+
+    fn location(index: u64) -> Location<'static> {
+        Location::new().with_physical_location(PhysicalLocation::new(
+            ArtifactLocation::new("src/main.rs").with_index(index),
+        ))
+    }
+
+    fn run() -> Run<'static> {
+        Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_artifacts([
+                Artifact::new().with_location(ArtifactLocation::new("vendor.zip").with_index(0)),
+                Artifact::new()
+                    .with_location(ArtifactLocation::new("src/main.rs").with_index(1))
+                    .with_parent_index(0),
+            ])
+            .with_invocation(
+                Invocation::new(true)
+                    .with_stdout(ArtifactLocation::new("stdout.txt").with_index(0)),
+            )
+            .with_thread_flow_location(ThreadFlowLocation::new().with_location(location(1)))
+            .with_result(
+                Result::new(Message::new("unused variable: `x`"))
+                    .with_location(location(1))
+                    .with_code_flow(CodeFlow::new(ThreadFlow::new(
+                        ThreadFlowLocation::new().with_index(0),
+                    ))),
+            )
+    }
+
+    #[test]
+    fn separate_tools() {
+        let mut log = SarifLog::new(SchemaVersion::V2_1_0).with_run(run());
+        log.merge(
+            SarifLog::new(SchemaVersion::V2_1_0)
+                .with_run(Run::new(Tool::new(ToolComponent::new("rustfmt")))),
+        );
+
+        let runs = log.runs.expect("log should have runs");
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0], run());
+        assert_eq!(runs[1].tool.driver.name, "rustfmt");
+    }
+
+    #[test]
+    fn empty_log() {
+        let mut log = SarifLog::new(SchemaVersion::V2_1_0);
+        log.runs = None;
+        log.merge(SarifLog::new(SchemaVersion::V2_1_0).with_run(run()));
+
+        assert_eq!(log.runs.expect("log should have runs"), [run()]);
+    }
+
+    #[test]
+    fn same_tool() {
+        let mut log = SarifLog::new(SchemaVersion::V2_1_0).with_run(run());
+        log.merge(SarifLog::new(SchemaVersion::V2_1_0).with_run(run()));
+
+        let runs = log.runs.expect("log should have runs");
+        assert_eq!(runs.len(), 1);
+
+        let run = &runs[0];
+        assert_eq!(run.artifacts.len(), 4);
+        assert_eq!(run.artifacts[3].parent_index, Some(2));
+        assert_eq!(
+            run.artifacts[3]
+                .location
+                .as_ref()
+                .and_then(|location| location.index),
+            Some(3)
+        );
+        assert_eq!(
+            run.invocations[1]
+                .stdout
+                .as_ref()
+                .and_then(|location| location.index),
+            Some(2)
+        );
+        assert_eq!(
+            run.thread_flow_locations[1],
+            ThreadFlowLocation::new().with_location(location(3))
+        );
+
+        assert_eq!(run.results.len(), 2);
+        assert_eq!(run.results[1].locations, [location(3)]);
+        assert_eq!(
+            run.results[1].code_flows[0].thread_flows[0].locations[0].index,
+            Some(1)
+        );
+    }
+}
@@ -0,0 +1,493 @@
+use alloc::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactContent, ArtifactLocation, Message, PropertyBag};
+
+/// The role an [`Artifact`] plays in the analysis.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum ArtifactRole {
+    /// The artifact was scanned by the analysis tool.
+    AnalysisTarget,
+    /// The artifact is an attachment to a result.
+    Attachment,
+    /// The artifact is a response file containing command line arguments.
+    ResponseFile,
+    /// The artifact is a file referenced by a result.
+    ResultFile,
+    /// The artifact is a standard stream, e.g. `stdout`, of the tool.
+    StandardStream,
+    /// The artifact was traced during the analysis.
+    TracedFile,
+    /// The artifact was not modified.
+    Unmodified,
+    /// The artifact was modified.
+    Modified,
+    /// The artifact was added.
+    Added,
+    /// The artifact was deleted.
+    Deleted,
+    /// The artifact was renamed.
+    Renamed,
+    /// The artifact is not under version control.
+    Uncontrolled,
+    /// The artifact is the driver of the tool.
+    Driver,
+    /// The artifact is an extension of the tool.
+    Extension,
+    /// The artifact is a translation of a tool component.
+    Translation,
+    /// The artifact is a taxonomy.
+    Taxonomy,
+    /// The artifact is a policy.
+    Policy,
+    /// The artifact was referenced on the command line.
+    ReferencedOnCommandLine,
+    /// The artifact represents the contents of memory.
+    MemoryContents,
+    /// The artifact is a directory.
+    Directory,
+    /// The artifact is a configuration file specified by the user.
+    UserSpecifiedConfiguration,
+    /// The artifact is a configuration file specified by the tool.
+    ToolSpecifiedConfiguration,
+    /// The artifact is a file containing debug output of the tool.
+    DebugOutputFile,
+}
+
+/// A single artifact, e.g. a source file, analyzed or referenced during the run.
+///
+/// In some cases, an artifact might be nested within another artifact, e.g. a file within an
+/// archive, in which case [`parent_index`] refers to the parent in [`Run::artifacts`].
+///
+/// [`parent_index`]: Self::parent_index
+/// [`Run::artifacts`]: crate::schema::Run::artifacts
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Artifact<'s> {
+    /// A short description of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// The location of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<ArtifactLocation<'s>>,
+
+    /// The index within [`Run::artifacts`] of the artifact containing this artifact.
+    ///
+    /// [`Run::artifacts`]: crate::schema::Run::artifacts
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub parent_index: Option<u64>,
+
+    /// The offset in bytes of the artifact within its containing artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub offset: Option<u64>,
+
+    /// The length of the artifact in bytes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub length: Option<u64>,
+
+    /// The roles played by the artifact in the analysis.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "BTreeSet::is_empty")
+    )]
+    pub roles: BTreeSet<ArtifactRole>,
+
+    /// The MIME type of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub mime_type: Option<Cow<'s, str>>,
+
+    /// The contents of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub contents: Option<ArtifactContent<'s>>,
+
+    /// The name of the encoding of a text artifact, e.g. `UTF-8`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub encoding: Option<Cow<'s, str>>,
+
+    /// The language of the artifact, e.g. `rust`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub source_language: Option<Cow<'s, str>>,
+
+    /// The hashes of the artifact, keyed by the name of the hash function, e.g. `sha-256`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub hashes: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// The time at which the artifact was most recently modified, in ISO 8601 format.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub last_modified_time_utc: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Artifact<'s> {
+    /// Creates a new, empty `Artifact`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new();
+    ///
+    /// assert!(artifact.location.is_none());
+    /// assert!(artifact.roles.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            description: None,
+            location: None,
+            parent_index: None,
+            offset: None,
+            length: None,
+            roles: BTreeSet::new(),
+            mime_type: None,
+            contents: None,
+            encoding: None,
+            source_language: None,
+            hashes: BTreeMap::new(),
+            last_modified_time_utc: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the description of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, Message};
+    ///
+    /// let artifact = Artifact::new().with_description(Message::new("entry point"));
+    ///
+    /// assert_eq!(artifact.description.unwrap().text.unwrap(), "entry point");
+    /// ```
+    #[must_use]
+    pub fn with_description(mut self, description: Message<'s>) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the location of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation};
+    ///
+    /// let artifact = Artifact::new().with_location(ArtifactLocation::new("src/main.rs"));
+    ///
+    /// assert_eq!(artifact.location.unwrap().uri.unwrap(), "src/main.rs");
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: ArtifactLocation<'s>) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Sets the index of the artifact containing this artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_parent_index(0);
+    ///
+    /// assert_eq!(artifact.parent_index, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn with_parent_index(mut self, parent_index: u64) -> Self {
+        self.parent_index = Some(parent_index);
+        self
+    }
+
+    /// Sets the offset in bytes of the artifact within its containing artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_parent_index(0).with_offset(512);
+    ///
+    /// assert_eq!(artifact.offset, Some(512));
+    /// ```
+    #[must_use]
+    pub const fn with_offset(mut self, offset: u64) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Sets the length of the artifact in bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_length(1024);
+    ///
+    /// assert_eq!(artifact.length, Some(1024));
+    /// ```
+    #[must_use]
+    pub const fn with_length(mut self, length: u64) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// Adds a role played by the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactRole};
+    ///
+    /// let artifact = Artifact::new().with_role(ArtifactRole::AnalysisTarget);
+    ///
+    /// assert!(artifact.roles.contains(&ArtifactRole::AnalysisTarget));
+    /// ```
+    #[must_use]
+    pub fn with_role(mut self, role: ArtifactRole) -> Self {
+        self.roles.insert(role);
+        self
+    }
+
+    /// Adds roles played by the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactRole};
+    ///
+    /// let artifact = Artifact::new().with_roles([ArtifactRole::ResultFile, ArtifactRole::Modified]);
+    ///
+    /// assert_eq!(artifact.roles.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_roles(mut self, roles: impl IntoIterator<Item = ArtifactRole>) -> Self {
+        self.roles.extend(roles);
+        self
+    }
+
+    /// Sets the MIME type of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_mime_type("text/x-rust");
+    ///
+    /// assert_eq!(artifact.mime_type.unwrap(), "text/x-rust");
+    /// ```
+    #[must_use]
+    pub fn with_mime_type(mut self, mime_type: impl Into<Cow<'s, str>>) -> Self {
+        self.mime_type = Some(mime_type.into());
+        self
+    }
+
+    /// Sets the contents of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactContent};
+    ///
+    /// let artifact = Artifact::new().with_contents(ArtifactContent::new().with_text("fn main() {}"));
+    ///
+    /// assert_eq!(artifact.contents.unwrap().text.unwrap(), "fn main() {}");
+    /// ```
+    #[must_use]
+    pub fn with_contents(mut self, contents: ArtifactContent<'s>) -> Self {
+        self.contents = Some(contents);
+        self
+    }
+
+    /// Sets the name of the encoding of a text artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_encoding("UTF-8");
+    ///
+    /// assert_eq!(artifact.encoding.unwrap(), "UTF-8");
+    /// ```
+    #[must_use]
+    pub fn with_encoding(mut self, encoding: impl Into<Cow<'s, str>>) -> Self {
+        self.encoding = Some(encoding.into());
+        self
+    }
+
+    /// Sets the language of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_source_language("rust");
+    ///
+    /// assert_eq!(artifact.source_language.unwrap(), "rust");
+    /// ```
+    #[must_use]
+    pub fn with_source_language(mut self, source_language: impl Into<Cow<'s, str>>) -> Self {
+        self.source_language = Some(source_language.into());
+        self
+    }
+
+    /// Adds a hash of the artifact computed by the given hash function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_hash(
+    ///     "sha-256",
+    ///     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+    /// );
+    ///
+    /// assert!(artifact.hashes.contains_key("sha-256"));
+    /// ```
+    #[must_use]
+    pub fn with_hash(
+        mut self,
+        algorithm: impl Into<Cow<'s, str>>,
+        hash: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.hashes.insert(algorithm.into(), hash.into());
+        self
+    }
+
+    /// Sets the time at which the artifact was most recently modified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_last_modified_time_utc("2024-01-01T12:00:00Z");
+    ///
+    /// assert_eq!(
+    ///     artifact.last_modified_time_utc.unwrap(),
+    ///     "2024-01-01T12:00:00Z"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_last_modified_time_utc(mut self, time: impl Into<Cow<'s, str>>) -> Self {
+        self.last_modified_time_utc = Some(time.into());
+        self
+    }
+
+    /// Sets the properties of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Artifact;
+    ///
+    /// let artifact = Artifact::new().with_properties(|properties| properties.with_tag("generated"));
+    ///
+    /// assert!(artifact.properties.tags.iter().eq(["generated"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, Artifact, ArtifactContent, ArtifactLocation, ArtifactRole, Message,
+        Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    #[test]
+    fn artifacts() {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_artifacts([
+            Artifact::new()
+                .with_location(ArtifactLocation::new("vendor.zip").with_uri_base_id("SRCROOT"))
+                .with_role(ArtifactRole::AnalysisTarget)
+                .with_mime_type("application/zip")
+                .with_length(2048)
+                .with_hash("sha-256", "0123456789abcdef"),
+            Artifact::new()
+                .with_location(ArtifactLocation::new("src/main.rs"))
+                .with_description(Message::new("file inside of the archive"))
+                .with_parent_index(0)
+                .with_offset(128)
+                .with_roles([ArtifactRole::ResultFile, ArtifactRole::Modified])
+                .with_contents(ArtifactContent::new().with_text("fn main() {}"))
+                .with_encoding("UTF-8")
+                .with_source_language("rust")
+                .with_last_modified_time_utc("2024-01-01T12:00:00Z"),
+        ]);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
@@ -0,0 +1,188 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{MultiformatMessageString, PropertyBag};
+
+/// Represents the contents of an artifact.
+///
+/// The contents can be specified as [`text`], as Base64-encoded [`binary`] data, or as a
+/// [`rendered`] representation, e.g. for binary artifacts which cannot be represented as text.
+///
+/// [`text`]: Self::text
+/// [`binary`]: Self::binary
+/// [`rendered`]: Self::rendered
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ArtifactContent<'s> {
+    /// UTF-8-encoded content from a text artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub text: Option<Cow<'s, str>>,
+
+    /// MIME Base64-encoded content from a binary artifact, or from a text artifact in its original
+    /// encoding.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub binary: Option<Cow<'s, str>>,
+
+    /// An alternate rendered representation of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rendered: Option<MultiformatMessageString<'s>>,
+
+    /// Key/value pairs that provide additional information about the artifact content.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactContent<'s> {
+    /// Creates a new, empty `ArtifactContent`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactContent;
+    ///
+    /// let content = ArtifactContent::new();
+    ///
+    /// assert!(content.text.is_none());
+    /// assert!(content.binary.is_none());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            text: None,
+            binary: None,
+            rendered: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the UTF-8-encoded content of a text artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactContent;
+    ///
+    /// let content = ArtifactContent::new().with_text("let x = 5;");
+    ///
+    /// assert_eq!(content.text.unwrap(), "let x = 5;");
+    /// ```
+    #[must_use]
+    pub fn with_text(mut self, text: impl Into<Cow<'s, str>>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Sets the Base64-encoded content of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactContent;
+    ///
+    /// let content = ArtifactContent::new().with_binary("bGV0IHggPSA1Ow==");
+    ///
+    /// assert_eq!(content.binary.unwrap(), "bGV0IHggPSA1Ow==");
+    /// ```
+    #[must_use]
+    pub fn with_binary(mut self, binary: impl Into<Cow<'s, str>>) -> Self {
+        self.binary = Some(binary.into());
+        self
+    }
+
+    /// Sets an alternate rendered representation of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactContent, MultiformatMessageString};
+    ///
+    /// let content = ArtifactContent::new().with_rendered(MultiformatMessageString::new("let x = 5;"));
+    ///
+    /// assert_eq!(content.rendered.unwrap().text, "let x = 5;");
+    /// ```
+    #[must_use]
+    pub fn with_rendered(mut self, rendered: MultiformatMessageString<'s>) -> Self {
+        self.rendered = Some(rendered);
+        self
+    }
+
+    /// Sets the properties of the artifact content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactContent;
+    ///
+    /// let content = ArtifactContent::new()
+    ///     .with_text("let x = 5;")
+    ///     .with_properties(|properties| properties.with_property("lines", 1));
+    ///
+    /// assert_eq!(content.properties.additional.get("lines"), Some(&1.into()));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, Artifact, ArtifactContent, MultiformatMessageString, Run, SarifLog,
+        SchemaVersion, Tool, ToolComponent,
+    };
+
+    fn log(contents: ArtifactContent<'_>) -> SarifLog<'_> {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_artifact(Artifact::new().with_contents(contents));
+
+        SarifLog::new(SchemaVersion::V2_1_0).with_run(run)
+    }
+
+    #[test]
+    fn text() {
+        validate_schema(&log(ArtifactContent::new().with_text("fn main() {}")));
+    }
+
+    #[test]
+    fn binary() {
+        validate_schema(&log(ArtifactContent::new().with_binary("Zm4gbWFpbigpIHt9")));
+    }
+
+    #[test]
+    fn full() {
+        validate_schema(&log(ArtifactContent::new()
+            .with_text("fn main() {}")
+            .with_binary("Zm4gbWFpbigpIHt9")
+            .with_rendered(
+                MultiformatMessageString::new("fn main() {}").with_markdown("`fn main() {}`"),
+            )
+            .with_properties(|properties| properties.with_tag("entrypoint"))));
+    }
+}
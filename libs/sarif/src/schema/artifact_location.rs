@@ -0,0 +1,169 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Message, PropertyBag};
+
+/// Specifies the location of an artifact.
+///
+/// The [`uri`] may be relative, in which case [`uri_base_id`] names the base against which it is
+/// resolved.
+///
+/// [`uri`]: Self::uri
+/// [`uri_base_id`]: Self::uri_base_id
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ArtifactLocation<'s> {
+    /// A string containing a valid relative or absolute URI.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub uri: Option<Cow<'s, str>>,
+
+    /// A string which indirectly specifies the absolute URI with respect to which a relative URI
+    /// in the [`uri`] property is interpreted.
+    ///
+    /// [`uri`]: Self::uri
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub uri_base_id: Option<Cow<'s, str>>,
+
+    /// The index within [`Run::artifacts`] of the artifact described by this location.
+    ///
+    /// [`Run::artifacts`]: crate::schema::Run::artifacts
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<u64>,
+
+    /// A short description of the artifact location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// Key/value pairs that provide additional information about the artifact location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactLocation<'s> {
+    /// Creates a new `ArtifactLocation` pointing to the given URI.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs");
+    ///
+    /// assert_eq!(location.uri.unwrap(), "src/lib.rs");
+    /// ```
+    #[must_use]
+    pub fn new(uri: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            uri: Some(uri.into()),
+            uri_base_id: None,
+            index: None,
+            description: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the base against which a relative [`uri`] is resolved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs").with_uri_base_id("SRCROOT");
+    ///
+    /// assert_eq!(location.uri_base_id.unwrap(), "SRCROOT");
+    /// ```
+    ///
+    /// [`uri`]: Self::uri
+    #[must_use]
+    pub fn with_uri_base_id(mut self, uri_base_id: impl Into<Cow<'s, str>>) -> Self {
+        self.uri_base_id = Some(uri_base_id.into());
+        self
+    }
+
+    /// Sets the index within [`Run::artifacts`] of the artifact described by this location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs").with_index(0);
+    ///
+    /// assert_eq!(location.index, Some(0));
+    /// ```
+    ///
+    /// [`Run::artifacts`]: crate::schema::Run::artifacts
+    #[must_use]
+    pub const fn with_index(mut self, index: u64) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Sets the description of the artifact location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Message};
+    ///
+    /// let location =
+    ///     ArtifactLocation::new("src/lib.rs").with_description(Message::new("the crate root"));
+    ///
+    /// assert_eq!(
+    ///     location.description.unwrap().text.unwrap(),
+    ///     "the crate root"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_description(mut self, description: Message<'s>) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Sets the properties of the artifact location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ArtifactLocation;
+    ///
+    /// let location = ArtifactLocation::new("src/lib.rs")
+    ///     .with_properties(|properties| properties.with_property("crate", "sarif"));
+    ///
+    /// assert_eq!(
+    ///     location.properties.additional.get("crate"),
+    ///     Some(&"sarif".into())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
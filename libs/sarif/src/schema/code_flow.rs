@@ -0,0 +1,182 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Message, PropertyBag, ThreadFlow};
+
+/// A set of threads of execution that, taken together, illustrate the behavior that led to a
+/// [`Result`].
+///
+/// A code flow consists of at least one [`ThreadFlow`].
+///
+/// [`Result`]: crate::schema::Result
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct CodeFlow<'s> {
+    /// A message relevant to the code flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// The threads of execution which were followed.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub thread_flows: Vec<ThreadFlow<'s>>,
+
+    /// Key/value pairs that provide additional information about the code flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> CodeFlow<'s> {
+    /// Creates a new `CodeFlow` consisting of the given thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, Location, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let code_flow = CodeFlow::new(ThreadFlow::new(
+    ///     ThreadFlowLocation::new().with_location(Location::new()),
+    /// ));
+    ///
+    /// assert_eq!(code_flow.thread_flows.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn new(thread_flow: ThreadFlow<'s>) -> Self {
+        Self {
+            message: None,
+            thread_flows: Vec::from([thread_flow]),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets a message relevant to the code flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, Message, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let code_flow = CodeFlow::new(ThreadFlow::new(ThreadFlowLocation::new()))
+    ///     .with_message(Message::new("path to the null dereference"));
+    ///
+    /// assert_eq!(
+    ///     code_flow.message.unwrap().text.unwrap(),
+    ///     "path to the null dereference"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Adds a thread flow to the code flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let code_flow = CodeFlow::new(ThreadFlow::new(ThreadFlowLocation::new()))
+    ///     .with_thread_flow(ThreadFlow::new(ThreadFlowLocation::new()));
+    ///
+    /// assert_eq!(code_flow.thread_flows.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_thread_flow(mut self, thread_flow: ThreadFlow<'s>) -> Self {
+        self.thread_flows.push(thread_flow);
+        self
+    }
+
+    /// Sets the properties of the code flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let code_flow = CodeFlow::new(ThreadFlow::new(ThreadFlowLocation::new()))
+    ///     .with_properties(|properties| properties.with_tag("borrow-check"));
+    ///
+    /// assert!(code_flow.properties.tags.iter().eq(["borrow-check"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, CodeFlow, Importance, Location, Message,
+        MultiformatMessageString, PhysicalLocation, Region, Result, Run, SarifLog, SchemaVersion,
+        ThreadFlow, ThreadFlowLocation, Tool, ToolComponent,
+    };
+
+    fn location(line: u32) -> Location<'static> {
+        Location::new().with_physical_location(
+            PhysicalLocation::new(ArtifactLocation::new("src/main.rs"))
+                .with_region(Region::new(line)),
+        )
+    }
+
+    #[test]
+    fn code_flows() {
+        let thread_flow = ThreadFlow::new(
+            ThreadFlowLocation::new()
+                .with_location(location(3))
+                .with_kinds(["acquire", "lock"])
+                .with_module("main")
+                .with_state("x", MultiformatMessageString::new("5"))
+                .with_nesting_level(0)
+                .with_execution_order(0),
+        )
+        .with_id("main")
+        .with_message(Message::new("main thread"))
+        .with_initial_state("x", MultiformatMessageString::new("uninitialized"))
+        .with_immutable_state("PID", MultiformatMessageString::new("1234"))
+        .with_location(
+            ThreadFlowLocation::new()
+                .with_index(0)
+                .with_location(location(7))
+                .with_nesting_level(1)
+                .with_execution_order(1)
+                .with_execution_time_utc("2024-01-01T12:00:00Z")
+                .with_importance(Importance::Essential),
+        );
+
+        let result = Result::new(Message::new("deadlock")).with_code_flow(
+            CodeFlow::new(thread_flow)
+                .with_message(Message::new("path to the deadlock"))
+                .with_thread_flow(ThreadFlow::new(
+                    ThreadFlowLocation::new()
+                        .with_location(location(12))
+                        .with_importance(Importance::Unimportant),
+                )),
+        );
+
+        let run = Run::new(Tool::new(ToolComponent::new("miri"))).with_result(result);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
@@ -0,0 +1,371 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactContent, ArtifactLocation, Message, PropertyBag, Region};
+
+/// A proposed fix for the problem represented by a [`Result`].
+///
+/// A fix specifies a set of artifacts to modify. For each artifact, it specifies a set of bytes to
+/// remove, and provides a set of new bytes to replace them.
+///
+/// [`Result`]: crate::schema::Result
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Fix<'s> {
+    /// A message that describes the proposed fix, enabling viewers to present the proposed change
+    /// to an end user.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub description: Option<Message<'s>>,
+
+    /// One or more artifact changes that comprise the fix.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub artifact_changes: Vec<ArtifactChange<'s>>,
+
+    /// Key/value pairs that provide additional information about the fix.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Fix<'s> {
+    /// Creates a new `Fix` consisting of the given artifact change.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Fix, Region, Replacement};
+    ///
+    /// let fix = Fix::new(ArtifactChange::new(
+    ///     ArtifactLocation::new("src/main.rs"),
+    ///     Replacement::new(Region::new(3)),
+    /// ));
+    ///
+    /// assert_eq!(fix.artifact_changes.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn new(artifact_change: ArtifactChange<'s>) -> Self {
+        Self {
+            description: None,
+            artifact_changes: Vec::from([artifact_change]),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the description of the fix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Fix, Message, Region, Replacement};
+    ///
+    /// let fix = Fix::new(ArtifactChange::new(
+    ///     ArtifactLocation::new("src/main.rs"),
+    ///     Replacement::new(Region::new(3)),
+    /// ))
+    /// .with_description(Message::new("remove the unused import"));
+    ///
+    /// assert_eq!(
+    ///     fix.description.unwrap().text.unwrap(),
+    ///     "remove the unused import"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_description(mut self, description: Message<'s>) -> Self {
+        self.description = Some(description);
+        self
+    }
+
+    /// Adds an artifact change to the fix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Fix, Region, Replacement};
+    ///
+    /// let fix = Fix::new(ArtifactChange::new(
+    ///     ArtifactLocation::new("src/main.rs"),
+    ///     Replacement::new(Region::new(3)),
+    /// ))
+    /// .with_artifact_change(ArtifactChange::new(
+    ///     ArtifactLocation::new("src/lib.rs"),
+    ///     Replacement::new(Region::new(1)),
+    /// ));
+    ///
+    /// assert_eq!(fix.artifact_changes.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_artifact_change(mut self, artifact_change: ArtifactChange<'s>) -> Self {
+        self.artifact_changes.push(artifact_change);
+        self
+    }
+
+    /// Sets the properties of the fix.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Fix, Region, Replacement};
+    ///
+    /// let fix = Fix::new(ArtifactChange::new(
+    ///     ArtifactLocation::new("src/main.rs"),
+    ///     Replacement::new(Region::new(3)),
+    /// ))
+    /// .with_properties(|properties| properties.with_property("applicability", "machine-applicable"));
+    ///
+    /// assert_eq!(
+    ///     fix.properties.additional.get("applicability"),
+    ///     Some(&"machine-applicable".into())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+/// A change to a single artifact.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ArtifactChange<'s> {
+    /// The location of the artifact to change.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub artifact_location: ArtifactLocation<'s>,
+
+    /// The replacements to apply to the artifact.
+    ///
+    /// The replacements are applied in order, the regions of later replacements refer to the
+    /// original contents of the artifact.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub replacements: Vec<Replacement<'s>>,
+
+    /// Key/value pairs that provide additional information about the change.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ArtifactChange<'s> {
+    /// Creates a new `ArtifactChange` applying the given replacement to the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Region, Replacement};
+    ///
+    /// let change = ArtifactChange::new(
+    ///     ArtifactLocation::new("src/main.rs"),
+    ///     Replacement::new(Region::new(3)),
+    /// );
+    ///
+    /// assert_eq!(change.artifact_location.uri.unwrap(), "src/main.rs");
+    /// assert_eq!(change.replacements.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn new(artifact_location: ArtifactLocation<'s>, replacement: Replacement<'s>) -> Self {
+        Self {
+            artifact_location,
+            replacements: Vec::from([replacement]),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Adds a replacement to the change.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Region, Replacement};
+    ///
+    /// let change = ArtifactChange::new(
+    ///     ArtifactLocation::new("src/main.rs"),
+    ///     Replacement::new(Region::new(3)),
+    /// )
+    /// .with_replacement(Replacement::new(Region::new(5)));
+    ///
+    /// assert_eq!(change.replacements.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_replacement(mut self, replacement: Replacement<'s>) -> Self {
+        self.replacements.push(replacement);
+        self
+    }
+
+    /// Sets the properties of the change.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactChange, ArtifactLocation, Region, Replacement};
+    ///
+    /// let change = ArtifactChange::new(
+    ///     ArtifactLocation::new("src/main.rs"),
+    ///     Replacement::new(Region::new(3)),
+    /// )
+    /// .with_properties(|properties| properties.with_tag("formatting"));
+    ///
+    /// assert!(change.properties.tags.iter().eq(["formatting"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+/// The replacement of a single region of an artifact.
+///
+/// If [`inserted_content`] is not set, the region is deleted.
+///
+/// [`inserted_content`]: Self::inserted_content
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Replacement<'s> {
+    /// The region of the artifact to delete.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub deleted_region: Region<'s>,
+
+    /// The content to insert at the location specified by [`deleted_region`].
+    ///
+    /// [`deleted_region`]: Self::deleted_region
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub inserted_content: Option<ArtifactContent<'s>>,
+
+    /// Key/value pairs that provide additional information about the replacement.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Replacement<'s> {
+    /// Creates a new `Replacement` deleting the given region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Region, Replacement};
+    ///
+    /// let replacement = Replacement::new(Region::new(3));
+    ///
+    /// assert_eq!(replacement.deleted_region.start_line, Some(3));
+    /// assert!(replacement.inserted_content.is_none());
+    /// ```
+    #[must_use]
+    pub const fn new(deleted_region: Region<'s>) -> Self {
+        Self {
+            deleted_region,
+            inserted_content: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the content to insert in place of the deleted region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactContent, Region, Replacement};
+    ///
+    /// let replacement = Replacement::new(Region::new(3).with_start_column(18).with_end_column(20))
+    ///     .with_inserted_content(ArtifactContent::new().with_text("&x"));
+    ///
+    /// assert_eq!(replacement.inserted_content.unwrap().text.unwrap(), "&x");
+    /// ```
+    #[must_use]
+    pub fn with_inserted_content(mut self, inserted_content: ArtifactContent<'s>) -> Self {
+        self.inserted_content = Some(inserted_content);
+        self
+    }
+
+    /// Sets the properties of the replacement.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Region, Replacement};
+    ///
+    /// let replacement = Replacement::new(Region::new(3))
+    ///     .with_properties(|properties| properties.with_property("automatic", true));
+    ///
+    /// assert_eq!(
+    ///     replacement.properties.additional.get("automatic"),
+    ///     Some(&true.into())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactChange, ArtifactContent, ArtifactLocation, Fix, Message,
+        Region, Replacement, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    #[test]
+    fn fixes() {
+        let fix = Fix::new(
+            ArtifactChange::new(
+                ArtifactLocation::new("src/main.rs"),
+                Replacement::new(Region::new(3).with_start_column(18).with_end_column(20))
+                    .with_inserted_content(ArtifactContent::new().with_text("&x")),
+            )
+            .with_replacement(Replacement::new(Region::default().with_byte_range(512, 12))),
+        )
+        .with_description(Message::new("consider borrowing here"))
+        .with_artifact_change(ArtifactChange::new(
+            ArtifactLocation::new("src/lib.rs"),
+            Replacement::new(Region::default().with_char_range(0, 24)),
+        ));
+
+        let result = Result::new(Message::new("mismatched types")).with_fix(fix);
+        let run = Run::new(Tool::new(ToolComponent::new("rustc"))).with_result(result);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
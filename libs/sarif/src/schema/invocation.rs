@@ -0,0 +1,709 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactLocation, Notification, PropertyBag};
+
+/// The runtime environment of the analysis tool run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Invocation<'s> {
+    /// The command line used to invoke the tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub command_line: Option<Cow<'s, str>>,
+
+    /// The command line arguments, excluding the name of the executable, used to invoke the tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub arguments: Vec<Cow<'s, str>>,
+
+    /// The locations of any response files specified on the command line.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub response_files: Vec<ArtifactLocation<'s>>,
+
+    /// The time at which the invocation started, in ISO 8601 format.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_time_utc: Option<Cow<'s, str>>,
+
+    /// The time at which the invocation ended, in ISO 8601 format.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_time_utc: Option<Cow<'s, str>>,
+
+    /// The process exit code.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_code: Option<i64>,
+
+    /// The reason for the process exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_code_description: Option<Cow<'s, str>>,
+
+    /// The name of the signal that caused the process to exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_signal_name: Option<Cow<'s, str>>,
+
+    /// The numeric value of the signal that caused the process to exit.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub exit_signal_number: Option<i64>,
+
+    /// The reason given by the operating system that the process failed to start.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub process_start_failure_message: Option<Cow<'s, str>>,
+
+    /// Specifies whether the tool's execution completed successfully.
+    pub execution_successful: bool,
+
+    /// The machine on which the invocation occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub machine: Option<Cow<'s, str>>,
+
+    /// The account under which the invocation occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub account: Option<Cow<'s, str>>,
+
+    /// The id of the process in which the invocation occurred.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub process_id: Option<i64>,
+
+    /// The location of the executable of the tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub executable_location: Option<ArtifactLocation<'s>>,
+
+    /// The working directory of the invocation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub working_directory: Option<ArtifactLocation<'s>>,
+
+    /// The environment variables associated with the analysis tool process.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub environment_variables: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// A file containing the standard input stream to the process.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub stdin: Option<ArtifactLocation<'s>>,
+
+    /// A file containing the standard output stream from the process.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub stdout: Option<ArtifactLocation<'s>>,
+
+    /// A file containing the standard error stream from the process.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub stderr: Option<ArtifactLocation<'s>>,
+
+    /// A file containing the interleaved standard output and standard error stream from the
+    /// process.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub stdout_stderr: Option<ArtifactLocation<'s>>,
+
+    /// A list of conditions detected by the tool that are relevant to the tool's execution.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub tool_execution_notifications: Vec<Notification<'s>>,
+
+    /// A list of conditions detected by the tool that are relevant to the tool's configuration.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub tool_configuration_notifications: Vec<Notification<'s>>,
+
+    /// Key/value pairs that provide additional information about the invocation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Invocation<'s> {
+    /// Creates a new `Invocation`, which completed successfully or not.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true);
+    ///
+    /// assert!(invocation.execution_successful);
+    /// assert!(invocation.exit_code.is_none());
+    /// ```
+    #[must_use]
+    pub const fn new(execution_successful: bool) -> Self {
+        Self {
+            command_line: None,
+            arguments: Vec::new(),
+            response_files: Vec::new(),
+            start_time_utc: None,
+            end_time_utc: None,
+            exit_code: None,
+            exit_code_description: None,
+            exit_signal_name: None,
+            exit_signal_number: None,
+            process_start_failure_message: None,
+            execution_successful,
+            machine: None,
+            account: None,
+            process_id: None,
+            executable_location: None,
+            working_directory: None,
+            environment_variables: BTreeMap::new(),
+            stdin: None,
+            stdout: None,
+            stderr: None,
+            stdout_stderr: None,
+            tool_execution_notifications: Vec::new(),
+            tool_configuration_notifications: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the command line used to invoke the tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_command_line("cargo clippy --workspace");
+    ///
+    /// assert_eq!(invocation.command_line.unwrap(), "cargo clippy --workspace");
+    /// ```
+    #[must_use]
+    pub fn with_command_line(mut self, command_line: impl Into<Cow<'s, str>>) -> Self {
+        self.command_line = Some(command_line.into());
+        self
+    }
+
+    /// Adds command line arguments used to invoke the tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_arguments(["clippy", "--workspace"]);
+    ///
+    /// assert_eq!(invocation.arguments, ["clippy", "--workspace"]);
+    /// ```
+    #[must_use]
+    pub fn with_arguments(
+        mut self,
+        arguments: impl IntoIterator<Item = impl Into<Cow<'s, str>>>,
+    ) -> Self {
+        self.arguments.extend(arguments.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds the location of a response file specified on the command line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Invocation};
+    ///
+    /// let invocation = Invocation::new(true).with_response_file(ArtifactLocation::new("args.rsp"));
+    ///
+    /// assert_eq!(invocation.response_files.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_response_file(mut self, response_file: ArtifactLocation<'s>) -> Self {
+        self.response_files.push(response_file);
+        self
+    }
+
+    /// Sets the time at which the invocation started.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_start_time_utc("2024-01-01T12:00:00Z");
+    ///
+    /// assert_eq!(invocation.start_time_utc.unwrap(), "2024-01-01T12:00:00Z");
+    /// ```
+    #[must_use]
+    pub fn with_start_time_utc(mut self, start_time_utc: impl Into<Cow<'s, str>>) -> Self {
+        self.start_time_utc = Some(start_time_utc.into());
+        self
+    }
+
+    /// Sets the time at which the invocation ended.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_end_time_utc("2024-01-01T12:01:00Z");
+    ///
+    /// assert_eq!(invocation.end_time_utc.unwrap(), "2024-01-01T12:01:00Z");
+    /// ```
+    #[must_use]
+    pub fn with_end_time_utc(mut self, end_time_utc: impl Into<Cow<'s, str>>) -> Self {
+        self.end_time_utc = Some(end_time_utc.into());
+        self
+    }
+
+    /// Sets the process exit code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(false).with_exit_code(101);
+    ///
+    /// assert_eq!(invocation.exit_code, Some(101));
+    /// ```
+    #[must_use]
+    pub const fn with_exit_code(mut self, exit_code: i64) -> Self {
+        self.exit_code = Some(exit_code);
+        self
+    }
+
+    /// Sets the reason for the process exit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(false)
+    ///     .with_exit_code(101)
+    ///     .with_exit_code_description("the tool panicked");
+    ///
+    /// assert_eq!(
+    ///     invocation.exit_code_description.unwrap(),
+    ///     "the tool panicked"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_exit_code_description(mut self, description: impl Into<Cow<'s, str>>) -> Self {
+        self.exit_code_description = Some(description.into());
+        self
+    }
+
+    /// Sets the name and the numeric value of the signal that caused the process to exit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(false).with_exit_signal("SIGKILL", 9);
+    ///
+    /// assert_eq!(invocation.exit_signal_name.unwrap(), "SIGKILL");
+    /// assert_eq!(invocation.exit_signal_number, Some(9));
+    /// ```
+    #[must_use]
+    pub fn with_exit_signal(mut self, name: impl Into<Cow<'s, str>>, number: i64) -> Self {
+        self.exit_signal_name = Some(name.into());
+        self.exit_signal_number = Some(number);
+        self
+    }
+
+    /// Sets the reason given by the operating system that the process failed to start.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation =
+    ///     Invocation::new(false).with_process_start_failure_message("No such file or directory");
+    ///
+    /// assert_eq!(
+    ///     invocation.process_start_failure_message.unwrap(),
+    ///     "No such file or directory"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_process_start_failure_message(mut self, message: impl Into<Cow<'s, str>>) -> Self {
+        self.process_start_failure_message = Some(message.into());
+        self
+    }
+
+    /// Sets the machine on which the invocation occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_machine("ci-runner-3");
+    ///
+    /// assert_eq!(invocation.machine.unwrap(), "ci-runner-3");
+    /// ```
+    #[must_use]
+    pub fn with_machine(mut self, machine: impl Into<Cow<'s, str>>) -> Self {
+        self.machine = Some(machine.into());
+        self
+    }
+
+    /// Sets the account under which the invocation occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_account("runner");
+    ///
+    /// assert_eq!(invocation.account.unwrap(), "runner");
+    /// ```
+    #[must_use]
+    pub fn with_account(mut self, account: impl Into<Cow<'s, str>>) -> Self {
+        self.account = Some(account.into());
+        self
+    }
+
+    /// Sets the id of the process in which the invocation occurred.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_process_id(1234);
+    ///
+    /// assert_eq!(invocation.process_id, Some(1234));
+    /// ```
+    #[must_use]
+    pub const fn with_process_id(mut self, process_id: i64) -> Self {
+        self.process_id = Some(process_id);
+        self
+    }
+
+    /// Sets the location of the executable of the tool.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Invocation};
+    ///
+    /// let invocation = Invocation::new(true)
+    ///     .with_executable_location(ArtifactLocation::new("file:///usr/local/bin/cargo-clippy"));
+    ///
+    /// assert!(invocation.executable_location.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_executable_location(mut self, location: ArtifactLocation<'s>) -> Self {
+        self.executable_location = Some(location);
+        self
+    }
+
+    /// Sets the working directory of the invocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Invocation};
+    ///
+    /// let invocation =
+    ///     Invocation::new(true).with_working_directory(ArtifactLocation::new("file:///src/"));
+    ///
+    /// assert_eq!(
+    ///     invocation.working_directory.unwrap().uri.unwrap(),
+    ///     "file:///src/"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_working_directory(mut self, working_directory: ArtifactLocation<'s>) -> Self {
+        self.working_directory = Some(working_directory);
+        self
+    }
+
+    /// Adds an environment variable of the analysis tool process.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_environment_variable("RUST_LOG", "debug");
+    ///
+    /// assert_eq!(invocation.environment_variables["RUST_LOG"], "debug");
+    /// ```
+    #[must_use]
+    pub fn with_environment_variable(
+        mut self,
+        name: impl Into<Cow<'s, str>>,
+        value: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.environment_variables.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the file containing the standard input stream to the process.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Invocation};
+    ///
+    /// let invocation = Invocation::new(true).with_stdin(ArtifactLocation::new("stdin.txt"));
+    ///
+    /// assert!(invocation.stdin.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_stdin(mut self, stdin: ArtifactLocation<'s>) -> Self {
+        self.stdin = Some(stdin);
+        self
+    }
+
+    /// Sets the file containing the standard output stream from the process.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Invocation};
+    ///
+    /// let invocation = Invocation::new(true).with_stdout(ArtifactLocation::new("stdout.txt"));
+    ///
+    /// assert!(invocation.stdout.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_stdout(mut self, stdout: ArtifactLocation<'s>) -> Self {
+        self.stdout = Some(stdout);
+        self
+    }
+
+    /// Sets the file containing the standard error stream from the process.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Invocation};
+    ///
+    /// let invocation = Invocation::new(true).with_stderr(ArtifactLocation::new("stderr.txt"));
+    ///
+    /// assert!(invocation.stderr.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_stderr(mut self, stderr: ArtifactLocation<'s>) -> Self {
+        self.stderr = Some(stderr);
+        self
+    }
+
+    /// Sets the file containing the interleaved standard output and standard error stream from
+    /// the process.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Invocation};
+    ///
+    /// let invocation = Invocation::new(true).with_stdout_stderr(ArtifactLocation::new("output.txt"));
+    ///
+    /// assert!(invocation.stdout_stderr.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_stdout_stderr(mut self, stdout_stderr: ArtifactLocation<'s>) -> Self {
+        self.stdout_stderr = Some(stdout_stderr);
+        self
+    }
+
+    /// Adds a condition relevant to the tool's execution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Invocation, Message, Notification};
+    ///
+    /// let invocation = Invocation::new(false).with_tool_execution_notification(Notification::new(
+    ///     Message::new("could not read `Cargo.lock`"),
+    /// ));
+    ///
+    /// assert_eq!(invocation.tool_execution_notifications.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_tool_execution_notification(mut self, notification: Notification<'s>) -> Self {
+        self.tool_execution_notifications.push(notification);
+        self
+    }
+
+    /// Adds a condition relevant to the tool's configuration.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Invocation, Message, Notification};
+    ///
+    /// let invocation = Invocation::new(true).with_tool_configuration_notification(Notification::new(
+    ///     Message::new("unknown lint `clippy::foo`"),
+    /// ));
+    ///
+    /// assert_eq!(invocation.tool_configuration_notifications.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_tool_configuration_notification(mut self, notification: Notification<'s>) -> Self {
+        self.tool_configuration_notifications.push(notification);
+        self
+    }
+
+    /// Sets the properties of the invocation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Invocation;
+    ///
+    /// let invocation = Invocation::new(true).with_properties(|properties| {
+    ///     properties
+    ///         .with_property("profile", "release")
+    ///         .with_tag("ci")
+    /// });
+    ///
+    /// assert_eq!(
+    ///     invocation.properties.additional.get("profile"),
+    ///     Some(&"release".into())
+    /// );
+    /// assert!(invocation.properties.tags.iter().eq(["ci"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, Invocation, Level, Location, Message,
+        Notification, PhysicalLocation, Region, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    #[test]
+    fn minimal() {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_invocation(Invocation::new(true));
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+
+    #[test]
+    fn failed() {
+        let invocation = Invocation::new(false)
+            .with_command_line("cargo clippy --workspace")
+            .with_arguments(["clippy", "--workspace"])
+            .with_response_file(ArtifactLocation::new("args.rsp"))
+            .with_start_time_utc("2024-01-01T12:00:00Z")
+            .with_end_time_utc("2024-01-01T12:01:00Z")
+            .with_exit_code(101)
+            .with_exit_code_description("the tool panicked")
+            .with_exit_signal("SIGABRT", 6)
+            .with_machine("ci-runner-3")
+            .with_account("runner")
+            .with_process_id(1234)
+            .with_executable_location(ArtifactLocation::new("file:///usr/local/bin/cargo"))
+            .with_working_directory(ArtifactLocation::new("file:///src/"))
+            .with_environment_variable("RUST_LOG", "debug")
+            .with_stdout(ArtifactLocation::new("stdout.txt"))
+            .with_stderr(ArtifactLocation::new("stderr.txt"))
+            .with_tool_execution_notification(
+                Notification::new(Message::new("could not read `Cargo.lock`"))
+                    .with_level(Level::Error)
+                    .with_thread_id(4)
+                    .with_time_utc("2024-01-01T12:00:30Z")
+                    .with_location(
+                        Location::new().with_physical_location(
+                            PhysicalLocation::new(ArtifactLocation::new("Cargo.lock"))
+                                .with_region(Region::new(1)),
+                        ),
+                    ),
+            )
+            .with_tool_configuration_notification(Notification::new(Message::new(
+                "unknown lint `clippy::foo`",
+            )));
+
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_invocation(invocation);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+
+    #[test]
+    fn process_start_failure() {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_invocation(
+            Invocation::new(false)
+                .with_process_start_failure_message("No such file or directory")
+                .with_stdin(ArtifactLocation::new("stdin.txt"))
+                .with_stdout_stderr(ArtifactLocation::new("output.txt")),
+        );
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
@@ -0,0 +1,184 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Message, PhysicalLocation, PropertyBag};
+
+/// A location within a programming artifact.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+#[expect(
+    clippy::struct_field_names,
+    reason = "`physical_location` is the name used by the SARIF specification"
+)]
+pub struct Location<'s> {
+    /// Value that distinguishes this location from all other locations within a single result
+    /// object.
+    ///
+    /// Embedded links in a [`Message`] refer to a location by this identifier.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<u64>,
+
+    /// Identifies the artifact and region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub physical_location: Option<PhysicalLocation<'s>>,
+
+    /// A message relevant to the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// Key/value pairs that provide additional information about the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Location<'s> {
+    /// Creates a new, empty `Location`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Location;
+    ///
+    /// let location = Location::new();
+    ///
+    /// assert!(location.physical_location.is_none());
+    /// assert!(location.message.is_none());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            id: None,
+            physical_location: None,
+            message: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the identifier of the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Location;
+    ///
+    /// let location = Location::new().with_id(3);
+    ///
+    /// assert_eq!(location.id, Some(3));
+    /// ```
+    #[must_use]
+    pub const fn with_id(mut self, id: u64) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Sets the artifact and region of the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, PhysicalLocation};
+    ///
+    /// let location = Location::new()
+    ///     .with_physical_location(PhysicalLocation::new(ArtifactLocation::new("src/lib.rs")));
+    ///
+    /// assert!(location.physical_location.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_physical_location(mut self, physical_location: PhysicalLocation<'s>) -> Self {
+        self.physical_location = Some(physical_location);
+        self
+    }
+
+    /// Sets the message relevant to the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message};
+    ///
+    /// let location = Location::new().with_message(Message::new("value was moved here"));
+    ///
+    /// assert_eq!(
+    ///     location.message.unwrap().text.unwrap(),
+    ///     "value was moved here"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets the properties of the location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Location;
+    ///
+    /// let location =
+    ///     Location::new().with_properties(|properties| properties.with_tag("macro-expansion"));
+    ///
+    /// assert!(location.properties.tags.iter().eq(["macro-expansion"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, Location, Message, PhysicalLocation, Region,
+        Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    #[test]
+    fn empty() {
+        let result = Result::new(Message::new("unused variable")).with_location(Location::new());
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(result);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+
+    #[test]
+    fn full() {
+        let location = Location::new()
+            .with_id(1)
+            .with_physical_location(
+                PhysicalLocation::new(ArtifactLocation::new("src/main.rs"))
+                    .with_region(Region::new(3)),
+            )
+            .with_message(Message::new("variable is declared here"))
+            .with_properties(|properties| properties.with_tag("declaration"));
+        let result = Result::new(Message::new("unused variable")).with_location(location);
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(result);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
@@ -480,3 +480,88 @@ pub struct Message<'s> {
     )]
     pub properties: PropertyBag<'s>,
 }
+
+impl<'s> Message<'s> {
+    /// Creates a new plain text `Message`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::new("mismatched types");
+    ///
+    /// assert_eq!(message.text.unwrap(), "mismatched types");
+    /// assert!(message.markdown.is_none());
+    /// ```
+    #[must_use]
+    pub fn new(text: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: Some(text.into()),
+            markdown: None,
+            id: None,
+            arguments: Vec::new(),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the formatted representation of the message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Message;
+    ///
+    /// let message = Message::new("mismatched types").with_markdown("mismatched **types**");
+    ///
+    /// assert_eq!(message.markdown.unwrap(), "mismatched **types**");
+    /// ```
+    #[must_use]
+    pub fn with_markdown(mut self, markdown: impl Into<Cow<'s, str>>) -> Self {
+        self.markdown = Some(markdown.into());
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use alloc::vec;
+
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, Message, PropertyBag, Result, Run, SarifLog, SchemaVersion, Tool,
+        ToolComponent,
+    };
+
+    fn log(message: Message<'_>) -> SarifLog<'_> {
+        let run =
+            Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(Result::new(message));
+
+        SarifLog::new(SchemaVersion::V2_1_0).with_run(run)
+    }
+
+    #[test]
+    fn text() {
+        validate_schema(&log(Message::new("mismatched types")));
+    }
+
+    #[test]
+    fn markdown() {
+        validate_schema(&log(
+            Message::new("mismatched types").with_markdown("mismatched **types**")
+        ));
+    }
+
+    #[test]
+    fn id() {
+        validate_schema(&log(Message {
+            text: None,
+            markdown: None,
+            id: Some("default".into()),
+            arguments: vec!["u8".into(), "u16".into()],
+            properties: PropertyBag::new().with_tag("lookup"),
+        }));
+    }
+}
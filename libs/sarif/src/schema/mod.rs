@@ -1,23 +1,47 @@
 //! The JSON schema of the SARIF log file format as a Rust module.
 
+mod artifact;
+mod artifact_content;
+mod artifact_location;
+mod code_flow;
+mod fix;
+mod invocation;
+mod location;
 mod log;
 mod message;
 mod multiformat_message_string;
+mod notification;
+mod physical_location;
 mod properties;
+mod region;
 mod reporting_descriptor;
+mod result;
 mod run;
+mod thread_flow;
 mod tool;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub use self::{
+    artifact::{Artifact, ArtifactRole},
+    artifact_content::ArtifactContent,
+    artifact_location::ArtifactLocation,
+    code_flow::CodeFlow,
+    fix::{ArtifactChange, Fix, Replacement},
+    invocation::Invocation,
+    location::Location,
     log::SarifLog,
     message::Message,
     multiformat_message_string::MultiformatMessageString,
+    notification::Notification,
+    physical_location::PhysicalLocation,
     properties::PropertyBag,
+    region::Region,
     reporting_descriptor::ReportingDescriptor,
+    result::{BaselineState, Level, Result, ResultKind},
     run::Run,
+    thread_flow::{Importance, ThreadFlow, ThreadFlowLocation},
     tool::{Tool, ToolComponent},
 };
 
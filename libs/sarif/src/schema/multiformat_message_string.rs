@@ -87,3 +87,68 @@ pub struct MultiformatMessageString<'s> {
     )]
     pub properties: PropertyBag<'s>,
 }
+
+impl<'s> MultiformatMessageString<'s> {
+    /// Creates a new `MultiformatMessageString` with the given plain text representation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::MultiformatMessageString;
+    ///
+    /// let message = MultiformatMessageString::new("mismatched types");
+    ///
+    /// assert_eq!(message.text, "mismatched types");
+    /// assert!(message.markdown.is_none());
+    /// ```
+    #[must_use]
+    pub fn new(text: impl Into<Cow<'s, str>>) -> Self {
+        Self {
+            text: text.into(),
+            markdown: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the Markdown representation of the message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::MultiformatMessageString;
+    ///
+    /// let message =
+    ///     MultiformatMessageString::new("mismatched types").with_markdown("mismatched **types**");
+    ///
+    /// assert_eq!(message.markdown.unwrap(), "mismatched **types**");
+    /// ```
+    #[must_use]
+    pub fn with_markdown(mut self, markdown: impl Into<Cow<'s, str>>) -> Self {
+        self.markdown = Some(markdown.into());
+        self
+    }
+
+    /// Sets the properties of the message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::MultiformatMessageString;
+    ///
+    /// let message = MultiformatMessageString::new("mismatched types")
+    ///     .with_properties(|properties| properties.with_property("language", "en-US"));
+    ///
+    /// assert_eq!(
+    ///     message.properties.additional.get("language"),
+    ///     Some(&"en-US".into())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
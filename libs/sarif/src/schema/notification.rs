@@ -0,0 +1,227 @@
+use alloc::{borrow::Cow, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Level, Location, Message, PropertyBag};
+
+/// A condition relevant to the tool itself, as opposed to being relevant to a target being
+/// analyzed by the tool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Notification<'s> {
+    /// The locations relevant to this notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub locations: Vec<Location<'s>>,
+
+    /// A message that describes the condition that was encountered.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub message: Message<'s>,
+
+    /// The severity of the notification.
+    ///
+    /// If not specified, consumers assume [`Level::Warning`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub level: Option<Level>,
+
+    /// The thread identifier of the code that generated the notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub thread_id: Option<i64>,
+
+    /// The time at which the analysis tool generated the notification, in ISO 8601 format.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub time_utc: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the notification.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Notification<'s> {
+    /// Creates a new `Notification` with the given message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification};
+    ///
+    /// let notification = Notification::new(Message::new("could not read `Cargo.lock`"));
+    ///
+    /// assert_eq!(
+    ///     notification.message.text.unwrap(),
+    ///     "could not read `Cargo.lock`"
+    /// );
+    /// assert!(notification.level.is_none());
+    /// ```
+    #[must_use]
+    pub const fn new(message: Message<'s>) -> Self {
+        Self {
+            locations: Vec::new(),
+            message,
+            level: None,
+            thread_id: None,
+            time_utc: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Adds a location relevant to this notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, Message, Notification, PhysicalLocation};
+    ///
+    /// let notification = Notification::new(Message::new("could not read `Cargo.lock`"))
+    ///     .with_location(
+    ///         Location::new()
+    ///             .with_physical_location(PhysicalLocation::new(ArtifactLocation::new("Cargo.lock"))),
+    ///     );
+    ///
+    /// assert_eq!(notification.locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: Location<'s>) -> Self {
+        self.locations.push(location);
+        self
+    }
+
+    /// Sets the severity of the notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Level, Message, Notification};
+    ///
+    /// let notification =
+    ///     Notification::new(Message::new("could not read `Cargo.lock`")).with_level(Level::Error);
+    ///
+    /// assert_eq!(notification.level, Some(Level::Error));
+    /// ```
+    #[must_use]
+    pub const fn with_level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Sets the thread identifier of the code that generated the notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification};
+    ///
+    /// let notification =
+    ///     Notification::new(Message::new("could not read `Cargo.lock`")).with_thread_id(4);
+    ///
+    /// assert_eq!(notification.thread_id, Some(4));
+    /// ```
+    #[must_use]
+    pub const fn with_thread_id(mut self, thread_id: i64) -> Self {
+        self.thread_id = Some(thread_id);
+        self
+    }
+
+    /// Sets the time at which the notification was generated.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification};
+    ///
+    /// let notification = Notification::new(Message::new("could not read `Cargo.lock`"))
+    ///     .with_time_utc("2024-01-01T12:00:00Z");
+    ///
+    /// assert_eq!(notification.time_utc.unwrap(), "2024-01-01T12:00:00Z");
+    /// ```
+    #[must_use]
+    pub fn with_time_utc(mut self, time_utc: impl Into<Cow<'s, str>>) -> Self {
+        self.time_utc = Some(time_utc.into());
+        self
+    }
+
+    /// Sets the properties of the notification.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Notification};
+    ///
+    /// let notification = Notification::new(Message::new("could not read `Cargo.lock`"))
+    ///     .with_properties(|properties| properties.with_tag("io"));
+    ///
+    /// assert!(notification.properties.tags.iter().eq(["io"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, Invocation, Level, Location, Message,
+        Notification, PhysicalLocation, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    fn log(notification: Notification<'_>) -> SarifLog<'_> {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_invocation(
+            Invocation::new(false)
+                .with_tool_execution_notification(notification.clone())
+                .with_tool_configuration_notification(notification),
+        );
+
+        SarifLog::new(SchemaVersion::V2_1_0).with_run(run)
+    }
+
+    #[test]
+    fn message() {
+        validate_schema(&log(Notification::new(Message::new(
+            "could not read configuration",
+        ))));
+    }
+
+    #[test]
+    fn full() {
+        validate_schema(&log(Notification::new(Message::new(
+            "could not read configuration",
+        ))
+        .with_location(
+            Location::new().with_physical_location(PhysicalLocation::new(ArtifactLocation::new(
+                "clippy.toml",
+            ))),
+        )
+        .with_level(Level::Error)
+        .with_thread_id(1)
+        .with_time_utc("2024-01-01T12:00:00Z")
+        .with_properties(|properties| properties.with_tag("configuration"))));
+    }
+}
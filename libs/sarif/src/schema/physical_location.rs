@@ -0,0 +1,140 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactLocation, PropertyBag, Region};
+
+/// A physical location relevant to a result.
+///
+/// Specifies a reference to a programming artifact together with a range of bytes or characters
+/// within that artifact.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct PhysicalLocation<'s> {
+    /// The location of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub artifact_location: Option<ArtifactLocation<'s>>,
+
+    /// The relevant portion of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub region: Option<Region<'s>>,
+
+    /// Key/value pairs that provide additional information about the physical location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> PhysicalLocation<'s> {
+    /// Creates a new `PhysicalLocation` referring to the given artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PhysicalLocation};
+    ///
+    /// let location = PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"));
+    ///
+    /// assert_eq!(
+    ///     location.artifact_location.unwrap().uri.unwrap(),
+    ///     "src/lib.rs"
+    /// );
+    /// ```
+    #[must_use]
+    pub const fn new(artifact_location: ArtifactLocation<'s>) -> Self {
+        Self {
+            artifact_location: Some(artifact_location),
+            region: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the relevant portion of the artifact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PhysicalLocation, Region};
+    ///
+    /// let location =
+    ///     PhysicalLocation::new(ArtifactLocation::new("src/lib.rs")).with_region(Region::new(42));
+    ///
+    /// assert_eq!(location.region.unwrap().start_line, Some(42));
+    /// ```
+    #[must_use]
+    pub fn with_region(mut self, region: Region<'s>) -> Self {
+        self.region = Some(region);
+        self
+    }
+
+    /// Sets the properties of the physical location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, PhysicalLocation};
+    ///
+    /// let location = PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"))
+    ///     .with_properties(|properties| properties.with_property("generated", false));
+    ///
+    /// assert_eq!(
+    ///     location.properties.additional.get("generated"),
+    ///     Some(&false.into())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, Location, Message, PhysicalLocation, Region,
+        Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    fn log(physical_location: PhysicalLocation<'_>) -> SarifLog<'_> {
+        let result = Result::new(Message::new("unused variable"))
+            .with_location(Location::new().with_physical_location(physical_location));
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(result);
+
+        SarifLog::new(SchemaVersion::V2_1_0).with_run(run)
+    }
+
+    #[test]
+    fn artifact_location() {
+        validate_schema(&log(PhysicalLocation::new(ArtifactLocation::new(
+            "src/main.rs",
+        ))));
+    }
+
+    #[test]
+    fn full() {
+        validate_schema(&log(PhysicalLocation::new(
+            ArtifactLocation::new("src/main.rs").with_uri_base_id("SRCROOT"),
+        )
+        .with_region(Region::new(3).with_start_column(9))
+        .with_properties(|properties| properties.with_property("generated", false))));
+    }
+}
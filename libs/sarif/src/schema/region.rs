@@ -0,0 +1,351 @@
+use alloc::borrow::Cow;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactContent, Message, PropertyBag};
+
+/// A region within an artifact where a result was detected.
+///
+/// Line and column numbers are 1-based. If only [`start_line`] is set, the region spans the whole
+/// line. Alternatively, a region can be specified by a 0-based character or byte offset and length.
+///
+/// [`start_line`]: Self::start_line
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Region<'s> {
+    /// The line number of the first character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_line: Option<u32>,
+
+    /// The column number of the first character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub start_column: Option<u32>,
+
+    /// The line number of the last character in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_line: Option<u32>,
+
+    /// The column number of the character following the end of the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub end_column: Option<u32>,
+
+    /// The zero-based offset from the beginning of the artifact of the first character in the
+    /// region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub char_offset: Option<u64>,
+
+    /// The length of the region in characters.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub char_length: Option<u64>,
+
+    /// The zero-based offset from the beginning of the artifact of the first byte in the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub byte_offset: Option<u64>,
+
+    /// The length of the region in bytes.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub byte_length: Option<u64>,
+
+    /// The portion of the artifact contents within the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub snippet: Option<ArtifactContent<'s>>,
+
+    /// A message relevant to the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// The language of the region, if it differs from the language of the artifact.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub source_language: Option<Cow<'s, str>>,
+
+    /// Key/value pairs that provide additional information about the region.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Region<'s> {
+    /// Creates a new `Region` starting at the given line.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new(42);
+    ///
+    /// assert_eq!(region.start_line, Some(42));
+    /// assert_eq!(region.start_column, None);
+    /// ```
+    #[must_use]
+    pub const fn new(start_line: u32) -> Self {
+        Self {
+            start_line: Some(start_line),
+            start_column: None,
+            end_line: None,
+            end_column: None,
+            char_offset: None,
+            char_length: None,
+            byte_offset: None,
+            byte_length: None,
+            snippet: None,
+            message: None,
+            source_language: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the column number of the first character in the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new(42).with_start_column(7);
+    ///
+    /// assert_eq!(region.start_column, Some(7));
+    /// ```
+    #[must_use]
+    pub const fn with_start_column(mut self, start_column: u32) -> Self {
+        self.start_column = Some(start_column);
+        self
+    }
+
+    /// Sets the line number of the last character in the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new(42).with_end_line(45);
+    ///
+    /// assert_eq!(region.end_line, Some(45));
+    /// ```
+    #[must_use]
+    pub const fn with_end_line(mut self, end_line: u32) -> Self {
+        self.end_line = Some(end_line);
+        self
+    }
+
+    /// Sets the column number of the character following the end of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new(42).with_end_column(12);
+    ///
+    /// assert_eq!(region.end_column, Some(12));
+    /// ```
+    #[must_use]
+    pub const fn with_end_column(mut self, end_column: u32) -> Self {
+        self.end_column = Some(end_column);
+        self
+    }
+
+    /// Sets the character offset and the length in characters of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::default().with_char_range(120, 8);
+    ///
+    /// assert_eq!(region.char_offset, Some(120));
+    /// assert_eq!(region.char_length, Some(8));
+    /// ```
+    #[must_use]
+    pub const fn with_char_range(mut self, offset: u64, length: u64) -> Self {
+        self.char_offset = Some(offset);
+        self.char_length = Some(length);
+        self
+    }
+
+    /// Sets the byte offset and the length in bytes of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::default().with_byte_range(256, 16);
+    ///
+    /// assert_eq!(region.byte_offset, Some(256));
+    /// assert_eq!(region.byte_length, Some(16));
+    /// ```
+    #[must_use]
+    pub const fn with_byte_range(mut self, offset: u64, length: u64) -> Self {
+        self.byte_offset = Some(offset);
+        self.byte_length = Some(length);
+        self
+    }
+
+    /// Sets the portion of the artifact contents within the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactContent, Region};
+    ///
+    /// let region =
+    ///     Region::new(3).with_snippet(ArtifactContent::new().with_text("let x: i32 = \"5\";"));
+    ///
+    /// assert_eq!(region.snippet.unwrap().text.unwrap(), "let x: i32 = \"5\";");
+    /// ```
+    #[must_use]
+    pub fn with_snippet(mut self, snippet: ArtifactContent<'s>) -> Self {
+        self.snippet = Some(snippet);
+        self
+    }
+
+    /// Sets a message relevant to the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Region};
+    ///
+    /// let region = Region::new(3).with_message(Message::new("expected `i32`"));
+    ///
+    /// assert_eq!(region.message.unwrap().text.unwrap(), "expected `i32`");
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Sets the language of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new(3).with_source_language("sql");
+    ///
+    /// assert_eq!(region.source_language.unwrap(), "sql");
+    /// ```
+    #[must_use]
+    pub fn with_source_language(mut self, source_language: impl Into<Cow<'s, str>>) -> Self {
+        self.source_language = Some(source_language.into());
+        self
+    }
+
+    /// Sets the properties of the region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::Region;
+    ///
+    /// let region = Region::new(42).with_properties(|properties| properties.with_tag("unsafe-block"));
+    ///
+    /// assert!(region.properties.tags.iter().eq(["unsafe-block"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactContent, ArtifactLocation, Location, Message,
+        PhysicalLocation, Region, Result, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+    };
+
+    fn log(region: Region<'_>) -> SarifLog<'_> {
+        let result = Result::new(Message::new("unused variable")).with_location(
+            Location::new().with_physical_location(
+                PhysicalLocation::new(ArtifactLocation::new("src/main.rs")).with_region(region),
+            ),
+        );
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(result);
+
+        SarifLog::new(SchemaVersion::V2_1_0).with_run(run)
+    }
+
+    #[test]
+    fn text_region() {
+        validate_schema(&log(Region::new(3)));
+        validate_schema(&log(Region::new(3)
+            .with_start_column(9)
+            .with_end_line(4)
+            .with_end_column(2)));
+    }
+
+    #[test]
+    fn char_range() {
+        validate_schema(&log(Region::new(3).with_char_range(42, 5)));
+    }
+
+    #[test]
+    fn byte_range() {
+        validate_schema(&log(Region::new(3).with_byte_range(42, 5)));
+    }
+
+    #[test]
+    fn full() {
+        validate_schema(&log(Region::new(3)
+            .with_start_column(9)
+            .with_end_column(14)
+            .with_snippet(ArtifactContent::new().with_text("let x = 5;"))
+            .with_message(Message::new("the variable `x`"))
+            .with_source_language("rust")
+            .with_properties(|properties| properties.with_tag("variable"))));
+    }
+}
@@ -0,0 +1,588 @@
+use alloc::{borrow::Cow, collections::BTreeMap, vec::Vec};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{ArtifactLocation, CodeFlow, Fix, Location, Message, PropertyBag};
+
+/// The severity of a [`Result`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum Level {
+    /// The concept of “severity” does not apply to this result.
+    None,
+    /// A minor problem or an opportunity to improve the code was found.
+    Note,
+    /// A problem was found.
+    Warning,
+    /// A serious problem was found.
+    Error,
+}
+
+/// The nature of a [`Result`], i.e. whether a problem was found or the rule was evaluated at all.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum ResultKind {
+    /// The rule was not evaluated, because it does not apply to the analysis target.
+    NotApplicable,
+    /// The rule was evaluated, and no problem was found.
+    Pass,
+    /// The rule was evaluated, and a problem was found.
+    Fail,
+    /// The rule was evaluated, and a human should review the analysis target.
+    Review,
+    /// The rule was evaluated, but the tool could not determine if a problem was found.
+    Open,
+    /// The result conveys information which is not a problem.
+    Informational,
+}
+
+/// The state of a [`Result`] relative to a baseline of a previous run.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum BaselineState {
+    /// The result was detected in the current run, but not in the baseline.
+    New,
+    /// The result was detected in both runs and is considered unchanged.
+    Unchanged,
+    /// The result was detected in both runs, but some of its properties have changed.
+    Updated,
+    /// The result was detected in the baseline, but not in the current run.
+    Absent,
+}
+
+/// A result produced by an analysis tool.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct Result<'s> {
+    /// The stable, unique identifier of the rule, if any, to which this result is relevant.
+    ///
+    /// This corresponds to [`ReportingDescriptor::id`].
+    ///
+    /// [`ReportingDescriptor::id`]: crate::schema::ReportingDescriptor::id
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rule_id: Option<Cow<'s, str>>,
+
+    /// The index within [`ToolComponent::rules`] of the rule to which this result is relevant.
+    ///
+    /// [`ToolComponent::rules`]: crate::schema::ToolComponent::rules
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub rule_index: Option<u64>,
+
+    /// The nature of the result.
+    ///
+    /// If not specified, consumers assume [`ResultKind::Fail`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub kind: Option<ResultKind>,
+
+    /// The severity of the result.
+    ///
+    /// If not specified, consumers assume [`Level::Warning`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub level: Option<Level>,
+
+    /// A message that describes the result.
+    ///
+    /// The first sentence of the message only will be displayed when visible space is limited.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub message: Message<'s>,
+
+    /// The artifact that the analysis tool was instructed to scan.
+    ///
+    /// This need not be the same as the artifact where the result was detected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub analysis_target: Option<ArtifactLocation<'s>>,
+
+    /// The locations where the result was detected.
+    ///
+    /// Only one location should be specified unless the problem indicated by the result can only
+    /// be corrected by making a change at every specified location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub locations: Vec<Location<'s>>,
+
+    /// A set of locations relevant to this result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub related_locations: Vec<Location<'s>>,
+
+    /// The code flows which illustrate the behavior that led to this result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub code_flows: Vec<CodeFlow<'s>>,
+
+    /// The proposed fixes for the problem represented by this result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub fixes: Vec<Fix<'s>>,
+
+    /// Stable, unique fingerprints of the result, keyed by the name of the fingerprint method.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub fingerprints: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// Partial fingerprints of the result, keyed by the name of the fingerprint method.
+    ///
+    /// Contrary to [`fingerprints`], a partial fingerprint alone does not necessarily identify a
+    /// result uniquely.
+    ///
+    /// [`fingerprints`]: Self::fingerprints
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub partial_fingerprints: BTreeMap<Cow<'s, str>, Cow<'s, str>>,
+
+    /// The state of the result relative to a baseline of a previous run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub baseline_state: Option<BaselineState>,
+
+    /// Key/value pairs that provide additional information about the result.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> Result<'s> {
+    /// Creates a new `Result` with the given message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types"));
+    ///
+    /// assert_eq!(result.message.text.unwrap(), "mismatched types");
+    /// assert!(result.locations.is_empty());
+    /// ```
+    #[must_use]
+    pub const fn new(message: Message<'s>) -> Self {
+        Self {
+            rule_id: None,
+            rule_index: None,
+            kind: None,
+            level: None,
+            message,
+            analysis_target: None,
+            locations: Vec::new(),
+            related_locations: Vec::new(),
+            code_flows: Vec::new(),
+            fixes: Vec::new(),
+            fingerprints: BTreeMap::new(),
+            partial_fingerprints: BTreeMap::new(),
+            baseline_state: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the identifier of the rule to which this result is relevant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_rule_id("E0308");
+    ///
+    /// assert_eq!(result.rule_id.unwrap(), "E0308");
+    /// ```
+    #[must_use]
+    pub fn with_rule_id(mut self, rule_id: impl Into<Cow<'s, str>>) -> Self {
+        self.rule_id = Some(rule_id.into());
+        self
+    }
+
+    /// Sets the index of the rule to which this result is relevant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types"))
+    ///     .with_rule_id("E0308")
+    ///     .with_rule_index(0);
+    ///
+    /// assert_eq!(result.rule_index, Some(0));
+    /// ```
+    #[must_use]
+    pub const fn with_rule_index(mut self, rule_index: u64) -> Self {
+        self.rule_index = Some(rule_index);
+        self
+    }
+
+    /// Sets the nature of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, ResultKind};
+    ///
+    /// let result = Result::new(Message::new("no unsafe code found")).with_kind(ResultKind::Pass);
+    ///
+    /// assert_eq!(result.kind, Some(ResultKind::Pass));
+    /// ```
+    #[must_use]
+    pub const fn with_kind(mut self, kind: ResultKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Sets the severity of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Level, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_level(Level::Error);
+    ///
+    /// assert_eq!(result.level, Some(Level::Error));
+    /// ```
+    #[must_use]
+    pub const fn with_level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    /// Sets the artifact that the analysis tool was instructed to scan.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types"))
+    ///     .with_analysis_target(ArtifactLocation::new("src/lib.rs"));
+    ///
+    /// assert_eq!(result.analysis_target.unwrap().uri.unwrap(), "src/lib.rs");
+    /// ```
+    #[must_use]
+    pub fn with_analysis_target(mut self, analysis_target: ArtifactLocation<'s>) -> Self {
+        self.analysis_target = Some(analysis_target);
+        self
+    }
+
+    /// Adds a location where the result was detected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ArtifactLocation, Location, Message, PhysicalLocation, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_location(
+    ///     Location::new()
+    ///         .with_physical_location(PhysicalLocation::new(ArtifactLocation::new("src/lib.rs"))),
+    /// );
+    ///
+    /// assert_eq!(result.locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: Location<'s>) -> Self {
+        self.locations.push(location);
+        self
+    }
+
+    /// Adds a location relevant to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types"))
+    ///     .with_related_location(Location::new().with_message(Message::new("expected due to this")));
+    ///
+    /// assert_eq!(result.related_locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_related_location(mut self, location: Location<'s>) -> Self {
+        self.related_locations.push(location);
+        self
+    }
+
+    /// Adds locations relevant to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_related_locations([
+    ///     Location::new().with_message(Message::new("expected due to this")),
+    ///     Location::new().with_message(Message::new("found this")),
+    /// ]);
+    ///
+    /// assert_eq!(result.related_locations.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_related_locations(
+        mut self,
+        locations: impl IntoIterator<Item = Location<'s>>,
+    ) -> Self {
+        self.related_locations.extend(locations);
+        self
+    }
+
+    /// Adds a code flow which illustrates the behavior that led to the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{CodeFlow, Message, Result, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let result = Result::new(Message::new("deadlock"))
+    ///     .with_code_flow(CodeFlow::new(ThreadFlow::new(ThreadFlowLocation::new())));
+    ///
+    /// assert_eq!(result.code_flows.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_code_flow(mut self, code_flow: CodeFlow<'s>) -> Self {
+        self.code_flows.push(code_flow);
+        self
+    }
+
+    /// Adds a proposed fix for the problem represented by the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{
+    ///     ArtifactChange, ArtifactContent, ArtifactLocation, Fix, Message, Region, Replacement,
+    ///     Result,
+    /// };
+    ///
+    /// let result =
+    ///     Result::new(Message::new("mismatched types")).with_fix(Fix::new(ArtifactChange::new(
+    ///         ArtifactLocation::new("src/main.rs"),
+    ///         Replacement::new(Region::new(3).with_start_column(18).with_end_column(20))
+    ///             .with_inserted_content(ArtifactContent::new().with_text("&x")),
+    ///     )));
+    ///
+    /// assert_eq!(result.fixes.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_fix(mut self, fix: Fix<'s>) -> Self {
+        self.fixes.push(fix);
+        self
+    }
+
+    /// Adds a fingerprint of the result computed by the given method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result =
+    ///     Result::new(Message::new("mismatched types")).with_fingerprint("stableId/v1", "E0308/1");
+    ///
+    /// assert_eq!(result.fingerprints["stableId/v1"], "E0308/1");
+    /// ```
+    #[must_use]
+    pub fn with_fingerprint(
+        mut self,
+        method: impl Into<Cow<'s, str>>,
+        fingerprint: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.fingerprints.insert(method.into(), fingerprint.into());
+        self
+    }
+
+    /// Adds a partial fingerprint of the result computed by the given method.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types"))
+    ///     .with_partial_fingerprint("primaryLocationLineHash", "39fa2ee980eb94b0:1");
+    ///
+    /// assert_eq!(
+    ///     result.partial_fingerprints["primaryLocationLineHash"],
+    ///     "39fa2ee980eb94b0:1"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_partial_fingerprint(
+        mut self,
+        method: impl Into<Cow<'s, str>>,
+        fingerprint: impl Into<Cow<'s, str>>,
+    ) -> Self {
+        self.partial_fingerprints
+            .insert(method.into(), fingerprint.into());
+        self
+    }
+
+    /// Sets the state of the result relative to a baseline.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{BaselineState, Message, Result};
+    ///
+    /// let result =
+    ///     Result::new(Message::new("mismatched types")).with_baseline_state(BaselineState::New);
+    ///
+    /// assert_eq!(result.baseline_state, Some(BaselineState::New));
+    /// ```
+    #[must_use]
+    pub const fn with_baseline_state(mut self, baseline_state: BaselineState) -> Self {
+        self.baseline_state = Some(baseline_state);
+        self
+    }
+
+    /// Sets the properties of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result};
+    ///
+    /// let result = Result::new(Message::new("mismatched types")).with_properties(|properties| {
+    ///     properties
+    ///         .with_property("precision", "very-high")
+    ///         .with_tag("correctness")
+    /// });
+    ///
+    /// assert_eq!(
+    ///     result.properties.additional.get("precision"),
+    ///     Some(&"very-high".into())
+    /// );
+    /// assert!(result.properties.tags.iter().eq(["correctness"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, BaselineState, Level, Location, Message,
+        PhysicalLocation, Region, Result, ResultKind, Run, SarifLog, SchemaVersion, Tool,
+        ToolComponent,
+    };
+
+    #[test]
+    fn minimal() {
+        let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+            .with_result(Result::new(Message::new("mismatched types")));
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+
+    #[test]
+    fn with_locations() {
+        let result = Result::new(Message::new("mismatched types"))
+            .with_rule_id("E0308")
+            .with_level(Level::Error)
+            .with_location(
+                Location::new().with_physical_location(
+                    PhysicalLocation::new(
+                        ArtifactLocation::new("src/main.rs").with_uri_base_id("SRCROOT"),
+                    )
+                    .with_region(
+                        Region::new(3)
+                            .with_start_column(18)
+                            .with_end_line(3)
+                            .with_end_column(20),
+                    ),
+                ),
+            )
+            .with_related_locations([
+                Location::new()
+                    .with_id(0)
+                    .with_message(Message::new("expected due to this")),
+                Location::new().with_message(Message::new("consider borrowing here")),
+            ]);
+
+        let run = Run::new(Tool::new(ToolComponent::new("rustc"))).with_result(result);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+
+    #[test]
+    fn with_kind() {
+        let result = Result::new(Message::new("no unsafe code found"))
+            .with_rule_id("unsafe_code")
+            .with_rule_index(0)
+            .with_kind(ResultKind::Pass)
+            .with_level(Level::None)
+            .with_analysis_target(ArtifactLocation::new("src/lib.rs"));
+
+        let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_result(result);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+
+    #[test]
+    fn with_fingerprints() {
+        let result = Result::new(Message::new("mismatched types"))
+            .with_rule_id("E0308")
+            .with_fingerprint("stableId/v1", "E0308/1")
+            .with_partial_fingerprint("primaryLocationLineHash", "39fa2ee980eb94b0:1")
+            .with_baseline_state(BaselineState::Unchanged);
+
+        let run = Run::new(Tool::new(ToolComponent::new("rustc"))).with_result(result);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
@@ -1,7 +1,9 @@
+use alloc::vec::Vec;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::schema::Tool;
+use crate::schema::{Artifact, Invocation, Result, ThreadFlowLocation, Tool};
 
 /// Describes a single run of an analysis tool, and contains the reported output of that run.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -19,6 +21,42 @@ pub struct Run<'s> {
     /// command-line arguments and the like) is identical for all aggregated files.
     #[cfg_attr(feature = "serde", serde(borrow))]
     pub tool: Tool<'s>,
+
+    /// Describes the invocations of the analysis tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub invocations: Vec<Invocation<'s>>,
+
+    /// The artifacts analyzed by or relevant to the run.
+    ///
+    /// Artifacts are referenced by their index, e.g. by [`ArtifactLocation::index`].
+    ///
+    /// [`ArtifactLocation::index`]: crate::schema::ArtifactLocation::index
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub artifacts: Vec<Artifact<'s>>,
+
+    /// The set of results contained in the SARIF log.
+    ///
+    /// The results are in the order in which they were produced by the tool.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub results: Vec<Result<'s>>,
+
+    /// Thread flow locations shared between the code flows of the results.
+    ///
+    /// These are referenced by [`ThreadFlowLocation::index`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub thread_flow_locations: Vec<ThreadFlowLocation<'s>>,
 }
 
 impl<'s> Run<'s> {
@@ -35,7 +73,125 @@ impl<'s> Run<'s> {
     /// ```
     #[must_use]
     pub const fn new(tool: Tool<'s>) -> Self {
-        Self { tool }
+        Self {
+            tool,
+            invocations: Vec::new(),
+            artifacts: Vec::new(),
+            results: Vec::new(),
+            thread_flow_locations: Vec::new(),
+        }
+    }
+
+    /// Add an invocation of the analysis tool to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Invocation, Run, Tool, ToolComponent};
+    ///
+    /// let run =
+    ///     Run::new(Tool::new(ToolComponent::new("clippy"))).with_invocation(Invocation::new(true));
+    ///
+    /// assert_eq!(run.invocations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_invocation(mut self, invocation: Invocation<'s>) -> Self {
+        self.invocations.push(invocation);
+        self
+    }
+
+    /// Add an artifact to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_artifact(Artifact::new().with_location(ArtifactLocation::new("src/lib.rs")));
+    ///
+    /// assert_eq!(run.artifacts.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_artifact(mut self, artifact: Artifact<'s>) -> Self {
+        self.artifacts.push(artifact);
+        self
+    }
+
+    /// Add artifacts to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Artifact, ArtifactLocation, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_artifacts([
+    ///     Artifact::new().with_location(ArtifactLocation::new("src/lib.rs")),
+    ///     Artifact::new().with_location(ArtifactLocation::new("src/main.rs")),
+    /// ]);
+    ///
+    /// assert_eq!(run.artifacts.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_artifacts(mut self, artifacts: impl IntoIterator<Item = Artifact<'s>>) -> Self {
+        self.artifacts.extend(artifacts);
+        self
+    }
+
+    /// Add a result to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy")))
+    ///     .with_result(Result::new(Message::new("unused variable")));
+    ///
+    /// assert_eq!(run.results.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_result(mut self, result: Result<'s>) -> Self {
+        self.results.push(result);
+        self
+    }
+
+    /// Add results to the run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, Result, Run, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("clippy"))).with_results([
+    ///     Result::new(Message::new("unused variable")),
+    ///     Result::new(Message::new("unused import")),
+    /// ]);
+    ///
+    /// assert_eq!(run.results.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_results(mut self, results: impl IntoIterator<Item = Result<'s>>) -> Self {
+        self.results.extend(results);
+        self
+    }
+
+    /// Add a thread flow location shared between the code flows of the results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Run, ThreadFlowLocation, Tool, ToolComponent};
+    ///
+    /// let run = Run::new(Tool::new(ToolComponent::new("miri")))
+    ///     .with_thread_flow_location(ThreadFlowLocation::new().with_location(Location::new()));
+    ///
+    /// assert_eq!(run.thread_flow_locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn with_thread_flow_location(mut self, location: ThreadFlowLocation<'s>) -> Self {
+        self.thread_flow_locations.push(location);
+        self
     }
 }
 
@@ -45,7 +201,8 @@ pub(crate) mod tests {
     use coverage_helper::test;
 
     use crate::schema::{
-        tests::validate_schema, Run, SarifLog, SchemaVersion, Tool, ToolComponent,
+        tests::validate_schema, ArtifactLocation, Location, Message, PhysicalLocation, Region,
+        Result, Run, SarifLog, SchemaVersion, ThreadFlowLocation, Tool, ToolComponent,
     };
 
     #[test]
@@ -54,4 +211,20 @@ pub(crate) mod tests {
 
         validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
     }
+
+    #[test]
+    fn thread_flow_locations() {
+        let run = Run::new(Tool::new(ToolComponent::new("miri")))
+            .with_thread_flow_location(
+                ThreadFlowLocation::new().with_location(
+                    Location::new().with_physical_location(
+                        PhysicalLocation::new(ArtifactLocation::new("src/main.rs"))
+                            .with_region(Region::new(3)),
+                    ),
+                ),
+            )
+            .with_result(Result::new(Message::new("data race")));
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
 }
@@ -0,0 +1,611 @@
+use alloc::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::schema::{Location, Message, MultiformatMessageString, PropertyBag};
+
+/// Describes a sequence of code locations that specify a path through a single thread of
+/// execution such as an operating system or fiber.
+///
+/// A thread flow consists of at least one [`ThreadFlowLocation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ThreadFlow<'s> {
+    /// An string that uniquely identifies the thread flow within the [`CodeFlow`] in which it
+    /// occurs.
+    ///
+    /// [`CodeFlow`]: crate::schema::CodeFlow
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub id: Option<Cow<'s, str>>,
+
+    /// A message relevant to the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub message: Option<Message<'s>>,
+
+    /// Values of relevant expressions at the start of the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub initial_state: BTreeMap<Cow<'s, str>, MultiformatMessageString<'s>>,
+
+    /// Values of relevant expressions that remain constant throughout the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub immutable_state: BTreeMap<Cow<'s, str>, MultiformatMessageString<'s>>,
+
+    /// A temporally ordered array of locations visited by the thread of execution.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub locations: Vec<ThreadFlowLocation<'s>>,
+
+    /// Key/value pairs that provide additional information about the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ThreadFlow<'s> {
+    /// Creates a new `ThreadFlow` starting at the given location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow = ThreadFlow::new(ThreadFlowLocation::new());
+    ///
+    /// assert_eq!(thread_flow.locations.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn new(location: ThreadFlowLocation<'s>) -> Self {
+        Self {
+            id: None,
+            message: None,
+            initial_state: BTreeMap::new(),
+            immutable_state: BTreeMap::new(),
+            locations: Vec::from([location]),
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the identifier of the thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow = ThreadFlow::new(ThreadFlowLocation::new()).with_id("main");
+    ///
+    /// assert_eq!(thread_flow.id.unwrap(), "main");
+    /// ```
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<Cow<'s, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Sets a message relevant to the thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Message, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow =
+    ///     ThreadFlow::new(ThreadFlowLocation::new()).with_message(Message::new("main thread"));
+    ///
+    /// assert_eq!(thread_flow.message.unwrap().text.unwrap(), "main thread");
+    /// ```
+    #[must_use]
+    pub fn with_message(mut self, message: Message<'s>) -> Self {
+        self.message = Some(message);
+        self
+    }
+
+    /// Adds the value of an expression at the start of the thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{MultiformatMessageString, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow = ThreadFlow::new(ThreadFlowLocation::new())
+    ///     .with_initial_state("x", MultiformatMessageString::new("5"));
+    ///
+    /// assert_eq!(thread_flow.initial_state["x"].text, "5");
+    /// ```
+    #[must_use]
+    pub fn with_initial_state(
+        mut self,
+        expression: impl Into<Cow<'s, str>>,
+        value: MultiformatMessageString<'s>,
+    ) -> Self {
+        self.initial_state.insert(expression.into(), value);
+        self
+    }
+
+    /// Adds the value of an expression, which remains constant throughout the thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{MultiformatMessageString, ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow = ThreadFlow::new(ThreadFlowLocation::new())
+    ///     .with_immutable_state("PID", MultiformatMessageString::new("1234"));
+    ///
+    /// assert_eq!(thread_flow.immutable_state["PID"].text, "1234");
+    /// ```
+    #[must_use]
+    pub fn with_immutable_state(
+        mut self,
+        expression: impl Into<Cow<'s, str>>,
+        value: MultiformatMessageString<'s>,
+    ) -> Self {
+        self.immutable_state.insert(expression.into(), value);
+        self
+    }
+
+    /// Adds a location visited by the thread of execution.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow = ThreadFlow::new(ThreadFlowLocation::new())
+    ///     .with_location(ThreadFlowLocation::new().with_execution_order(1));
+    ///
+    /// assert_eq!(thread_flow.locations.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: ThreadFlowLocation<'s>) -> Self {
+        self.locations.push(location);
+        self
+    }
+
+    /// Sets the properties of the thread flow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{ThreadFlow, ThreadFlowLocation};
+    ///
+    /// let thread_flow = ThreadFlow::new(ThreadFlowLocation::new())
+    ///     .with_properties(|properties| properties.with_property("thread", "main"));
+    ///
+    /// assert_eq!(
+    ///     thread_flow.properties.additional.get("thread"),
+    ///     Some(&"main".into())
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+/// How important a [`ThreadFlowLocation`] is to understand the [`ThreadFlow`] it belongs to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub enum Importance {
+    /// The location is important, but not essential.
+    Important,
+    /// The location is essential to understand the thread flow.
+    Essential,
+    /// The location is not important and can be omitted when space is limited.
+    Unimportant,
+}
+
+/// A location visited by an analysis tool while simulating or monitoring the execution of a
+/// program.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(rename_all = "camelCase")
+)]
+#[non_exhaustive]
+pub struct ThreadFlowLocation<'s> {
+    /// The index within [`Run::thread_flow_locations`] to use as the base of this location.
+    ///
+    /// [`Run::thread_flow_locations`]: crate::schema::Run::thread_flow_locations
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub index: Option<u64>,
+
+    /// The code location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub location: Option<Location<'s>>,
+
+    /// A set of distinct strings that categorize the thread flow location, e.g. `acquire` or
+    /// `release`.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeSet::is_empty")
+    )]
+    pub kinds: BTreeSet<Cow<'s, str>>,
+
+    /// The name of the module that contains the code that is executing.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub module: Option<Cow<'s, str>>,
+
+    /// Values of relevant expressions at this location of the thread flow.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "BTreeMap::is_empty")
+    )]
+    pub state: BTreeMap<Cow<'s, str>, MultiformatMessageString<'s>>,
+
+    /// The nesting level of this location, used by viewers to indent the location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub nesting_level: Option<u64>,
+
+    /// The order in which this location was executed relative to other locations in the run.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub execution_order: Option<u64>,
+
+    /// The time at which this location was executed, in ISO 8601 format.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "Option::is_none")
+    )]
+    pub execution_time_utc: Option<Cow<'s, str>>,
+
+    /// How important this location is.
+    ///
+    /// If not specified, consumers assume [`Importance::Important`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub importance: Option<Importance>,
+
+    /// Key/value pairs that provide additional information about the thread flow location.
+    #[cfg_attr(
+        feature = "serde",
+        serde(borrow, default, skip_serializing_if = "PropertyBag::is_empty")
+    )]
+    pub properties: PropertyBag<'s>,
+}
+
+impl<'s> ThreadFlowLocation<'s> {
+    /// Creates a new, empty `ThreadFlowLocation`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ThreadFlowLocation;
+    ///
+    /// let location = ThreadFlowLocation::new();
+    ///
+    /// assert!(location.location.is_none());
+    /// assert!(location.importance.is_none());
+    /// ```
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            index: None,
+            location: None,
+            kinds: BTreeSet::new(),
+            module: None,
+            state: BTreeMap::new(),
+            nesting_level: None,
+            execution_order: None,
+            execution_time_utc: None,
+            importance: None,
+            properties: PropertyBag::new(),
+        }
+    }
+
+    /// Sets the index within [`Run::thread_flow_locations`] to use as the base of this location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ThreadFlowLocation;
+    ///
+    /// let location = ThreadFlowLocation::new().with_index(0);
+    ///
+    /// assert_eq!(location.index, Some(0));
+    /// ```
+    ///
+    /// [`Run::thread_flow_locations`]: crate::schema::Run::thread_flow_locations
+    #[must_use]
+    pub const fn with_index(mut self, index: u64) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Sets the code location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Location, Message, ThreadFlowLocation};
+    ///
+    /// let location = ThreadFlowLocation::new()
+    ///     .with_location(Location::new().with_message(Message::new("lock acquired")));
+    ///
+    /// assert!(location.location.is_some());
+    /// ```
+    #[must_use]
+    pub fn with_location(mut self, location: Location<'s>) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Adds a kind categorizing the thread flow location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ThreadFlowLocation;
+    ///
+    /// let location = ThreadFlowLocation::new().with_kind("acquire");
+    ///
+    /// assert!(location.kinds.contains("acquire"));
+    /// ```
+    #[must_use]
+    pub fn with_kind(mut self, kind: impl Into<Cow<'s, str>>) -> Self {
+        self.kinds.insert(kind.into());
+        self
+    }
+
+    /// Adds kinds categorizing the thread flow location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ThreadFlowLocation;
+    ///
+    /// let location = ThreadFlowLocation::new().with_kinds(["acquire", "lock"]);
+    ///
+    /// assert_eq!(location.kinds.len(), 2);
+    /// ```
+    #[must_use]
+    pub fn with_kinds(mut self, kinds: impl IntoIterator<Item = impl Into<Cow<'s, str>>>) -> Self {
+        self.kinds.extend(kinds.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets the name of the module that contains the code that is executing.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ThreadFlowLocation;
+    ///
+    /// let location = ThreadFlowLocation::new().with_module("main");
+    ///
+    /// assert_eq!(location.module.unwrap(), "main");
+    /// ```
+    #[must_use]
+    pub fn with_module(mut self, module: impl Into<Cow<'s, str>>) -> Self {
+        self.module = Some(module.into());
+        self
+    }
+
+    /// Adds the value of an expression at this location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{MultiformatMessageString, ThreadFlowLocation};
+    ///
+    /// let location = ThreadFlowLocation::new().with_state("x", MultiformatMessageString::new("5"));
+    ///
+    /// assert_eq!(location.state["x"].text, "5");
+    /// ```
+    #[must_use]
+    pub fn with_state(
+        mut self,
+        expression: impl Into<Cow<'s, str>>,
+        value: MultiformatMessageString<'s>,
+    ) -> Self {
+        self.state.insert(expression.into(), value);
+        self
+    }
+
+    /// Sets the nesting level of this location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ThreadFlowLocation;
+    ///
+    /// let location = ThreadFlowLocation::new().with_nesting_level(2);
+    ///
+    /// assert_eq!(location.nesting_level, Some(2));
+    /// ```
+    #[must_use]
+    pub const fn with_nesting_level(mut self, nesting_level: u64) -> Self {
+        self.nesting_level = Some(nesting_level);
+        self
+    }
+
+    /// Sets the order in which this location was executed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ThreadFlowLocation;
+    ///
+    /// let location = ThreadFlowLocation::new().with_execution_order(3);
+    ///
+    /// assert_eq!(location.execution_order, Some(3));
+    /// ```
+    #[must_use]
+    pub const fn with_execution_order(mut self, execution_order: u64) -> Self {
+        self.execution_order = Some(execution_order);
+        self
+    }
+
+    /// Sets the time at which this location was executed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ThreadFlowLocation;
+    ///
+    /// let location = ThreadFlowLocation::new().with_execution_time_utc("2024-01-01T12:00:00Z");
+    ///
+    /// assert_eq!(location.execution_time_utc.unwrap(), "2024-01-01T12:00:00Z");
+    /// ```
+    #[must_use]
+    pub fn with_execution_time_utc(mut self, time: impl Into<Cow<'s, str>>) -> Self {
+        self.execution_time_utc = Some(time.into());
+        self
+    }
+
+    /// Sets how important this location is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::{Importance, ThreadFlowLocation};
+    ///
+    /// let location = ThreadFlowLocation::new().with_importance(Importance::Essential);
+    ///
+    /// assert_eq!(location.importance, Some(Importance::Essential));
+    /// ```
+    #[must_use]
+    pub const fn with_importance(mut self, importance: Importance) -> Self {
+        self.importance = Some(importance);
+        self
+    }
+
+    /// Sets the properties of the thread flow location.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use sarif::schema::ThreadFlowLocation;
+    ///
+    /// let location =
+    ///     ThreadFlowLocation::new().with_properties(|properties| properties.with_tag("entry"));
+    ///
+    /// assert!(location.properties.tags.iter().eq(["entry"]));
+    /// ```
+    #[must_use]
+    pub fn with_properties(
+        mut self,
+        properties: impl FnOnce(PropertyBag<'s>) -> PropertyBag<'s>,
+    ) -> Self {
+        self.properties = properties(self.properties);
+        self
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+pub(crate) mod tests {
+    use coverage_helper::test;
+
+    use crate::schema::{
+        tests::validate_schema, ArtifactLocation, CodeFlow, Importance, Location, Message,
+        MultiformatMessageString, PhysicalLocation, Region, Result, Run, SarifLog, SchemaVersion,
+        ThreadFlow, ThreadFlowLocation, Tool, ToolComponent,
+    };
+
+    fn log(thread_flow: ThreadFlow<'_>) -> SarifLog<'_> {
+        let result = Result::new(Message::new("data race"))
+            .with_code_flow(CodeFlow::new(thread_flow).with_message(Message::new("thread 1")));
+        let run = Run::new(Tool::new(ToolComponent::new("miri"))).with_result(result);
+
+        SarifLog::new(SchemaVersion::V2_1_0).with_run(run)
+    }
+
+    #[test]
+    fn empty_location() {
+        validate_schema(&log(ThreadFlow::new(ThreadFlowLocation::new())));
+    }
+
+    #[test]
+    fn full() {
+        let location = ThreadFlowLocation::new()
+            .with_location(
+                Location::new().with_physical_location(
+                    PhysicalLocation::new(ArtifactLocation::new("src/main.rs"))
+                        .with_region(Region::new(3)),
+                ),
+            )
+            .with_kinds(["acquire", "lock"])
+            .with_kind("memory")
+            .with_module("main")
+            .with_state("x", MultiformatMessageString::new("5"))
+            .with_nesting_level(1)
+            .with_execution_order(2)
+            .with_execution_time_utc("2024-01-01T12:00:00Z")
+            .with_importance(Importance::Essential)
+            .with_properties(|properties| properties.with_tag("lock"));
+
+        let thread_flow = ThreadFlow::new(location)
+            .with_location(ThreadFlowLocation::new().with_importance(Importance::Unimportant))
+            .with_id("thread-1")
+            .with_message(Message::new("writes to `x`"))
+            .with_initial_state("x", MultiformatMessageString::new("0"))
+            .with_immutable_state("PID", MultiformatMessageString::new("1234"))
+            .with_properties(|properties| properties.with_property("threads", 2));
+
+        validate_schema(&log(thread_flow));
+    }
+
+    #[test]
+    fn indexed_location() {
+        let location = ThreadFlowLocation::new()
+            .with_location(Location::new().with_message(Message::new("shared location")));
+        let thread_flow = ThreadFlow::new(ThreadFlowLocation::new().with_index(0));
+
+        let result =
+            Result::new(Message::new("data race")).with_code_flow(CodeFlow::new(thread_flow));
+        let run = Run::new(Tool::new(ToolComponent::new("miri")))
+            .with_thread_flow_location(location)
+            .with_result(result);
+
+        validate_schema(&SarifLog::new(SchemaVersion::V2_1_0).with_run(run));
+    }
+}
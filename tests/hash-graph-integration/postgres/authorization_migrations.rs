@@ -0,0 +1,224 @@
+use authorization::{
+    backend::{InMemoryBackend, ZanzibarBackend},
+    migration::SCHEMA_MIGRATIONS,
+};
+use error_stack::{Report, ResultExt};
+use futures::{future::BoxFuture, FutureExt};
+use graph::store::{
+    error::MigrationError, AsClient, MigrationState, PostgresStore, RelationshipBackfills,
+};
+use graph_types::account::AccountId;
+use pretty_assertions::assert_eq;
+use tokio_postgres::Transaction;
+use uuid::Uuid;
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+/// Forgets the applied authorization schema migrations inside the test transaction.
+async fn clear_applied_migrations(api: &DatabaseApi<'_>) {
+    api.store
+        .as_client()
+        .batch_execute("DELETE FROM authorization_schema_migrations;")
+        .await
+        .expect("could not clear applied migrations");
+}
+
+#[tokio::test]
+async fn apply_all() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([], [], [])
+        .await
+        .expect("could not seed database");
+    clear_applied_migrations(&api).await;
+
+    let mut authorization_api = InMemoryBackend::new();
+    _ = api
+        .store
+        .check_authorization_schema(&authorization_api)
+        .await
+        .expect_err("migrations should be pending");
+
+    let migrations = api
+        .store
+        .run_authorization_migrations(&mut authorization_api)
+        .await
+        .expect("could not run migrations");
+    assert_eq!(
+        migrations
+            .iter()
+            .map(|migration| migration.name().to_owned())
+            .collect::<Vec<_>>(),
+        SCHEMA_MIGRATIONS
+            .iter()
+            .map(|migration| format!("v{}__{}", migration.version(), migration.name()))
+            .collect::<Vec<_>>()
+    );
+    assert!(
+        migrations
+            .iter()
+            .zip(SCHEMA_MIGRATIONS)
+            .all(|(applied, migration)| applied.hash() == migration.hash()
+                && matches!(applied.state(), MigrationState::Applied { .. }))
+    );
+
+    api.store
+        .check_authorization_schema(&authorization_api)
+        .await
+        .expect("schema should be up to date");
+    assert!(
+        api.store
+            .run_authorization_migrations(&mut authorization_api)
+            .await
+            .expect("could not run migrations")
+            .is_empty()
+    );
+}
+
+#[tokio::test]
+async fn restore_modified_schema() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([], [], [])
+        .await
+        .expect("could not seed database");
+    clear_applied_migrations(&api).await;
+
+    let mut authorization_api = InMemoryBackend::new();
+    api.store
+        .run_authorization_migrations(&mut authorization_api)
+        .await
+        .expect("could not run migrations");
+
+    authorization_api
+        .import_schema(SCHEMA_MIGRATIONS[0].schema())
+        .await
+        .expect("could not import schema");
+    _ = api
+        .store
+        .check_authorization_schema(&authorization_api)
+        .await
+        .expect_err("modified schema should be detected");
+
+    assert!(
+        api.store
+            .run_authorization_migrations(&mut authorization_api)
+            .await
+            .expect("could not run migrations")
+            .is_empty()
+    );
+    api.store
+        .check_authorization_schema(&authorization_api)
+        .await
+        .expect("schema should be restored");
+}
+
+/// The account created by the backfills below.
+const BACKFILLED_ACCOUNT: Uuid = Uuid::from_u128(0x0a5c_bf11_4e9d_4c71_9f0e_2b1d_48f6_7c3a);
+
+fn backfill_account<'t>(
+    store: &'t PostgresStore<Transaction<'_>>,
+    _: &'t mut InMemoryBackend,
+) -> BoxFuture<'t, Result<(), Report<MigrationError>>> {
+    async move {
+        store
+            .as_client()
+            .execute(
+                "INSERT INTO accounts (account_id) VALUES ($1) ON CONFLICT DO NOTHING;",
+                &[&AccountId::new(BACKFILLED_ACCOUNT)],
+            )
+            .await
+            .change_context(MigrationError)?;
+        Ok(())
+    }
+    .boxed()
+}
+
+fn failing_backfill<'t>(
+    store: &'t PostgresStore<Transaction<'_>>,
+    authorization_api: &'t mut InMemoryBackend,
+) -> BoxFuture<'t, Result<(), Report<MigrationError>>> {
+    async move {
+        backfill_account(store, authorization_api).await?;
+        Err(Report::new(MigrationError).attach_printable("backfill failed"))
+    }
+    .boxed()
+}
+
+async fn applied_versions(api: &DatabaseApi<'_>) -> Vec<i64> {
+    api.store
+        .as_client()
+        .query(
+            "SELECT version FROM authorization_schema_migrations ORDER BY version;",
+            &[],
+        )
+        .await
+        .expect("could not read applied migrations")
+        .into_iter()
+        .map(|row| row.get(0))
+        .collect()
+}
+
+async fn backfilled_account_exists(api: &DatabaseApi<'_>) -> bool {
+    api.store
+        .as_client()
+        .query_one(
+            "SELECT EXISTS (SELECT 1 FROM accounts WHERE account_id = $1);",
+            &[&AccountId::new(BACKFILLED_ACCOUNT)],
+        )
+        .await
+        .expect("could not read accounts")
+        .get(0)
+}
+
+#[tokio::test]
+async fn backfill_relationships() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed([], [], [])
+        .await
+        .expect("could not seed database");
+    clear_applied_migrations(&api).await;
+
+    let mut authorization_api = InMemoryBackend::new();
+    assert!(
+        SCHEMA_MIGRATIONS.len() > 1,
+        "the backfill is registered for the second migration"
+    );
+    let version = SCHEMA_MIGRATIONS[1].version();
+
+    _ = api
+        .store
+        .run_authorization_migrations_with_backfills(
+            &mut authorization_api,
+            &RelationshipBackfills::new().register(version, failing_backfill),
+        )
+        .await
+        .expect_err("backfill should fail");
+    assert_eq!(
+        applied_versions(&api).await,
+        [i64::from(SCHEMA_MIGRATIONS[0].version())]
+    );
+    assert!(
+        !backfilled_account_exists(&api).await,
+        "the failed backfill should be rolled back with its migration"
+    );
+
+    let migrations = api
+        .store
+        .run_authorization_migrations_with_backfills(
+            &mut authorization_api,
+            &RelationshipBackfills::new().register(version, backfill_account),
+        )
+        .await
+        .expect("could not run migrations");
+    assert_eq!(migrations.len(), SCHEMA_MIGRATIONS.len() - 1);
+    assert_eq!(
+        applied_versions(&api).await,
+        SCHEMA_MIGRATIONS
+            .iter()
+            .map(|migration| i64::from(migration.version()))
+            .collect::<Vec<_>>()
+    );
+    assert!(backfilled_account_exists(&api).await);
+}
@@ -5,12 +5,15 @@
     clippy::unwrap_used
 )]
 
+mod authorization_migrations;
 mod data_type;
 mod drafts;
 mod entity;
 mod entity_type;
 mod links;
 mod property_type;
+mod reconcile;
+mod restricted_properties;
 mod sorting;
 
 use std::{borrow::Cow, str::FromStr};
@@ -0,0 +1,195 @@
+use authorization::{
+    backend::{InMemoryBackend, ZanzibarBackend},
+    migration::SchemaMigration,
+    schema::{EntityNamespace, EntityOwnerSubject, EntityRelationAndSubject, EntityViewerSubject},
+    zanzibar::{
+        types::{RelationshipFilter, ResourceFilter},
+        Consistency,
+    },
+};
+use futures::TryStreamExt;
+use graph_test_data::{data_type, entity, entity_type, property_type};
+use graph_types::{
+    knowledge::entity::{EntityId, EntityUuid},
+    owned_by_id::OwnedById,
+};
+use pretty_assertions::assert_eq;
+use time::{Duration, OffsetDateTime};
+use type_system::url::{BaseUrl, VersionedUrl};
+use uuid::Uuid;
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+const fn owner(id: OwnedById) -> EntityRelationAndSubject {
+    EntityRelationAndSubject::Owner {
+        subject: EntityOwnerSubject::Web { id },
+        level: 0,
+    }
+}
+
+const fn public_viewer(expires_at: OffsetDateTime) -> EntityRelationAndSubject {
+    EntityRelationAndSubject::Viewer {
+        subject: EntityViewerSubject::Public,
+        level: 0,
+        expires_at: Some(expires_at),
+    }
+}
+
+async fn seed(database: &mut DatabaseTestWrapper) -> DatabaseApi<'_> {
+    database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [property_type::NAME_V1, property_type::AGE_V1],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+async fn create_alice(api: &mut DatabaseApi<'_>) -> EntityId {
+    api.create_entity(
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity"),
+        VersionedUrl {
+            base_url: BaseUrl::new(
+                "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+            )
+            .expect("couldn't construct Base URL"),
+            version: 1,
+        },
+        None,
+        false,
+    )
+    .await
+    .expect("could not create entity")
+    .record_id
+    .entity_id
+}
+
+async fn authorization_api() -> InMemoryBackend {
+    let mut authorization_api = InMemoryBackend::new();
+    authorization_api
+        .import_schema(SchemaMigration::latest().schema())
+        .await
+        .expect("could not import schema");
+    authorization_api
+}
+
+async fn entity_relations(
+    authorization_api: &InMemoryBackend,
+    entity_uuid: EntityUuid,
+) -> Vec<EntityRelationAndSubject> {
+    authorization_api
+        .read_relations::<(EntityUuid, EntityRelationAndSubject)>(
+            RelationshipFilter::from_resource(ResourceFilter::from_kind(EntityNamespace::Entity)),
+            Consistency::FullyConsistent,
+        )
+        .await
+        .expect("could not read relations")
+        .try_filter(|(resource, _)| std::future::ready(*resource == entity_uuid))
+        .map_ok(|(_, relation)| relation)
+        .try_collect()
+        .await
+        .expect("could not read relations")
+}
+
+#[tokio::test]
+async fn wrong_owner() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+    let entity_id = create_alice(&mut api).await;
+
+    let mut authorization_api = authorization_api().await;
+    let wrong_web = OwnedById::new(Uuid::new_v4());
+    authorization_api
+        .touch_relationships([(entity_id.entity_uuid, owner(wrong_web))])
+        .await
+        .expect("could not create relationship");
+
+    let discrepancies = api
+        .store
+        .find_authorization_discrepancies(&authorization_api)
+        .await
+        .expect("could not find discrepancies");
+    assert!(discrepancies
+        .entities
+        .missing
+        .contains(&(entity_id.entity_uuid, owner(entity_id.owned_by_id))));
+    assert_eq!(
+        discrepancies.entities.wrong_owner,
+        [(entity_id.entity_uuid, owner(wrong_web))]
+    );
+
+    api.store
+        .repair_authorization_discrepancies(&discrepancies, &mut authorization_api)
+        .await
+        .expect("could not repair discrepancies");
+
+    assert_eq!(
+        entity_relations(&authorization_api, entity_id.entity_uuid).await,
+        [owner(entity_id.owned_by_id)]
+    );
+    let discrepancies = api
+        .store
+        .find_authorization_discrepancies(&authorization_api)
+        .await
+        .expect("could not find discrepancies");
+    assert!(discrepancies.is_empty(), "{discrepancies:?}");
+}
+
+#[tokio::test]
+async fn renewed_expired_relationship() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+    let entity_id = create_alice(&mut api).await;
+    let expired_entity_id = create_alice(&mut api).await;
+
+    let mut authorization_api = authorization_api().await;
+    let now = OffsetDateTime::now_utc()
+        .replace_nanosecond(0)
+        .expect("valid nanosecond");
+    let expired_at = now - Duration::hours(1);
+    authorization_api
+        .touch_relationships([
+            (entity_id.entity_uuid, owner(entity_id.owned_by_id)),
+            (entity_id.entity_uuid, public_viewer(expired_at)),
+            (
+                expired_entity_id.entity_uuid,
+                owner(expired_entity_id.owned_by_id),
+            ),
+            (expired_entity_id.entity_uuid, public_viewer(expired_at)),
+        ])
+        .await
+        .expect("could not create relationships");
+
+    let discrepancies = api
+        .store
+        .find_authorization_discrepancies(&authorization_api)
+        .await
+        .expect("could not find discrepancies");
+    assert_eq!(discrepancies.expired.entities.len(), 2);
+
+    // The share is renewed after the discrepancies were found, so it must not be revoked.
+    let renewed_until = now + Duration::hours(1);
+    authorization_api
+        .touch_relationships([(entity_id.entity_uuid, public_viewer(renewed_until))])
+        .await
+        .expect("could not renew relationship");
+
+    api.store
+        .repair_authorization_discrepancies(&discrepancies, &mut authorization_api)
+        .await
+        .expect("could not repair discrepancies");
+
+    let relations = entity_relations(&authorization_api, entity_id.entity_uuid).await;
+    assert_eq!(relations.len(), 2, "{relations:?}");
+    assert!(relations.contains(&public_viewer(renewed_until)));
+    assert_eq!(
+        entity_relations(&authorization_api, expired_entity_id.entity_uuid).await,
+        [owner(expired_entity_id.owned_by_id)]
+    );
+}
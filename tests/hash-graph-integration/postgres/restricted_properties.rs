@@ -0,0 +1,783 @@
+use std::borrow::Cow;
+
+use authorization::{
+    backend::{InMemoryBackend, ModifyRelationshipOperation, ZanzibarBackend},
+    migration::SchemaMigration,
+    schema::{
+        DataTypeId, DataTypeRelationAndSubject, DataTypeViewerSubject, EntityEditorSubject,
+        EntityRelationAndSubject, EntityRestrictedPropertyViewerSubject, EntityTypeId,
+        EntityTypeInstantiatorSubject, EntityTypeRelationAndSubject, EntityTypeViewerSubject,
+        EntityViewerSubject, PropertyTypeId, PropertyTypeRelationAndSubject,
+        PropertyTypeViewerSubject,
+    },
+    zanzibar::ZanzibarClient,
+    AuthorizationApi, NoAuthorization,
+};
+use error_stack::Result;
+use graph::{
+    knowledge::EntityQueryPath,
+    store::{
+        account::InsertAccountIdParams,
+        knowledge::{GetEntityParams, UpdateEntityParams},
+        ontology::SetPropertyTypeRestrictionParams,
+        query::{Filter, FilterExpression, JsonPath, Parameter, PathToken},
+        AccountStore, EntityQuerySorting, EntityStore, PropertyTypeStore, QueryError,
+    },
+    subgraph::{
+        edges::{EdgeDirection, GraphResolveDepths, KnowledgeGraphEdgeKind},
+        query::StructuralQuery,
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
+    },
+};
+use graph_test_data::{data_type, entity, entity_type, property_type};
+use graph_types::{
+    account::AccountId,
+    knowledge::{
+        entity::{Entity, EntityId, EntityProperties},
+        link::EntityLinkOrder,
+    },
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::{
+    url::{BaseUrl, VersionedUrl},
+    DataType, EntityType, PropertyType,
+};
+use uuid::Uuid;
+
+use crate::{generate_decision_time, DatabaseApi, DatabaseTestWrapper};
+
+/// An entity type with a list of nested property objects.
+const CONTACT_V1: &str = r#"
+{
+  "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/entity-type",
+  "kind": "entityType",
+  "$id": "https://blockprotocol.org/@alice/types/entity-type/contact/v/1",
+  "type": "object",
+  "title": "Contact",
+  "properties": {
+    "https://blockprotocol.org/@alice/types/property-type/name/": {
+      "$ref": "https://blockprotocol.org/@alice/types/property-type/name/v/1"
+    },
+    "https://blockprotocol.org/@alice/types/property-type/contact-information/": {
+      "type": "array",
+      "items": {
+        "$ref": "https://blockprotocol.org/@alice/types/property-type/contact-information/v/1"
+      }
+    }
+  }
+}
+"#;
+
+fn person_entity_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    }
+}
+
+fn name_property_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/property-type/name/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    }
+}
+
+fn contact_entity_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/contact/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    }
+}
+
+fn email_property_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/property-type/email/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: 1,
+    }
+}
+
+fn name_property_path() -> EntityQueryPath<'static> {
+    EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(vec![PathToken::Field(
+        Cow::Borrowed("https://blockprotocol.org/@alice/types/property-type/name/"),
+    )])))
+}
+
+fn email_property_path() -> EntityQueryPath<'static> {
+    EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(vec![
+        PathToken::Field(Cow::Borrowed(
+            "https://blockprotocol.org/@alice/types/property-type/contact-information/",
+        )),
+        PathToken::Index(0),
+        PathToken::Field(Cow::Borrowed(
+            "https://blockprotocol.org/@alice/types/property-type/email/",
+        )),
+    ])))
+}
+
+fn alice() -> EntityProperties {
+    serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity")
+}
+
+/// Returns the properties of Alice's contact with one contact information per `(email,
+/// phone_number)` pair, `email` is set to `None` to leave it out.
+fn alice_contact(contacts: &[(Option<&str>, &str)]) -> EntityProperties {
+    let contact_information = contacts
+        .iter()
+        .map(|(email, phone_number)| {
+            let mut contact_information = json!({
+                "https://blockprotocol.org/@alice/types/property-type/phone-number/": phone_number,
+            });
+            if let Some(email) = email {
+                contact_information
+                    .as_object_mut()
+                    .expect("contact information should be an object")
+                    .insert(
+                        "https://blockprotocol.org/@alice/types/property-type/email/".to_owned(),
+                        json!(email),
+                    );
+            }
+            contact_information
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::from_value(json!({
+        "https://blockprotocol.org/@alice/types/property-type/name/": "Alice",
+        "https://blockprotocol.org/@alice/types/property-type/contact-information/":
+            contact_information,
+    }))
+    .expect("could not parse entity")
+}
+
+async fn seed(database: &mut DatabaseTestWrapper) -> DatabaseApi<'_> {
+    database
+        .seed(
+            [data_type::TEXT_V1, data_type::NUMBER_V1],
+            [property_type::NAME_V1, property_type::AGE_V1],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+/// Creates Alice and restricts the `name` property type.
+async fn create_restricted_alice(api: &mut DatabaseApi<'_>) -> EntityId {
+    let entity_id = api
+        .create_entity(alice(), person_entity_type_id(), None, false)
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+
+    api.store
+        .set_property_type_restriction(
+            api.account_id,
+            &mut NoAuthorization,
+            SetPropertyTypeRestrictionParams {
+                property_type_id: Cow::Owned(name_property_type_id()),
+                restricted: true,
+            },
+        )
+        .await
+        .expect("could not restrict property type");
+
+    entity_id
+}
+
+async fn seed_contact(database: &mut DatabaseTestWrapper) -> DatabaseApi<'_> {
+    database
+        .seed(
+            [data_type::TEXT_V1],
+            [
+                property_type::NAME_V1,
+                property_type::EMAIL_V1,
+                property_type::PHONE_NUMBER_V1,
+                property_type::CONTACT_INFORMATION_V1,
+            ],
+            [CONTACT_V1],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+/// Creates Alice's contact from `contacts` and restricts the email property type.
+async fn create_restricted_alice_contact(
+    api: &mut DatabaseApi<'_>,
+    contacts: &[(Option<&str>, &str)],
+) -> EntityId {
+    let entity_id = api
+        .create_entity(
+            alice_contact(contacts),
+            contact_entity_type_id(),
+            None,
+            false,
+        )
+        .await
+        .expect("could not create entity")
+        .record_id
+        .entity_id;
+    api.store
+        .set_property_type_restriction(
+            api.account_id,
+            &mut NoAuthorization,
+            SetPropertyTypeRestrictionParams {
+                property_type_id: Cow::Owned(email_property_type_id()),
+                restricted: true,
+            },
+        )
+        .await
+        .expect("could not restrict property type");
+    entity_id
+}
+
+/// Creates an authorization API which allows everyone to view and instantiate the seeded types.
+/// Creates an account which can be granted access to the entities, e.g. to update them.
+async fn create_account(api: &mut DatabaseApi<'_>) -> AccountId {
+    let account_id = AccountId::new(Uuid::new_v4());
+    api.store
+        .insert_account_id(
+            account_id,
+            &mut NoAuthorization,
+            InsertAccountIdParams { account_id },
+        )
+        .await
+        .expect("could not insert account id");
+    account_id
+}
+
+async fn authorization_api() -> ZanzibarClient<InMemoryBackend> {
+    let mut backend = InMemoryBackend::new();
+    backend
+        .import_schema(SchemaMigration::latest().schema())
+        .await
+        .expect("could not import schema");
+    let mut authorization_api = ZanzibarClient::new(backend);
+
+    authorization_api
+        .modify_data_type_relations([data_type::TEXT_V1, data_type::NUMBER_V1].map(|data_type| {
+            let data_type: DataType =
+                serde_json::from_str(data_type).expect("could not parse data type");
+            (
+                ModifyRelationshipOperation::Create,
+                DataTypeId::from_url(data_type.id()),
+                DataTypeRelationAndSubject::Viewer {
+                    subject: DataTypeViewerSubject::Public,
+                    level: 0,
+                },
+            )
+        }))
+        .await
+        .expect("could not create data type relations");
+    authorization_api
+        .modify_property_type_relations(
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::EMAIL_V1,
+                property_type::PHONE_NUMBER_V1,
+                property_type::CONTACT_INFORMATION_V1,
+            ]
+            .map(|property_type| {
+                let property_type: PropertyType =
+                    serde_json::from_str(property_type).expect("could not parse property type");
+                (
+                    ModifyRelationshipOperation::Create,
+                    PropertyTypeId::from_url(property_type.id()),
+                    PropertyTypeRelationAndSubject::Viewer {
+                        subject: PropertyTypeViewerSubject::Public,
+                        level: 0,
+                    },
+                )
+            }),
+        )
+        .await
+        .expect("could not create property type relations");
+    authorization_api
+        .modify_entity_type_relations([entity_type::PERSON_V1, CONTACT_V1].into_iter().flat_map(
+            |entity_type| {
+                let entity_type: EntityType =
+                    serde_json::from_str(entity_type).expect("could not parse entity type");
+                let entity_type_id = EntityTypeId::from_url(entity_type.id());
+                [
+                    (
+                        ModifyRelationshipOperation::Create,
+                        entity_type_id,
+                        EntityTypeRelationAndSubject::Viewer {
+                            subject: EntityTypeViewerSubject::Public,
+                            level: 0,
+                        },
+                    ),
+                    (
+                        ModifyRelationshipOperation::Create,
+                        entity_type_id,
+                        EntityTypeRelationAndSubject::Instantiator {
+                            subject: EntityTypeInstantiatorSubject::Public,
+                            level: 0,
+                        },
+                    ),
+                ]
+            },
+        ))
+        .await
+        .expect("could not create entity type relations");
+
+    authorization_api
+}
+
+async fn add_entity_relation(
+    authorization_api: &mut ZanzibarClient<InMemoryBackend>,
+    entity_id: EntityId,
+    relation: EntityRelationAndSubject,
+) {
+    authorization_api
+        .modify_entity_relations([(ModifyRelationshipOperation::Create, entity_id, relation)])
+        .await
+        .expect("could not create entity relation");
+}
+
+async fn get_entities(
+    api: &DatabaseApi<'_>,
+    actor_id: AccountId,
+    authorization_api: &ZanzibarClient<InMemoryBackend>,
+    filter: Filter<'_, Entity>,
+) -> Result<Vec<Entity>, QueryError> {
+    Ok(api
+        .store
+        .get_entity(
+            actor_id,
+            authorization_api,
+            GetEntityParams {
+                query: StructuralQuery {
+                    filter,
+                    graph_resolve_depths: GraphResolveDepths::default(),
+                    temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                        pinned: PinnedTemporalAxisUnresolved::new(None),
+                        variable: VariableTemporalAxisUnresolved::new(None, None),
+                    },
+                    include_drafts: false,
+                },
+                sorting: EntityQuerySorting {
+                    paths: Vec::new(),
+                    cursor: None,
+                },
+                limit: None,
+            },
+        )
+        .await?
+        .0
+        .vertices
+        .entities
+        .into_values()
+        .collect())
+}
+
+fn filter_by_name(entity_id: EntityId, path: EntityQueryPath<'static>) -> Filter<'static, Entity> {
+    Filter::All(vec![
+        Filter::for_entity_by_entity_id(entity_id),
+        Filter::Equal(
+            Some(FilterExpression::Path(path)),
+            Some(FilterExpression::Parameter(Parameter::Any(
+                serde_json::Value::String("Alice".to_owned()),
+            ))),
+        ),
+    ])
+}
+
+#[tokio::test]
+async fn read() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+    let entity_id = create_restricted_alice(&mut api).await;
+
+    let viewer_id = AccountId::new(Uuid::new_v4());
+    let mut authorization_api = authorization_api().await;
+    add_entity_relation(
+        &mut authorization_api,
+        entity_id,
+        EntityRelationAndSubject::Viewer {
+            subject: EntityViewerSubject::Account { id: viewer_id },
+            level: 0,
+            expires_at: None,
+        },
+    )
+    .await;
+
+    let entities = get_entities(
+        &api,
+        viewer_id,
+        &authorization_api,
+        Filter::for_entity_by_entity_id(entity_id),
+    )
+    .await
+    .expect("could not get entity");
+    assert_eq!(entities.len(), 1);
+    assert!(entities[0].properties.properties().is_empty());
+
+    add_entity_relation(
+        &mut authorization_api,
+        entity_id,
+        EntityRelationAndSubject::RestrictedPropertyViewer {
+            subject: EntityRestrictedPropertyViewerSubject::Account { id: viewer_id },
+            level: 0,
+            expires_at: None,
+        },
+    )
+    .await;
+
+    let entities = get_entities(
+        &api,
+        viewer_id,
+        &authorization_api,
+        Filter::for_entity_by_entity_id(entity_id),
+    )
+    .await
+    .expect("could not get entity");
+    assert_eq!(entities.len(), 1);
+    assert_eq!(entities[0].properties, alice());
+}
+
+#[tokio::test]
+async fn filter() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+    let entity_id = create_restricted_alice(&mut api).await;
+
+    let viewer_id = AccountId::new(Uuid::new_v4());
+    let mut authorization_api = authorization_api().await;
+    add_entity_relation(
+        &mut authorization_api,
+        entity_id,
+        EntityRelationAndSubject::Viewer {
+            subject: EntityViewerSubject::Account { id: viewer_id },
+            level: 0,
+            expires_at: None,
+        },
+    )
+    .await;
+
+    let entities = get_entities(
+        &api,
+        viewer_id,
+        &authorization_api,
+        filter_by_name(entity_id, name_property_path()),
+    )
+    .await
+    .expect("could not get entity");
+    assert!(entities.is_empty());
+
+    add_entity_relation(
+        &mut authorization_api,
+        entity_id,
+        EntityRelationAndSubject::RestrictedPropertyViewer {
+            subject: EntityRestrictedPropertyViewerSubject::Account { id: viewer_id },
+            level: 0,
+            expires_at: None,
+        },
+    )
+    .await;
+
+    let entities = get_entities(
+        &api,
+        viewer_id,
+        &authorization_api,
+        filter_by_name(entity_id, name_property_path()),
+    )
+    .await
+    .expect("could not get entity");
+    assert_eq!(entities.len(), 1);
+    assert_eq!(entities[0].properties, alice());
+}
+
+#[tokio::test]
+async fn filter_linked_entity() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+    let entity_id = create_restricted_alice(&mut api).await;
+
+    assert!(
+        get_entities(
+            &api,
+            api.account_id,
+            &authorization_api().await,
+            filter_by_name(
+                entity_id,
+                EntityQueryPath::EntityEdge {
+                    edge_kind: KnowledgeGraphEdgeKind::HasLeftEntity,
+                    path: Box::new(name_property_path()),
+                    direction: EdgeDirection::Outgoing,
+                },
+            ),
+        )
+        .await
+        .is_err(),
+        "could filter by a restricted property of a linked entity"
+    );
+}
+
+#[tokio::test]
+async fn update() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+    let entity_id = create_restricted_alice(&mut api).await;
+
+    let editor_id = create_account(&mut api).await;
+    let mut authorization_api = authorization_api().await;
+    add_entity_relation(
+        &mut authorization_api,
+        entity_id,
+        EntityRelationAndSubject::Editor {
+            subject: EntityEditorSubject::Account { id: editor_id },
+            level: 0,
+            expires_at: None,
+        },
+    )
+    .await;
+
+    // The editor is not allowed to view the name, so the redacted entity is written back.
+    api.store
+        .update_entity(
+            editor_id,
+            &mut authorization_api,
+            None,
+            UpdateEntityParams {
+                entity_id,
+                decision_time: Some(generate_decision_time()),
+                entity_type_id: person_entity_type_id(),
+                properties: EntityProperties::empty(),
+                link_order: EntityLinkOrder {
+                    left_to_right: None,
+                    right_to_left: None,
+                },
+                archived: false,
+                draft: false,
+            },
+        )
+        .await
+        .expect("could not update entity");
+
+    let entity = api
+        .get_latest_entity(entity_id)
+        .await
+        .expect("could not get entity");
+    assert_eq!(entity.properties, alice());
+}
+
+#[tokio::test]
+async fn nested() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed_contact(&mut database).await;
+    let entity_id = create_restricted_alice_contact(
+        &mut api,
+        &[(Some("alice@example.com"), "+44 20 7946 0000")],
+    )
+    .await;
+
+    let editor_id = create_account(&mut api).await;
+    let mut authorization_api = authorization_api().await;
+    add_entity_relation(
+        &mut authorization_api,
+        entity_id,
+        EntityRelationAndSubject::Editor {
+            subject: EntityEditorSubject::Account { id: editor_id },
+            level: 0,
+            expires_at: None,
+        },
+    )
+    .await;
+
+    let entities = get_entities(
+        &api,
+        editor_id,
+        &authorization_api,
+        Filter::for_entity_by_entity_id(entity_id),
+    )
+    .await
+    .expect("could not get entity");
+    assert_eq!(entities.len(), 1);
+    assert_eq!(
+        entities[0].properties,
+        alice_contact(&[(None, "+44 20 7946 0000")])
+    );
+
+    let entities = get_entities(
+        &api,
+        editor_id,
+        &authorization_api,
+        Filter::All(vec![
+            Filter::for_entity_by_entity_id(entity_id),
+            Filter::Equal(
+                Some(FilterExpression::Path(email_property_path())),
+                Some(FilterExpression::Parameter(Parameter::Any(
+                    serde_json::Value::String("alice@example.com".to_owned()),
+                ))),
+            ),
+        ]),
+    )
+    .await
+    .expect("could not get entity");
+    assert!(entities.is_empty());
+
+    // The redacted entity is written back, the email is kept.
+    api.store
+        .update_entity(
+            editor_id,
+            &mut authorization_api,
+            None,
+            UpdateEntityParams {
+                entity_id,
+                decision_time: Some(generate_decision_time()),
+                entity_type_id: contact_entity_type_id(),
+                properties: alice_contact(&[(None, "+44 20 7946 0000")]),
+                link_order: EntityLinkOrder {
+                    left_to_right: None,
+                    right_to_left: None,
+                },
+                archived: false,
+                draft: false,
+            },
+        )
+        .await
+        .expect("could not update entity");
+
+    let entity = api
+        .get_latest_entity(entity_id)
+        .await
+        .expect("could not get entity");
+    assert_eq!(
+        entity.properties,
+        alice_contact(&[(Some("alice@example.com"), "+44 20 7946 0000")])
+    );
+}
+
+#[tokio::test]
+async fn nested_modified_array() {
+    let original_contact = [
+        (Some("alice@example.com"), "+44 20 7946 0000"),
+        (Some("alice@example.org"), "+44 20 7946 0001"),
+    ];
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed_contact(&mut database).await;
+    let entity_id = create_restricted_alice_contact(&mut api, &original_contact).await;
+
+    let editor_id = create_account(&mut api).await;
+    let mut authorization_api = authorization_api().await;
+    add_entity_relation(
+        &mut authorization_api,
+        entity_id,
+        EntityRelationAndSubject::Editor {
+            subject: EntityEditorSubject::Account { id: editor_id },
+            level: 0,
+            expires_at: None,
+        },
+    )
+    .await;
+
+    let entities = get_entities(
+        &api,
+        editor_id,
+        &authorization_api,
+        Filter::for_entity_by_entity_id(entity_id),
+    )
+    .await
+    .expect("could not get entity");
+    assert_eq!(entities.len(), 1);
+    assert_eq!(
+        entities[0].properties,
+        alice_contact(&[(None, "+44 20 7946 0000"), (None, "+44 20 7946 0001")])
+    );
+
+    // The emails can't be assigned to the contact information of the modified redacted entity
+    // anymore, so the updates are rejected.
+    for properties in [
+        // reordered
+        alice_contact(&[(None, "+44 20 7946 0001"), (None, "+44 20 7946 0000")]),
+        // removed
+        alice_contact(&[(None, "+44 20 7946 0001")]),
+        // inserted
+        alice_contact(&[
+            (None, "+44 20 7946 0002"),
+            (None, "+44 20 7946 0000"),
+            (None, "+44 20 7946 0001"),
+        ]),
+        // changed
+        alice_contact(&[(None, "+44 20 7946 0002"), (None, "+44 20 7946 0001")]),
+    ] {
+        _ = api
+            .store
+            .update_entity(
+                editor_id,
+                &mut authorization_api,
+                None,
+                UpdateEntityParams {
+                    entity_id,
+                    decision_time: Some(generate_decision_time()),
+                    entity_type_id: contact_entity_type_id(),
+                    properties,
+                    link_order: EntityLinkOrder {
+                        left_to_right: None,
+                        right_to_left: None,
+                    },
+                    archived: false,
+                    draft: false,
+                },
+            )
+            .await
+            .expect_err("could update entity with modified restricted array");
+    }
+
+    let entity = api
+        .get_latest_entity(entity_id)
+        .await
+        .expect("could not get entity");
+    assert_eq!(entity.properties, alice_contact(&original_contact));
+
+    // The owner is allowed to view the emails, so the array may be reordered.
+    let reordered_contact = [original_contact[1], original_contact[0]];
+    api.store
+        .update_entity(
+            api.account_id,
+            &mut NoAuthorization,
+            None,
+            UpdateEntityParams {
+                entity_id,
+                decision_time: Some(generate_decision_time()),
+                entity_type_id: contact_entity_type_id(),
+                properties: alice_contact(&reordered_contact),
+                link_order: EntityLinkOrder {
+                    left_to_right: None,
+                    right_to_left: None,
+                },
+                archived: false,
+                draft: false,
+            },
+        )
+        .await
+        .expect("could not update entity");
+
+    let entity = api
+        .get_latest_entity(entity_id)
+        .await
+        .expect("could not get entity");
+    assert_eq!(entity.properties, alice_contact(&reordered_contact));
+}